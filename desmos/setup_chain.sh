@@ -16,7 +16,16 @@ USER2_ADDRESS=desmos1ptvq7l4jt7n9sc3fky22mfvc6waf2jd8nuc0jv
 # Chain link data files
 COSMOS_CHAIN_LINK_DATA="$SCRIPT_DIR/cosmos-chain-link-data.json"
 OSMOSIS_CHAIN_LINK_DATA="$SCRIPT_DIR/osmosis-chain-link-data.json"
+# Major version of the Desmos chain, can be changed with the -v argument.
+DESMOS_VERSION=4
 
+while getopts "v:" arg; do
+  case $arg in
+    v)
+      DESMOS_VERSION=$OPTARG
+      ;;
+  esac
+done
 
 desmos() {
 	"$SCRIPT_DIR/desmos" --home="$DESMOS_HOME" "$@"
@@ -175,6 +184,30 @@ echo $KEYRING_PASS | desmos tx wasm execute "$CONTRACT" "$MSG" \
   --from $USER1 \
   --chain-id=testchain --keyring-backend=file -b=block -y
 
+# Posts can be moved between subspaces and transferred to other users only starting from Desmos v5
+if [ "$DESMOS_VERSION" -ge 5 ]; then
+  # Create a test post that can be moved to another subspace
+  echo "Create a movable post"
+  MSG="{\"desmos_messages\":{\"msgs\":[{\"custom\":{\"posts\":{\"create_post\":{\"subspace_id\":\"1\",\"section_id\":0,\"external_id\":null,\"text\":\"Movable post\",\"entities\":null,\"tags\":[],\"attachments\":null,\"author\":\"$CONTRACT\",\"conversation_id\":null,\"reply_settings\":\"REPLY_SETTING_EVERYONE\",\"referenced_posts\":[]}}}}]}}"
+  echo $KEYRING_PASS | desmos tx wasm execute "$CONTRACT" "$MSG" \
+    --from $USER1 \
+    --chain-id=testchain --keyring-backend=file -b=block -y
+
+  # Create a test post whose ownership can be transferred
+  echo "Create a transferable post"
+  MSG="{\"desmos_messages\":{\"msgs\":[{\"custom\":{\"posts\":{\"create_post\":{\"subspace_id\":\"1\",\"section_id\":0,\"external_id\":null,\"text\":\"Transferable post\",\"entities\":null,\"tags\":[],\"attachments\":null,\"author\":\"$CONTRACT\",\"conversation_id\":null,\"reply_settings\":\"REPLY_SETTING_EVERYONE\",\"referenced_posts\":[]}}}}]}}"
+  echo $KEYRING_PASS | desmos tx wasm execute "$CONTRACT" "$MSG" \
+    --from $USER1 \
+    --chain-id=testchain --keyring-backend=file -b=block -y
+
+  # Create a subspace where the movable post can be moved
+  MSG="{\"desmos_messages\":{\"msgs\":[{\"custom\":{\"subspaces\":{\"create_subspace\":{\"name\":\"Target subspace\",\"description\":\"\",\"treasury\":\"$CONTRACT\",\"owner\":\"$CONTRACT\",\"creator\":\"$CONTRACT\"}}}}]}}"
+  echo "Create target subspace"
+  echo $KEYRING_PASS | desmos tx wasm execute "$CONTRACT" "$MSG" \
+    --from $USER1 \
+    --chain-id=testchain --keyring-backend=file -b=block -y
+fi
+
 # Create a test reason that can be used to create a report
 echo "Create a test reason"
 MSG="{\"desmos_messages\":{\"msgs\":[{\"custom\":{\"reports\":{\"add_reason\":{\"subspace_id\":\"1\",\"title\":\"Test reason\",\"description\":\"Test reason description\",\"signer\":\"$CONTRACT\"}}}}]}}"
@@ -1,6 +1,9 @@
-use crate::consts::{GAS, USER1_KEY};
+use crate::consts::{EVENTS_FILE_ENV, FIXTURES_FILE_ENV, GAS, USER1_KEY};
 use crate::models::{ListContractByCode, TxResponse, WasmQueryResponse};
-use cosmwasm_std::Uint64;
+use cosmwasm_std::{CosmosMsg, QueryRequest, Uint64};
+use desmos_bindings::mocks::mock_events::EventFixtures;
+use desmos_bindings::mocks::mock_fixtures::QueryFixtures;
+use desmos_bindings::mocks::JsonFile;
 use desmos_bindings::subspaces::models_query::{
     QuerySubspaceResponse, QuerySubspacesResponse, QueryUserGroupMembersResponse,
     QueryUserGroupResponse, QueryUserGroupsResponse,
@@ -11,6 +14,7 @@ use std::env;
 use std::ffi::OsStr;
 use std::path::Path;
 use std::process::Command;
+use test_contract::msg::{ExecuteMsg, QueryMsg};
 
 pub struct DesmosCli {
     desmos_bin: String,
@@ -145,14 +149,47 @@ impl DesmosCli {
         // Serialize the msg to execute.
         let serialized = serde_json::to_string(msg).unwrap();
 
-        self.execute_tx([
+        let response = self.execute_tx([
             "wasm",
             "execute",
             contract,
             &serialized,
             &format!("--from={}", USER1_KEY),
             &format!("--gas={}", GAS),
-        ])
+        ]);
+        DesmosCli::record_events(msg, &response);
+        response
+    }
+
+    /// Records the events emitted by the chain for the Desmos message executed through the test contract
+    /// inside the events file, if the [`EVENTS_FILE_ENV`] environment variable is set.
+    /// Transactions that failed or that executed more than one Desmos message are not recorded
+    /// since their events can not be matched with a single message.
+    ///
+    /// * `msg` - Message sent to the smart contract.
+    /// * `response` - Response of the executed transaction.
+    fn record_events<T>(msg: &T, response: &TxResponse)
+    where
+        T: ?Sized + Serialize,
+    {
+        let path = match env::var(EVENTS_FILE_ENV) {
+            Ok(path) => path,
+            Err(_) => return,
+        };
+        if !response.is_success() {
+            return;
+        }
+
+        let execute_msg = serde_json::to_value(msg)
+            .ok()
+            .and_then(|value| serde_json::from_value::<ExecuteMsg>(value).ok());
+        if let Some(ExecuteMsg::DesmosMessages { msgs }) = execute_msg {
+            if let [CosmosMsg::Custom(desmos_msg)] = msgs.as_slice() {
+                let mut fixtures = EventFixtures::load_or_default(&path).unwrap();
+                fixtures.record(desmos_msg, &response.events()).unwrap();
+                fixtures.save(&path).unwrap();
+            }
+        }
     }
 
     /// Send a query request to a smart contract
@@ -176,7 +213,36 @@ impl DesmosCli {
             "--output=json",
         ]));
 
-        serde_json::from_str(&result).unwrap()
+        let response = serde_json::from_str(&result).unwrap();
+        DesmosCli::record_fixture(query, &response);
+        response
+    }
+
+    /// Records the Desmos query performed through the test contract together with its response
+    /// inside the fixtures file, if the [`FIXTURES_FILE_ENV`] environment variable is set.
+    ///
+    /// * `query` - Query message sent to the smart contract.
+    /// * `response` - Response returned by the smart contract.
+    fn record_fixture<T>(query: &T, response: &WasmQueryResponse)
+    where
+        T: ?Sized + Serialize,
+    {
+        let path = match env::var(FIXTURES_FILE_ENV) {
+            Ok(path) => path,
+            Err(_) => return,
+        };
+
+        let query_msg = serde_json::to_value(query)
+            .ok()
+            .and_then(|value| serde_json::from_value::<QueryMsg>(value).ok());
+        if let Some(QueryMsg::DesmosChain {
+            request: QueryRequest::Custom(request),
+        }) = query_msg
+        {
+            let mut fixtures = QueryFixtures::load_or_default(&path).unwrap();
+            fixtures.record(request, &response.data.data).unwrap();
+            fixtures.save(&path).unwrap();
+        }
     }
 
     /// Queries all the subspaces created.
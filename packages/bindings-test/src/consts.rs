@@ -2,14 +2,26 @@ use cosmwasm_std::Uint64;
 
 pub const GAS: Uint64 = Uint64::new(300000);
 
+/// Environment variable with the path of the file where the queries performed
+/// through the test contract are recorded as fixtures.
+pub const FIXTURES_FILE_ENV: &str = "DESMOS_FIXTURES_FILE";
+
+/// Environment variable with the path of the file where the events emitted by the chain
+/// for the messages executed through the test contract are recorded.
+pub const EVENTS_FILE_ENV: &str = "DESMOS_EVENTS_FILE";
+
 pub const USER1_KEY: &str = "user1";
 pub const USER1_ADDRESS: &str = "desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3";
 pub const USER2_KEY: &str = "user2";
 pub const USER2_ADDRESS: &str = "desmos1ptvq7l4jt7n9sc3fky22mfvc6waf2jd8nuc0jv";
+pub const TEST_DENOM: &str = "stake";
 pub const TEST_SUBSPACE: Uint64 = Uint64::new(1);
 pub const TEST_SUBSPACE_USER_GROUP: u32 = 1;
 pub const TEST_SUBSPACE_EDITABLE_POST_ID: Uint64 = Uint64::new(1);
 pub const TEST_SUBSPACE_DELETABLE_POST_ID: Uint64 = Uint64::new(2);
+pub const TEST_SUBSPACE_MOVABLE_POST_ID: Uint64 = Uint64::new(4);
+pub const TEST_SUBSPACE_TRANSFERABLE_POST_ID: Uint64 = Uint64::new(5);
+pub const TEST_TARGET_SUBSPACE: Uint64 = Uint64::new(2);
 pub const TEST_DELETABLE_ATTACHMENT_ID: u32 = 2;
 pub const TEST_POLL_ID: u32 = 1;
 pub const TEST_REASON_ID: u32 = 1;
@@ -9,6 +9,7 @@ mod reactions;
 mod relationships;
 mod reports;
 mod subspaces;
+mod supply;
 
 fn main() {
     println!("Hello, world!");
@@ -1,4 +1,4 @@
-use cosmwasm_std::Binary;
+use cosmwasm_std::{Binary, Event};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
@@ -12,6 +12,36 @@ pub struct TxResponse {
     pub code: u32,
     /// The output of the application's logger.
     pub raw_log: String,
+    /// The logs of the executed messages.
+    #[serde(default)]
+    pub logs: Vec<TxLog>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TxLog {
+    /// Index of the message inside the transaction.
+    #[serde(default)]
+    pub msg_index: u32,
+    /// Events emitted while executing the message.
+    #[serde(default)]
+    pub events: Vec<TxEvent>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TxEvent {
+    /// Type of the event.
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Attributes of the event.
+    #[serde(default)]
+    pub attributes: Vec<TxEventAttribute>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TxEventAttribute {
+    pub key: String,
+    #[serde(default)]
+    pub value: String,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -44,6 +74,36 @@ pub struct WasmData {
 }
 
 impl TxResponse {
+    /// Returns the events emitted while executing the messages of the transaction.
+    pub fn events(&self) -> Vec<Event> {
+        self.logs
+            .iter()
+            .flat_map(|log| log.events.iter())
+            .map(|event| {
+                Event::new(&event.ty).add_attributes(
+                    event
+                        .attributes
+                        .iter()
+                        .map(|attribute| (attribute.key.clone(), attribute.value.clone())),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the value of the first attribute with the given key among the events of the given type.
+    ///
+    /// * `ty` - Type of the event of interest.
+    /// * `key` - Key of the attribute of interest.
+    pub fn event_attribute(&self, ty: &str, key: &str) -> Option<String> {
+        self.logs
+            .iter()
+            .flat_map(|log| log.events.iter())
+            .filter(|event| event.ty == ty)
+            .flat_map(|event| event.attributes.iter())
+            .find(|attribute| attribute.key == key)
+            .map(|attribute| attribute.value.clone())
+    }
+
     /// Tells if the transaction has been performed successfully.
     pub fn is_success(&self) -> bool {
         return self.code == 0;
@@ -5,6 +5,11 @@ mod test {
         TEST_DELETABLE_ATTACHMENT_ID, TEST_POLL_ID, TEST_SUBSPACE, TEST_SUBSPACE_DELETABLE_POST_ID,
         TEST_SUBSPACE_EDITABLE_POST_ID,
     };
+    #[cfg(feature = "desmos-v5")]
+    use crate::consts::{
+        TEST_SUBSPACE_MOVABLE_POST_ID, TEST_SUBSPACE_TRANSFERABLE_POST_ID, TEST_TARGET_SUBSPACE,
+        USER2_ADDRESS, USER2_KEY,
+    };
     use cosmwasm_std::{Addr, Uint64};
     use desmos_bindings::posts::models::{
         Entities, PostAttachment, PostReference, PostReferenceType, ProvidedAnswer, ReplySetting,
@@ -195,4 +200,99 @@ mod test {
             )
             .assert_success();
     }
+
+    #[test]
+    #[cfg(feature = "desmos-v5")]
+    fn test_move_post() {
+        let desmos_cli = DesmosCli::default();
+        let contract_address = desmos_cli.get_contract_by_code(1);
+
+        let msg = PostsMsg::MovePost {
+            subspace_id: TEST_SUBSPACE,
+            post_id: TEST_SUBSPACE_MOVABLE_POST_ID,
+            target_subspace_id: TEST_TARGET_SUBSPACE,
+            target_section_id: 0,
+            owner: Addr::unchecked(&contract_address),
+        };
+
+        desmos_cli
+            .wasm_execute(
+                &contract_address,
+                &ExecuteMsg::DesmosMessages {
+                    msgs: vec![msg.into()],
+                },
+            )
+            .assert_success();
+    }
+
+    #[test]
+    #[cfg(feature = "desmos-v5")]
+    fn test_post_owner_transfer() {
+        let desmos_cli = DesmosCli::default();
+        let contract_address = desmos_cli.get_contract_by_code(1);
+        let subspace_id = TEST_SUBSPACE.to_string();
+        let post_id = TEST_SUBSPACE_TRANSFERABLE_POST_ID.to_string();
+        let execute = |msg: PostsMsg| {
+            desmos_cli
+                .wasm_execute(
+                    &contract_address,
+                    &ExecuteMsg::DesmosMessages {
+                        msgs: vec![msg.into()],
+                    },
+                )
+                .assert_success();
+        };
+        let request_to_user2 = || PostsMsg::RequestPostOwnerTransfer {
+            subspace_id: TEST_SUBSPACE,
+            post_id: TEST_SUBSPACE_TRANSFERABLE_POST_ID,
+            receiver: Addr::unchecked(USER2_ADDRESS),
+            sender: Addr::unchecked(&contract_address),
+        };
+        let request_to_contract = || {
+            desmos_cli
+                .execute_tx([
+                    "posts",
+                    "request-post-owner-transfer",
+                    &subspace_id,
+                    &post_id,
+                    &contract_address,
+                    &format!("--from={}", USER2_KEY),
+                ])
+                .assert_success();
+        };
+
+        // Request the transfer of the post from the smart contract to the user2, then cancel it
+        execute(request_to_user2());
+        execute(PostsMsg::CancelPostOwnerTransferRequest {
+            subspace_id: TEST_SUBSPACE,
+            post_id: TEST_SUBSPACE_TRANSFERABLE_POST_ID,
+            sender: Addr::unchecked(&contract_address),
+        });
+
+        // Transfer the post to the user2, so that it can request to transfer it back to the smart contract
+        execute(request_to_user2());
+        desmos_cli
+            .execute_tx([
+                "posts",
+                "accept-post-owner-transfer",
+                &subspace_id,
+                &post_id,
+                &format!("--from={}", USER2_KEY),
+            ])
+            .assert_success();
+
+        // Refuse the first request made to the smart contract, then accept the second one
+        request_to_contract();
+        execute(PostsMsg::RefusePostOwnerTransferRequest {
+            subspace_id: TEST_SUBSPACE,
+            post_id: TEST_SUBSPACE_TRANSFERABLE_POST_ID,
+            receiver: Addr::unchecked(&contract_address),
+        });
+        request_to_contract();
+        execute(PostsMsg::AcceptPostOwnerTransferRequest {
+            subspace_id: TEST_SUBSPACE,
+            post_id: TEST_SUBSPACE_TRANSFERABLE_POST_ID,
+            receiver: Addr::unchecked(&contract_address),
+        });
+    }
 }
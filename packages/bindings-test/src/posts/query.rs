@@ -7,8 +7,8 @@ mod test {
         Entities, Post, PostAttachment, ProvidedAnswer, ReplySetting, UrlEntity,
     };
     use desmos_bindings::posts::models_query::{
-        QueryPollAnswersResponse, QueryPostAttachmentsResponse, QueryPostResponse,
-        QuerySectionPostsResponse, QuerySubspacePostsResponse,
+        QueryParamsResponse, QueryPollAnswersResponse, QueryPostAttachmentsResponse,
+        QueryPostResponse, QuerySectionPostsResponse, QuerySubspacePostsResponse,
     };
     use desmos_bindings::posts::msg::PostsMsg;
     use desmos_bindings::posts::query::PostsQuery;
@@ -36,6 +36,8 @@ mod test {
             }),
             tags: vec![],
             author: Addr::unchecked(contract_address),
+            #[cfg(feature = "desmos-v5")]
+            owner: Addr::unchecked(contract_address),
             conversation_id: Some(Uint64::new(0)),
             referenced_posts: vec![],
             reply_settings: ReplySetting::Everyone,
@@ -54,6 +56,8 @@ mod test {
         assert_eq!(post_l.text, post_r.text);
         assert_eq!(post_l.entities, post_r.entities);
         assert_eq!(post_l.author, post_r.author);
+        #[cfg(feature = "desmos-v5")]
+        assert_eq!(post_l.owner, post_r.owner);
         assert_eq!(post_l.conversation_id, post_r.conversation_id);
         assert_eq!(post_l.referenced_posts, post_r.referenced_posts);
         assert_eq!(post_l.reply_settings, post_r.reply_settings);
@@ -219,4 +223,20 @@ mod test {
         assert_eq!(Addr::unchecked(&contract_address), answer.user);
         assert_eq!(vec![0], answer.answers_indexes)
     }
+
+    #[test]
+    fn test_query_params() {
+        let desmos_cli = DesmosCli::default();
+        let contract_address = desmos_cli.get_contract_by_code(1);
+
+        let query_msg = DesmosChain {
+            request: PostsQuery::Params {}.into(),
+        };
+        let response: QueryParamsResponse = desmos_cli
+            .wasm_query(&contract_address, &query_msg)
+            .to_object();
+
+        // The test chain uses the default x/posts params
+        assert_eq!(500, response.params.max_text_length);
+    }
 }
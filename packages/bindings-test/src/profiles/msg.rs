@@ -4,7 +4,8 @@ mod tests {
     use crate::consts::{USER1_ADDRESS, USER1_KEY, USER2_ADDRESS, USER2_KEY};
     use cosmwasm_std::{Addr, Binary};
     use desmos_bindings::profiles::models_chain_links::{
-        Address, ChainConfig, Proof, Signature, SignatureValueType,
+        Address, ChainConfig, Proof, Signature, SignatureValueType, BECH32_ADDRESS_TYPE_URI,
+        SINGLE_SIGNATURE_TYPE_URI,
     };
     use desmos_bindings::profiles::msg::ProfilesMsg;
     use desmos_bindings::profiles::msg::ProfilesMsg::{DeleteProfile, SaveProfile};
@@ -207,7 +208,7 @@ mod tests {
         // Prepare the LinkChainAccount msg for the smart contract
         let link_chain_account = ProfilesMsg::LinkChainAccount {
             chain_address: Address {
-                proto_type: "/desmos.profiles.v3.Bech32Address".to_string(),
+                proto_type: BECH32_ADDRESS_TYPE_URI.to_string(),
                 value: "cosmos1wrx0kayjzuf27gaaqult0z576y0xggq00mrc2r".to_string(),
                 prefix: Some("cosmos".to_string()),
             },
@@ -217,7 +218,7 @@ mod tests {
                     key: Binary::from_base64("A6p7imM9YY/uFgZFV/ZiNQ45Ki2xbyR4zjG//BFzkVtY").unwrap(),
                 },
                 signature: Signature {
-                    proto_type: "/desmos.profiles.v3.SingleSignature".to_string(),
+                    proto_type: SINGLE_SIGNATURE_TYPE_URI.to_string(),
                     value_type: SignatureValueType::Raw,
                     signature: Binary::from_base64("tNuudGWFCKhjzN1twCYMkZHWYNxlCcXPeD7PL1rGiO0oUjhYglADFT6mjecKiHQLyW4COeRpvKSnGByQkCZZkA==").unwrap(),
                 },
@@ -267,7 +268,7 @@ mod tests {
         // Prepare the LinkChainAccount msg of the first address for the smart contract
         let link_first_chain_account = ProfilesMsg::LinkChainAccount {
             chain_address: Address {
-                proto_type: "/desmos.profiles.v3.Bech32Address".to_string(),
+                proto_type: BECH32_ADDRESS_TYPE_URI.to_string(),
                 value: "cosmos1wrx0kayjzuf27gaaqult0z576y0xggq00mrc2r".to_string(),
                 prefix: Some("cosmos".to_string()),
             },
@@ -277,7 +278,7 @@ mod tests {
                     key: Binary::from_base64("A6p7imM9YY/uFgZFV/ZiNQ45Ki2xbyR4zjG//BFzkVtY").unwrap(),
                 },
                 signature: Signature {
-                    proto_type: "/desmos.profiles.v3.SingleSignature".to_string(),
+                    proto_type: SINGLE_SIGNATURE_TYPE_URI.to_string(),
                     value_type: SignatureValueType::Raw,
                     signature: Binary::from_base64("tNuudGWFCKhjzN1twCYMkZHWYNxlCcXPeD7PL1rGiO0oUjhYglADFT6mjecKiHQLyW4COeRpvKSnGByQkCZZkA==").unwrap(),
                 },
@@ -290,7 +291,7 @@ mod tests {
         // Prepare the LinkChainAccount msg of the second address for the smart contract
         let link_second_chain_account = ProfilesMsg::LinkChainAccount {
             chain_address: Address {
-                proto_type: "/desmos.profiles.v3.Bech32Address".to_string(),
+                proto_type: BECH32_ADDRESS_TYPE_URI.to_string(),
                 value: "cosmos13n9wek2ktpxhpgfrd39zlaqaeahxuyusxrsfvn".to_string(),
                 prefix: Some("cosmos".to_string()),
             },
@@ -300,7 +301,7 @@ mod tests {
                     key: Binary::from_base64("AqYZhHKaeBcrYktZEvor/SUDlHCkv5JBplaG2vc2bvfS").unwrap(),
                 },
                 signature: Signature {
-                    proto_type: "/desmos.profiles.v3.SingleSignature".to_string(),
+                    proto_type: SINGLE_SIGNATURE_TYPE_URI.to_string(),
                     value_type: SignatureValueType::Raw,
                     signature: Binary::from_base64("gLIWKbyZ8nUtCVvr8TfPGDYU1rybwPDi6neMuEjfvkwNXJVuNcmthqVeuvxEln7K15PIEPUGTMTV/kU0n3iGPw==").unwrap(),
                 },
@@ -2,13 +2,15 @@
 mod tests {
     use crate::chain_communication::DesmosCli;
     use crate::consts::{USER1_ADDRESS, USER2_ADDRESS};
-    use cosmwasm_std::Addr;
-    use desmos_bindings::profiles::models_chain_links::ChainLinkOwnerDetails;
+    use cosmwasm_std::{Addr, Uint64};
+    use desmos_bindings::profiles::models_chain_links::{
+        ChainLinkOwnerDetails, BECH32_ADDRESS_TYPE_URI,
+    };
     use desmos_bindings::profiles::models_profile::Pictures;
     use desmos_bindings::profiles::models_query::{
         QueryChainLinkOwnersResponse, QueryChainLinksResponse,
         QueryDefaultExternalAddressesResponse, QueryIncomingDtagTransferRequestsResponse,
-        QueryProfileResponse,
+        QueryParamsResponse, QueryProfileResponse,
     };
     use desmos_bindings::profiles::query::ProfilesQuery;
     use test_contract::msg::QueryMsg::DesmosChain;
@@ -86,10 +88,7 @@ mod tests {
 
         assert_eq!(2, result.links.len());
         let cosmos_address = result.links.first().unwrap();
-        assert_eq!(
-            "/desmos.profiles.v3.Bech32Address",
-            cosmos_address.address.proto_type
-        );
+        assert_eq!(BECH32_ADDRESS_TYPE_URI, cosmos_address.address.proto_type);
         assert_eq!(
             "cosmos1wrx0kayjzuf27gaaqult0z576y0xggq00mrc2r",
             cosmos_address.address.value
@@ -97,10 +96,7 @@ mod tests {
         assert_eq!("cosmos", cosmos_address.address.prefix.as_ref().unwrap());
 
         let osmosis_address = result.links.last().unwrap();
-        assert_eq!(
-            "/desmos.profiles.v3.Bech32Address",
-            osmosis_address.address.proto_type
-        );
+        assert_eq!(BECH32_ADDRESS_TYPE_URI, osmosis_address.address.proto_type);
         assert_eq!(
             "osmo1wrx0kayjzuf27gaaqult0z576y0xggq08qsgu3",
             osmosis_address.address.value
@@ -128,10 +124,7 @@ mod tests {
             .to_object();
 
         let cosmos_address = result.links.first().unwrap();
-        assert_eq!(
-            "/desmos.profiles.v3.Bech32Address",
-            cosmos_address.address.proto_type
-        );
+        assert_eq!(BECH32_ADDRESS_TYPE_URI, cosmos_address.address.proto_type);
         assert_eq!(
             "cosmos1wrx0kayjzuf27gaaqult0z576y0xggq00mrc2r",
             cosmos_address.address.value
@@ -159,10 +152,7 @@ mod tests {
             .to_object();
 
         let cosmos_address = result.links.first().unwrap();
-        assert_eq!(
-            "/desmos.profiles.v3.Bech32Address",
-            cosmos_address.address.proto_type
-        );
+        assert_eq!(BECH32_ADDRESS_TYPE_URI, cosmos_address.address.proto_type);
         assert_eq!(
             "cosmos1wrx0kayjzuf27gaaqult0z576y0xggq00mrc2r",
             cosmos_address.address.value
@@ -214,14 +204,33 @@ mod tests {
             .to_object();
 
         let cosmos_address = result.links.first().unwrap();
-        assert_eq!(
-            "/desmos.profiles.v3.Bech32Address",
-            cosmos_address.address.proto_type
-        );
+        assert_eq!(BECH32_ADDRESS_TYPE_URI, cosmos_address.address.proto_type);
         assert_eq!(
             "cosmos1wrx0kayjzuf27gaaqult0z576y0xggq00mrc2r",
             cosmos_address.address.value
         );
         assert_eq!("cosmos", cosmos_address.address.prefix.as_ref().unwrap());
     }
+
+    #[test]
+    fn test_query_params() {
+        let desmos_cli = DesmosCli::default();
+        let contract_address = desmos_cli.get_contract_by_code(1);
+
+        let query_msg = DesmosChain {
+            request: ProfilesQuery::Params {}.into(),
+        };
+        let response: QueryParamsResponse = desmos_cli
+            .wasm_query(&contract_address, &query_msg)
+            .to_object();
+
+        // The test chain uses the default x/profiles params
+        let params = response.params;
+        assert_eq!(Uint64::new(2), params.nickname.min_length);
+        assert_eq!(Uint64::new(1000), params.nickname.max_length);
+        assert_eq!("^[A-Za-z0-9_]+$", params.dtag.reg_ex.as_str());
+        assert_eq!(Uint64::new(3), params.dtag.min_length);
+        assert_eq!(Uint64::new(30), params.dtag.max_length);
+        assert_eq!(Uint64::new(1000), params.bio.max_length);
+    }
 }
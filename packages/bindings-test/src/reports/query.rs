@@ -8,7 +8,8 @@ mod tests {
     use cosmwasm_std::Addr;
     use desmos_bindings::reports::models::{RawReportTarget, ReportTarget};
     use desmos_bindings::reports::models_query::{
-        QueryReasonResponse, QueryReasonsResponse, QueryReportResponse, QueryReportsResponse,
+        QueryParamsResponse, QueryReasonResponse, QueryReasonsResponse, QueryReportResponse,
+        QueryReportsResponse,
     };
     use desmos_bindings::reports::query::ReportsQuery;
     use test_contract::msg::QueryMsg::DesmosChain;
@@ -167,4 +168,27 @@ mod tests {
             reason.description.unwrap().as_str()
         );
     }
+
+    #[test]
+    fn test_query_standard_reasons() {
+        let desmos_cli = DesmosCli::default();
+        let contract_address = desmos_cli.get_contract_by_code(1);
+
+        // The standard reasons are stored inside the module params
+        let query = DesmosChain {
+            request: ReportsQuery::Params {}.into(),
+        };
+        let response: QueryParamsResponse =
+            desmos_cli.wasm_query(&contract_address, &query).to_object();
+
+        // There should be at least the standard reason set inside the genesis
+        let standard_reason = response.params.standard_reasons.first().unwrap();
+
+        assert_eq!(1, standard_reason.id);
+        assert_eq!("Spam", standard_reason.title.as_str());
+        assert_eq!(
+            "Spam user or content",
+            standard_reason.description.as_ref().unwrap().as_str()
+        );
+    }
 }
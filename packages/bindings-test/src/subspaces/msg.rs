@@ -2,9 +2,11 @@
 mod tests {
     use crate::chain_communication::DesmosCli;
     use crate::consts::{TEST_SUBSPACE, TEST_SUBSPACE_USER_GROUP, USER2_ADDRESS};
+    use crate::models::TxResponse;
     use cosmwasm_std::{Addr, Uint64};
-    use desmos_bindings::subspaces::models::Permission;
+    use desmos_bindings::subspaces::models::{Grantee, Permission};
     use desmos_bindings::subspaces::msg::SubspacesMsg;
+    use desmos_bindings::types::{Allowance, Authorization};
     use test_contract::msg::ExecuteMsg;
     use test_contract::msg::ExecuteMsg::DesmosMessages;
 
@@ -91,6 +93,87 @@ mod tests {
             .assert_success();
     }
 
+    fn build_create_section_msg(name: &str, contract_address: &str) -> ExecuteMsg {
+        DesmosMessages {
+            msgs: vec![SubspacesMsg::CreateSection {
+                subspace_id: TEST_SUBSPACE,
+                name: name.to_string(),
+                description: None,
+                parent_id: None,
+                creator: Addr::unchecked(contract_address),
+            }
+            .into()],
+        }
+    }
+
+    fn created_section_id(response: &TxResponse) -> u32 {
+        response
+            .event_attribute("create_section", "section_id")
+            .unwrap()
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    pub fn test_create_edit_move_delete_section() {
+        let desmos_cli = DesmosCli::default();
+        let contract_address = desmos_cli.get_contract_by_code(1);
+
+        // Create the sections to edit, move and delete.
+        let response = desmos_cli.wasm_execute(
+            &contract_address,
+            &build_create_section_msg("test_section", &contract_address),
+        );
+        response.assert_success();
+        let section_id = created_section_id(&response);
+        let response = desmos_cli.wasm_execute(
+            &contract_address,
+            &build_create_section_msg("test_nested_section", &contract_address),
+        );
+        response.assert_success();
+        let nested_section_id = created_section_id(&response);
+
+        let edit_section = SubspacesMsg::EditSection {
+            subspace_id: TEST_SUBSPACE,
+            section_id,
+            name: Some("test_edited_section".to_string()),
+            description: Some("Edited section".to_string()),
+            editor: Addr::unchecked(&contract_address),
+        };
+        let move_section = SubspacesMsg::MoveSection {
+            subspace_id: TEST_SUBSPACE,
+            section_id: nested_section_id,
+            new_parent_id: section_id,
+            signer: Addr::unchecked(&contract_address),
+        };
+        let delete_nested_section = SubspacesMsg::DeleteSection {
+            subspace_id: TEST_SUBSPACE,
+            section_id: nested_section_id,
+            signer: Addr::unchecked(&contract_address),
+        };
+        let delete_section = SubspacesMsg::DeleteSection {
+            subspace_id: TEST_SUBSPACE,
+            section_id,
+            signer: Addr::unchecked(&contract_address),
+        };
+
+        for msg in [
+            edit_section,
+            move_section,
+            delete_nested_section,
+            delete_section,
+        ] {
+            desmos_cli
+                .wasm_execute(
+                    &contract_address,
+                    &DesmosMessages {
+                        msgs: vec![msg.into()],
+                    },
+                )
+                .assert_success();
+        }
+    }
+
     fn build_create_user_group_msg(subspace_id: Uint64, contract_address: &str) -> ExecuteMsg {
         DesmosMessages {
             msgs: vec![SubspacesMsg::CreateUserGroup {
@@ -280,4 +363,116 @@ mod tests {
             .wasm_execute(&contract_address, &msg)
             .assert_success();
     }
+
+    #[test]
+    pub fn test_move_user_group() {
+        let desmos_cli = DesmosCli::default();
+        let contract_address = desmos_cli.get_contract_by_code(1);
+
+        // Create the section where the user group will be moved.
+        let response = desmos_cli.wasm_execute(
+            &contract_address,
+            &build_create_section_msg("test_user_group_section", &contract_address),
+        );
+        response.assert_success();
+        let section_id = created_section_id(&response);
+
+        // Move the user group inside the section, then back to the root section.
+        for new_section_id in [section_id, 0] {
+            let move_user_group = SubspacesMsg::MoveUserGroup {
+                subspace_id: TEST_SUBSPACE,
+                group_id: TEST_SUBSPACE_USER_GROUP,
+                new_section_id,
+                signer: Addr::unchecked(&contract_address),
+            };
+
+            let msg = DesmosMessages {
+                msgs: vec![move_user_group.into()],
+            };
+
+            desmos_cli
+                .wasm_execute(&contract_address, &msg)
+                .assert_success();
+        }
+    }
+
+    #[test]
+    pub fn test_grant_revoke_treasury_authorization() {
+        let desmos_cli = DesmosCli::default();
+        let contract_address = desmos_cli.get_contract_by_code(1);
+
+        let grant_treasury_authorization = SubspacesMsg::grant_treasury_authorization(
+            TEST_SUBSPACE.u64(),
+            Addr::unchecked(&contract_address),
+            Addr::unchecked(USER2_ADDRESS),
+            Authorization::Generic {
+                msg: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            },
+            None,
+        );
+
+        let msg = DesmosMessages {
+            msgs: vec![grant_treasury_authorization.into()],
+        };
+
+        desmos_cli
+            .wasm_execute(&contract_address, &msg)
+            .assert_success();
+
+        let revoke_treasury_authorization = SubspacesMsg::revoke_treasury_authorization(
+            TEST_SUBSPACE.u64(),
+            Addr::unchecked(&contract_address),
+            Addr::unchecked(USER2_ADDRESS),
+            "/cosmos.bank.v1beta1.MsgSend",
+        );
+
+        let msg = DesmosMessages {
+            msgs: vec![revoke_treasury_authorization.into()],
+        };
+
+        desmos_cli
+            .wasm_execute(&contract_address, &msg)
+            .assert_success();
+    }
+
+    #[test]
+    pub fn test_grant_revoke_allowance() {
+        let desmos_cli = DesmosCli::default();
+        let contract_address = desmos_cli.get_contract_by_code(1);
+        let grantee = || Grantee::User {
+            user: Addr::unchecked(USER2_ADDRESS),
+        };
+
+        let grant_allowance = SubspacesMsg::grant_allowance(
+            TEST_SUBSPACE.u64(),
+            Addr::unchecked(&contract_address),
+            grantee(),
+            Allowance::Basic {
+                spend_limit: vec![],
+                expiration: None,
+            },
+        );
+
+        let msg = DesmosMessages {
+            msgs: vec![grant_allowance.into()],
+        };
+
+        desmos_cli
+            .wasm_execute(&contract_address, &msg)
+            .assert_success();
+
+        let revoke_allowance = SubspacesMsg::revoke_allowance(
+            TEST_SUBSPACE.u64(),
+            Addr::unchecked(&contract_address),
+            grantee(),
+        );
+
+        let msg = DesmosMessages {
+            msgs: vec![revoke_allowance.into()],
+        };
+
+        desmos_cli
+            .wasm_execute(&contract_address, &msg)
+            .assert_success();
+    }
 }
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use crate::chain_communication::DesmosCli;
+    use crate::consts::TEST_DENOM;
+    use cosmwasm_std::{Uint128, Uint64};
+    use desmos_bindings::supply::models_query::{QueryCirculatingResponse, QueryTotalResponse};
+    use desmos_bindings::supply::query::SupplyQuery;
+    use test_contract::msg::QueryMsg::DesmosChain;
+
+    fn query_total_supply(desmos_cli: &DesmosCli, contract_address: &str) -> Uint128 {
+        let query = DesmosChain {
+            request: SupplyQuery::Total {
+                denom: TEST_DENOM.to_string(),
+                divider_exponent: Uint64::zero(),
+            }
+            .into(),
+        };
+
+        let response: QueryTotalResponse =
+            desmos_cli.wasm_query(contract_address, &query).to_object();
+        response.total_supply
+    }
+
+    #[test]
+    fn test_query_total() {
+        let desmos_cli = DesmosCli::default();
+        let contract_address = desmos_cli.get_contract_by_code(1);
+
+        let total_supply = query_total_supply(&desmos_cli, &contract_address);
+        assert!(!total_supply.is_zero());
+    }
+
+    #[test]
+    fn test_query_total_with_divider() {
+        let desmos_cli = DesmosCli::default();
+        let contract_address = desmos_cli.get_contract_by_code(1);
+
+        let query = DesmosChain {
+            request: SupplyQuery::Total {
+                denom: TEST_DENOM.to_string(),
+                divider_exponent: Uint64::new(6),
+            }
+            .into(),
+        };
+
+        let response: QueryTotalResponse =
+            desmos_cli.wasm_query(&contract_address, &query).to_object();
+
+        // The divided supply can't be greater than the raw one.
+        let total_supply = query_total_supply(&desmos_cli, &contract_address);
+        assert!(!response.total_supply.is_zero());
+        assert!(response.total_supply <= total_supply / Uint128::new(1_000_000));
+    }
+
+    #[test]
+    fn test_query_circulating() {
+        let desmos_cli = DesmosCli::default();
+        let contract_address = desmos_cli.get_contract_by_code(1);
+
+        let query = DesmosChain {
+            request: SupplyQuery::Circulating {
+                denom: TEST_DENOM.to_string(),
+                divider_exponent: Uint64::zero(),
+            }
+            .into(),
+        };
+
+        let response: QueryCirculatingResponse =
+            desmos_cli.wasm_query(&contract_address, &query).to_object();
+
+        let total_supply = query_total_supply(&desmos_cli, &contract_address);
+        assert!(!response.circulating_supply.is_zero());
+        assert!(response.circulating_supply <= total_supply);
+    }
+}
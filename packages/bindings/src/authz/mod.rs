@@ -0,0 +1,4 @@
+//! Contains utilities to let a contract act on behalf of the Desmos users
+//! that granted it permissions through the Cosmos x/authz module.
+
+pub mod msg;
@@ -0,0 +1,243 @@
+//! Contains the functions that build the Cosmos x/authz messages as `Stargate` messages.
+//!
+//! A contract can perform an action on behalf of a user (eg. creating a post having the user
+//! as `author`) once the user has granted the contract the permission to execute that kind of
+//! message. The grant must be sent by the user itself, as an example using
+//! [`generic_grants`] to scope it to some Desmos messages type urls:
+//! ```
+//! use cosmwasm_std::Addr;
+//! use desmos_bindings::authz::msg::generic_grants;
+//! use desmos_bindings::posts::msg::MSG_CREATE_POST_TYPE_URI;
+//! use desmos_bindings::reactions::msg::MSG_ADD_REACTION_TYPE_URI;
+//!
+//! let grants = generic_grants(
+//!     Addr::unchecked("desmos1user"),
+//!     Addr::unchecked("desmos1contract"),
+//!     &[MSG_CREATE_POST_TYPE_URI, MSG_ADD_REACTION_TYPE_URI],
+//!     None,
+//! ).unwrap();
+//! ```
+//! After that, the contract can wrap the messages signed by the user with [`exec`]:
+//! ```
+//! use cosmwasm_std::{Addr, Response};
+//! use desmos_bindings::authz::msg::exec;
+//! use desmos_bindings::msg::DesmosMsg;
+//! use desmos_bindings::reactions::{models::ReactionValue, msg::ReactionsMsg};
+//!
+//! let add_reaction = ReactionsMsg::add_reaction(
+//!     1,
+//!     1,
+//!     ReactionValue::FreeText { text: "👍".to_string() },
+//!     Addr::unchecked("desmos1user"),
+//! );
+//! let msg = exec(Addr::unchecked("desmos1contract"), vec![add_reaction.into()]).unwrap();
+//! let response: Response<DesmosMsg> = Response::new().add_message(msg);
+//! ```
+
+use crate::msg::DesmosMsg;
+use crate::proto::{any, authz_grant, ProtoWriter};
+use crate::types::AuthzGrant;
+use cosmwasm_std::{Addr, CosmosMsg, StdError, StdResult, Timestamp};
+
+/// Type url of the x/authz `MsgExec` message.
+pub const MSG_EXEC_TYPE_URI: &str = "/cosmos.authz.v1beta1.MsgExec";
+/// Type url of the x/authz `MsgGrant` message.
+pub const MSG_GRANT_TYPE_URI: &str = "/cosmos.authz.v1beta1.MsgGrant";
+/// Type url of the x/authz `MsgRevoke` message.
+pub const MSG_REVOKE_TYPE_URI: &str = "/cosmos.authz.v1beta1.MsgRevoke";
+
+/// Wraps the provided messages inside a x/authz `MsgExec`, so that they are executed
+/// by the `grantee` on behalf of their signers (eg. the `author`, `user` or `signer` field),
+/// that must have granted the `grantee` the permission to execute them.
+///
+/// * `grantee` - Address executing the messages, usually the contract address.
+/// * `msgs` - Messages to be executed.
+pub fn exec(grantee: Addr, msgs: Vec<DesmosMsg>) -> StdResult<CosmosMsg<DesmosMsg>> {
+    if msgs.is_empty() {
+        return Err(StdError::generic_err("msgs cannot be empty"));
+    }
+    let msgs = msgs
+        .iter()
+        .map(|msg| Ok(any(msg.type_url(), &msg.to_proto_bytes()?)))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(CosmosMsg::Stargate {
+        type_url: MSG_EXEC_TYPE_URI.to_string(),
+        value: ProtoWriter::new()
+            .string(1, grantee.as_str())
+            .repeated_message(2, &msgs)
+            .finish()
+            .into(),
+    })
+}
+
+/// Creates a x/authz `MsgGrant` giving the `grantee` the provided authorization
+/// on behalf of the `granter`, that must be the signer of the message.
+///
+/// * `granter` - Address giving the authorization.
+/// * `grantee` - Address receiving the authorization.
+/// * `grant` - Authorization to give.
+pub fn grant(granter: Addr, grantee: Addr, grant: AuthzGrant) -> StdResult<CosmosMsg<DesmosMsg>> {
+    Ok(CosmosMsg::Stargate {
+        type_url: MSG_GRANT_TYPE_URI.to_string(),
+        value: ProtoWriter::new()
+            .string(1, granter.as_str())
+            .string(2, grantee.as_str())
+            .message(3, &authz_grant(&grant)?)
+            .finish()
+            .into(),
+    })
+}
+
+/// Creates a x/authz `MsgGrant` for each of the provided message type urls,
+/// giving the `grantee` the permission to execute them on behalf of the `granter`.
+///
+/// * `granter` - Address giving the authorizations.
+/// * `grantee` - Address receiving the authorizations.
+/// * `msg_type_urls` - Type urls of the messages that the grantee is allowed to execute.
+/// * `expiration` - Time after which the authorizations expire, if `None` they never expire.
+pub fn generic_grants(
+    granter: Addr,
+    grantee: Addr,
+    msg_type_urls: &[&str],
+    expiration: Option<Timestamp>,
+) -> StdResult<Vec<CosmosMsg<DesmosMsg>>> {
+    msg_type_urls
+        .iter()
+        .map(|msg_type_url| {
+            grant(
+                granter.clone(),
+                grantee.clone(),
+                AuthzGrant::generic(msg_type_url, expiration),
+            )
+        })
+        .collect()
+}
+
+/// Creates a x/authz `MsgRevoke` removing the permission of the `grantee`
+/// to execute the messages having the provided type url on behalf of the `granter`.
+///
+/// * `granter` - Address that gave the authorization.
+/// * `grantee` - Address that received the authorization.
+/// * `msg_type_url` - Type url of the message whose authorization is revoked.
+pub fn revoke(granter: Addr, grantee: Addr, msg_type_url: &str) -> CosmosMsg<DesmosMsg> {
+    CosmosMsg::Stargate {
+        type_url: MSG_REVOKE_TYPE_URI.to_string(),
+        value: ProtoWriter::new()
+            .string(1, granter.as_str())
+            .string(2, grantee.as_str())
+            .string(3, msg_type_url)
+            .finish()
+            .into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::posts::msg::MSG_CREATE_POST_TYPE_URI;
+    use crate::relationships::msg::RelationshipsMsg;
+    use crate::relationships::msg::MSG_CREATE_RELATIONSHIP_TYPE_URI;
+    use crate::types::Authorization;
+    use cosmwasm_std::Binary;
+
+    #[test]
+    fn test_exec() {
+        let msg: DesmosMsg = RelationshipsMsg::create_relationship(
+            Addr::unchecked("user"),
+            Addr::unchecked("counterparty"),
+            1,
+        )
+        .into();
+        let expected_msg = any(
+            MSG_CREATE_RELATIONSHIP_TYPE_URI,
+            &msg.to_proto_bytes().unwrap(),
+        );
+        assert_eq!(
+            CosmosMsg::Stargate {
+                type_url: MSG_EXEC_TYPE_URI.to_string(),
+                value: ProtoWriter::new()
+                    .string(1, "contract")
+                    .message(2, &expected_msg)
+                    .finish()
+                    .into(),
+            },
+            exec(Addr::unchecked("contract"), vec![msg]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_exec_without_msgs_error() {
+        exec(Addr::unchecked("contract"), vec![]).unwrap_err();
+    }
+
+    #[test]
+    fn test_generic_grants() {
+        let grants = generic_grants(
+            Addr::unchecked("user"),
+            Addr::unchecked("contract"),
+            &[MSG_CREATE_POST_TYPE_URI, MSG_CREATE_RELATIONSHIP_TYPE_URI],
+            Some(Timestamp::from_seconds(1)),
+        )
+        .unwrap();
+        let expected: Vec<CosmosMsg<DesmosMsg>> =
+            [MSG_CREATE_POST_TYPE_URI, MSG_CREATE_RELATIONSHIP_TYPE_URI]
+                .iter()
+                .map(|msg_type_url| {
+                    let authorization = any(
+                        "/cosmos.authz.v1beta1.GenericAuthorization",
+                        &ProtoWriter::new().string(1, msg_type_url).finish(),
+                    );
+                    CosmosMsg::Stargate {
+                        type_url: MSG_GRANT_TYPE_URI.to_string(),
+                        value: ProtoWriter::new()
+                            .string(1, "user")
+                            .string(2, "contract")
+                            .message(
+                                3,
+                                &ProtoWriter::new()
+                                    .message(1, &authorization)
+                                    .message(2, &[0x08, 0x01])
+                                    .finish(),
+                            )
+                            .finish()
+                            .into(),
+                    }
+                })
+                .collect();
+        assert_eq!(expected, grants);
+    }
+
+    #[test]
+    fn test_grant_invalid_expiration_error() {
+        let authz_grant = AuthzGrant {
+            authorization: Authorization::Generic {
+                msg: MSG_CREATE_POST_TYPE_URI.to_string(),
+            }
+            .into(),
+            expiration: Some("invalid".to_string()),
+        };
+        grant(
+            Addr::unchecked("user"),
+            Addr::unchecked("contract"),
+            authz_grant,
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn test_revoke() {
+        let mut value = vec![0x0a, 0x04];
+        value.extend_from_slice(b"user");
+        value.extend_from_slice(&[0x12, 0x08]);
+        value.extend_from_slice(b"contract");
+        value.extend_from_slice(&[0x1a, 0x02]);
+        value.extend_from_slice(b"/a");
+        assert_eq!(
+            CosmosMsg::Stargate {
+                type_url: MSG_REVOKE_TYPE_URI.to_string(),
+                value: Binary::from(value),
+            },
+            revoke(Addr::unchecked("user"), Addr::unchecked("contract"), "/a")
+        );
+    }
+}
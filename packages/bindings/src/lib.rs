@@ -4,6 +4,12 @@
 //! smart contract.
 
 extern crate core;
+
+#[cfg(all(feature = "tokenfactory", not(feature = "desmos-v6")))]
+compile_error!("the tokenfactory feature requires the desmos-v6 feature, since the module is available only starting from Desmos v6");
+
+#[cfg(feature = "authz")]
+pub mod authz;
 #[cfg(feature = "iterators")]
 pub mod iter;
 #[cfg(not(target_arch = "wasm32"))]
@@ -13,6 +19,8 @@ pub mod msg;
 pub mod posts;
 #[cfg(feature = "profiles")]
 pub mod profiles;
+#[cfg(feature = "authz")]
+mod proto;
 pub mod query;
 #[cfg(feature = "reactions")]
 pub mod reactions;
@@ -22,5 +30,9 @@ pub mod relationships;
 pub mod reports;
 #[cfg(feature = "subspaces")]
 pub mod subspaces;
+#[cfg(feature = "supply")]
+pub mod supply;
+#[cfg(feature = "tokenfactory")]
+pub mod tokenfactory;
 
 pub mod types;
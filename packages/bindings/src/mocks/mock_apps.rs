@@ -2,21 +2,52 @@
 
 #![cfg(not(tarpaulin_include))]
 use crate::mocks::mock_keeper::DesmosKeeper;
+use crate::mocks::mock_snapshot::DesmosStateSnapshot;
 use crate::msg::DesmosMsg;
 use crate::query::DesmosQuery;
+use anyhow::Result as AnyResult;
 use cosmwasm_std::testing::{MockApi, MockStorage};
-use cosmwasm_std::{Addr, Api, Empty, GovMsg, IbcMsg, IbcQuery, Storage};
+use cosmwasm_std::{Api, BlockInfo, Empty, GovMsg, IbcMsg, IbcQuery, StdResult, Storage};
 use cw_multi_test::{
     App, BankKeeper, BasicAppBuilder, DistributionKeeper, FailingModule, Module, Router,
     StakeKeeper, WasmKeeper,
 };
 
 /// Defines the module trait for desmos app.
-pub trait DesmosModule: Module<ExecT = DesmosMsg, QueryT = DesmosQuery, SudoT = Empty> {}
+pub trait DesmosModule: Module<ExecT = DesmosMsg, QueryT = DesmosQuery, SudoT = Empty> {
+    /// Performs the operations that the chain executes at the end of each block.
+    /// By default it does nothing.
+    fn end_block(&self, _storage: &mut dyn Storage, _block: &BlockInfo) -> AnyResult<()> {
+        Ok(())
+    }
+}
 
-impl DesmosModule for DesmosKeeper {}
+impl DesmosModule for DesmosKeeper {
+    fn end_block(&self, storage: &mut dyn Storage, block: &BlockInfo) -> AnyResult<()> {
+        DesmosKeeper::end_block(self, storage, block)
+    }
+}
 impl DesmosModule for FailingModule<DesmosMsg, DesmosQuery, Empty> {}
 
+/// Extends the [`DesmosApp`] with the block lifecycle of the Desmos chain.
+///
+/// cw-multi-test has no end-blocker hook, so after [`App::update_block`] the Desmos end-blocker
+/// runs lazily, right before the first Desmos message or query of the new block.
+/// [`DesmosAppExt::update_desmos_block`] runs it as soon as the block is advanced instead.
+pub trait DesmosAppExt {
+    /// Advances the block with the given action like [`App::update_block`] does,
+    /// then runs the end-blocker of the Desmos module once on the new block.
+    fn update_desmos_block<F: Fn(&mut BlockInfo)>(&mut self, action: F) -> AnyResult<()>;
+}
+
+impl<M: DesmosModule> DesmosAppExt for DesmosApp<M> {
+    fn update_desmos_block<F: Fn(&mut BlockInfo)>(&mut self, action: F) -> AnyResult<()> {
+        self.update_block(action);
+        let block = self.block_info();
+        self.init_modules(|router, _, storage| router.custom.end_block(storage, &block))
+    }
+}
+
 /// DesmosApp wraps the desmos custom module into a mock app for integration tests.
 /// It always returns successful response with proper events.
 pub type DesmosApp<M = DesmosKeeper> =
@@ -51,26 +82,101 @@ pub fn mock_failing_desmos_app() -> DesmosApp<FailingModule<DesmosMsg, DesmosQue
 }
 
 /// Returns a mock default desmos app.
+///
+/// Its keeper does not track the Desmos state, so every message succeeds with canned events and
+/// every query returns the mocked response. Use [`mock_stateful_desmos_app`] to test contracts
+/// against the state changes performed by their messages.
 pub fn mock_desmos_app() -> DesmosApp {
     BasicAppBuilder::<DesmosMsg, DesmosQuery>::new_custom()
         .with_custom(DesmosKeeper::new())
         .build(|_, _, _| {})
 }
 
+/// Returns a mock desmos app whose keeper tracks the Desmos state,
+/// as created by [`DesmosKeeper::new_stateful`].
+///
+/// The polls tally and the application links verification and expiration are performed by the
+/// end-blocker of the Desmos module. When the block is advanced with [`App::update_block`], the
+/// end-blocker runs before the first Desmos message or query of the new block, while
+/// [`DesmosAppExt::update_desmos_block`] runs it right away.
+///
+/// **Warning:** unlike the Desmos chain, the end-blocker runs only once when many blocks are
+/// skipped at the same time, on the last of them.
+///
+/// ```
+/// use desmos_bindings::mocks::mock_apps::{mock_stateful_desmos_app, DesmosAppExt};
+///
+/// let mut app = mock_stateful_desmos_app();
+/// app.update_desmos_block(|block| {
+///     block.height += 1;
+///     block.time = block.time.plus_seconds(5);
+/// })
+/// .unwrap();
+/// ```
+pub fn mock_stateful_desmos_app() -> DesmosApp {
+    BasicAppBuilder::<DesmosMsg, DesmosQuery>::new_custom()
+        .with_custom(DesmosKeeper::new_stateful())
+        .build(|_, _, _| {})
+}
+
+/// Takes a snapshot of the Desmos state of the provided app, leaving out the one of the other modules.
+pub fn snapshot_desmos_state<M: DesmosModule>(
+    app: &DesmosApp<M>,
+) -> StdResult<DesmosStateSnapshot> {
+    app.read_module(|_, _, storage| DesmosStateSnapshot::take(storage))
+}
+
+/// Replaces the Desmos state of the provided app with the one contained in the given snapshot,
+/// leaving untouched the state of the other modules.
+pub fn restore_desmos_state<M: DesmosModule>(
+    app: &mut DesmosApp<M>,
+    snapshot: &DesmosStateSnapshot,
+) -> StdResult<()> {
+    app.init_modules(|_, _, storage| snapshot.restore(storage))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mocks::mock_events::EventFixtures;
+    use crate::mocks::mock_failures::{DesmosError, MockFailure};
+    use crate::mocks::mock_gas::{GasConfig, MockGasMeter, GAS_EVENT};
+    use crate::mocks::mock_oracle::MockOracleResponse;
+    use crate::mocks::mock_recorder::MockRecorder;
+    use crate::mocks::mock_state;
+    use crate::mocks::JsonFile;
+    #[cfg(feature = "desmos-v5")]
+    use crate::posts::models::PostOwnerTransferRequest;
+    #[cfg(feature = "tokenfactory")]
+    use crate::tokenfactory::{
+        mocks::MockTokenFactoryQueries, models_query::QuerySubspaceDenomsResponse,
+        msg::TokenFactoryMsg, querier::TokenFactoryQuerier,
+    };
+    use crate::types::{format_rfc3339_time, Allowance, Authorization, Height};
     use crate::{
         posts::{
-            mocks::MockPostsQueries, models_query::QueryPostResponse, msg::PostsMsg,
+            mocks::MockPostsQueries,
+            models::{
+                AnswerResult, PollTallyResults, PostAttachment, ProvidedAnswer, ReplySetting,
+            },
+            models_query::QueryPostResponse,
+            msg::PostsMsg,
             querier::PostsQuerier,
         },
         profiles::{
-            mocks::MockProfilesQueries, models_query::QueryProfileResponse, msg::ProfilesMsg,
+            mocks::MockProfilesQueries,
+            models_app_links::{AppLinkResult, ApplicationLink, ApplicationLinkState, Data},
+            models_dtag_requests::DtagTransferRequest,
+            models_query::QueryProfileResponse,
+            msg::ProfilesMsg,
             querier::ProfilesQuerier,
+            query::ProfilesQuery,
         },
         reactions::{
-            mocks::MockReactionsQueries, models_query::QueryReactionsResponse, msg::ReactionsMsg,
+            mocks::MockReactionsQueries,
+            models::{FreeTextValueParams, ReactionValue, RegisteredReactionValueParams},
+            models_query::QueryReactionsResponse,
+            msg::ReactionsMsg,
             querier::ReactionsQuerier,
         },
         relationships::{
@@ -78,15 +184,22 @@ mod tests {
             msg::RelationshipsMsg, querier::RelationshipsQuerier,
         },
         reports::{
-            mocks::MockReportsQueries, models_query::QueryReportResponse, msg::ReportsMsg,
-            querier::ReportsQuerier,
+            mocks::MockReportsQueries, models::ReportTarget, models_query::QueryReportResponse,
+            msg::ReportsMsg, querier::ReportsQuerier,
         },
         subspaces::{
-            mocks::MockSubspacesQueries, models_query::QuerySubspaceResponse, msg::SubspacesMsg,
-            querier::SubspacesQuerier,
+            mocks::MockSubspacesQueries, models::Grantee, models_query::QuerySubspaceResponse,
+            msg::SubspacesMsg, querier::SubspacesQuerier, query::SubspacesQuery,
+        },
+        supply::{
+            mocks::MockSupplyQueries, models_query::QueryTotalResponse, querier::SupplyQuerier,
         },
     };
-    use cw_multi_test::Executor;
+    #[cfg(feature = "tokenfactory")]
+    use cosmwasm_std::coin;
+    use cosmwasm_std::{Addr, Event, Timestamp, Uint64};
+    use cw_multi_test::{next_block, AppResponse, Executor};
+    use std::convert::TryFrom;
     use std::ops::Deref;
     const SENDER: &str = "sender";
 
@@ -101,162 +214,2309 @@ mod tests {
     }
 
     #[test]
-    fn execute_relationships_msg_properly() {
-        let mut app = mock_desmos_app();
-        let result = app.execute(
-            Addr::unchecked(SENDER),
-            DesmosMsg::Relationships(RelationshipsMsg::unblock_user(
-                Addr::unchecked(SENDER),
-                Addr::unchecked(SENDER),
-                1,
+    fn execute_dtag_transfer_properly() {
+        let mut app = mock_stateful_desmos_app();
+        let sender = Addr::unchecked(SENDER);
+        let receiver = Addr::unchecked("receiver");
+        let save_profile = |dtag: &str, creator: &Addr| {
+            DesmosMsg::Profiles(ProfilesMsg::save_profile(
+                dtag,
+                creator.clone(),
+                "",
+                "",
+                "",
+                "",
+            ))
+        };
+        app.execute(sender.clone(), save_profile("sender_dtag", &sender).into())
+            .unwrap();
+        app.execute(receiver.clone(), save_profile("goldrake", &receiver).into())
+            .unwrap();
+
+        app.execute(
+            sender.clone(),
+            DesmosMsg::Profiles(ProfilesMsg::request_dtag_transfer(
+                sender.clone(),
+                receiver.clone(),
             ))
             .into(),
+        )
+        .unwrap();
+        let app_querier = app.wrap();
+        let querier = ProfilesQuerier::new(app_querier.deref());
+        let response = querier
+            .query_incoming_dtag_transfer_requests(receiver.clone(), None)
+            .unwrap();
+        assert_eq!(
+            vec![DtagTransferRequest {
+                dtag_to_trade: "goldrake".to_string(),
+                sender: sender.clone(),
+                receiver: receiver.clone(),
+            }],
+            response.requests
         );
-        assert!(result.is_ok());
+
+        app.execute(
+            receiver.clone(),
+            DesmosMsg::Profiles(ProfilesMsg::accept_dtag_transfer_request(
+                "new_dtag",
+                sender.clone(),
+                receiver.clone(),
+            ))
+            .into(),
+        )
+        .unwrap();
+        let app_querier = app.wrap();
+        let querier = ProfilesQuerier::new(app_querier.deref());
+        let sender_profile = querier.query_profile(sender).unwrap().profile;
+        assert_eq!("goldrake", sender_profile.dtag);
+        let receiver_profile = querier.query_profile(receiver.clone()).unwrap().profile;
+        assert_eq!("new_dtag", receiver_profile.dtag);
+        let response = querier
+            .query_incoming_dtag_transfer_requests(receiver, None)
+            .unwrap();
+        assert!(response.requests.is_empty());
     }
 
     #[test]
-    fn execute_subspaces_msg_properly() {
-        let mut app = mock_desmos_app();
+    fn execute_invalid_dtag_transfer_request_error() {
+        let mut app = mock_stateful_desmos_app();
+        let sender = Addr::unchecked(SENDER);
+        let receiver = Addr::unchecked("receiver");
+        let request_dtag_transfer = |sender: &Addr, receiver: &Addr| {
+            DesmosMsg::Profiles(ProfilesMsg::request_dtag_transfer(
+                sender.clone(),
+                receiver.clone(),
+            ))
+        };
+
         let result = app.execute(
-            Addr::unchecked(SENDER),
-            DesmosMsg::Subspaces(SubspacesMsg::delete_subspace(1, Addr::unchecked(SENDER))).into(),
+            sender.clone(),
+            request_dtag_transfer(&sender, &receiver).into(),
         );
-        assert!(result.is_ok());
+        assert!(result.is_err());
+
+        app.execute(
+            receiver.clone(),
+            DesmosMsg::Profiles(ProfilesMsg::save_profile(
+                "goldrake",
+                receiver.clone(),
+                "",
+                "",
+                "",
+                "",
+            ))
+            .into(),
+        )
+        .unwrap();
+        let result = app.execute(
+            receiver.clone(),
+            request_dtag_transfer(&receiver, &receiver).into(),
+        );
+        assert!(result.is_err());
+
+        app.execute(
+            sender.clone(),
+            request_dtag_transfer(&sender, &receiver).into(),
+        )
+        .unwrap();
+        let result = app.execute(
+            sender.clone(),
+            request_dtag_transfer(&sender, &receiver).into(),
+        );
+        assert!(result.is_err());
     }
 
     #[test]
-    fn execute_posts_msg_properly() {
-        let mut app = mock_desmos_app();
-        let result = app.execute(
-            Addr::unchecked(SENDER),
-            DesmosMsg::Posts(PostsMsg::delete_post(1, 4, Addr::unchecked(SENDER))).into(),
+    fn execute_refuse_and_cancel_dtag_transfer_request_properly() {
+        let mut app = mock_stateful_desmos_app();
+        let sender = Addr::unchecked(SENDER);
+        let receiver = Addr::unchecked("receiver");
+        app.execute(
+            receiver.clone(),
+            DesmosMsg::Profiles(ProfilesMsg::save_profile(
+                "goldrake",
+                receiver.clone(),
+                "",
+                "",
+                "",
+                "",
+            ))
+            .into(),
+        )
+        .unwrap();
+        let request_dtag_transfer = DesmosMsg::Profiles(ProfilesMsg::request_dtag_transfer(
+            sender.clone(),
+            receiver.clone(),
+        ));
+
+        app.execute(sender.clone(), request_dtag_transfer.clone().into())
+            .unwrap();
+        app.execute(
+            receiver.clone(),
+            DesmosMsg::Profiles(ProfilesMsg::refuse_dtag_transfer_request(
+                sender.clone(),
+                receiver.clone(),
+            ))
+            .into(),
+        )
+        .unwrap();
+
+        app.execute(sender.clone(), request_dtag_transfer.into())
+            .unwrap();
+        let cancel_dtag_transfer_request = DesmosMsg::Profiles(
+            ProfilesMsg::cancel_dtag_transfer_request(receiver.clone(), sender.clone()),
         );
-        assert!(result.is_ok());
+        app.execute(sender.clone(), cancel_dtag_transfer_request.clone().into())
+            .unwrap();
+        let result = app.execute(sender, cancel_dtag_transfer_request.into());
+        assert!(result.is_err());
+    }
+
+    fn link_application(user: &Addr, timeout_height: u64, timeout_timestamp: u64) -> DesmosMsg {
+        DesmosMsg::Profiles(ProfilesMsg::link_application(
+            user.clone(),
+            Data {
+                application: "twitter".to_string(),
+                username: "goldrake".to_string(),
+            },
+            "7b22757365726e616d65223a22676f6c6472616b65227d".to_string(),
+            "ibc-profiles".to_string(),
+            "channel-0".to_string(),
+            Height {
+                revision_number: Uint64::new(0),
+                revision_height: Uint64::new(timeout_height),
+            },
+            timeout_timestamp,
+        ))
+    }
+
+    fn query_application_link(app: &DesmosApp, client_id: &str) -> ApplicationLink {
+        let app_querier = app.wrap();
+        let querier = ProfilesQuerier::new(app_querier.deref());
+        querier
+            .query_application_link_by_client_id(client_id)
+            .unwrap()
+            .link
     }
 
     #[test]
-    fn execute_reports_msg_properly() {
-        let mut app = mock_desmos_app();
+    fn execute_link_application_properly() {
+        let mut app = mock_stateful_desmos_app();
+        let user = Addr::unchecked(SENDER);
+        let timeout_height = app.block_info().height + 10;
+        app.execute(
+            user.clone(),
+            link_application(&user, timeout_height, 0).into(),
+        )
+        .unwrap();
+
+        let client_id = "sender-twitter-goldrake";
+        let link = query_application_link(&app, client_id);
+        assert_eq!(ApplicationLinkState::Initialized, link.state);
+        assert_eq!(user, link.user);
+        assert_eq!(None, link.result);
+
+        app.update_desmos_block(next_block).unwrap();
+        let link = query_application_link(&app, client_id);
+        assert_eq!(ApplicationLinkState::VerificationSuccess, link.state);
+        assert_eq!(
+            MockProfilesQueries::get_mock_application_link().result,
+            link.result
+        );
+
+        app.execute(
+            user.clone(),
+            DesmosMsg::Profiles(ProfilesMsg::unlink_application(
+                "twitter",
+                "goldrake",
+                user.clone(),
+            ))
+            .into(),
+        )
+        .unwrap();
         let result = app.execute(
-            Addr::unchecked(SENDER),
-            DesmosMsg::Reports(ReportsMsg::delete_report(1, 1, Addr::unchecked(SENDER))).into(),
+            user.clone(),
+            DesmosMsg::Profiles(ProfilesMsg::unlink_application("twitter", "goldrake", user))
+                .into(),
         );
-        assert!(result.is_ok());
+        assert!(result.is_err());
     }
 
     #[test]
-    fn execute_reactions_msg_properly() {
-        let mut app = mock_desmos_app();
-        let result = app.execute(
-            Addr::unchecked(SENDER),
-            DesmosMsg::Reactions(ReactionsMsg::remove_reaction(
-                1,
-                1,
-                1,
-                Addr::unchecked(SENDER),
+    fn end_block_runs_after_update_block_properly() {
+        let mut app = mock_stateful_desmos_app();
+        let user = Addr::unchecked(SENDER);
+        let timeout_height = app.block_info().height + 10;
+        app.execute(
+            user.clone(),
+            link_application(&user, timeout_height, 0).into(),
+        )
+        .unwrap();
+        let load_link_state = |app: &DesmosApp| {
+            app.read_module(|_, _, storage| {
+                mock_state::APPLICATION_LINKS
+                    .load(storage, (&user, "twitter", "goldrake"))
+                    .unwrap()
+                    .state
+            })
+        };
+
+        // The queries of the new block see the end-blocker effects without changing the state
+        app.update_block(next_block);
+        let client_id = "sender-twitter-goldrake";
+        let link = query_application_link(&app, client_id);
+        assert_eq!(ApplicationLinkState::VerificationSuccess, link.state);
+        assert_eq!(ApplicationLinkState::Initialized, load_link_state(&app));
+
+        // The first message of the new block runs the end-blocker on the state
+        app.execute(
+            user.clone(),
+            DesmosMsg::Profiles(ProfilesMsg::save_profile(
+                "goldrake",
+                user.clone(),
+                "",
+                "",
+                "",
+                "",
             ))
             .into(),
+        )
+        .unwrap();
+        assert_eq!(
+            ApplicationLinkState::VerificationSuccess,
+            load_link_state(&app)
         );
-        assert!(result.is_ok());
     }
+
     #[test]
-    fn test_profiles_query_properly() {
-        let app = mock_desmos_app();
+    fn execute_link_application_with_custom_oracle_properly() {
+        let keeper =
+            DesmosKeeper::new_stateful().with_custom_oracle(|_, _| MockOracleResponse::Failure {
+                error: "invalid signature".to_string(),
+            });
+        let mut app = custom_desmos_app(keeper, |_, _, _| {});
+        let user = Addr::unchecked(SENDER);
+        let timeout_height = app.block_info().height + 10;
+        app.execute(
+            user.clone(),
+            link_application(&user, timeout_height, 0).into(),
+        )
+        .unwrap();
+
+        app.update_desmos_block(next_block).unwrap();
+        let link = query_application_link(&app, "sender-twitter-goldrake");
+        assert_eq!(ApplicationLinkState::VerificationError, link.state);
+        assert_eq!(
+            Some(AppLinkResult::Failed {
+                error: "invalid signature".to_string()
+            }),
+            link.result
+        );
+    }
+
+    #[test]
+    fn execute_link_application_timed_out_properly() {
+        let keeper =
+            DesmosKeeper::new_stateful().with_custom_oracle(|_, _| MockOracleResponse::Pending);
+        let mut app = custom_desmos_app(keeper, |_, _, _| {});
+        let user = Addr::unchecked(SENDER);
+        let timeout_timestamp = app.block_info().time.plus_seconds(10).nanos();
+        app.execute(
+            user.clone(),
+            link_application(&user, 0, timeout_timestamp).into(),
+        )
+        .unwrap();
+
+        app.update_desmos_block(next_block).unwrap();
+        let client_id = "sender-twitter-goldrake";
+        let link = query_application_link(&app, client_id);
+        assert_eq!(ApplicationLinkState::VerificationStarted, link.state);
+
+        app.update_desmos_block(|block| block.time = block.time.plus_seconds(10))
+            .unwrap();
+        let link = query_application_link(&app, client_id);
+        assert_eq!(ApplicationLinkState::TimedOut, link.state);
+        assert_eq!(None, link.result);
+    }
+
+    #[test]
+    fn execute_application_link_expiration_properly() {
+        let mut app = mock_stateful_desmos_app();
+        let user = Addr::unchecked(SENDER);
+        let timeout_height = app.block_info().height + 10;
+        app.execute(
+            user.clone(),
+            link_application(&user, timeout_height, 0).into(),
+        )
+        .unwrap();
+
+        app.update_desmos_block(next_block).unwrap();
+        let client_id = "sender-twitter-goldrake";
+        let link = query_application_link(&app, client_id);
+        assert_eq!(ApplicationLinkState::VerificationSuccess, link.state);
+
+        // The mock params make the links valid for one year.
+        app.update_desmos_block(|block| block.time = block.time.plus_seconds(31536000))
+            .unwrap();
         let app_querier = app.wrap();
         let querier = ProfilesQuerier::new(app_querier.deref());
-        let expected = QueryProfileResponse {
-            profile: MockProfilesQueries::get_mock_profile(),
+        assert!(querier
+            .query_application_link_by_client_id(client_id)
+            .is_err());
+
+        // The expired link has been deleted, so it can not be unlinked anymore.
+        app.execute(
+            user.clone(),
+            DesmosMsg::Profiles(ProfilesMsg::unlink_application(
+                "twitter",
+                "goldrake",
+                user.clone(),
+            ))
+            .into(),
+        )
+        .unwrap_err();
+    }
+
+    fn event_types(response: &AppResponse) -> Vec<&str> {
+        response
+            .events
+            .iter()
+            .map(|event| event.ty.as_str())
+            .collect()
+    }
+
+    fn create_post(subspace_id: u64, section_id: u32, author: &Addr) -> DesmosMsg {
+        DesmosMsg::Posts(PostsMsg::create_post(
+            subspace_id,
+            section_id,
+            None,
+            Some("Hello"),
+            None,
+            vec![],
+            None,
+            author.clone(),
+            None,
+            ReplySetting::Everyone,
+            vec![],
+        ))
+    }
+
+    fn create_poll(
+        subspace_id: u64,
+        post_id: u64,
+        end_date: Timestamp,
+        author: &Addr,
+    ) -> DesmosMsg {
+        let answer = |text: &str| ProvidedAnswer {
+            text: Some(text.to_string()),
+            attachments: vec![],
         };
-        let response = querier.query_profile(Addr::unchecked("")).unwrap();
-        assert_eq!(expected, response)
+        DesmosMsg::Posts(PostsMsg::add_post_attachment(
+            subspace_id,
+            post_id,
+            PostAttachment::Poll {
+                question: "What animal is best?".to_string(),
+                provided_answers: vec![answer("Cat"), answer("Dog"), answer("Cow")],
+                end_date: format_rfc3339_time(end_date),
+                allows_multiple_answers: true,
+                allows_answer_edits: true,
+                final_tally_results: None,
+            },
+            author.clone(),
+        ))
+    }
+
+    fn add_reaction(subspace_id: u64, post_id: u64, user: &Addr) -> DesmosMsg {
+        DesmosMsg::Reactions(ReactionsMsg::add_reaction(
+            subspace_id,
+            post_id,
+            ReactionValue::FreeText {
+                text: "👍".to_string(),
+            },
+            user.clone(),
+        ))
+    }
+
+    fn create_report(subspace_id: u64, target: ReportTarget, reporter: &Addr) -> DesmosMsg {
+        DesmosMsg::Reports(ReportsMsg::create_report(
+            subspace_id,
+            vec![1],
+            None::<String>,
+            reporter.clone(),
+            target,
+        ))
     }
 
     #[test]
-    fn test_subspaces_query_properly() {
-        let app = mock_desmos_app();
+    fn execute_poll_tally_properly() {
+        let mut app = mock_stateful_desmos_app();
+        let user = Addr::unchecked(SENDER);
+        let other = Addr::unchecked("other");
+        let end_date = app.block_info().time.plus_seconds(60);
+        app.execute(user.clone(), create_post(1, 0, &user).into())
+            .unwrap();
+        app.execute(user.clone(), create_poll(1, 1, end_date, &user).into())
+            .unwrap();
+        for (signer, answers) in [(&user, vec![0]), (&other, vec![0, 2]), (&user, vec![1])] {
+            app.execute(
+                signer.clone(),
+                DesmosMsg::Posts(PostsMsg::answer_poll(1, 1, 1, answers, signer.clone())).into(),
+            )
+            .unwrap();
+        }
+
         let app_querier = app.wrap();
-        let querier = SubspacesQuerier::new(app_querier.deref());
-        let response = querier.query_subspace(1).unwrap();
-        let expected = QuerySubspaceResponse {
-            subspace: MockSubspacesQueries::get_mock_subspace(),
+        let querier = PostsQuerier::new(app_querier.deref());
+        let answers = querier.query_poll_answers(1, 1, 1, None, None).unwrap();
+        assert_eq!(2, answers.answers.len());
+        let answers = querier
+            .query_poll_answers(1, 1, 1, Some(user.clone()), None)
+            .unwrap();
+        assert_eq!(vec![1], answers.answers[0].answers_indexes);
+        let attachments = querier.query_post_attachments(1, 1, None).unwrap();
+        match PostAttachment::try_from(attachments.attachments[0].content.clone()).unwrap() {
+            PostAttachment::Poll {
+                final_tally_results,
+                ..
+            } => assert_eq!(None, final_tally_results),
+            _ => panic!("expected a poll"),
+        }
+
+        app.update_desmos_block(|block| block.time = end_date)
+            .unwrap();
+        let app_querier = app.wrap();
+        let querier = PostsQuerier::new(app_querier.deref());
+        let attachments = querier.query_post_attachments(1, 1, None).unwrap();
+        let votes = |votes: u64, answer_index: u32| AnswerResult {
+            answer_index,
+            votes: Uint64::new(votes),
         };
-        assert_eq!(expected, response);
+        match PostAttachment::try_from(attachments.attachments[0].content.clone()).unwrap() {
+            PostAttachment::Poll {
+                final_tally_results,
+                ..
+            } => assert_eq!(
+                Some(PollTallyResults {
+                    results: vec![votes(1, 0), votes(1, 1), votes(1, 2)]
+                }),
+                final_tally_results
+            ),
+            _ => panic!("expected a poll"),
+        }
+
+        let err = app
+            .execute(
+                other.clone(),
+                DesmosMsg::Posts(PostsMsg::answer_poll(1, 1, 1, vec![1], other.clone())).into(),
+            )
+            .unwrap_err();
+        assert_eq!(
+            "the poll voting period has already ended",
+            err.root_cause().to_string()
+        );
     }
 
     #[test]
-    fn test_relationships_query_properly() {
-        let app = mock_desmos_app();
-        let app_querier = app.wrap();
-        let querier = RelationshipsQuerier::new(app_querier.deref());
-        let response = querier
-            .query_relationships(
+    fn execute_delete_subspace_cascade_properly() {
+        let mut app = mock_stateful_desmos_app();
+        let user = Addr::unchecked(SENDER);
+        let other = Addr::unchecked("other");
+        let msgs = vec![
+            DesmosMsg::Subspaces(SubspacesMsg::create_subspace(
+                "Test subspace",
+                "",
+                user.clone(),
+                user.clone(),
+                user.clone(),
+            )),
+            DesmosMsg::Subspaces(SubspacesMsg::create_section(
+                1,
+                "Test section",
+                None,
+                None,
+                user.clone(),
+            )),
+            DesmosMsg::Subspaces(SubspacesMsg::create_user_group(
                 1,
-                Some(Addr::unchecked("")),
-                Some(Addr::unchecked("")),
                 None,
+                "Test group".to_string(),
+                None,
+                vec![],
+                vec![],
+                user.clone(),
+            )),
+            create_post(1, 1, &user),
+            add_reaction(1, 1, &other),
+            create_report(
+                1,
+                ReportTarget::Post {
+                    post_id: Uint64::new(1),
+                },
+                &other,
+            ),
+            DesmosMsg::Reactions(ReactionsMsg::add_registered_reaction(
+                1,
+                ":like:",
+                "👍",
+                user.clone(),
+            )),
+            DesmosMsg::Reports(ReportsMsg::add_reason(
+                1,
+                "Spam",
+                None::<String>,
+                user.clone(),
+            )),
+            DesmosMsg::Relationships(RelationshipsMsg::create_relationship(
+                user.clone(),
+                other.clone(),
+                1,
+            )),
+            DesmosMsg::Relationships(RelationshipsMsg::create_relationship(
+                user.clone(),
+                other.clone(),
+                2,
+            )),
+            DesmosMsg::Relationships(RelationshipsMsg::block_user(
+                other.clone(),
+                user.clone(),
+                "".to_string(),
+                1,
+            )),
+        ];
+        for msg in msgs {
+            app.execute(user.clone(), msg.into()).unwrap();
+        }
+
+        let response = app
+            .execute(
+                user.clone(),
+                DesmosMsg::Subspaces(SubspacesMsg::delete_subspace(1, user)).into(),
             )
             .unwrap();
-        let expected = QueryRelationshipsResponse {
-            relationships: vec![MockRelationshipsQueries::get_mock_relationship()],
-            pagination: Default::default(),
-        };
-        assert_eq!(expected, response)
+        assert_eq!(
+            vec![
+                "delete_subspace",
+                "delete_section",
+                "delete_user_group",
+                "delete_post",
+                "remove_reaction",
+                "delete_report",
+                "remove_registered_reaction",
+                "remove_reason",
+                "delete_relationship",
+                "unblock_user",
+            ],
+            event_types(&response)
+        );
     }
 
     #[test]
-    fn test_posts_query_properly() {
-        let app = mock_desmos_app();
-        let app_querier = app.wrap();
-        let querier = PostsQuerier::new(app_querier.deref());
-        let response = querier.query_post(1, 1).unwrap();
-        let expected = QueryPostResponse {
-            post: MockPostsQueries::get_mocked_post(1u64.into(), 1u64.into()),
-        };
-        assert_eq!(expected, response)
-    }
+    fn execute_delete_post_cascade_properly() {
+        let mut app = mock_stateful_desmos_app();
+        let user = Addr::unchecked(SENDER);
+        let msgs = vec![
+            create_post(1, 0, &user),
+            DesmosMsg::Posts(PostsMsg::add_post_attachment(
+                1,
+                1,
+                PostAttachment::Media {
+                    mime_type: "image/png".to_string(),
+                    uri: "ftp://domain.io/image.png".to_string(),
+                },
+                user.clone(),
+            )),
+            DesmosMsg::Posts(PostsMsg::answer_poll(1, 1, 1, vec![0], user.clone())),
+            add_reaction(1, 1, &user),
+            create_report(
+                1,
+                ReportTarget::Post {
+                    post_id: Uint64::new(1),
+                },
+                &user,
+            ),
+            create_report(
+                1,
+                ReportTarget::User {
+                    user: Addr::unchecked("other"),
+                },
+                &user,
+            ),
+        ];
+        for msg in msgs {
+            app.execute(user.clone(), msg.into()).unwrap();
+        }
 
-    #[test]
-    fn test_reactions_query_properly() {
-        let app = mock_desmos_app();
-        let app_querier = app.wrap();
-        let querier = ReactionsQuerier::new(app_querier.deref());
-        let response = querier.query_reactions(1, 1, None, None).unwrap();
-        let expected = QueryReactionsResponse {
-            reactions: vec![MockReactionsQueries::get_mock_reaction()],
-            pagination: Default::default(),
-        };
-        assert_eq!(expected, response)
+        let response = app
+            .execute(
+                user.clone(),
+                DesmosMsg::Posts(PostsMsg::delete_post(1, 1, user.clone())).into(),
+            )
+            .unwrap();
+        assert_eq!(
+            vec![
+                "delete_post",
+                "remove_post_attachment",
+                "remove_reaction",
+                "delete_report",
+            ],
+            event_types(&response)
+        );
+
+        let response = app
+            .execute(
+                user.clone(),
+                DesmosMsg::Subspaces(SubspacesMsg::delete_subspace(1, user)).into(),
+            )
+            .unwrap();
+        assert_eq!(
+            vec!["delete_subspace", "delete_report"],
+            event_types(&response)
+        );
     }
 
     #[test]
-    fn test_reports_query_properly() {
-        let app = mock_desmos_app();
-        let app_querier = app.wrap();
-        let querier = ReportsQuerier::new(app_querier.deref());
-        let response = querier.query_report(1, 1).unwrap();
-        let expected = QueryReportResponse {
-            report: MockReportsQueries::get_mocked_report(&1u64.into()),
-        };
-        assert_eq!(expected, response)
+    fn execute_delete_section_with_posts_error() {
+        let mut app = mock_stateful_desmos_app();
+        let user = Addr::unchecked(SENDER);
+        app.execute(
+            user.clone(),
+            DesmosMsg::Subspaces(SubspacesMsg::create_section(
+                1,
+                "Test section",
+                None,
+                None,
+                user.clone(),
+            ))
+            .into(),
+        )
+        .unwrap();
+        app.execute(user.clone(), create_post(1, 1, &user).into())
+            .unwrap();
+
+        let delete_section = DesmosMsg::Subspaces(SubspacesMsg::delete_section(1, 1, user.clone()));
+        let result = app.execute(user.clone(), delete_section.clone().into());
+        assert!(result.is_err());
+
+        app.execute(
+            user.clone(),
+            DesmosMsg::Posts(PostsMsg::delete_post(1, 1, user.clone())).into(),
+        )
+        .unwrap();
+        app.execute(user, delete_section.into()).unwrap();
     }
 
     #[test]
-    fn failing_app_excute_error() {
-        let mut app = mock_failing_desmos_app();
-        let result = app.execute(
-            Addr::unchecked(SENDER),
-            DesmosMsg::Profiles(ProfilesMsg::delete_profile(Addr::unchecked(SENDER))).into(),
+    #[cfg(feature = "desmos-v5")]
+    fn execute_move_missing_post_error() {
+        let mut app = mock_stateful_desmos_app();
+        let user = Addr::unchecked(SENDER);
+        app.execute(user.clone(), create_post(1, 0, &user).into())
+            .unwrap();
+
+        let err = app
+            .execute(
+                user.clone(),
+                DesmosMsg::Posts(PostsMsg::move_post(1, 2, 1, 0, user.clone())).into(),
+            )
+            .unwrap_err();
+        assert_eq!("post with id 2 not found", err.root_cause().to_string());
+
+        // The failed move must not use up an id inside the target subspace
+        let response = app
+            .execute(
+                user.clone(),
+                DesmosMsg::Posts(PostsMsg::move_post(1, 1, 1, 0, user)).into(),
+            )
+            .unwrap();
+        assert_eq!(
+            Some("2"),
+            response
+                .events
+                .iter()
+                .find(|event| event.ty == "move_post")
+                .unwrap()
+                .attributes
+                .iter()
+                .find(|attribute| attribute.key == "new_post_id")
+                .map(|attribute| attribute.value.as_str())
         );
-        assert!(result.is_err());
     }
 
     #[test]
-    fn failing_app_query_error() {
-        let app = mock_failing_desmos_app();
-        let app_querier = app.wrap();
-        let querier = ProfilesQuerier::new(app_querier.deref());
-        let result = querier.query_profile(Addr::unchecked(""));
+    #[cfg(feature = "desmos-v5")]
+    fn execute_post_owner_transfer_properly() {
+        let mut app = mock_stateful_desmos_app();
+        let sender = Addr::unchecked(SENDER);
+        let receiver = Addr::unchecked("receiver");
+        app.execute(sender.clone(), create_post(1, 0, &sender).into())
+            .unwrap();
+
+        app.execute(
+            sender.clone(),
+            DesmosMsg::Posts(PostsMsg::request_post_owner_transfer(
+                1,
+                1,
+                receiver.clone(),
+                sender.clone(),
+            ))
+            .into(),
+        )
+        .unwrap();
+        let app_querier = app.wrap();
+        let querier = PostsQuerier::new(app_querier.deref());
+        let response = querier
+            .query_incoming_post_owner_transfer_requests(1, Some(receiver.clone()), None)
+            .unwrap();
+        assert_eq!(
+            vec![PostOwnerTransferRequest {
+                subspace_id: Uint64::new(1),
+                post_id: Uint64::new(1),
+                sender: sender.clone(),
+                receiver: receiver.clone(),
+            }],
+            response.requests
+        );
+        let response = querier
+            .query_incoming_post_owner_transfer_requests(1, Some(sender.clone()), None)
+            .unwrap();
+        assert!(response.requests.is_empty());
+
+        app.execute(
+            receiver.clone(),
+            DesmosMsg::Posts(PostsMsg::accept_post_owner_transfer_request(
+                1,
+                1,
+                receiver.clone(),
+            ))
+            .into(),
+        )
+        .unwrap();
+        let app_querier = app.wrap();
+        let querier = PostsQuerier::new(app_querier.deref());
+        let post = querier.query_post(1, 1).unwrap().post;
+        assert_eq!(receiver, post.owner);
+        let response = querier
+            .query_incoming_post_owner_transfer_requests(1, None, None)
+            .unwrap();
+        assert!(response.requests.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "desmos-v5")]
+    fn execute_missing_post_owner_transfer_request_error() {
+        let mut app = mock_stateful_desmos_app();
+        let sender = Addr::unchecked(SENDER);
+        let receiver = Addr::unchecked("receiver");
+        app.execute(sender.clone(), create_post(1, 0, &sender).into())
+            .unwrap();
+        let msgs = vec![
+            (
+                &sender,
+                PostsMsg::cancel_post_owner_transfer_request(1, 1, sender.clone()),
+            ),
+            (
+                &receiver,
+                PostsMsg::accept_post_owner_transfer_request(1, 1, receiver.clone()),
+            ),
+            (
+                &receiver,
+                PostsMsg::refuse_post_owner_transfer_request(1, 1, receiver.clone()),
+            ),
+        ];
+
+        for (signer, msg) in msgs.clone() {
+            let err = app
+                .execute(signer.clone(), DesmosMsg::Posts(msg).into())
+                .unwrap_err();
+            assert_eq!(
+                "post owner transfer request for post with id 1 not found",
+                err.root_cause().to_string()
+            );
+        }
+        let post = PostsQuerier::new(app.wrap().deref())
+            .query_post(1, 1)
+            .unwrap()
+            .post;
+        assert_eq!(sender, post.owner);
+
+        // Only the owner can send a request, and only the receiver can answer it
+        let result = app.execute(
+            receiver.clone(),
+            DesmosMsg::Posts(PostsMsg::request_post_owner_transfer(
+                1,
+                1,
+                receiver.clone(),
+                receiver.clone(),
+            ))
+            .into(),
+        );
+        assert!(result.is_err());
+        app.execute(
+            sender.clone(),
+            DesmosMsg::Posts(PostsMsg::request_post_owner_transfer(
+                1,
+                1,
+                receiver.clone(),
+                sender.clone(),
+            ))
+            .into(),
+        )
+        .unwrap();
+        let result = app.execute(
+            sender.clone(),
+            DesmosMsg::Posts(PostsMsg::accept_post_owner_transfer_request(
+                1,
+                1,
+                sender.clone(),
+            ))
+            .into(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn execute_delete_profile_cascade_properly() {
+        let mut app = mock_stateful_desmos_app();
+        let user = Addr::unchecked(SENDER);
+        let other = Addr::unchecked("other");
+        let msgs = vec![
+            DesmosMsg::Profiles(ProfilesMsg::save_profile(
+                "goldrake",
+                user.clone(),
+                "",
+                "",
+                "",
+                "",
+            )),
+            DesmosMsg::Relationships(RelationshipsMsg::create_relationship(
+                user.clone(),
+                other.clone(),
+                1,
+            )),
+            DesmosMsg::Relationships(RelationshipsMsg::create_relationship(
+                other.clone(),
+                user.clone(),
+                1,
+            )),
+            DesmosMsg::Relationships(RelationshipsMsg::create_relationship(
+                other.clone(),
+                Addr::unchecked("third"),
+                1,
+            )),
+            DesmosMsg::Relationships(RelationshipsMsg::block_user(
+                other,
+                user.clone(),
+                "".to_string(),
+                1,
+            )),
+        ];
+        for msg in msgs {
+            app.execute(user.clone(), msg.into()).unwrap();
+        }
+
+        let response = app
+            .execute(
+                user.clone(),
+                DesmosMsg::Profiles(ProfilesMsg::delete_profile(user)).into(),
+            )
+            .unwrap();
+        assert_eq!(
+            vec![
+                "delete_profile",
+                "delete_relationship",
+                "delete_relationship",
+                "unblock_user",
+            ],
+            event_types(&response)
+        );
+    }
+
+    #[test]
+    fn execute_relationships_msg_properly() {
+        let mut app = mock_desmos_app();
+        let result = app.execute(
+            Addr::unchecked(SENDER),
+            DesmosMsg::Relationships(RelationshipsMsg::unblock_user(
+                Addr::unchecked(SENDER),
+                Addr::unchecked(SENDER),
+                1,
+            ))
+            .into(),
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn execute_subspaces_msg_properly() {
+        let mut app = mock_desmos_app();
+        let result = app.execute(
+            Addr::unchecked(SENDER),
+            DesmosMsg::Subspaces(SubspacesMsg::delete_subspace(1, Addr::unchecked(SENDER))).into(),
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn execute_posts_msg_properly() {
+        let mut app = mock_desmos_app();
+        let result = app.execute(
+            Addr::unchecked(SENDER),
+            DesmosMsg::Posts(PostsMsg::delete_post(1, 4, Addr::unchecked(SENDER))).into(),
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn execute_reports_msg_properly() {
+        let mut app = mock_desmos_app();
+        let result = app.execute(
+            Addr::unchecked(SENDER),
+            DesmosMsg::Reports(ReportsMsg::delete_report(1, 1, Addr::unchecked(SENDER))).into(),
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn execute_reactions_msg_properly() {
+        let mut app = mock_desmos_app();
+        let result = app.execute(
+            Addr::unchecked(SENDER),
+            DesmosMsg::Reactions(ReactionsMsg::remove_reaction(
+                1,
+                1,
+                1,
+                Addr::unchecked(SENDER),
+            ))
+            .into(),
+        );
+        assert!(result.is_ok());
+    }
+    #[test]
+    #[cfg(feature = "tokenfactory")]
+    fn execute_tokenfactory_msg_properly() {
+        let mut app = mock_stateful_desmos_app();
+        let denom = MockTokenFactoryQueries::get_mock_denom();
+
+        let result = app.execute(
+            Addr::unchecked(SENDER),
+            DesmosMsg::TokenFactory(TokenFactoryMsg::mint(
+                Addr::unchecked(SENDER),
+                1,
+                coin(100, &denom),
+                Addr::unchecked(SENDER),
+            ))
+            .into(),
+        );
+        assert!(result.is_ok());
+        let balance = app.wrap().query_balance(SENDER, &denom).unwrap();
+        assert_eq!(coin(100, &denom), balance);
+
+        let result = app.execute(
+            Addr::unchecked(SENDER),
+            DesmosMsg::TokenFactory(TokenFactoryMsg::burn(
+                Addr::unchecked(SENDER),
+                1,
+                coin(40, &denom),
+                Addr::unchecked(SENDER),
+            ))
+            .into(),
+        );
+        assert!(result.is_ok());
+        let balance = app.wrap().query_balance(SENDER, &denom).unwrap();
+        assert_eq!(coin(60, &denom), balance);
+
+        // Burning more tokens than the available ones must fail
+        let result = app.execute(
+            Addr::unchecked(SENDER),
+            DesmosMsg::TokenFactory(TokenFactoryMsg::burn(
+                Addr::unchecked(SENDER),
+                1,
+                coin(100, &denom),
+                Addr::unchecked(SENDER),
+            ))
+            .into(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "tokenfactory")]
+    fn execute_create_denom_properly() {
+        let mut app = mock_stateful_desmos_app();
+        let sender = Addr::unchecked(SENDER);
+        let treasury = Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3");
+
+        // Denoms can't be created for subspaces that do not exist
+        let err = app
+            .execute(
+                sender.clone(),
+                DesmosMsg::TokenFactory(TokenFactoryMsg::create_denom(
+                    sender.clone(),
+                    1,
+                    "minttoken",
+                ))
+                .into(),
+            )
+            .unwrap_err();
+        assert_eq!("subspace with id 1 not found", err.root_cause().to_string());
+
+        // Denoms can't be created for subspaces without a treasury
+        app.execute(
+            sender.clone(),
+            DesmosMsg::Subspaces(SubspacesMsg::create_subspace(
+                "test",
+                "",
+                Addr::unchecked(""),
+                sender.clone(),
+                sender.clone(),
+            ))
+            .into(),
+        )
+        .unwrap();
+        let err = app
+            .execute(
+                sender.clone(),
+                DesmosMsg::TokenFactory(TokenFactoryMsg::create_denom(
+                    sender.clone(),
+                    1,
+                    "minttoken",
+                ))
+                .into(),
+            )
+            .unwrap_err();
+        assert_eq!(
+            "subspace with id 1 has no treasury",
+            err.root_cause().to_string()
+        );
+
+        // The denom belongs to the subspace treasury rather than to the sender
+        app.execute(
+            sender.clone(),
+            DesmosMsg::Subspaces(SubspacesMsg::edit_subspace(
+                1,
+                "[do-not-modify]",
+                "[do-not-modify]",
+                treasury,
+                Addr::unchecked("[do-not-modify]"),
+                sender.clone(),
+            ))
+            .into(),
+        )
+        .unwrap();
+        let response = app
+            .execute(
+                sender.clone(),
+                DesmosMsg::TokenFactory(TokenFactoryMsg::create_denom(
+                    sender.clone(),
+                    1,
+                    "minttoken",
+                ))
+                .into(),
+            )
+            .unwrap();
+        let event = response
+            .events
+            .iter()
+            .find(|event| event.ty == "create_denom")
+            .unwrap();
+        assert!(event
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "new_token_denom"
+                && attribute.value == MockTokenFactoryQueries::get_mock_denom()));
+
+        // The same denom can't be created twice
+        let err = app
+            .execute(
+                sender.clone(),
+                DesmosMsg::TokenFactory(TokenFactoryMsg::create_denom(sender, 1, "minttoken"))
+                    .into(),
+            )
+            .unwrap_err();
+        assert_eq!(
+            format!(
+                "denom {} already exists",
+                MockTokenFactoryQueries::get_mock_denom()
+            ),
+            err.root_cause().to_string()
+        );
+    }
+
+    #[test]
+    fn test_profiles_query_properly() {
+        let app = mock_desmos_app();
+        let app_querier = app.wrap();
+        let querier = ProfilesQuerier::new(app_querier.deref());
+        let expected = QueryProfileResponse {
+            profile: MockProfilesQueries::get_mock_profile(),
+        };
+        let response = querier.query_profile(Addr::unchecked("")).unwrap();
+        assert_eq!(expected, response)
+    }
+
+    #[test]
+    fn test_stateful_profiles_query_properly() {
+        let mut app = mock_stateful_desmos_app();
+        let user = Addr::unchecked(SENDER);
+
+        // Profiles that have not been created are not found
+        let err = ProfilesQuerier::new(app.wrap().deref())
+            .query_profile(user.clone())
+            .unwrap_err();
+        assert!(err.to_string().contains("profile not found"));
+
+        app.execute(
+            user.clone(),
+            DesmosMsg::Profiles(ProfilesMsg::save_profile(
+                "goldrake",
+                user.clone(),
+                "Goldrake",
+                "",
+                "",
+                "",
+            ))
+            .into(),
+        )
+        .unwrap();
+        let app_querier = app.wrap();
+        let querier = ProfilesQuerier::new(app_querier.deref());
+        let profile = querier.query_profile(user.clone()).unwrap().profile;
+        assert_eq!(user, profile.account.address);
+        assert_eq!("goldrake", profile.dtag);
+        assert_eq!("Goldrake", profile.nickname);
+        assert_eq!(
+            profile,
+            querier
+                .query_profile(Addr::unchecked("goldrake"))
+                .unwrap()
+                .profile
+        );
+
+        // Deleted profiles are not returned anymore
+        app.execute(
+            user.clone(),
+            DesmosMsg::Profiles(ProfilesMsg::delete_profile(user.clone())).into(),
+        )
+        .unwrap();
+        let err = ProfilesQuerier::new(app.wrap().deref())
+            .query_profile(user)
+            .unwrap_err();
+        assert!(err.to_string().contains("profile not found"));
+    }
+
+    #[test]
+    fn test_subspaces_query_properly() {
+        let app = mock_desmos_app();
+        let app_querier = app.wrap();
+        let querier = SubspacesQuerier::new(app_querier.deref());
+        let response = querier.query_subspace(1).unwrap();
+        let expected = QuerySubspaceResponse {
+            subspace: MockSubspacesQueries::get_mock_subspace(),
+        };
+        assert_eq!(expected, response);
+    }
+
+    #[test]
+    fn test_stateful_subspaces_query_properly() {
+        let mut app = mock_stateful_desmos_app();
+        let user = Addr::unchecked(SENDER);
+        let msgs = vec![
+            SubspacesMsg::create_subspace("Test", "", user.clone(), user.clone(), user.clone()),
+            SubspacesMsg::create_section(1, "Test section", None, None, user.clone()),
+            SubspacesMsg::create_user_group(
+                1,
+                Some(1),
+                "Admins".to_string(),
+                None,
+                vec![],
+                vec![],
+                user.clone(),
+            ),
+        ];
+        for msg in msgs {
+            app.execute(user.clone(), DesmosMsg::Subspaces(msg).into())
+                .unwrap();
+        }
+
+        {
+            let app_querier = app.wrap();
+            let querier = SubspacesQuerier::new(app_querier.deref());
+            let subspace = querier.query_subspace(1).unwrap().subspace;
+            assert_eq!("Test", subspace.name);
+            assert_eq!(user, subspace.owner);
+            assert_eq!(
+                vec![subspace],
+                querier.query_subspaces(None).unwrap().subspaces
+            );
+            let section = querier.query_section(1, 1).unwrap().section;
+            assert_eq!("Test section", section.name);
+            assert_eq!(
+                vec![section],
+                querier.query_sections(1, None).unwrap().sections
+            );
+            let group = querier.query_user_group(1, 1).unwrap().group;
+            assert_eq!("Admins", group.name);
+            assert_eq!(
+                vec![group],
+                querier.query_user_groups(1, Some(1), None).unwrap().groups
+            );
+            assert!(querier
+                .query_user_groups(1, Some(2), None)
+                .unwrap()
+                .groups
+                .is_empty());
+        }
+
+        // Deleted subspaces and their contents are not returned anymore
+        app.execute(
+            user.clone(),
+            DesmosMsg::Subspaces(SubspacesMsg::delete_subspace(1, user)).into(),
+        )
+        .unwrap();
+        let app_querier = app.wrap();
+        let querier = SubspacesQuerier::new(app_querier.deref());
+        let err = querier.query_subspace(1).unwrap_err();
+        assert!(err.to_string().contains("subspace with id 1 not found"));
+        let err = querier.query_section(1, 1).unwrap_err();
+        assert!(err.to_string().contains("section with id 1 not found"));
+        let err = querier.query_user_group(1, 1).unwrap_err();
+        assert!(err.to_string().contains("group with id 1 not found"));
+        assert!(querier.query_subspaces(None).unwrap().subspaces.is_empty());
+        assert!(querier.query_sections(1, None).unwrap().sections.is_empty());
+        assert!(querier
+            .query_user_groups(1, None, None)
+            .unwrap()
+            .groups
+            .is_empty());
+    }
+
+    #[test]
+    fn test_relationships_query_properly() {
+        let app = mock_desmos_app();
+        let app_querier = app.wrap();
+        let querier = RelationshipsQuerier::new(app_querier.deref());
+        let response = querier
+            .query_relationships(
+                1,
+                Some(Addr::unchecked("")),
+                Some(Addr::unchecked("")),
+                None,
+            )
+            .unwrap();
+        let expected = QueryRelationshipsResponse {
+            relationships: vec![MockRelationshipsQueries::get_mock_relationship()],
+            pagination: Default::default(),
+        };
+        assert_eq!(expected, response)
+    }
+
+    #[test]
+    fn test_posts_query_properly() {
+        let app = mock_desmos_app();
+        let app_querier = app.wrap();
+        let querier = PostsQuerier::new(app_querier.deref());
+        let response = querier.query_post(1, 1).unwrap();
+        let expected = QueryPostResponse {
+            post: MockPostsQueries::get_mocked_post(1u64.into(), 1u64.into()),
+        };
+        assert_eq!(expected, response)
+    }
+
+    #[test]
+    fn test_stateful_posts_query_properly() {
+        let mut app = mock_stateful_desmos_app();
+        let user = Addr::unchecked(SENDER);
+        app.execute(user.clone(), create_post(1, 0, &user).into())
+            .unwrap();
+        app.execute(user.clone(), create_post(1, 1, &user).into())
+            .unwrap();
+
+        {
+            let app_querier = app.wrap();
+            let querier = PostsQuerier::new(app_querier.deref());
+            let post = querier.query_post(1, 1).unwrap().post;
+            assert_eq!(Uint64::new(1), post.id);
+            assert_eq!(Some("Hello".to_string()), post.text);
+            assert_eq!(user, post.author);
+            assert_eq!(
+                2,
+                querier.query_subspace_posts(1, None).unwrap().posts.len()
+            );
+            assert_eq!(
+                vec![post],
+                querier.query_section_posts(1, 0, None).unwrap().posts
+            );
+        }
+
+        // Deleted posts are not returned anymore
+        app.execute(
+            user.clone(),
+            DesmosMsg::Posts(PostsMsg::delete_post(1, 1, user)).into(),
+        )
+        .unwrap();
+        let app_querier = app.wrap();
+        let querier = PostsQuerier::new(app_querier.deref());
+        let err = querier.query_post(1, 1).unwrap_err();
+        assert!(err.to_string().contains("post with id 1 not found"));
+        let posts = querier.query_subspace_posts(1, None).unwrap().posts;
+        assert_eq!(
+            vec![Uint64::new(2)],
+            posts.iter().map(|post| post.id).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_reactions_query_properly() {
+        let app = mock_desmos_app();
+        let app_querier = app.wrap();
+        let querier = ReactionsQuerier::new(app_querier.deref());
+        let response = querier.query_reactions(1, 1, None, None).unwrap();
+        let expected = QueryReactionsResponse {
+            reactions: vec![MockReactionsQueries::get_mock_reaction()],
+            pagination: Default::default(),
+        };
+        assert_eq!(expected, response)
+    }
+
+    #[test]
+    fn test_stateful_reactions_query_properly() {
+        let mut app = mock_stateful_desmos_app();
+        let user = Addr::unchecked(SENDER);
+        let other = Addr::unchecked("other");
+        let msgs = vec![
+            create_post(1, 0, &user),
+            add_reaction(1, 1, &user),
+            add_reaction(1, 1, &other),
+            DesmosMsg::Reactions(ReactionsMsg::add_registered_reaction(
+                1,
+                ":like:",
+                "👍",
+                user.clone(),
+            )),
+        ];
+        for msg in msgs {
+            app.execute(user.clone(), msg.into()).unwrap();
+        }
+
+        {
+            let app_querier = app.wrap();
+            let querier = ReactionsQuerier::new(app_querier.deref());
+            let reaction = querier.query_reaction(1, 1, 1).unwrap().reaction;
+            assert_eq!(user, reaction.author);
+            assert_eq!(
+                ReactionValue::FreeText {
+                    text: "👍".to_string()
+                },
+                ReactionValue::try_from(reaction.value.clone()).unwrap()
+            );
+            assert_eq!(
+                2,
+                querier
+                    .query_reactions(1, 1, None, None)
+                    .unwrap()
+                    .reactions
+                    .len()
+            );
+            assert_eq!(
+                vec![reaction],
+                querier
+                    .query_reactions(1, 1, Some(user.clone()), None)
+                    .unwrap()
+                    .reactions
+            );
+            let registered_reaction = querier
+                .query_registered_reaction(1, 1)
+                .unwrap()
+                .registered_reaction;
+            assert_eq!(":like:", registered_reaction.shorthand_code);
+            assert_eq!(
+                vec![registered_reaction],
+                querier
+                    .query_registered_reactions(1, None)
+                    .unwrap()
+                    .registered_reactions
+            );
+        }
+
+        // Reactions of deleted posts are not returned anymore
+        let msgs = vec![
+            DesmosMsg::Posts(PostsMsg::delete_post(1, 1, user.clone())),
+            DesmosMsg::Reactions(ReactionsMsg::remove_registered_reaction(1, 1, user.clone())),
+        ];
+        for msg in msgs {
+            app.execute(user.clone(), msg.into()).unwrap();
+        }
+        let app_querier = app.wrap();
+        let querier = ReactionsQuerier::new(app_querier.deref());
+        let err = querier.query_reaction(1, 1, 1).unwrap_err();
+        assert!(err.to_string().contains("reaction with id 1 not found"));
+        assert!(querier
+            .query_reactions(1, 1, None, None)
+            .unwrap()
+            .reactions
+            .is_empty());
+        let err = querier.query_registered_reaction(1, 1).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("registered reaction with id 1 not found"));
+    }
+
+    #[test]
+    fn test_reports_query_properly() {
+        let app = mock_desmos_app();
+        let app_querier = app.wrap();
+        let querier = ReportsQuerier::new(app_querier.deref());
+        let response = querier.query_report(1, 1).unwrap();
+        let expected = QueryReportResponse {
+            report: MockReportsQueries::get_mocked_report(&1u64.into()),
+        };
+        assert_eq!(expected, response)
+    }
+
+    #[test]
+    fn test_stateful_reports_query_properly() {
+        let mut app = mock_stateful_desmos_app();
+        let user = Addr::unchecked(SENDER);
+        let post_target = ReportTarget::Post {
+            post_id: Uint64::new(1),
+        };
+        let msgs = vec![
+            DesmosMsg::Reports(ReportsMsg::add_reason(
+                1,
+                "Spam",
+                None::<String>,
+                user.clone(),
+            )),
+            DesmosMsg::Reports(ReportsMsg::support_standard_reason(1, 2, user.clone())),
+            create_post(1, 0, &user),
+            create_report(1, post_target.clone(), &user),
+            create_report(
+                1,
+                ReportTarget::User {
+                    user: Addr::unchecked("other"),
+                },
+                &user,
+            ),
+        ];
+        for msg in msgs {
+            app.execute(user.clone(), msg.into()).unwrap();
+        }
+
+        {
+            let app_querier = app.wrap();
+            let querier = ReportsQuerier::new(app_querier.deref());
+            let report = querier.query_report(1, 1).unwrap().report;
+            assert_eq!(vec![1], report.reasons_ids);
+            assert_eq!(
+                post_target,
+                ReportTarget::try_from(report.target.clone()).unwrap()
+            );
+            assert_eq!(
+                vec![report],
+                querier
+                    .query_reports(1, Some(post_target), None, None)
+                    .unwrap()
+                    .reports
+            );
+            assert_eq!(
+                2,
+                querier
+                    .query_reports(1, None, None, None)
+                    .unwrap()
+                    .reports
+                    .len()
+            );
+            assert_eq!("Spam", querier.query_reason(1, 1).unwrap().reason.title);
+            let reasons = querier.query_reasons(1, None).unwrap().reasons;
+            assert_eq!(
+                vec!["Spam", "Scam"],
+                reasons
+                    .iter()
+                    .map(|reason| reason.title.as_str())
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        // Reports about deleted posts and removed reasons are not returned anymore
+        let msgs = vec![
+            DesmosMsg::Posts(PostsMsg::delete_post(1, 1, user.clone())),
+            DesmosMsg::Reports(ReportsMsg::remove_reason(1, 1, user.clone())),
+        ];
+        for msg in msgs {
+            app.execute(user.clone(), msg.into()).unwrap();
+        }
+        let app_querier = app.wrap();
+        let querier = ReportsQuerier::new(app_querier.deref());
+        let err = querier.query_report(1, 1).unwrap_err();
+        assert!(err.to_string().contains("report with id 1 not found"));
+        assert_eq!(
+            1,
+            querier
+                .query_reports(1, None, None, None)
+                .unwrap()
+                .reports
+                .len()
+        );
+        let err = querier.query_reason(1, 1).unwrap_err();
+        assert!(err.to_string().contains("reason with id 1 not found"));
+        assert_eq!(1, querier.query_reasons(1, None).unwrap().reasons.len());
+    }
+
+    #[test]
+    fn test_supply_query_properly() {
+        let app = mock_stateful_desmos_app();
+        let app_querier = app.wrap();
+        let querier = SupplyQuerier::new(app_querier.deref());
+        let response = querier.query_total("udsm", 6).unwrap();
+        let expected = QueryTotalResponse {
+            total_supply: MockSupplyQueries::get_mock_total_supply(),
+        };
+        assert_eq!(expected, response)
+    }
+
+    #[test]
+    #[cfg(feature = "tokenfactory")]
+    fn test_tokenfactory_query_properly() {
+        let mut app = mock_stateful_desmos_app();
+        let sender = Addr::unchecked(SENDER);
+        app.execute(
+            sender.clone(),
+            DesmosMsg::Subspaces(SubspacesMsg::create_subspace(
+                "test",
+                "",
+                Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3"),
+                sender.clone(),
+                sender.clone(),
+            ))
+            .into(),
+        )
+        .unwrap();
+        app.execute(
+            sender.clone(),
+            DesmosMsg::TokenFactory(TokenFactoryMsg::create_denom(sender, 1, "minttoken")).into(),
+        )
+        .unwrap();
+
+        let app_querier = app.wrap();
+        let querier = TokenFactoryQuerier::new(app_querier.deref());
+        let response = querier.query_subspace_denoms(1).unwrap();
+        let expected = QuerySubspaceDenomsResponse {
+            denoms: vec![MockTokenFactoryQueries::get_mock_denom()],
+        };
+        assert_eq!(expected, response);
+        let response = querier.query_subspace_denoms(2).unwrap();
+        assert!(response.denoms.is_empty());
+    }
+
+    #[test]
+    fn execute_nth_msg_failure_properly() {
+        let keeper = DesmosKeeper::new_stateful()
+            .with_msg_failure(MockFailure::at(2, DesmosError::ProfileNotFound));
+        let mut app = custom_desmos_app(keeper, |_, _, _| {});
+        let user = Addr::unchecked(SENDER);
+        let msg = DesmosMsg::Profiles(ProfilesMsg::delete_profile(user.clone()));
+
+        app.execute(user.clone(), msg.clone().into()).unwrap();
+        let err = app.execute(user.clone(), msg.clone().into()).unwrap_err();
+        assert_eq!("profile not found", err.root_cause().to_string());
+        app.execute(user, msg.into()).unwrap();
+    }
+
+    #[test]
+    fn execute_matching_msg_failure_properly() {
+        let keeper = DesmosKeeper::new_stateful().with_msg_failure(MockFailure::matching(
+            |msg| {
+                matches!(
+                    msg,
+                    DesmosMsg::Subspaces(SubspacesMsg::DeleteSubspace { subspace_id, .. })
+                        if subspace_id.u64() == 5
+                )
+            },
+            DesmosError::SubspaceNotFound(5),
+        ));
+        let mut app = custom_desmos_app(keeper, |_, _, _| {});
+        let user = Addr::unchecked(SENDER);
+
+        let err = app
+            .execute(
+                user.clone(),
+                DesmosMsg::Subspaces(SubspacesMsg::delete_subspace(5, user.clone())).into(),
+            )
+            .unwrap_err();
+        assert_eq!("subspace with id 5 not found", err.root_cause().to_string());
+        app.execute(
+            user.clone(),
+            DesmosMsg::Subspaces(SubspacesMsg::delete_subspace(1, user)).into(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn query_failure_properly() {
+        let keeper = DesmosKeeper::new_stateful().with_query_failure(MockFailure::matching(
+            |query| matches!(query, DesmosQuery::Profiles(_)),
+            DesmosError::ProfileNotFound,
+        ));
+        let app = custom_desmos_app(keeper, |_, _, _| {});
+        let app_querier = app.wrap();
+
+        let err = ProfilesQuerier::new(app_querier.deref())
+            .query_profile(Addr::unchecked(SENDER))
+            .unwrap_err();
+        assert!(err.to_string().contains("profile not found"));
+        SubspacesQuerier::new(app_querier.deref())
+            .query_subspaces(None)
+            .unwrap();
+    }
+
+    #[test]
+    fn injected_and_natural_errors_match_the_chain_properly() {
+        let keeper = DesmosKeeper::new_stateful().with_query_failure(MockFailure::matching(
+            |query| {
+                matches!(
+                    query,
+                    DesmosQuery::Subspaces(SubspacesQuery::Subspace { subspace_id })
+                        if subspace_id.u64() == 5
+                )
+            },
+            DesmosError::SubspaceNotFound(5),
+        ));
+        let app = custom_desmos_app(keeper, |_, _, _| {});
+        let app_querier = app.wrap();
+        let querier = SubspacesQuerier::new(app_querier.deref());
+
+        // The injected error is returned for the subspace 5, while the keeper fails on its own
+        // for the subspace 6 that does not exist, and both use the message of the chain.
+        assert_eq!(
+            "Generic error: Querier contract error: subspace with id 5 not found",
+            querier.query_subspace(5).unwrap_err().to_string()
+        );
+        assert_eq!(
+            "Generic error: Querier contract error: subspace with id 6 not found",
+            querier.query_subspace(6).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn recorder_records_msgs_and_queries_properly() {
+        let recorder = MockRecorder::new();
+        let keeper = DesmosKeeper::new_stateful().with_recorder(recorder.clone());
+        let user = Addr::unchecked(SENDER);
+        let mut profile = MockProfilesQueries::get_mock_profile();
+        profile.account.address = user.clone();
+        profile.dtag = "sender".to_string();
+        let mut app = custom_desmos_app(keeper, |_, _, storage| {
+            mock_state::PROFILES.save(storage, &user, &profile).unwrap();
+        });
+
+        app.execute(user.clone(), create_post(1, 0, &user).into())
+            .unwrap();
+        app.update_desmos_block(next_block).unwrap();
+        app.execute(
+            user.clone(),
+            DesmosMsg::Posts(PostsMsg::delete_post(1, 1, user.clone())).into(),
+        )
+        .unwrap();
+        let app_querier = app.wrap();
+        ProfilesQuerier::new(app_querier.deref())
+            .query_profile(user.clone())
+            .unwrap();
+
+        recorder.assert_dispatched(|msg| {
+            matches!(
+                msg,
+                DesmosMsg::Posts(PostsMsg::CreatePost { author, .. }) if author == &user
+            )
+        });
+        recorder.assert_not_dispatched(|msg| matches!(msg, DesmosMsg::Subspaces(_)));
+        recorder.assert_msg_count(|msg| matches!(msg, DesmosMsg::Posts(_)), 2);
+        recorder.assert_query_count(
+            |query| matches!(query, DesmosQuery::Profiles(ProfilesQuery::Profile { .. })),
+            1,
+        );
+
+        let msgs = recorder.msgs();
+        assert_eq!(msgs[0].height + 1, msgs[1].height);
+        assert_eq!(user, msgs[1].sender);
+        assert_eq!(Some(msgs[1].height), recorder.queries()[0].height);
+
+        recorder.clear();
+        assert!(recorder.msgs().is_empty());
+        assert!(recorder.queries().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn recorder_assert_dispatched_panics_without_matching_msgs() {
+        let recorder = MockRecorder::new();
+        recorder.assert_dispatched(|msg| matches!(msg, DesmosMsg::Posts(_)));
+    }
+
+    #[test]
+    fn snapshot_and_restore_desmos_state_properly() {
+        let mut app = mock_stateful_desmos_app();
+        let user = Addr::unchecked(SENDER);
+        app.execute(user.clone(), create_post(1, 0, &user).into())
+            .unwrap();
+        let snapshot = snapshot_desmos_state(&app).unwrap();
+        assert!(snapshot
+            .entries
+            .iter()
+            .all(|entry| entry.namespace.starts_with("desmos_")));
+
+        // Deleting the post makes the section deletable.
+        let delete_section =
+            || DesmosMsg::Subspaces(SubspacesMsg::delete_section(1, 0, user.clone())).into();
+        app.execute(
+            user.clone(),
+            DesmosMsg::Posts(PostsMsg::delete_post(1, 1, user.clone())).into(),
+        )
+        .unwrap();
+        let mut branch = snapshot_desmos_state(&app).unwrap();
+        assert_ne!(snapshot, branch);
+
+        // Restoring the snapshot brings the post back.
+        let json = snapshot.to_json().unwrap();
+        restore_desmos_state(&mut app, &DesmosStateSnapshot::from_json(&json).unwrap()).unwrap();
+        assert_eq!(snapshot, snapshot_desmos_state(&app).unwrap());
+        app.execute(user.clone(), delete_section()).unwrap_err();
+        let response = app
+            .execute(user.clone(), create_post(1, 0, &user).into())
+            .unwrap();
+        assert!(response.has_event(&Event::new("create_post").add_attribute("post_id", "2")));
+
+        // The other branch can be restored as well.
+        branch
+            .entries
+            .retain(|entry| entry.namespace != "desmos_posts");
+        restore_desmos_state(&mut app, &branch).unwrap();
+        app.execute(user.clone(), delete_section()).unwrap();
+    }
+
+    /// Events recorded by bindings-test from the Desmos version targeted by the enabled features.
+    #[cfg(not(feature = "desmos-v5"))]
+    const GOLDEN_EVENTS: &str = include_str!("../../testdata/events_v4.json");
+    #[cfg(feature = "desmos-v5")]
+    const GOLDEN_EVENTS: &str = include_str!("../../testdata/events_v5.json");
+
+    #[test]
+    fn execute_msgs_emit_golden_events() {
+        let golden = EventFixtures::from_json(GOLDEN_EVENTS).unwrap();
+        if golden.msgs.is_empty() {
+            eprintln!(
+                "skipping the golden events check: no events have been recorded from a chain yet"
+            );
+            return;
+        }
+        let mut app = mock_stateful_desmos_app();
+        let end_date = app.block_info().time.plus_seconds(60);
+        let mut execute = |sender: &Addr, msg: DesmosMsg| {
+            let response = app.execute(sender.clone(), msg.clone().into()).unwrap();
+            golden.check(&msg, &response.events).unwrap();
+        };
+        let user = Addr::unchecked(SENDER);
+        let other = Addr::unchecked("other");
+        let removed = Addr::unchecked("removed");
+
+        // Subspaces
+        let subspaces = |msg: SubspacesMsg| DesmosMsg::Subspaces(msg);
+        for _ in 0..2 {
+            execute(
+                &user,
+                subspaces(SubspacesMsg::create_subspace(
+                    "Test subspace",
+                    "",
+                    user.clone(),
+                    user.clone(),
+                    user.clone(),
+                )),
+            );
+        }
+        execute(
+            &user,
+            subspaces(SubspacesMsg::edit_subspace(
+                1,
+                "Edited subspace",
+                "",
+                user.clone(),
+                user.clone(),
+                user.clone(),
+            )),
+        );
+        execute(
+            &user,
+            subspaces(SubspacesMsg::delete_subspace(2, user.clone())),
+        );
+        for name in ["Section", "Nested section"] {
+            execute(
+                &user,
+                subspaces(SubspacesMsg::create_section(
+                    1,
+                    name,
+                    None,
+                    None,
+                    user.clone(),
+                )),
+            );
+        }
+        execute(
+            &user,
+            subspaces(SubspacesMsg::edit_section(
+                1,
+                1,
+                Some("Edited section".to_string()),
+                None,
+                user.clone(),
+            )),
+        );
+        execute(
+            &user,
+            subspaces(SubspacesMsg::move_section(1, 2, 1, user.clone())),
+        );
+        execute(
+            &user,
+            subspaces(SubspacesMsg::delete_section(1, 2, user.clone())),
+        );
+        execute(
+            &user,
+            subspaces(SubspacesMsg::create_user_group(
+                1,
+                None,
+                "Group".to_string(),
+                None,
+                vec![],
+                vec![],
+                user.clone(),
+            )),
+        );
+        execute(
+            &user,
+            subspaces(SubspacesMsg::edit_user_group(
+                1,
+                1,
+                Some("Edited group".to_string()),
+                None,
+                user.clone(),
+            )),
+        );
+        execute(
+            &user,
+            subspaces(SubspacesMsg::move_user_group(1, 1, 1, user.clone())),
+        );
+        execute(
+            &user,
+            subspaces(SubspacesMsg::set_user_group_permissions(
+                1,
+                1,
+                vec![],
+                user.clone(),
+            )),
+        );
+        execute(
+            &user,
+            subspaces(SubspacesMsg::add_user_to_user_group(
+                1,
+                1,
+                other.clone(),
+                user.clone(),
+            )),
+        );
+        execute(
+            &user,
+            subspaces(SubspacesMsg::remove_user_from_user_group(
+                1,
+                1,
+                other.clone(),
+                user.clone(),
+            )),
+        );
+        execute(
+            &user,
+            subspaces(SubspacesMsg::delete_user_group(1, 1, user.clone())),
+        );
+        execute(
+            &user,
+            subspaces(SubspacesMsg::set_user_permissions(
+                1,
+                0,
+                other.clone(),
+                vec![],
+                user.clone(),
+            )),
+        );
+        execute(
+            &user,
+            subspaces(SubspacesMsg::grant_treasury_authorization(
+                1,
+                user.clone(),
+                other.clone(),
+                Authorization::Generic {
+                    msg: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                },
+                None,
+            )),
+        );
+        execute(
+            &user,
+            subspaces(SubspacesMsg::revoke_treasury_authorization(
+                1,
+                user.clone(),
+                other.clone(),
+                "/cosmos.bank.v1beta1.MsgSend",
+            )),
+        );
+        let grantee = || Grantee::User {
+            user: other.clone(),
+        };
+        execute(
+            &user,
+            subspaces(SubspacesMsg::grant_allowance(
+                1,
+                user.clone(),
+                grantee(),
+                Allowance::Basic {
+                    spend_limit: vec![],
+                    expiration: None,
+                },
+            )),
+        );
+        execute(
+            &user,
+            subspaces(SubspacesMsg::revoke_allowance(1, user.clone(), grantee())),
+        );
+
+        // Profiles
+        let profiles = |msg: ProfilesMsg| DesmosMsg::Profiles(msg);
+        for (dtag, creator) in [("user", &user), ("other", &other), ("removed", &removed)] {
+            execute(
+                creator,
+                profiles(ProfilesMsg::save_profile(
+                    dtag,
+                    creator.clone(),
+                    "",
+                    "",
+                    "",
+                    "",
+                )),
+            );
+        }
+        execute(
+            &removed,
+            profiles(ProfilesMsg::delete_profile(removed.clone())),
+        );
+        let request_dtag_transfer = || {
+            profiles(ProfilesMsg::request_dtag_transfer(
+                user.clone(),
+                other.clone(),
+            ))
+        };
+        execute(&user, request_dtag_transfer());
+        execute(
+            &other,
+            profiles(ProfilesMsg::refuse_dtag_transfer_request(
+                user.clone(),
+                other.clone(),
+            )),
+        );
+        execute(&user, request_dtag_transfer());
+        execute(
+            &user,
+            profiles(ProfilesMsg::cancel_dtag_transfer_request(
+                other.clone(),
+                user.clone(),
+            )),
+        );
+        execute(&user, request_dtag_transfer());
+        execute(
+            &other,
+            profiles(ProfilesMsg::accept_dtag_transfer_request(
+                "new_dtag",
+                user.clone(),
+                other.clone(),
+            )),
+        );
+        let chain_link = MockProfilesQueries::get_mock_chain_link();
+        execute(
+            &user,
+            profiles(ProfilesMsg::link_chain_account(
+                chain_link.address.clone(),
+                chain_link.proof,
+                chain_link.chain_config.clone(),
+                user.clone(),
+            )),
+        );
+        execute(
+            &user,
+            profiles(ProfilesMsg::set_default_external_address(
+                &chain_link.chain_config.name,
+                &chain_link.address.value,
+                user.clone(),
+            )),
+        );
+        execute(
+            &user,
+            profiles(ProfilesMsg::unlink_chain_account(
+                user.clone(),
+                &chain_link.chain_config.name,
+                &chain_link.address.value,
+            )),
+        );
+        // The application links are not checked since the test chain has no oracle
+        // to verify them, so bindings-test can not record their events.
+
+        // Relationships
+        let relationships = |msg: RelationshipsMsg| DesmosMsg::Relationships(msg);
+        execute(
+            &user,
+            relationships(RelationshipsMsg::create_relationship(
+                user.clone(),
+                other.clone(),
+                1,
+            )),
+        );
+        execute(
+            &user,
+            relationships(RelationshipsMsg::delete_relationship(
+                user.clone(),
+                other.clone(),
+                1,
+            )),
+        );
+        execute(
+            &user,
+            relationships(RelationshipsMsg::block_user(
+                user.clone(),
+                other.clone(),
+                "".to_string(),
+                1,
+            )),
+        );
+        execute(
+            &user,
+            relationships(RelationshipsMsg::unblock_user(
+                user.clone(),
+                other.clone(),
+                1,
+            )),
+        );
+
+        // Posts
+        let posts = |msg: PostsMsg| DesmosMsg::Posts(msg);
+        execute(&user, create_post(1, 0, &user));
+        execute(
+            &user,
+            posts(PostsMsg::edit_post(
+                1,
+                1,
+                Some("Edited"),
+                None,
+                user.clone(),
+            )),
+        );
+        execute(&user, create_poll(1, 1, end_date, &user));
+        execute(&user, create_poll(1, 1, end_date, &user));
+        execute(
+            &user,
+            posts(PostsMsg::remove_post_attachment(1, 1, 2, user.clone())),
+        );
+        execute(
+            &user,
+            posts(PostsMsg::answer_poll(1, 1, 1, vec![0], user.clone())),
+        );
+        execute(&user, create_post(1, 0, &user));
+        execute(&user, posts(PostsMsg::delete_post(1, 2, user.clone())));
+        #[cfg(feature = "desmos-v5")]
+        {
+            execute(&user, create_post(1, 0, &user));
+            execute(&user, posts(PostsMsg::move_post(1, 3, 1, 0, user.clone())));
+            let request_post_owner_transfer = || {
+                posts(PostsMsg::request_post_owner_transfer(
+                    1,
+                    1,
+                    other.clone(),
+                    user.clone(),
+                ))
+            };
+            execute(&user, request_post_owner_transfer());
+            execute(
+                &user,
+                posts(PostsMsg::cancel_post_owner_transfer_request(
+                    1,
+                    1,
+                    user.clone(),
+                )),
+            );
+            execute(&user, request_post_owner_transfer());
+            execute(
+                &other,
+                posts(PostsMsg::refuse_post_owner_transfer_request(
+                    1,
+                    1,
+                    other.clone(),
+                )),
+            );
+            execute(&user, request_post_owner_transfer());
+            execute(
+                &other,
+                posts(PostsMsg::accept_post_owner_transfer_request(
+                    1,
+                    1,
+                    other.clone(),
+                )),
+            );
+        }
+
+        // Reactions
+        let reactions = |msg: ReactionsMsg| DesmosMsg::Reactions(msg);
+        for _ in 0..2 {
+            execute(
+                &user,
+                reactions(ReactionsMsg::add_registered_reaction(
+                    1,
+                    ":smile:",
+                    "😄",
+                    user.clone(),
+                )),
+            );
+        }
+        execute(
+            &user,
+            reactions(ReactionsMsg::edit_registered_reaction(
+                1,
+                1,
+                ":smile:",
+                "😊",
+                user.clone(),
+            )),
+        );
+        execute(
+            &user,
+            reactions(ReactionsMsg::remove_registered_reaction(1, 2, user.clone())),
+        );
+        execute(&user, add_reaction(1, 1, &user));
+        execute(
+            &user,
+            reactions(ReactionsMsg::remove_reaction(1, 1, 1, user.clone())),
+        );
+        execute(
+            &user,
+            reactions(ReactionsMsg::set_reactions_params(
+                1,
+                RegisteredReactionValueParams { enabled: true },
+                FreeTextValueParams {
+                    enabled: true,
+                    max_length: 100,
+                    reg_ex: "".to_string(),
+                },
+                user.clone(),
+            )),
+        );
+
+        // Reports
+        let reports = |msg: ReportsMsg| DesmosMsg::Reports(msg);
+        for title in ["Spam", "Scam"] {
+            execute(
+                &user,
+                reports(ReportsMsg::add_reason(
+                    1,
+                    title,
+                    None::<String>,
+                    user.clone(),
+                )),
+            );
+        }
+        execute(
+            &user,
+            reports(ReportsMsg::remove_reason(1, 2, user.clone())),
+        );
+        execute(
+            &user,
+            reports(ReportsMsg::support_standard_reason(1, 1, user.clone())),
+        );
+        execute(
+            &user,
+            create_report(
+                1,
+                ReportTarget::User {
+                    user: other.clone(),
+                },
+                &user,
+            ),
+        );
+        execute(
+            &user,
+            reports(ReportsMsg::delete_report(1, 1, user.clone())),
+        );
+    }
+
+    #[test]
+    fn gas_meter_charges_msgs_and_queries_properly() {
+        let meter = MockGasMeter::new(GasConfig::default());
+        let keeper = DesmosKeeper::new_stateful().with_gas_meter(meter.clone());
+        let mut app = custom_desmos_app(keeper, |_, _, _| {});
+        let user = Addr::unchecked(SENDER);
+        let end_date = app.block_info().time.plus_seconds(60);
+        let response = app
+            .execute(user.clone(), create_post(1, 0, &user).into())
+            .unwrap();
+        assert!(response.has_event(&Event::new(GAS_EVENT).add_attribute("gas_used", "4000")));
+        for _ in 0..2 {
+            app.execute(user.clone(), create_poll(1, 1, end_date, &user).into())
+                .unwrap();
+        }
+        assert_eq!(12000, meter.gas_used());
+
+        meter.reset();
+        let app_querier = app.wrap();
+        let querier = PostsQuerier::new(app_querier.deref());
+        querier.query_post_attachments(1, 1, None).unwrap();
+        assert_eq!(3060, meter.gas_used());
+        querier.query_post(1, 1).unwrap();
+        assert_eq!(5090, meter.gas_used());
+    }
+
+    #[test]
+    fn gas_meter_limit_error() {
+        let meter = MockGasMeter::new(GasConfig::default()).with_limit(5000);
+        let keeper = DesmosKeeper::new_stateful().with_gas_meter(meter.clone());
+        let mut app = custom_desmos_app(keeper, |_, _, _| {});
+        let user = Addr::unchecked(SENDER);
+        app.execute(user.clone(), create_post(1, 0, &user).into())
+            .unwrap();
+        let err = app
+            .execute(user.clone(), create_post(1, 0, &user).into())
+            .unwrap_err();
+        assert_eq!(
+            "out of gas in location: desmos msg; gasWanted: 5000, gasUsed: 8000",
+            err.root_cause().to_string()
+        );
+    }
+
+    #[test]
+    fn failing_app_excute_error() {
+        let mut app = mock_failing_desmos_app();
+        let result = app.execute(
+            Addr::unchecked(SENDER),
+            DesmosMsg::Profiles(ProfilesMsg::delete_profile(Addr::unchecked(SENDER))).into(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn failing_app_query_error() {
+        let app = mock_failing_desmos_app();
+        let app_querier = app.wrap();
+        let querier = ProfilesQuerier::new(app_querier.deref());
+        let result = querier.query_profile(Addr::unchecked(""));
         assert!(result.is_err())
     }
+
+    #[test]
+    fn update_desmos_block_on_failing_app_properly() {
+        let mut app = mock_failing_desmos_app();
+        let height = app.block_info().height;
+        app.update_desmos_block(next_block).unwrap();
+        assert_eq!(height + 1, app.block_info().height);
+    }
 }
@@ -0,0 +1,636 @@
+//! Contains the canned responses returned by a [`DesmosKeeper`](crate::mocks::mock_keeper::DesmosKeeper)
+//! that does not track the Desmos state.
+//! Every message succeeds with the events described by the specs of its module,
+//! using the ids of the mocked query responses for the newly created objects.
+
+#![cfg(not(tarpaulin_include))]
+use crate::msg::DesmosMsg;
+#[cfg(feature = "posts")]
+use crate::posts::msg::PostsMsg;
+#[cfg(feature = "profiles")]
+use crate::profiles::msg::ProfilesMsg;
+#[cfg(feature = "reactions")]
+use crate::reactions::msg::ReactionsMsg;
+#[cfg(feature = "relationships")]
+use crate::relationships::msg::RelationshipsMsg;
+#[cfg(feature = "reports")]
+use crate::reports::{models::ReportTarget, msg::ReportsMsg};
+#[cfg(feature = "tokenfactory")]
+use crate::subspaces::mocks::MockSubspacesQueries;
+#[cfg(feature = "subspaces")]
+use crate::subspaces::{
+    models::{Grantee, RawGrantee},
+    msg::SubspacesMsg,
+};
+#[cfg(feature = "tokenfactory")]
+use crate::tokenfactory::{models::get_token_denom, msg::TokenFactoryMsg};
+#[cfg(any(
+    feature = "profiles",
+    feature = "subspaces",
+    feature = "posts",
+    feature = "reports"
+))]
+use crate::types::format_rfc3339_time;
+use cosmwasm_std::BlockInfo;
+#[cfg(any(
+    feature = "profiles",
+    feature = "subspaces",
+    feature = "relationships",
+    feature = "posts",
+    feature = "reactions",
+    feature = "reports",
+    feature = "tokenfactory"
+))]
+use cosmwasm_std::Event;
+use cw_multi_test::AppResponse;
+#[cfg(any(feature = "subspaces", feature = "reports"))]
+use std::convert::TryFrom;
+
+/// Returns the canned response of the provided message, which always succeeds
+/// without reading or changing any state.
+/// * `block` - Block in which the message is executed.
+/// * `msg` - Message to be executed.
+#[cfg_attr(
+    not(any(
+        feature = "profiles",
+        feature = "subspaces",
+        feature = "posts",
+        feature = "reports"
+    )),
+    allow(unused_variables)
+)]
+pub(crate) fn canned_msg_response(block: &BlockInfo, msg: DesmosMsg) -> AppResponse {
+    let events = match msg {
+        #[cfg(feature = "profiles")]
+        DesmosMsg::Profiles(msg) => canned_profiles_events(block, msg),
+        #[cfg(feature = "subspaces")]
+        DesmosMsg::Subspaces(msg) => canned_subspaces_events(block, msg),
+        #[cfg(feature = "relationships")]
+        DesmosMsg::Relationships(msg) => canned_relationships_events(msg),
+        #[cfg(feature = "posts")]
+        DesmosMsg::Posts(msg) => canned_posts_events(block, msg),
+        #[cfg(feature = "reports")]
+        DesmosMsg::Reports(msg) => canned_reports_events(block, msg),
+        #[cfg(feature = "reactions")]
+        DesmosMsg::Reactions(msg) => canned_reactions_events(msg),
+        #[cfg(feature = "tokenfactory")]
+        DesmosMsg::TokenFactory(msg) => canned_tokenfactory_events(msg),
+    };
+
+    // Hide this warning since when we compile the package without any module feature
+    // the messages can not be built.
+    #[allow(unreachable_code)]
+    AppResponse { events, data: None }
+}
+
+/// Returns the canned events of the provided [`ProfilesMsg`], following the
+/// [specs](https://github.com/desmos-labs/desmos/blob/master/x/profiles/spec/05-events.md).
+#[cfg(feature = "profiles")]
+fn canned_profiles_events(block: &BlockInfo, msg: ProfilesMsg) -> Vec<Event> {
+    match msg {
+        ProfilesMsg::SaveProfile { dtag, creator, .. } => vec![Event::new("save_profile")
+            .add_attribute("profile_dtag", dtag)
+            .add_attribute("profile_creator", creator)
+            .add_attribute("profile_creation_time", format_rfc3339_time(block.time))],
+        ProfilesMsg::DeleteProfile { creator, .. } => {
+            vec![Event::new("delete_profile").add_attribute("profile_creator", creator)]
+        }
+        ProfilesMsg::RequestDtagTransfer { sender, receiver } => {
+            vec![Event::new("request_dtag_transfer")
+                .add_attribute("dtag_to_trade", "test")
+                .add_attribute("request_sender", sender)
+                .add_attribute("request_receiver", receiver)]
+        }
+        ProfilesMsg::AcceptDtagTransferRequest {
+            new_dtag,
+            sender,
+            receiver,
+        } => vec![Event::new("accept_dtag_transfer_request")
+            .add_attribute("dtag_to_trade", "test")
+            .add_attribute("new_dtag", new_dtag)
+            .add_attribute("request_sender", sender)
+            .add_attribute("request_receiver", receiver)],
+        ProfilesMsg::RefuseDtagTransferRequest { sender, receiver } => {
+            vec![Event::new("refuse_dtag_transfer_request")
+                .add_attribute("request_sender", sender)
+                .add_attribute("request_receiver", receiver)]
+        }
+        ProfilesMsg::CancelDtagTransferRequest { sender, receiver } => {
+            vec![Event::new("cancel_dtag_transfer_request")
+                .add_attribute("request_sender", sender)
+                .add_attribute("request_receiver", receiver)]
+        }
+        ProfilesMsg::LinkChainAccount {
+            chain_address,
+            chain_config,
+            signer,
+            ..
+        } => vec![Event::new("link_chain_account")
+            .add_attribute("chain_link_account_target", chain_address.value)
+            .add_attribute("chain_link_source_chain_name", chain_config.name)
+            .add_attribute("chain_link_account_owner", signer)
+            .add_attribute("chain_link_creation_time", format_rfc3339_time(block.time))],
+        ProfilesMsg::UnlinkChainAccount {
+            owner,
+            chain_name,
+            target,
+        } => vec![Event::new("unlink_chain_account")
+            .add_attribute("chain_link_account_target", target)
+            .add_attribute("chain_link_source_chain_name", chain_name)
+            .add_attribute("chain_link_account_owner", owner)],
+        ProfilesMsg::SetDefaultExternalAddress {
+            chain_name,
+            target,
+            signer,
+        } => vec![Event::new("set_default_external_address")
+            .add_attribute("chain_link_source_chain_name", chain_name)
+            .add_attribute("chain_link_account_target", target)
+            .add_attribute("chain_link_account_owner", signer)],
+        ProfilesMsg::LinkApplication {
+            sender, link_data, ..
+        } => vec![Event::new("link_application")
+            .add_attribute("user", sender)
+            .add_attribute("application_name", link_data.application)
+            .add_attribute("application_username", link_data.username)
+            .add_attribute(
+                "application_link_creation_time",
+                format_rfc3339_time(block.time),
+            )],
+        ProfilesMsg::UnlinkApplication {
+            application,
+            username,
+            signer,
+        } => vec![Event::new("unlink_application")
+            .add_attribute("user", signer)
+            .add_attribute("application_name", application)
+            .add_attribute("application_username", username)],
+    }
+}
+
+/// Returns the canned events of the provided [`SubspacesMsg`], following the
+/// [specs](https://github.com/desmos-labs/desmos/blob/master/x/subspaces/spec/05-events.md).
+#[cfg(feature = "subspaces")]
+fn canned_subspaces_events(block: &BlockInfo, msg: SubspacesMsg) -> Vec<Event> {
+    match msg {
+        SubspacesMsg::CreateSubspace { name, creator, .. } => vec![Event::new("create_subspace")
+            .add_attribute("subspace_id", 1.to_string())
+            .add_attribute("subspace_name", name)
+            .add_attribute("subspace_creator", creator)
+            .add_attribute("creation_date", format_rfc3339_time(block.time))],
+        SubspacesMsg::EditSubspace { subspace_id, .. } => {
+            vec![Event::new("edit_subspace").add_attribute("subspace_id", subspace_id)]
+        }
+        SubspacesMsg::DeleteSubspace { subspace_id, .. } => {
+            vec![Event::new("delete_subspace").add_attribute("subspace_id", subspace_id)]
+        }
+        SubspacesMsg::CreateSection { subspace_id, .. } => vec![Event::new("create_section")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("section_id", 1.to_string())],
+        SubspacesMsg::EditSection {
+            subspace_id,
+            section_id,
+            ..
+        } => vec![Event::new("edit_section")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("section_id", section_id.to_string())],
+        SubspacesMsg::MoveSection {
+            subspace_id,
+            section_id,
+            ..
+        } => vec![Event::new("move_section")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("section_id", section_id.to_string())],
+        SubspacesMsg::DeleteSection {
+            subspace_id,
+            section_id,
+            ..
+        } => vec![Event::new("delete_section")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("section_id", section_id.to_string())],
+        SubspacesMsg::CreateUserGroup { subspace_id, .. } => {
+            vec![Event::new("create_user_group")
+                .add_attribute("subspace_id", subspace_id)
+                .add_attribute("user_group_id", 1.to_string())]
+        }
+        SubspacesMsg::EditUserGroup {
+            subspace_id,
+            group_id,
+            ..
+        } => vec![Event::new("edit_user_group")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("user_group_id", group_id.to_string())],
+        SubspacesMsg::MoveUserGroup {
+            subspace_id,
+            group_id,
+            ..
+        } => vec![Event::new("move_user_group")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("user_group_id", group_id.to_string())],
+        SubspacesMsg::SetUserGroupPermissions {
+            subspace_id,
+            group_id,
+            ..
+        } => vec![Event::new("set_user_group_permissions")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("user_group_id", group_id.to_string())],
+        SubspacesMsg::DeleteUserGroup {
+            subspace_id,
+            group_id,
+            ..
+        } => vec![Event::new("delete_user_group")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("user_group_id", group_id.to_string())],
+        SubspacesMsg::AddUserToUserGroup {
+            subspace_id,
+            group_id,
+            user,
+            ..
+        } => vec![Event::new("add_user_to_group")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("user_group_id", group_id.to_string())
+            .add_attribute("user", user)],
+        SubspacesMsg::RemoveUserFromUserGroup {
+            subspace_id,
+            group_id,
+            user,
+            ..
+        } => vec![Event::new("remove_user_from_group")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("user_group_id", group_id.to_string())
+            .add_attribute("user", user)],
+        SubspacesMsg::SetUserPermissions {
+            subspace_id, user, ..
+        } => vec![Event::new("set_user_permissions")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("user", user)],
+        SubspacesMsg::GrantTreasuryAuthorization {
+            subspace_id,
+            granter,
+            grantee,
+            ..
+        } => vec![Event::new("grant_treasury_authorization")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("granter", granter)
+            .add_attribute("grantee", grantee)],
+        SubspacesMsg::RevokeTreasuryAuthorization {
+            subspace_id,
+            granter,
+            grantee,
+            ..
+        } => vec![Event::new("revoke_treasury_authorization")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("granter", granter)
+            .add_attribute("grantee", grantee)],
+        SubspacesMsg::GrantAllowance {
+            subspace_id,
+            granter,
+            grantee,
+            ..
+        } => vec![with_grantee(
+            Event::new("grant_allowance")
+                .add_attribute("subspace_id", subspace_id)
+                .add_attribute("granter", granter),
+            grantee,
+        )],
+        SubspacesMsg::RevokeAllowance {
+            subspace_id,
+            granter,
+            grantee,
+        } => vec![with_grantee(
+            Event::new("revoke_allowance")
+                .add_attribute("subspace_id", subspace_id)
+                .add_attribute("granter", granter),
+            grantee,
+        )],
+    }
+}
+
+/// Adds the attribute identifying the provided allowance grantee to the given event,
+/// leaving it untouched if the grantee can not be decoded.
+#[cfg(feature = "subspaces")]
+fn with_grantee(event: Event, grantee: RawGrantee) -> Event {
+    match Grantee::try_from(grantee) {
+        Ok(Grantee::User { user }) => event.add_attribute("user_grantee", user),
+        Ok(Grantee::Group { group_id }) => {
+            event.add_attribute("group_grantee", group_id.to_string())
+        }
+        Err(_) => event,
+    }
+}
+
+/// Returns the canned events of the provided [`RelationshipsMsg`], following the
+/// [specs](https://github.com/desmos-labs/desmos/blob/master/x/relationships/spec/05-events.md).
+#[cfg(feature = "relationships")]
+fn canned_relationships_events(msg: RelationshipsMsg) -> Vec<Event> {
+    match msg {
+        RelationshipsMsg::CreateRelationship {
+            signer,
+            counterparty,
+            subspace_id,
+        } => vec![Event::new("create_relationship")
+            .add_attribute("creator", signer)
+            .add_attribute("counterparty", counterparty)
+            .add_attribute("subspace", subspace_id)],
+        RelationshipsMsg::DeleteRelationship {
+            signer,
+            counterparty,
+            subspace_id,
+        } => vec![Event::new("delete_relationship")
+            .add_attribute("creator", signer)
+            .add_attribute("counterparty", counterparty)
+            .add_attribute("subspace", subspace_id)],
+        RelationshipsMsg::BlockUser {
+            blocker,
+            blocked,
+            subspace_id,
+            ..
+        } => vec![Event::new("block_user")
+            .add_attribute("blocker", blocker)
+            .add_attribute("blocked", blocked)
+            .add_attribute("subspace", subspace_id)],
+        RelationshipsMsg::UnblockUser {
+            blocker,
+            blocked,
+            subspace_id,
+        } => vec![Event::new("unblock_user")
+            .add_attribute("blocker", blocker)
+            .add_attribute("blocked", blocked)
+            .add_attribute("subspace", subspace_id)],
+    }
+}
+
+/// Returns the canned events of the provided [`PostsMsg`], following the
+/// [specs](https://github.com/desmos-labs/desmos/blob/master/x/posts/spec/05-events.md).
+#[cfg(feature = "posts")]
+fn canned_posts_events(block: &BlockInfo, msg: PostsMsg) -> Vec<Event> {
+    match msg {
+        PostsMsg::CreatePost {
+            subspace_id,
+            section_id,
+            author,
+            ..
+        } => vec![Event::new("create_post")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("section_id", section_id.to_string())
+            .add_attribute("post_id", 1.to_string())
+            .add_attribute("author", author)
+            .add_attribute("creation_time", format_rfc3339_time(block.time))],
+        PostsMsg::EditPost {
+            subspace_id,
+            post_id,
+            ..
+        } => vec![Event::new("edit_post")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("post_id", post_id)
+            .add_attribute("last_edit_time", format_rfc3339_time(block.time))],
+        PostsMsg::DeletePost {
+            subspace_id,
+            post_id,
+            ..
+        } => vec![Event::new("delete_post")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("post_id", post_id)],
+        PostsMsg::AddPostAttachment {
+            subspace_id,
+            post_id,
+            ..
+        } => vec![Event::new("add_post_attachment")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("post_id", post_id)
+            .add_attribute("attachment_id", 1.to_string())
+            .add_attribute("last_edit_time", format_rfc3339_time(block.time))],
+        PostsMsg::RemovePostAttachment {
+            subspace_id,
+            post_id,
+            attachment_id,
+            ..
+        } => vec![Event::new("remove_post_attachment")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("post_id", post_id)
+            .add_attribute("attachment_id", attachment_id.to_string())
+            .add_attribute("last_edit_time", format_rfc3339_time(block.time))],
+        PostsMsg::AnswerPoll {
+            subspace_id,
+            post_id,
+            poll_id,
+            ..
+        } => vec![Event::new("answer_poll")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("post_id", post_id)
+            .add_attribute("poll_id", poll_id.to_string())],
+        #[cfg(feature = "desmos-v5")]
+        PostsMsg::MovePost {
+            subspace_id,
+            post_id,
+            target_subspace_id,
+            ..
+        } => vec![Event::new("move_post")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("post_id", post_id)
+            .add_attribute("new_subspace_id", target_subspace_id)
+            .add_attribute("new_post_id", 1.to_string())],
+        #[cfg(feature = "desmos-v5")]
+        PostsMsg::RequestPostOwnerTransfer {
+            subspace_id,
+            post_id,
+            receiver,
+            sender,
+        } => vec![Event::new("request_post_owner_transfer")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("post_id", post_id)
+            .add_attribute("receiver", receiver)
+            .add_attribute("sender", sender)],
+        #[cfg(feature = "desmos-v5")]
+        PostsMsg::CancelPostOwnerTransferRequest {
+            subspace_id,
+            post_id,
+            sender,
+        } => vec![Event::new("cancel_post_owner_transfer_request")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("post_id", post_id)
+            .add_attribute("sender", sender)],
+        #[cfg(feature = "desmos-v5")]
+        PostsMsg::AcceptPostOwnerTransferRequest {
+            subspace_id,
+            post_id,
+            receiver,
+        } => vec![Event::new("accept_post_owner_transfer_request")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("post_id", post_id)
+            .add_attribute("receiver", receiver)],
+        #[cfg(feature = "desmos-v5")]
+        PostsMsg::RefusePostOwnerTransferRequest {
+            subspace_id,
+            post_id,
+            receiver,
+        } => vec![Event::new("refuse_post_owner_transfer_request")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("post_id", post_id)
+            .add_attribute("receiver", receiver)],
+    }
+}
+
+/// Returns the canned events of the provided [`ReportsMsg`], following the
+/// [specs](https://github.com/desmos-labs/desmos/blob/master/x/reports/spec/05-events.md).
+#[cfg(feature = "reports")]
+fn canned_reports_events(block: &BlockInfo, msg: ReportsMsg) -> Vec<Event> {
+    match msg {
+        ReportsMsg::CreateReport {
+            subspace_id,
+            reporter,
+            target,
+            ..
+        } => {
+            let mut events = vec![Event::new("create_report")
+                .add_attribute("subspace_id", subspace_id)
+                .add_attribute("report_id", 1.to_string())
+                .add_attribute("reporter", &reporter)
+                .add_attribute("creation_time", format_rfc3339_time(block.time))];
+            match ReportTarget::try_from(target) {
+                Ok(ReportTarget::Post { post_id }) => events.push(
+                    Event::new("report_post")
+                        .add_attribute("subspace_id", subspace_id)
+                        .add_attribute("post_id", post_id)
+                        .add_attribute("reporter", reporter),
+                ),
+                Ok(ReportTarget::User { user }) => events.push(
+                    Event::new("report_user")
+                        .add_attribute("subspace_id", subspace_id)
+                        .add_attribute("user", user)
+                        .add_attribute("reporter", reporter),
+                ),
+                Err(_) => {}
+            }
+            events
+        }
+        ReportsMsg::DeleteReport {
+            subspace_id,
+            report_id,
+            ..
+        } => vec![Event::new("delete_report")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("report_id", report_id)],
+        ReportsMsg::SupportStandardReason {
+            subspace_id,
+            standard_reason_id,
+            ..
+        } => vec![Event::new("support_standard_reason")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("standard_reason_id", standard_reason_id.to_string())
+            .add_attribute("reason_id", 1.to_string())],
+        ReportsMsg::AddReason { subspace_id, .. } => vec![Event::new("add_reason")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("reason_id", 1.to_string())],
+        ReportsMsg::RemoveReason {
+            subspace_id,
+            reason_id,
+            ..
+        } => vec![Event::new("remove_reason")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("reason_id", reason_id.to_string())],
+    }
+}
+
+/// Returns the canned events of the provided [`ReactionsMsg`], following the
+/// [specs](https://github.com/desmos-labs/desmos/blob/master/x/reactions/spec/05-events.md).
+#[cfg(feature = "reactions")]
+fn canned_reactions_events(msg: ReactionsMsg) -> Vec<Event> {
+    match msg {
+        ReactionsMsg::AddReaction {
+            subspace_id,
+            post_id,
+            user,
+            ..
+        } => vec![Event::new("add_reaction")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("post_id", post_id)
+            .add_attribute("reaction_id", 1.to_string())
+            .add_attribute("user", user)],
+        ReactionsMsg::RemoveReaction {
+            subspace_id,
+            post_id,
+            reaction_id,
+            ..
+        } => vec![Event::new("remove_reaction")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("post_id", post_id)
+            .add_attribute("reaction_id", reaction_id.to_string())],
+        ReactionsMsg::AddRegisteredReaction { subspace_id, .. } => {
+            vec![Event::new("add_registered_reaction")
+                .add_attribute("subspace_id", subspace_id)
+                .add_attribute("registered_reaction_id", 1.to_string())]
+        }
+        ReactionsMsg::EditRegisteredReaction {
+            subspace_id,
+            registered_reaction_id,
+            ..
+        } => vec![Event::new("edit_registered_reaction")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("registered_reaction_id", registered_reaction_id.to_string())],
+        ReactionsMsg::RemoveRegisteredReaction {
+            subspace_id,
+            registered_reaction_id,
+            ..
+        } => vec![Event::new("remove_registered_reaction")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("registered_reaction_id", registered_reaction_id.to_string())],
+        ReactionsMsg::SetReactionsParams { subspace_id, .. } => {
+            vec![Event::new("set_reactions_params").add_attribute("subspace_id", subspace_id)]
+        }
+    }
+}
+
+/// Returns the canned events of the provided [`TokenFactoryMsg`], without minting nor burning any token.
+#[cfg(feature = "tokenfactory")]
+fn canned_tokenfactory_events(msg: TokenFactoryMsg) -> Vec<Event> {
+    match msg {
+        TokenFactoryMsg::CreateDenom {
+            sender,
+            subspace_id,
+            subdenom,
+        } => {
+            let treasury = MockSubspacesQueries::get_mock_subspace().treasury;
+            vec![Event::new("create_denom")
+                .add_attribute("subspace_id", subspace_id)
+                .add_attribute("creator", sender.as_str())
+                .add_attribute(
+                    "new_token_denom",
+                    get_token_denom(treasury.as_str(), &subdenom),
+                )]
+        }
+        TokenFactoryMsg::Mint {
+            subspace_id,
+            amount,
+            mint_to_address,
+            ..
+        } => vec![Event::new("tf_mint")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("mint_to_address", mint_to_address)
+            .add_attribute("amount", amount.to_string())],
+        TokenFactoryMsg::Burn {
+            subspace_id,
+            amount,
+            burn_from_address,
+            ..
+        } => vec![Event::new("tf_burn")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("burn_from_address", burn_from_address)
+            .add_attribute("amount", amount.to_string())],
+        TokenFactoryMsg::SetDenomMetadata {
+            subspace_id,
+            metadata,
+            ..
+        } => vec![Event::new("set_denom_metadata")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("denom", metadata.base)],
+        TokenFactoryMsg::ChangeAdmin {
+            subspace_id,
+            denom,
+            new_admin,
+            ..
+        } => vec![Event::new("change_admin")
+            .add_attribute("subspace_id", subspace_id)
+            .add_attribute("denom", denom)
+            .add_attribute("new_admin", new_admin)],
+    }
+}
@@ -0,0 +1,275 @@
+//! Contains the golden events emitted by a real chain for each [`DesmosMsg`], which allow to check
+//! that the events emitted by the [`DesmosKeeper`](crate::mocks::mock_keeper::DesmosKeeper)
+//! follow the specs of the Desmos modules.
+
+#![cfg(not(tarpaulin_include))]
+use crate::mocks::mock_gas::GAS_EVENT;
+use crate::mocks::JsonFile;
+use crate::msg::DesmosMsg;
+use anyhow::{anyhow, bail, Result as AnyResult};
+use cosmwasm_std::Event;
+use serde::{Deserialize, Serialize};
+
+/// Types of the events emitted by the chain for every transaction or contract execution,
+/// which are not relative to any Desmos module. The IBC packets sent when linking an application
+/// are ignored too since the keeper does not mock the IBC channels, as well as the gas reported
+/// by the keeper when it has a [`MockGasMeter`](crate::mocks::mock_gas::MockGasMeter).
+pub const IGNORED_EVENT_TYPES: &[&str] = &[
+    "message",
+    "tx",
+    "wasm",
+    "execute",
+    "coin_spent",
+    "coin_received",
+    "transfer",
+    "burn",
+    "mint",
+    "use_feegrant",
+    "set_feegrant",
+    "send_packet",
+    GAS_EVENT,
+];
+
+/// Represents the shape of an emitted event, without the values of its attributes
+/// since they depend on the state of the chain.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EventSpec {
+    /// Type of the event.
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Keys of the event attributes, sorted alphabetically.
+    pub attributes: Vec<String>,
+}
+
+impl EventSpec {
+    /// Returns the [`EventSpec`] of the given event.
+    /// * `event` - The emitted event.
+    pub fn from_event(event: &Event) -> Self {
+        let mut attributes = event
+            .attributes
+            .iter()
+            .map(|attribute| attribute.key.clone())
+            .collect::<Vec<_>>();
+        attributes.sort();
+        attributes.dedup();
+        EventSpec {
+            ty: event.ty.clone(),
+            attributes,
+        }
+    }
+
+    /// Returns the sorted and deduplicated [`EventSpec`]s of the given events,
+    /// leaving out the ones with a type contained inside [`IGNORED_EVENT_TYPES`].
+    /// * `events` - The emitted events.
+    pub fn from_events(events: &[Event]) -> Vec<Self> {
+        let mut specs = events
+            .iter()
+            .filter(|event| !IGNORED_EVENT_TYPES.contains(&event.ty.as_str()))
+            .map(EventSpec::from_event)
+            .collect::<Vec<_>>();
+        specs.sort();
+        specs.dedup();
+        specs
+    }
+}
+
+/// Represents the events emitted for a message type.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct MsgEvents {
+    /// Type of the message, as returned by [`msg_type`].
+    pub msg: String,
+    /// Events emitted when handling the message.
+    pub events: Vec<EventSpec>,
+}
+
+/// Represents the events emitted by a real chain for each message type, which can be saved into a JSON file
+/// and compared with the ones emitted by the [`DesmosKeeper`](crate::mocks::mock_keeper::DesmosKeeper).
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::{Addr, Event};
+/// use desmos_bindings::mocks::mock_events::EventFixtures;
+/// use desmos_bindings::msg::DesmosMsg;
+/// use desmos_bindings::relationships::msg::RelationshipsMsg;
+///
+/// let msg = DesmosMsg::Relationships(RelationshipsMsg::block_user(
+///     Addr::unchecked("blocker"),
+///     Addr::unchecked("blocked"),
+///     "".to_string(),
+///     1,
+/// ));
+/// let event = Event::new("block_user")
+///     .add_attribute("blocker", "blocker")
+///     .add_attribute("blocked", "blocked")
+///     .add_attribute("subspace", "1");
+///
+/// // Fixtures recorded by the integration tests can be loaded with `EventFixtures::load`.
+/// let mut fixtures = EventFixtures::new();
+/// fixtures.record(&msg, &[event.clone()]).unwrap();
+/// fixtures.check(&msg, &[event]).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct EventFixtures {
+    /// The events recorded for each message type.
+    pub msgs: Vec<MsgEvents>,
+}
+
+impl JsonFile for EventFixtures {}
+
+impl EventFixtures {
+    /// Returns a new [`EventFixtures`] without any recorded event.
+    pub fn new() -> Self {
+        EventFixtures::default()
+    }
+
+    /// Records the events emitted when handling the given message, replacing the ones
+    /// previously recorded for its type, if any.
+    /// The message types are kept sorted, so that the recorded file does not depend on the order
+    /// in which the messages have been handled.
+    /// * `msg` - The handled message.
+    /// * `events` - Events emitted when handling the message.
+    pub fn record(&mut self, msg: &DesmosMsg, events: &[Event]) -> AnyResult<()> {
+        let msg = msg_type(msg)?;
+        let events = EventSpec::from_events(events);
+        match self.msgs.iter_mut().find(|recorded| recorded.msg == msg) {
+            Some(recorded) => recorded.events = events,
+            None => {
+                self.msgs.push(MsgEvents { msg, events });
+                self.msgs
+                    .sort_by(|first, second| first.msg.cmp(&second.msg));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the events recorded for the type of the given message, if any.
+    /// * `msg` - The handled message.
+    pub fn expected(&self, msg: &DesmosMsg) -> AnyResult<Option<&[EventSpec]>> {
+        let msg = msg_type(msg)?;
+        Ok(self
+            .msgs
+            .iter()
+            .find(|recorded| recorded.msg == msg)
+            .map(|recorded| recorded.events.as_slice()))
+    }
+
+    /// Returns an error if the given events differ from the ones recorded for the type of the message,
+    /// or if no events have been recorded for it.
+    /// * `msg` - The handled message.
+    /// * `events` - Events emitted when handling the message.
+    pub fn check(&self, msg: &DesmosMsg, events: &[Event]) -> AnyResult<()> {
+        let expected = match self.expected(msg)? {
+            Some(expected) => expected,
+            None => bail!("no events recorded for message {}", msg_type(msg)?),
+        };
+        let emitted = EventSpec::from_events(events);
+        if emitted != expected {
+            bail!(
+                "unexpected events for message {}, expected: {:?}, emitted: {:?}",
+                msg_type(msg)?,
+                expected,
+                emitted
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Returns the type of the given message made of the module and message names (eg. `profiles.save_profile`).
+/// * `msg` - The message of interest.
+pub fn msg_type(msg: &DesmosMsg) -> AnyResult<String> {
+    let value = serde_json::to_value(msg)?;
+    let (module, msg) = value
+        .as_object()
+        .and_then(|module| module.iter().next())
+        .ok_or_else(|| anyhow!("invalid message {}", value))?;
+    let name = msg
+        .as_object()
+        .and_then(|msg| msg.keys().next())
+        .ok_or_else(|| anyhow!("invalid message {}", value))?;
+    Ok(format!("{}.{}", module, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::relationships::msg::RelationshipsMsg;
+    use cosmwasm_std::Addr;
+
+    fn block_user() -> DesmosMsg {
+        DesmosMsg::Relationships(RelationshipsMsg::block_user(
+            Addr::unchecked("blocker"),
+            Addr::unchecked("blocked"),
+            "".to_string(),
+            1,
+        ))
+    }
+
+    fn block_user_event() -> Event {
+        Event::new("block_user")
+            .add_attribute("blocker", "blocker")
+            .add_attribute("blocked", "blocked")
+            .add_attribute("subspace", "1")
+    }
+
+    #[test]
+    fn test_check_recorded_events() {
+        let mut fixtures = EventFixtures::new();
+        fixtures
+            .record(
+                &block_user(),
+                &[
+                    Event::new("message").add_attribute("action", "block_user"),
+                    block_user_event(),
+                ],
+            )
+            .unwrap();
+
+        fixtures
+            .check(&block_user(), &[block_user_event()])
+            .unwrap();
+        let err = fixtures
+            .check(
+                &block_user(),
+                &[block_user_event().add_attribute("reason", "")],
+            )
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("unexpected events for message relationships.block_user"));
+        fixtures.check(&block_user(), &[]).unwrap_err();
+    }
+
+    #[test]
+    fn test_record_keeps_msgs_sorted() {
+        let unblock_user = DesmosMsg::Relationships(RelationshipsMsg::unblock_user(
+            Addr::unchecked("blocker"),
+            Addr::unchecked("blocked"),
+            1,
+        ));
+        let mut fixtures = EventFixtures::new();
+        fixtures.record(&unblock_user, &[]).unwrap();
+        fixtures
+            .record(&block_user(), &[block_user_event()])
+            .unwrap();
+        assert_eq!(
+            vec!["relationships.block_user", "relationships.unblock_user"],
+            fixtures
+                .msgs
+                .iter()
+                .map(|recorded| recorded.msg.as_str())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_check_msgs_without_recorded_events() {
+        let fixtures = EventFixtures::from_json(r#"{"msgs":[]}"#).unwrap();
+        assert_eq!(None, fixtures.expected(&block_user()).unwrap());
+        let err = fixtures.check(&block_user(), &[]).unwrap_err();
+        assert_eq!(
+            "no events recorded for message relationships.block_user",
+            err.to_string()
+        );
+    }
+}
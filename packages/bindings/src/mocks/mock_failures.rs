@@ -0,0 +1,114 @@
+//! Contains the failures that can be injected into the [`DesmosKeeper`](crate::mocks::mock_keeper::DesmosKeeper)
+//! to test how contracts handle the errors returned by the Desmos modules.
+
+#![cfg(not(tarpaulin_include))]
+use anyhow::Result as AnyResult;
+use thiserror::Error;
+
+/// Represents the errors returned by the Desmos modules on chain.
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+pub enum DesmosError {
+    /// Error returned when the profile of a user does not exist.
+    #[error("profile not found")]
+    ProfileNotFound,
+    /// Error returned when a subspace does not exist.
+    #[error("subspace with id {0} not found")]
+    SubspaceNotFound(u64),
+    /// Error returned when a section does not exist inside a subspace.
+    #[error("section with id {0} not found")]
+    SectionNotFound(u32),
+    /// Error returned when a user group does not exist inside a subspace.
+    #[error("group with id {0} not found")]
+    UserGroupNotFound(u32),
+    /// Error returned when a post does not exist inside a subspace.
+    #[error("post with id {0} not found")]
+    PostNotFound(u64),
+    /// Error returned when a post attachment does not exist.
+    #[error("attachment with id {0} not found")]
+    AttachmentNotFound(u32),
+    /// Error returned when a poll does not exist.
+    #[error("poll with id {0} not found")]
+    PollNotFound(u32),
+    /// Error returned when a post does not have a pending owner transfer request.
+    #[error("post owner transfer request for post with id {0} not found")]
+    PostOwnerTransferRequestNotFound(u64),
+    /// Error returned when a reaction does not exist.
+    #[error("reaction with id {0} not found")]
+    ReactionNotFound(u32),
+    /// Error returned when a registered reaction does not exist inside a subspace.
+    #[error("registered reaction with id {0} not found")]
+    RegisteredReactionNotFound(u32),
+    /// Error returned when a report does not exist inside a subspace.
+    #[error("report with id {0} not found")]
+    ReportNotFound(u64),
+    /// Error returned when a reporting reason does not exist inside a subspace.
+    #[error("reason with id {0} not found")]
+    ReasonNotFound(u32),
+    /// Error returned when the signer of a message does not have the required permissions.
+    #[error("insufficient permissions to perform this action")]
+    InsufficientPermissions,
+    /// Error with a custom message.
+    #[error("{0}")]
+    Custom(String),
+}
+
+/// Function that tells whether a message or query of type `T` should fail, given its position
+/// among the ones handled by the keeper and the message or query itself.
+pub type MockFailureFilter<T> = Box<dyn Fn(u64, &T) -> bool>;
+
+/// Represents a failure injected into the [`DesmosKeeper`](crate::mocks::mock_keeper::DesmosKeeper),
+/// which makes it return an error for the messages or queries of type `T` matching a filter.
+pub struct MockFailure<T> {
+    filter: MockFailureFilter<T>,
+    error: String,
+}
+
+impl<T> MockFailure<T> {
+    /// Creates a new failure.
+    /// The filter receives the position (starting from 1) of the message or query among the ones
+    /// handled by the keeper, together with the message or query itself.
+    /// * `filter` - Function that tells whether a message or query should fail.
+    /// * `error` - Error to be returned.
+    pub fn new<F, E>(filter: F, error: E) -> Self
+    where
+        F: Fn(u64, &T) -> bool + 'static,
+        E: ToString,
+    {
+        MockFailure {
+            filter: Box::new(filter),
+            error: error.to_string(),
+        }
+    }
+
+    /// Creates a failure for the n-th message or query handled by the keeper, starting from 1.
+    /// * `n` - Position of the message or query that should fail.
+    /// * `error` - Error to be returned.
+    pub fn at<E: ToString>(n: u64, error: E) -> Self {
+        MockFailure::new(move |index, _| index == n, error)
+    }
+
+    /// Creates a failure for all the messages or queries matching the provided predicate.
+    /// * `predicate` - Function that tells whether a message or query should fail.
+    /// * `error` - Error to be returned.
+    pub fn matching<F, E>(predicate: F, error: E) -> Self
+    where
+        F: Fn(&T) -> bool + 'static,
+        E: ToString,
+    {
+        MockFailure::new(move |_, item| predicate(item), error)
+    }
+
+    /// Returns the error of the first failure that matches the given message or query, if any.
+    /// * `failures` - Failures to be checked.
+    /// * `index` - Position of the message or query among the ones handled by the keeper.
+    /// * `item` - Message or query being handled.
+    pub(crate) fn check(failures: &[MockFailure<T>], index: u64, item: &T) -> AnyResult<()> {
+        match failures
+            .iter()
+            .find(|failure| (failure.filter)(index, item))
+        {
+            Some(failure) => AnyResult::Err(anyhow::Error::msg(failure.error.clone())),
+            None => AnyResult::Ok(()),
+        }
+    }
+}
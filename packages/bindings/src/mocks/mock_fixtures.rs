@@ -0,0 +1,89 @@
+//! Contains the fixtures that allow the [`MockDesmosQuerier`](crate::mocks::mock_queriers::MockDesmosQuerier)
+//! to replay the query responses recorded from a real chain.
+
+#![cfg(not(tarpaulin_include))]
+use crate::mocks::JsonFile;
+use crate::query::DesmosQuery;
+use anyhow::Result as AnyResult;
+use cosmwasm_std::Binary;
+use serde::{Deserialize, Serialize};
+
+/// Represents a query performed towards a real chain together with the response it received.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct QueryFixture {
+    /// The performed query.
+    pub request: DesmosQuery,
+    /// JSON response returned by the chain.
+    pub response: serde_json::Value,
+}
+
+/// Represents a set of query responses recorded from a real chain, which can be saved into a JSON file
+/// and provided to the [`MockDesmosQuerier`](crate::mocks::mock_queriers::MockDesmosQuerier) to replay them.
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::to_binary;
+/// use desmos_bindings::mocks::mock_fixtures::QueryFixtures;
+/// use desmos_bindings::mocks::mock_queriers::MockDesmosQuerier;
+/// use desmos_bindings::profiles::mocks::MockProfilesQueries;
+/// use desmos_bindings::profiles::models_query::QueryProfileResponse;
+/// use desmos_bindings::profiles::query::ProfilesQuery;
+///
+/// let mut fixtures = QueryFixtures::new();
+/// fixtures
+///     .record(
+///         ProfilesQuery::Profile {
+///             user: "desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3".to_string(),
+///         }
+///         .into(),
+///         &to_binary(&QueryProfileResponse {
+///             profile: MockProfilesQueries::get_mock_profile(),
+///         })
+///         .unwrap(),
+///     )
+///     .unwrap();
+///
+/// // Fixtures recorded by the integration tests can be loaded with `QueryFixtures::load`.
+/// let querier = MockDesmosQuerier::default().with_fixtures(fixtures);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct QueryFixtures {
+    /// The recorded queries.
+    pub queries: Vec<QueryFixture>,
+}
+
+impl JsonFile for QueryFixtures {}
+
+impl QueryFixtures {
+    /// Returns a new [`QueryFixtures`] without any recorded query.
+    pub fn new() -> Self {
+        QueryFixtures::default()
+    }
+
+    /// Records the response received for the given query, replacing the one previously recorded for it, if any.
+    /// * `request` - The performed query.
+    /// * `response` - JSON response returned by the chain.
+    pub fn record(&mut self, request: DesmosQuery, response: &Binary) -> AnyResult<()> {
+        let response: serde_json::Value = serde_json::from_slice(response.as_slice())?;
+        match self
+            .queries
+            .iter_mut()
+            .find(|fixture| fixture.request == request)
+        {
+            Some(fixture) => fixture.response = response,
+            None => self.queries.push(QueryFixture { request, response }),
+        }
+        Ok(())
+    }
+
+    /// Returns the response recorded for the given query, if any.
+    /// The queries are matched exactly, including their pagination configs.
+    /// * `request` - The query to be answered.
+    pub fn response(&self, request: &DesmosQuery) -> AnyResult<Option<Binary>> {
+        self.queries
+            .iter()
+            .find(|fixture| &fixture.request == request)
+            .map(|fixture| Ok(Binary::from(serde_json::to_vec(&fixture.response)?)))
+            .transpose()
+    }
+}
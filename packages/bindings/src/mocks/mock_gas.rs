@@ -0,0 +1,200 @@
+//! Contains the gas meter that can be provided to the [`DesmosKeeper`](crate::mocks::mock_keeper::DesmosKeeper)
+//! and to the [`MockDesmosQuerier`](crate::mocks::mock_queriers::MockDesmosQuerier) to charge gas
+//! for the Desmos messages and queries, so that contracts iterating over too many items can be caught.
+//!
+//! The charged gas is only an approximation of the one used by the chain: the queries are charged by the
+//! number of returned items and the messages by the number of emitted events, not by the store accesses.
+
+#![cfg(not(tarpaulin_include))]
+use anyhow::{bail, Result as AnyResult};
+use cosmwasm_std::Binary;
+use cw_multi_test::AppResponse;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Type of the event added to the response of each message handled with a [`MockGasMeter`],
+/// whose `gas_used` attribute contains the gas charged for the message.
+pub const GAS_EVENT: &str = "desmos_gas";
+
+/// Represents the costs charged for the Desmos messages and queries.
+/// The default values are based on the gas charged by the chain for reading and writing its store,
+/// but the costs of the messages are approximated by the number of events they emit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasConfig {
+    /// Gas charged for each query.
+    pub query_base_cost: u64,
+    /// Gas charged for each item returned by a query.
+    pub query_item_cost: u64,
+    /// Gas charged for each message.
+    pub msg_base_cost: u64,
+    /// Gas charged for each event emitted by a message,
+    /// which approximates the writes performed to the store.
+    pub msg_event_cost: u64,
+}
+
+impl Default for GasConfig {
+    fn default() -> Self {
+        GasConfig {
+            query_base_cost: 1000,
+            query_item_cost: 1030,
+            msg_base_cost: 2000,
+            msg_event_cost: 2000,
+        }
+    }
+}
+
+impl GasConfig {
+    /// Returns the gas charged for a query given its response.
+    /// * `response` - JSON response returned by the query.
+    pub fn query_cost(&self, response: &Binary) -> u64 {
+        self.query_base_cost.saturating_add(
+            self.query_item_cost
+                .saturating_mul(returned_items(response)),
+        )
+    }
+
+    /// Returns the gas charged for a message given its response, which is approximated by the number of events
+    /// emitted by the message rather than by the store accesses performed by the chain.
+    /// * `response` - Response returned by the keeper after handling the message.
+    pub fn msg_cost(&self, response: &AppResponse) -> u64 {
+        self.msg_base_cost.saturating_add(
+            self.msg_event_cost
+                .saturating_mul(response.events.len() as u64),
+        )
+    }
+}
+
+/// Keeps track of the gas used by the Desmos messages and queries handled by the mocks it has been provided to,
+/// returning an out of gas error once the limit, if any, is exceeded.
+/// Cloning the meter returns a handle to the same gas counter, so that a clone can be kept by the test
+/// while the original is moved into the mock app.
+/// The gas charged for each message is also reported inside the `gas_used` attribute of the
+/// [`GAS_EVENT`] event added to its response.
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::{Addr, Event};
+/// use cw_multi_test::Executor;
+/// use desmos_bindings::mocks::mock_apps::custom_desmos_app;
+/// use desmos_bindings::mocks::mock_gas::{GasConfig, MockGasMeter, GAS_EVENT};
+/// use desmos_bindings::mocks::mock_keeper::DesmosKeeper;
+/// use desmos_bindings::msg::DesmosMsg;
+/// use desmos_bindings::subspaces::msg::SubspacesMsg;
+///
+/// let meter = MockGasMeter::new(GasConfig::default()).with_limit(100_000);
+/// let keeper = DesmosKeeper::new_stateful().with_gas_meter(meter.clone());
+/// let mut app = custom_desmos_app(keeper, |_, _, _| {});
+///
+/// let owner = Addr::unchecked("owner");
+/// let msg = DesmosMsg::Subspaces(SubspacesMsg::create_subspace(
+///     "Test subspace",
+///     "",
+///     owner.clone(),
+///     owner.clone(),
+///     owner.clone(),
+/// ));
+/// app.execute(owner.clone(), msg.into()).unwrap();
+/// meter.reset();
+///
+/// let msg = DesmosMsg::Subspaces(SubspacesMsg::delete_subspace(1, owner.clone()));
+/// let response = app.execute(owner, msg.into()).unwrap();
+/// assert_eq!(4000, meter.gas_used());
+/// assert!(response.has_event(&Event::new(GAS_EVENT).add_attribute("gas_used", "4000")));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MockGasMeter {
+    config: GasConfig,
+    limit: Option<u64>,
+    used: Rc<Cell<u64>>,
+}
+
+impl MockGasMeter {
+    /// Returns a new [`MockGasMeter`] without any limit.
+    /// * `config` - Costs charged for the messages and queries.
+    pub fn new(config: GasConfig) -> Self {
+        MockGasMeter {
+            config,
+            limit: None,
+            used: Rc::new(Cell::new(0)),
+        }
+    }
+
+    /// Function to set the maximum amount of gas that can be used.
+    /// * `limit` - The gas limit.
+    pub fn with_limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns the costs charged for the messages and queries.
+    pub fn config(&self) -> &GasConfig {
+        &self.config
+    }
+
+    /// Returns the gas limit, if any.
+    pub fn limit(&self) -> Option<u64> {
+        self.limit
+    }
+
+    /// Returns the gas used since the meter creation or its last reset.
+    pub fn gas_used(&self) -> u64 {
+        self.used.get()
+    }
+
+    /// Resets the gas used to zero.
+    pub fn reset(&self) {
+        self.used.set(0);
+    }
+
+    /// Consumes the given amount of gas, returning an error if the limit gets exceeded.
+    /// * `amount` - Amount of gas to be consumed.
+    /// * `descriptor` - Description of the operation consuming the gas.
+    pub fn consume(&self, amount: u64, descriptor: &str) -> AnyResult<()> {
+        let used = self.used.get().saturating_add(amount);
+        self.used.set(used);
+        if let Some(limit) = self.limit {
+            if used > limit {
+                bail!(
+                    "out of gas in location: {}; gasWanted: {}, gasUsed: {}",
+                    descriptor,
+                    limit,
+                    used
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Consumes the gas charged for a query given its response.
+    /// * `response` - JSON response returned by the query.
+    pub fn consume_query(&self, response: &Binary) -> AnyResult<()> {
+        self.consume(self.config.query_cost(response), "desmos query")
+    }
+
+    /// Consumes the gas charged for a message given its response, returning the consumed amount.
+    /// * `response` - Response returned by the keeper after handling the message.
+    pub fn consume_msg(&self, response: &AppResponse) -> AnyResult<u64> {
+        let cost = self.config.msg_cost(response);
+        self.consume(cost, "desmos msg")?;
+        Ok(cost)
+    }
+}
+
+/// Returns the number of items contained inside a query response, which is the total length of its
+/// top level lists, or one if the response does not contain any list (eg. a single profile).
+fn returned_items(response: &Binary) -> u64 {
+    let lists = match serde_json::from_slice(response.as_slice()) {
+        Ok(serde_json::Value::Array(items)) => vec![items.len()],
+        Ok(serde_json::Value::Object(fields)) => fields
+            .values()
+            .filter_map(|field| field.as_array())
+            .map(Vec::len)
+            .collect(),
+        _ => vec![],
+    };
+    if lists.is_empty() {
+        1
+    } else {
+        lists.iter().sum::<usize>() as u64
+    }
+}
@@ -2,72 +2,429 @@
 //! when performing integration tests.
 
 #![cfg(not(tarpaulin_include))]
+use crate::mocks::mock_canned::canned_msg_response;
+#[cfg(any(
+    feature = "profiles",
+    feature = "subspaces",
+    feature = "posts",
+    feature = "reactions",
+    feature = "reports"
+))]
+use crate::mocks::mock_failures::DesmosError;
+use crate::mocks::mock_failures::MockFailure;
+use crate::mocks::mock_gas::{MockGasMeter, GAS_EVENT};
 use crate::mocks::mock_queriers::MockDesmosQuerier;
+use crate::mocks::mock_recorder::MockRecorder;
+use crate::mocks::mock_state;
+#[cfg(feature = "profiles")]
+use crate::mocks::{
+    mock_oracle::{mock_oracle_response, MockOracle, MockOracleResponse},
+    mock_state::PendingApplicationLink,
+};
 use crate::msg::DesmosMsg;
+#[cfg(all(feature = "posts", feature = "desmos-v5"))]
+use crate::posts::{
+    models::PostOwnerTransferRequest, models_query::QueryIncomingPostOwnerTransferRequestsResponse,
+};
 #[cfg(feature = "posts")]
-use crate::posts::msg::PostsMsg;
+use crate::posts::{
+    models::{
+        AnswerResult, Attachment, PollTallyResults, Post, PostAttachment, RawPostAttachment,
+        UserAnswer,
+    },
+    models_query::{
+        QueryPollAnswersResponse, QueryPostAttachmentsResponse, QueryPostResponse,
+        QuerySectionPostsResponse, QuerySubspacePostsResponse,
+    },
+    msg::PostsMsg,
+    query::PostsQuery,
+};
 #[cfg(feature = "profiles")]
-use crate::profiles::msg::ProfilesMsg;
+use crate::profiles::{
+    mocks::MockProfilesQueries,
+    models_app_links::{
+        AppLinkResult, ApplicationLink, ApplicationLinkState, CallData, Data, OracleRequest,
+    },
+    models_dtag_requests::DtagTransferRequest,
+    models_profile::Profile,
+    models_query::{
+        QueryApplicationLinkByClientIDResponse, QueryIncomingDtagTransferRequestsResponse,
+        QueryProfileResponse,
+    },
+    msg::ProfilesMsg,
+    msg_builders::DO_NOT_MODIFY,
+    query::ProfilesQuery,
+};
 use crate::query::DesmosQuery;
 #[cfg(feature = "reactions")]
-use crate::reactions::msg::ReactionsMsg;
+use crate::reactions::{
+    models::{Reaction, RegisteredReaction},
+    models_query::{
+        QueryReactionResponse, QueryReactionsResponse, QueryRegisteredReactionResponse,
+        QueryRegisteredReactionsResponse,
+    },
+    msg::ReactionsMsg,
+    query::ReactionsQuery,
+};
 #[cfg(feature = "relationships")]
 use crate::relationships::msg::RelationshipsMsg;
 #[cfg(feature = "reports")]
-use crate::reports::{models::ReportTarget, msg::ReportsMsg};
+use crate::reports::{
+    mocks::MockReportsQueries,
+    models::{Reason, Report, ReportTarget},
+    models_query::{
+        QueryReasonResponse, QueryReasonsResponse, QueryReportResponse, QueryReportsResponse,
+    },
+    msg::ReportsMsg,
+    query::ReportsQuery,
+};
 #[cfg(feature = "subspaces")]
-use crate::subspaces::msg::SubspacesMsg;
+use crate::subspaces::{
+    models::{Grantee, Section, Subspace, UserGroup},
+    models_query::{
+        QuerySectionResponse, QuerySectionsResponse, QuerySubspaceResponse, QuerySubspacesResponse,
+        QueryUserGroupResponse, QueryUserGroupsResponse,
+    },
+    msg::SubspacesMsg,
+    msg_builders::DO_NOT_MODIFY as SUBSPACES_DO_NOT_MODIFY,
+    query::SubspacesQuery,
+};
+#[cfg(feature = "tokenfactory")]
+use crate::tokenfactory::{
+    models::get_token_denom, models_query::QuerySubspaceDenomsResponse, msg::TokenFactoryMsg,
+    query::TokenFactoryQuery,
+};
+#[cfg(any(
+    feature = "profiles",
+    feature = "subspaces",
+    feature = "posts",
+    feature = "reports"
+))]
+use crate::types::format_rfc3339_time;
+#[cfg(any(feature = "profiles", feature = "posts"))]
+use crate::types::parse_rfc3339_time;
+#[cfg(any(
+    feature = "profiles",
+    feature = "subspaces",
+    feature = "posts",
+    feature = "reactions",
+    feature = "reports",
+    feature = "tokenfactory"
+))]
+use anyhow::bail;
 use anyhow::Result as AnyResult;
+use cosmwasm_std::testing::MockStorage;
+#[cfg(any(
+    feature = "profiles",
+    feature = "subspaces",
+    feature = "posts",
+    feature = "reactions",
+    feature = "reports",
+    feature = "tokenfactory"
+))]
+use cosmwasm_std::to_binary;
+#[cfg(feature = "tokenfactory")]
+use cosmwasm_std::BankMsg;
+#[cfg(feature = "posts")]
+use cosmwasm_std::Timestamp;
+#[cfg(any(feature = "profiles", feature = "posts"))]
+use cosmwasm_std::Uint64;
 use cosmwasm_std::{
-    Addr, Api, Binary, BlockInfo, ContractResult, Empty, Event, Querier, QueryRequest, Storage,
+    Addr, Api, Binary, BlockInfo, ContractResult, CustomQuery, Empty, Event, Querier, QueryRequest,
+    Storage,
 };
+use cosmwasm_std::{Order, StdResult};
+#[cfg(feature = "tokenfactory")]
+use cw_multi_test::BankSudo;
 use cw_multi_test::{AppResponse, CosmosRouter, Module};
+use std::cell::Cell;
 use std::convert::TryFrom;
 
 /// Represents the implementation of [`Module`](cw_multi_test::Module) for handling the desmos execution and query messages.
-#[derive(Default)]
 pub struct DesmosKeeper {
     /// Querier used to handle the query requests.
     pub querier: MockDesmosQuerier,
+    #[cfg(feature = "profiles")]
+    oracle: MockOracle,
+    msg_failures: Vec<MockFailure<DesmosMsg>>,
+    query_failures: Vec<MockFailure<DesmosQuery>>,
+    handled_msgs: Cell<u64>,
+    handled_queries: Cell<u64>,
+    recorder: Option<MockRecorder>,
+    gas_meter: Option<MockGasMeter>,
+    stateful: bool,
+}
+
+impl Default for DesmosKeeper {
+    fn default() -> Self {
+        DesmosKeeper::new()
+    }
 }
 
 impl DesmosKeeper {
     /// Returns a new [DesmosKeeper].
+    /// The keeper does not track the Desmos state: every message succeeds with canned events
+    /// and every query is answered by its [MockDesmosQuerier].
     pub fn new() -> Self {
+        DesmosKeeper::with_custom_querier(MockDesmosQuerier::new(&[]))
+    }
+
+    /// Returns a new [DesmosKeeper] that tracks the Desmos state inside the app storage,
+    /// failing the messages and the queries as the chain would.
+    /// The queries about the modules whose state is not tracked are answered by its [MockDesmosQuerier].
+    ///
+    /// # Example
+    /// ```
+    /// use desmos_bindings::mocks::mock_keeper::DesmosKeeper;
+    /// use desmos_bindings::mocks::mock_apps::custom_desmos_app;
+    /// use desmos_bindings::profiles::querier::ProfilesQuerier;
+    /// use cosmwasm_std::Addr;
+    /// use std::ops::Deref;
+    ///
+    /// let app = custom_desmos_app(DesmosKeeper::new_stateful(), |_, _, _| {});
+    /// let querier = app.wrap();
+    /// let result = ProfilesQuerier::new(querier.deref()).query_profile(Addr::unchecked("user"));
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new_stateful() -> Self {
         DesmosKeeper {
-            querier: MockDesmosQuerier::new(&[]),
+            stateful: true,
+            ..DesmosKeeper::new()
         }
     }
 
     /// Returns a new [DesmosKeeper] with a custom instance of [MockDesmosQuerier].
+    /// Like [DesmosKeeper::new], the keeper does not track the Desmos state.
     pub fn with_custom_querier(querier: MockDesmosQuerier) -> Self {
-        DesmosKeeper { querier }
+        DesmosKeeper {
+            querier,
+            #[cfg(feature = "profiles")]
+            oracle: Box::new(mock_oracle_response),
+            msg_failures: vec![],
+            query_failures: vec![],
+            handled_msgs: Cell::new(0),
+            handled_queries: Cell::new(0),
+            recorder: None,
+            gas_meter: None,
+            stateful: false,
+        }
+    }
+
+    /// Function to provide the fake oracle used to verify the application links
+    /// created with [`ProfilesMsg::LinkApplication`](crate::profiles::msg::ProfilesMsg::LinkApplication).
+    /// The oracle is asked to verify each pending link starting from the block after the one
+    /// in which the link has been created, until it answers or the link times out.
+    /// Since a query can not change the state, the oracle may be asked more than once
+    /// for the same block, so it should answer the same way each time.
+    /// * `oracle` - Function that decides the outcome of the verification.
+    ///
+    /// # Example
+    /// ```
+    /// use desmos_bindings::mocks::mock_keeper::DesmosKeeper;
+    /// use desmos_bindings::mocks::mock_oracle::MockOracleResponse;
+    ///
+    /// let keeper = DesmosKeeper::new_stateful().with_custom_oracle(|link, _block| {
+    ///     if link.data.application == "twitter" {
+    ///         MockOracleResponse::Failure {
+    ///             error: "tweet not found".to_string(),
+    ///         }
+    ///     } else {
+    ///         MockOracleResponse::Pending
+    ///     }
+    /// });
+    /// ```
+    #[cfg(feature = "profiles")]
+    pub fn with_custom_oracle<F>(mut self, oracle: F) -> Self
+    where
+        F: Fn(&ApplicationLink, &BlockInfo) -> MockOracleResponse + 'static,
+    {
+        self.oracle = Box::new(oracle);
+        self
+    }
+
+    /// Function to make the keeper fail some of the messages it handles.
+    /// The messages are checked against the failures in the order in which they have been added,
+    /// and the ones that fail do not change the state of the keeper.
+    /// * `failure` - Failure to be injected.
+    ///
+    /// # Example
+    /// ```
+    /// use desmos_bindings::mocks::mock_failures::{DesmosError, MockFailure};
+    /// use desmos_bindings::mocks::mock_keeper::DesmosKeeper;
+    /// use desmos_bindings::msg::DesmosMsg;
+    ///
+    /// let keeper = DesmosKeeper::new()
+    ///     // Fail the second message handled by the keeper.
+    ///     .with_msg_failure(MockFailure::at(2, DesmosError::ProfileNotFound))
+    ///     // Fail all the subspaces messages.
+    ///     .with_msg_failure(MockFailure::matching(
+    ///         |msg| matches!(msg, DesmosMsg::Subspaces(_)),
+    ///         DesmosError::SubspaceNotFound(5),
+    ///     ));
+    /// ```
+    pub fn with_msg_failure(mut self, failure: MockFailure<DesmosMsg>) -> Self {
+        self.msg_failures.push(failure);
+        self
+    }
+
+    /// Function to make the keeper fail some of the queries it handles.
+    /// The queries are checked against the failures in the order in which they have been added.
+    /// * `failure` - Failure to be injected.
+    ///
+    /// # Example
+    /// ```
+    /// use desmos_bindings::mocks::mock_failures::{DesmosError, MockFailure};
+    /// use desmos_bindings::mocks::mock_keeper::DesmosKeeper;
+    /// use desmos_bindings::profiles::query::ProfilesQuery;
+    /// use desmos_bindings::query::DesmosQuery;
+    ///
+    /// let keeper = DesmosKeeper::new().with_query_failure(MockFailure::matching(
+    ///     |query| matches!(query, DesmosQuery::Profiles(ProfilesQuery::Profile { .. })),
+    ///     DesmosError::ProfileNotFound,
+    /// ));
+    /// ```
+    pub fn with_query_failure(mut self, failure: MockFailure<DesmosQuery>) -> Self {
+        self.query_failures.push(failure);
+        self
+    }
+
+    /// Function to provide a recorder that keeps track of all the messages and queries
+    /// handled by the keeper, including the ones that fail.
+    /// * `recorder` - Recorder used to store the messages and queries.
+    pub fn with_recorder(mut self, recorder: MockRecorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    /// Function to provide a gas meter that charges the messages and queries handled by the keeper,
+    /// making them fail once its limit is exceeded.
+    /// The keeper charges also the queries it forwards to its querier, so the same meter
+    /// should not be provided to the querier too.
+    /// * `gas_meter` - Gas meter used to charge the messages and queries.
+    pub fn with_gas_meter(mut self, gas_meter: MockGasMeter) -> Self {
+        self.gas_meter = Some(gas_meter);
+        self
     }
 
     /// Handles [`ProfilesMsg`](crate::profiles::msg::ProfilesMsg) then returns the response with proper [events](https://github.com/desmos-labs/desmos/blob/master/x/profiles/spec/05-events.md).
+    /// The profiles and the DTag transfer requests are tracked inside the provided storage
+    /// so that they can be traded between users as it happens on chain.
+    /// Deleting a profile also deletes the application links, relationships and blocks of its owner,
+    /// emitting the related events.
     #[cfg(feature = "profiles")]
-    pub fn handle_profiles_msg(block: &BlockInfo, msg: ProfilesMsg) -> AnyResult<AppResponse> {
+    pub fn handle_profiles_msg(
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        msg: ProfilesMsg,
+    ) -> AnyResult<AppResponse> {
         match msg {
-            ProfilesMsg::SaveProfile { dtag, creator, .. } => {
+            ProfilesMsg::SaveProfile {
+                dtag,
+                nickname,
+                bio,
+                profile_picture,
+                cover_picture,
+                creator,
+            } => {
+                let mut profile = mock_state::PROFILES
+                    .may_load(storage, &creator)?
+                    .unwrap_or_else(|| {
+                        DesmosKeeper::new_profile(&creator, format_rfc3339_time(block.time))
+                    });
+                let update = |current: &mut String, value: String| {
+                    if value != DO_NOT_MODIFY {
+                        *current = value;
+                    }
+                };
+                update(&mut profile.dtag, dtag);
+                update(&mut profile.nickname, nickname);
+                update(&mut profile.bio, bio);
+                update(&mut profile.pictures.profile, profile_picture);
+                update(&mut profile.pictures.cover, cover_picture);
+                if profile.dtag.is_empty() {
+                    bail!("invalid profile dtag");
+                }
+                if let Some(owner) = DesmosKeeper::get_profile_by_dtag(storage, &profile.dtag)? {
+                    if owner.account.address != creator {
+                        bail!(
+                            "a profile with dtag {} has already been created",
+                            profile.dtag
+                        );
+                    }
+                }
+                DesmosKeeper::save_profile(storage, &profile)?;
+
                 let events = vec![Event::new("save_profile")
-                    .add_attribute("profile_dtag", dtag)
+                    .add_attribute("profile_dtag", profile.dtag)
                     .add_attribute("profile_creator", creator)
-                    .add_attribute("profile_creation_time", block.time.to_string())];
+                    .add_attribute("profile_creation_time", profile.creation_date)];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
             ProfilesMsg::DeleteProfile { creator, .. } => {
-                let events =
-                    vec![Event::new("delete_profile").add_attribute("profile_creator", creator)];
+                DesmosKeeper::delete_incoming_dtag_transfer_requests(storage, &creator)?;
+                mock_state::PROFILES.remove(storage, &creator);
+                let mut events =
+                    vec![Event::new("delete_profile")
+                        .add_attribute("profile_creator", creator.clone())];
+                let links = mock_state::APPLICATION_LINKS
+                    .sub_prefix(&creator)
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| item.map(|(_, link)| link))
+                    .collect::<StdResult<Vec<_>>>()?;
+                for link in links {
+                    DesmosKeeper::delete_application_link(storage, &link);
+                    events.push(
+                        Event::new("unlink_application")
+                            .add_attribute("user", creator.clone())
+                            .add_attribute("application_name", link.data.application)
+                            .add_attribute("application_username", link.data.username),
+                    );
+                }
+                #[cfg(feature = "relationships")]
+                events.extend(DesmosKeeper::delete_relationships(
+                    storage,
+                    |first, second, _| first == &creator || second == &creator,
+                )?);
                 AnyResult::Ok(AppResponse { events, data: None })
             }
             ProfilesMsg::RequestDtagTransfer {
                 sender: request_sender,
                 receiver: request_receiver,
             } => {
-                let events = vec![Event::new("create_dtag_transfer_request")
-                    .add_attribute("dtag_to_trade", "test")
-                    .add_attribute("request_sender", request_sender)
-                    .add_attribute("request_receiver", request_receiver)];
+                if request_sender == request_receiver {
+                    bail!("the sender and receiver must be different");
+                }
+                let receiver_profile = match mock_state::PROFILES.may_load(storage, &request_receiver)? {
+                    Some(profile) => profile,
+                    None => bail!(
+                        "The user with address {} doesn't have a profile yet so their DTag cannot be transferred",
+                        request_receiver
+                    ),
+                };
+                if mock_state::DTAG_TRANSFER_REQUESTS
+                    .has(storage, (&request_receiver, &request_sender))
+                {
+                    bail!(
+                        "the transfer request from {} to {} has already been made",
+                        request_sender,
+                        request_receiver
+                    );
+                }
+                let request = DtagTransferRequest {
+                    dtag_to_trade: receiver_profile.dtag,
+                    sender: request_sender,
+                    receiver: request_receiver,
+                };
+                mock_state::DTAG_TRANSFER_REQUESTS.save(
+                    storage,
+                    (&request.receiver, &request.sender),
+                    &request,
+                )?;
+
+                let events = vec![Event::new("request_dtag_transfer")
+                    .add_attribute("dtag_to_trade", request.dtag_to_trade)
+                    .add_attribute("request_sender", request.sender)
+                    .add_attribute("request_receiver", request.receiver)];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
             ProfilesMsg::AcceptDtagTransferRequest {
@@ -75,8 +432,43 @@ impl DesmosKeeper {
                 sender: request_sender,
                 receiver: request_receiver,
             } => {
+                let request = match mock_state::DTAG_TRANSFER_REQUESTS
+                    .may_load(storage, (&request_receiver, &request_sender))?
+                {
+                    Some(request) => request,
+                    None => bail!("no request made from {}", request_sender),
+                };
+                if let Some(owner) = DesmosKeeper::get_profile_by_dtag(storage, &new_dtag)? {
+                    if owner.account.address != request_sender
+                        && owner.account.address != request_receiver
+                    {
+                        bail!("a profile with dtag {} has already been created", new_dtag);
+                    }
+                }
+
+                // As on chain, the receiver takes the new DTag while the sender gets the traded one.
+                mock_state::DTAG_TRANSFER_REQUESTS
+                    .remove(storage, (&request_receiver, &request_sender));
+                let mut receiver_profile = mock_state::PROFILES
+                    .may_load(storage, &request_receiver)?
+                    .unwrap_or_else(|| {
+                        DesmosKeeper::new_profile(
+                            &request_receiver,
+                            format_rfc3339_time(block.time),
+                        )
+                    });
+                receiver_profile.dtag = new_dtag.clone();
+                DesmosKeeper::save_profile(storage, &receiver_profile)?;
+                let mut sender_profile = mock_state::PROFILES
+                    .may_load(storage, &request_sender)?
+                    .unwrap_or_else(|| {
+                        DesmosKeeper::new_profile(&request_sender, format_rfc3339_time(block.time))
+                    });
+                sender_profile.dtag = request.dtag_to_trade.clone();
+                DesmosKeeper::save_profile(storage, &sender_profile)?;
+
                 let events = vec![Event::new("accept_dtag_transfer_request")
-                    .add_attribute("dtag_to_trade", "test")
+                    .add_attribute("dtag_to_trade", request.dtag_to_trade)
                     .add_attribute("new_dtag", new_dtag)
                     .add_attribute("request_sender", request_sender)
                     .add_attribute("request_receiver", request_receiver)];
@@ -86,6 +478,14 @@ impl DesmosKeeper {
                 sender: request_sender,
                 receiver: request_receiver,
             } => {
+                if !mock_state::DTAG_TRANSFER_REQUESTS
+                    .has(storage, (&request_receiver, &request_sender))
+                {
+                    bail!("no request made from {}", request_sender);
+                }
+                mock_state::DTAG_TRANSFER_REQUESTS
+                    .remove(storage, (&request_receiver, &request_sender));
+
                 let events = vec![Event::new("refuse_dtag_transfer_request")
                     .add_attribute("request_sender", request_sender)
                     .add_attribute("request_receiver", request_receiver)];
@@ -95,6 +495,18 @@ impl DesmosKeeper {
                 sender: request_sender,
                 receiver: request_receiver,
             } => {
+                if !mock_state::DTAG_TRANSFER_REQUESTS
+                    .has(storage, (&request_receiver, &request_sender))
+                {
+                    bail!(
+                        "request from {} to {} not found",
+                        request_sender,
+                        request_receiver
+                    );
+                }
+                mock_state::DTAG_TRANSFER_REQUESTS
+                    .remove(storage, (&request_receiver, &request_sender));
+
                 let events = vec![Event::new("cancel_dtag_transfer_request")
                     .add_attribute("request_sender", request_sender)
                     .add_attribute("request_receiver", request_receiver)];
@@ -110,7 +522,7 @@ impl DesmosKeeper {
                     .add_attribute("chain_link_account_target", chain_address.value)
                     .add_attribute("chain_link_source_chain_name", chain_config.name)
                     .add_attribute("chain_link_account_owner", owner)
-                    .add_attribute("chain_link_creation_time", block.time.to_string())];
+                    .add_attribute("chain_link_creation_time", format_rfc3339_time(block.time))];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
             ProfilesMsg::UnlinkChainAccount {
@@ -138,13 +550,72 @@ impl DesmosKeeper {
             ProfilesMsg::LinkApplication {
                 sender: user,
                 link_data,
+                call_data,
+                timeout_height,
+                timeout_timestamp,
                 ..
             } => {
+                let params = MockProfilesQueries::get_mock_params();
+                let validity_duration = params
+                    .app_links
+                    .validity_duration
+                    .trim_end_matches('s')
+                    .parse::<u64>()?;
+                let client_id =
+                    format!("{}-{}-{}", user, link_data.application, link_data.username);
+                let oracle_request_id = mock_state::LAST_ORACLE_REQUEST_ID
+                    .may_load(storage)?
+                    .unwrap_or_default()
+                    + 1;
+                mock_state::LAST_ORACLE_REQUEST_ID.save(storage, &oracle_request_id)?;
+                let link = ApplicationLink {
+                    user: user.clone(),
+                    data: Data {
+                        application: link_data.application.clone(),
+                        username: link_data.username.clone(),
+                    },
+                    state: ApplicationLinkState::Initialized,
+                    oracle_request: OracleRequest {
+                        id: Uint64::new(oracle_request_id),
+                        oracle_script_id: params.oracle.script_id,
+                        call_data: CallData {
+                            application: link_data.application.clone(),
+                            call_data,
+                        },
+                        client_id: client_id.clone(),
+                    },
+                    result: None,
+                    creation_time: format_rfc3339_time(block.time),
+                    expiration_time: format_rfc3339_time(
+                        block.time.plus_seconds(validity_duration),
+                    ),
+                };
+                mock_state::APPLICATION_LINKS.save(
+                    storage,
+                    (&user, &link_data.application, &link_data.username),
+                    &link,
+                )?;
+                mock_state::PENDING_APPLICATION_LINKS.save(
+                    storage,
+                    &client_id,
+                    &PendingApplicationLink {
+                        user: user.clone(),
+                        application: link_data.application.clone(),
+                        username: link_data.username.clone(),
+                        sent_height: block.height,
+                        timeout_height,
+                        timeout_timestamp,
+                    },
+                )?;
+
                 let events = vec![Event::new("link_application")
                     .add_attribute("user", user)
                     .add_attribute("application_name", link_data.application)
                     .add_attribute("application_username", link_data.username)
-                    .add_attribute("application_link_creation_time", block.time.to_string())];
+                    .add_attribute(
+                        "application_link_creation_time",
+                        format_rfc3339_time(block.time),
+                    )];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
             ProfilesMsg::UnlinkApplication {
@@ -152,6 +623,14 @@ impl DesmosKeeper {
                 username,
                 signer: user,
             } => {
+                let link = match mock_state::APPLICATION_LINKS
+                    .may_load(storage, (&user, &application, &username))?
+                {
+                    Some(link) => link,
+                    None => bail!("application link not found"),
+                };
+                DesmosKeeper::delete_application_link(storage, &link);
+
                 let events = vec![Event::new("unlink_application")
                     .add_attribute("user", user)
                     .add_attribute("application_name", application)
@@ -161,39 +640,1009 @@ impl DesmosKeeper {
         }
     }
 
+    /// Creates a new profile without a DTag for the provided user, based on
+    /// [`MockProfilesQueries::get_mock_profile`].
+    #[cfg(feature = "profiles")]
+    fn new_profile(user: &Addr, creation_date: String) -> Profile {
+        let mut profile = MockProfilesQueries::get_mock_profile();
+        profile.account.address = user.clone();
+        profile.dtag = String::new();
+        profile.nickname = String::new();
+        profile.bio = String::new();
+        profile.pictures.profile = String::new();
+        profile.pictures.cover = String::new();
+        profile.creation_date = creation_date;
+        profile
+    }
+
+    /// Returns the profile having the provided DTag, which is compared case-insensitively, if any.
+    #[cfg(feature = "profiles")]
+    fn get_profile_by_dtag(storage: &dyn Storage, dtag: &str) -> StdResult<Option<Profile>> {
+        mock_state::PROFILES
+            .range(storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, profile)| profile))
+            .find(|item| {
+                item.as_ref()
+                    .map_or(true, |profile| profile.dtag.eq_ignore_ascii_case(dtag))
+            })
+            .transpose()
+    }
+
+    /// Saves the provided profile, deleting the DTag transfer requests made to its owner
+    /// if the DTag has changed since they could no longer be accepted.
+    #[cfg(feature = "profiles")]
+    fn save_profile(storage: &mut dyn Storage, profile: &Profile) -> StdResult<()> {
+        let owner = &profile.account.address;
+        if let Some(existing) = mock_state::PROFILES.may_load(storage, owner)? {
+            if existing.dtag != profile.dtag {
+                DesmosKeeper::delete_incoming_dtag_transfer_requests(storage, owner)?;
+            }
+        }
+        mock_state::PROFILES.save(storage, owner, profile)
+    }
+
+    /// Returns the DTag transfer requests made to the provided user.
+    #[cfg(feature = "profiles")]
+    fn get_incoming_dtag_transfer_requests(
+        storage: &dyn Storage,
+        receiver: &Addr,
+    ) -> StdResult<Vec<DtagTransferRequest>> {
+        mock_state::DTAG_TRANSFER_REQUESTS
+            .prefix(receiver)
+            .range(storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, request)| request))
+            .collect()
+    }
+
+    /// Deletes the DTag transfer requests made to the provided user.
+    #[cfg(feature = "profiles")]
+    fn delete_incoming_dtag_transfer_requests(
+        storage: &mut dyn Storage,
+        receiver: &Addr,
+    ) -> StdResult<()> {
+        for request in DesmosKeeper::get_incoming_dtag_transfer_requests(storage, receiver)? {
+            mock_state::DTAG_TRANSFER_REQUESTS.remove(storage, (receiver, &request.sender));
+        }
+        Ok(())
+    }
+
+    /// Deletes the provided application link together with its pending oracle request.
+    #[cfg(feature = "profiles")]
+    fn delete_application_link(storage: &mut dyn Storage, link: &ApplicationLink) {
+        mock_state::PENDING_APPLICATION_LINKS.remove(storage, &link.oracle_request.client_id);
+        mock_state::APPLICATION_LINKS.remove(
+            storage,
+            (&link.user, &link.data.application, &link.data.username),
+        );
+    }
+
+    /// Answers the [`ProfilesQuery`](crate::profiles::query::ProfilesQuery) that can be served from the
+    /// profiles and DTag transfer requests tracked by the keeper, returning `None` for the others.
+    #[cfg(feature = "profiles")]
+    fn query_profiles_state(
+        storage: &dyn Storage,
+        query: &ProfilesQuery,
+    ) -> AnyResult<Option<Binary>> {
+        match query {
+            ProfilesQuery::Profile { user } => {
+                let profile =
+                    match mock_state::PROFILES.may_load(storage, &Addr::unchecked(user))? {
+                        Some(profile) => profile,
+                        None => match DesmosKeeper::get_profile_by_dtag(storage, user)? {
+                            Some(profile) => profile,
+                            None => bail!(DesmosError::ProfileNotFound),
+                        },
+                    };
+                AnyResult::Ok(Some(to_binary(&QueryProfileResponse { profile })?))
+            }
+            ProfilesQuery::IncomingDtagTransferRequests { receiver, .. } => {
+                let requests =
+                    DesmosKeeper::get_incoming_dtag_transfer_requests(storage, receiver)?;
+                let response = QueryIncomingDtagTransferRequestsResponse {
+                    requests,
+                    pagination: None,
+                };
+                AnyResult::Ok(Some(to_binary(&response)?))
+            }
+            ProfilesQuery::ApplicationLinkByClientID { client_id } => {
+                let link = mock_state::APPLICATION_LINKS
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| item.map(|(_, link)| link))
+                    .find(|item| {
+                        item.as_ref()
+                            .map_or(true, |link| link.oracle_request.client_id == *client_id)
+                    })
+                    .transpose()?;
+                let link = match link {
+                    Some(link) => link,
+                    // Once the keeper tracks the application links, the mocked one is not returned anymore.
+                    None if mock_state::LAST_ORACLE_REQUEST_ID
+                        .may_load(storage)?
+                        .is_some() =>
+                    {
+                        bail!("link for client id {} not found", client_id)
+                    }
+                    None => return AnyResult::Ok(None),
+                };
+                let response = QueryApplicationLinkByClientIDResponse { link };
+                AnyResult::Ok(Some(to_binary(&response)?))
+            }
+            _ => AnyResult::Ok(None),
+        }
+    }
+
+    /// Updates the state of an application link whose oracle request is still pending
+    /// based on the provided block, returning `true` if the link will not change anymore.
+    /// The packet is relayed to the oracle in the block after the one in which it has been sent,
+    /// then the oracle answers unless the packet times out first.
+    #[cfg(feature = "profiles")]
+    fn update_application_link(
+        &self,
+        link: &mut ApplicationLink,
+        pending: &PendingApplicationLink,
+        block: &BlockInfo,
+    ) -> bool {
+        let timeout_height = pending.timeout_height.revision_height.u64();
+        let timeout_timestamp = pending.timeout_timestamp.u64();
+        if (timeout_height != 0 && block.height >= timeout_height)
+            || (timeout_timestamp != 0 && block.time.nanos() >= timeout_timestamp)
+        {
+            link.state = ApplicationLinkState::TimedOut;
+            return true;
+        }
+        if block.height <= pending.sent_height {
+            return false;
+        }
+
+        link.state = ApplicationLinkState::VerificationStarted;
+        match (self.oracle)(link, block) {
+            MockOracleResponse::Success { value, signature } => {
+                link.state = ApplicationLinkState::VerificationSuccess;
+                link.result = Some(AppLinkResult::Success { value, signature });
+                true
+            }
+            MockOracleResponse::Failure { error } => {
+                link.state = ApplicationLinkState::VerificationError;
+                link.result = Some(AppLinkResult::Failed { error });
+                true
+            }
+            MockOracleResponse::Pending => false,
+        }
+    }
+
+    /// Advances the oracle flow of all the application links that are still pending
+    /// up to the provided block.
+    #[cfg(feature = "profiles")]
+    fn process_application_links(
+        &self,
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+    ) -> AnyResult<()> {
+        let pending_links = mock_state::PENDING_APPLICATION_LINKS
+            .range(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (client_id, pending) in pending_links {
+            let key = (
+                &pending.user,
+                pending.application.as_str(),
+                pending.username.as_str(),
+            );
+            let mut link = match mock_state::APPLICATION_LINKS.may_load(storage, key)? {
+                Some(link) => link,
+                None => {
+                    mock_state::PENDING_APPLICATION_LINKS.remove(storage, &client_id);
+                    continue;
+                }
+            };
+            if self.update_application_link(&mut link, &pending, block) {
+                mock_state::PENDING_APPLICATION_LINKS.remove(storage, &client_id);
+            }
+            mock_state::APPLICATION_LINKS.save(storage, key, &link)?;
+        }
+        AnyResult::Ok(())
+    }
+
+    /// Tells whether the provided application link has expired in the given block.
+    #[cfg(feature = "profiles")]
+    fn is_application_link_expired(link: &ApplicationLink, block: &BlockInfo) -> AnyResult<bool> {
+        Ok(parse_rfc3339_time(&link.expiration_time)? <= block.time)
+    }
+
+    /// Returns the end date of the poll represented by the provided attachment,
+    /// or `None` if the attachment is not a valid poll.
+    #[cfg(feature = "posts")]
+    fn poll_end_date(attachment: &RawPostAttachment) -> Option<Timestamp> {
+        match PostAttachment::try_from(attachment.clone()) {
+            Ok(PostAttachment::Poll { end_date, .. }) => parse_rfc3339_time(&end_date).ok(),
+            _ => None,
+        }
+    }
+
+    /// Computes the final tally results of the poll represented by the provided attachment
+    /// if its voting period has ended in the given block, returning the updated attachment.
+    /// Returns `None` if the attachment is not a poll, the poll is still open or it has already been tallied.
+    #[cfg(feature = "posts")]
+    fn tally_poll(
+        storage: &dyn Storage,
+        key: (u64, u64, u32),
+        attachment: &RawPostAttachment,
+        block: &BlockInfo,
+    ) -> AnyResult<Option<RawPostAttachment>> {
+        match DesmosKeeper::poll_end_date(attachment) {
+            Some(end_date) if end_date <= block.time => {}
+            _ => return AnyResult::Ok(None),
+        }
+        let answers = mock_state::POLL_ANSWERS
+            .may_load(storage, key)?
+            .unwrap_or_default();
+        match PostAttachment::try_from(attachment.clone()) {
+            Ok(PostAttachment::Poll {
+                question,
+                provided_answers,
+                end_date,
+                allows_multiple_answers,
+                allows_answer_edits,
+                final_tally_results: None,
+            }) => {
+                let results = (0..provided_answers.len() as u32)
+                    .map(|answer_index| AnswerResult {
+                        answer_index,
+                        votes: Uint64::new(
+                            answers
+                                .iter()
+                                .filter(|answer| answer.answers_indexes.contains(&answer_index))
+                                .count() as u64,
+                        ),
+                    })
+                    .collect();
+                let poll = PostAttachment::Poll {
+                    question,
+                    provided_answers,
+                    end_date,
+                    allows_multiple_answers,
+                    allows_answer_edits,
+                    final_tally_results: Some(PollTallyResults { results }),
+                };
+                AnyResult::Ok(Some(poll.into()))
+            }
+            _ => AnyResult::Ok(None),
+        }
+    }
+
+    /// Answers the [`PostsQuery`](crate::posts::query::PostsQuery) that can be served from the
+    /// posts tracked by the keeper, returning `None` for the others.
+    #[cfg(feature = "posts")]
+    fn query_posts_state(storage: &dyn Storage, query: &PostsQuery) -> AnyResult<Option<Binary>> {
+        match query {
+            PostsQuery::SubspacePosts { subspace_id, .. } => {
+                let posts = mock_state::POSTS
+                    .prefix(subspace_id.u64())
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| item.map(|(_, post)| post))
+                    .collect::<StdResult<Vec<_>>>()?;
+                let response = QuerySubspacePostsResponse {
+                    posts,
+                    pagination: None,
+                };
+                AnyResult::Ok(Some(to_binary(&response)?))
+            }
+            PostsQuery::SectionPosts {
+                subspace_id,
+                section_id,
+                ..
+            } => {
+                let posts = mock_state::POSTS
+                    .prefix(subspace_id.u64())
+                    .range(storage, None, None, Order::Ascending)
+                    .filter(|item| {
+                        item.as_ref()
+                            .map_or(true, |(_, post)| post.section_id == *section_id)
+                    })
+                    .map(|item| item.map(|(_, post)| post))
+                    .collect::<StdResult<Vec<_>>>()?;
+                let response = QuerySectionPostsResponse {
+                    posts,
+                    pagination: None,
+                };
+                AnyResult::Ok(Some(to_binary(&response)?))
+            }
+            PostsQuery::Post {
+                subspace_id,
+                post_id,
+            } => match mock_state::POSTS.may_load(storage, (subspace_id.u64(), post_id.u64()))? {
+                Some(post) => AnyResult::Ok(Some(to_binary(&QueryPostResponse { post })?)),
+                None => bail!(DesmosError::PostNotFound(post_id.u64())),
+            },
+            PostsQuery::PostAttachments {
+                subspace_id,
+                post_id,
+                ..
+            } => {
+                let attachments = mock_state::POST_ATTACHMENTS
+                    .prefix((subspace_id.u64(), post_id.u64()))
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| {
+                        item.map(|(id, content)| Attachment {
+                            subspace_id: *subspace_id,
+                            post_id: *post_id,
+                            id,
+                            content,
+                        })
+                    })
+                    .collect::<StdResult<Vec<_>>>()?;
+                let response = QueryPostAttachmentsResponse {
+                    attachments,
+                    pagination: None,
+                };
+                AnyResult::Ok(Some(to_binary(&response)?))
+            }
+            PostsQuery::PollAnswers {
+                subspace_id,
+                post_id,
+                poll_id,
+                user,
+                ..
+            } => {
+                let answers = mock_state::POLL_ANSWERS
+                    .may_load(storage, (subspace_id.u64(), post_id.u64(), *poll_id))?
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|answer| user.is_none() || user.as_ref() == Some(&answer.user))
+                    .collect();
+                let response = QueryPollAnswersResponse {
+                    answers,
+                    pagination: None,
+                };
+                AnyResult::Ok(Some(to_binary(&response)?))
+            }
+            #[cfg(feature = "desmos-v5")]
+            PostsQuery::IncomingPostOwnerTransferRequests {
+                subspace_id,
+                receiver,
+                ..
+            } => {
+                let requests = mock_state::POST_OWNER_TRANSFER_REQUESTS
+                    .prefix(subspace_id.u64())
+                    .range(storage, None, None, Order::Ascending)
+                    .filter(|item| {
+                        item.as_ref().map_or(true, |(_, request)| {
+                            receiver.is_none() || receiver.as_ref() == Some(&request.receiver)
+                        })
+                    })
+                    .map(|item| item.map(|(_, request)| request))
+                    .collect::<StdResult<Vec<_>>>()?;
+                let response = QueryIncomingPostOwnerTransferRequestsResponse {
+                    requests,
+                    pagination: None,
+                };
+                AnyResult::Ok(Some(to_binary(&response)?))
+            }
+            _ => AnyResult::Ok(None),
+        }
+    }
+
+    /// Answers the [`SubspacesQuery`](crate::subspaces::query::SubspacesQuery) that can be served
+    /// from the subspaces, sections and user groups tracked by the keeper, returning `None` for the others.
+    #[cfg(feature = "subspaces")]
+    fn query_subspaces_state(
+        storage: &dyn Storage,
+        query: &SubspacesQuery,
+    ) -> AnyResult<Option<Binary>> {
+        match query {
+            SubspacesQuery::Subspaces { .. } => {
+                let subspaces = mock_state::SUBSPACES
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| item.map(|(_, subspace)| subspace))
+                    .collect::<StdResult<Vec<_>>>()?;
+                let response = QuerySubspacesResponse {
+                    subspaces,
+                    pagination: None,
+                };
+                AnyResult::Ok(Some(to_binary(&response)?))
+            }
+            SubspacesQuery::Subspace { subspace_id } => {
+                match mock_state::SUBSPACES.may_load(storage, subspace_id.u64())? {
+                    Some(subspace) => {
+                        AnyResult::Ok(Some(to_binary(&QuerySubspaceResponse { subspace })?))
+                    }
+                    None => bail!(DesmosError::SubspaceNotFound(subspace_id.u64())),
+                }
+            }
+            SubspacesQuery::Sections { subspace_id, .. } => {
+                let sections = mock_state::SECTIONS
+                    .prefix(subspace_id.u64())
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| item.map(|(_, section)| section))
+                    .collect::<StdResult<Vec<_>>>()?;
+                let response = QuerySectionsResponse {
+                    sections,
+                    pagination: None,
+                };
+                AnyResult::Ok(Some(to_binary(&response)?))
+            }
+            SubspacesQuery::Section {
+                subspace_id,
+                section_id,
+            } => match mock_state::SECTIONS.may_load(storage, (subspace_id.u64(), *section_id))? {
+                Some(section) => AnyResult::Ok(Some(to_binary(&QuerySectionResponse { section })?)),
+                None => bail!(DesmosError::SectionNotFound(*section_id)),
+            },
+            SubspacesQuery::UserGroups {
+                subspace_id,
+                section_id,
+                ..
+            } => {
+                let groups = mock_state::USER_GROUPS
+                    .prefix(subspace_id.u64())
+                    .range(storage, None, None, Order::Ascending)
+                    .filter(|item| {
+                        item.as_ref().map_or(true, |(_, group)| {
+                            section_id.is_none() || *section_id == group.section_id
+                        })
+                    })
+                    .map(|item| item.map(|(_, group)| group))
+                    .collect::<StdResult<Vec<_>>>()?;
+                let response = QueryUserGroupsResponse {
+                    groups,
+                    pagination: None,
+                };
+                AnyResult::Ok(Some(to_binary(&response)?))
+            }
+            SubspacesQuery::UserGroup {
+                subspace_id,
+                group_id,
+            } => match mock_state::USER_GROUPS.may_load(storage, (subspace_id.u64(), *group_id))? {
+                Some(group) => AnyResult::Ok(Some(to_binary(&QueryUserGroupResponse { group })?)),
+                None => bail!(DesmosError::UserGroupNotFound(*group_id)),
+            },
+            _ => AnyResult::Ok(None),
+        }
+    }
+
+    /// Answers the [`ReactionsQuery`](crate::reactions::query::ReactionsQuery) that can be served
+    /// from the reactions tracked by the keeper, returning `None` for the others.
+    #[cfg(feature = "reactions")]
+    fn query_reactions_state(
+        storage: &dyn Storage,
+        query: &ReactionsQuery,
+    ) -> AnyResult<Option<Binary>> {
+        match query {
+            ReactionsQuery::Reactions {
+                subspace_id,
+                post_id,
+                user,
+                ..
+            } => {
+                let reactions = mock_state::REACTIONS
+                    .prefix((subspace_id.u64(), post_id.u64()))
+                    .range(storage, None, None, Order::Ascending)
+                    .filter(|item| {
+                        item.as_ref().map_or(true, |(_, reaction)| {
+                            user.is_none() || user.as_ref() == Some(&reaction.author)
+                        })
+                    })
+                    .map(|item| item.map(|(_, reaction)| reaction))
+                    .collect::<StdResult<Vec<_>>>()?;
+                let response = QueryReactionsResponse {
+                    reactions,
+                    pagination: None,
+                };
+                AnyResult::Ok(Some(to_binary(&response)?))
+            }
+            ReactionsQuery::Reaction {
+                subspace_id,
+                post_id,
+                reaction_id,
+            } => {
+                let key = (subspace_id.u64(), post_id.u64(), *reaction_id);
+                match mock_state::REACTIONS.may_load(storage, key)? {
+                    Some(reaction) => {
+                        AnyResult::Ok(Some(to_binary(&QueryReactionResponse { reaction })?))
+                    }
+                    None => bail!(DesmosError::ReactionNotFound(*reaction_id)),
+                }
+            }
+            ReactionsQuery::RegisteredReactions { subspace_id, .. } => {
+                let registered_reactions = mock_state::REGISTERED_REACTIONS
+                    .prefix(subspace_id.u64())
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| item.map(|(_, registered_reaction)| registered_reaction))
+                    .collect::<StdResult<Vec<_>>>()?;
+                let response = QueryRegisteredReactionsResponse {
+                    registered_reactions,
+                    pagination: None,
+                };
+                AnyResult::Ok(Some(to_binary(&response)?))
+            }
+            ReactionsQuery::RegisteredReaction {
+                subspace_id,
+                reaction_id,
+            } => {
+                let key = (subspace_id.u64(), *reaction_id);
+                match mock_state::REGISTERED_REACTIONS.may_load(storage, key)? {
+                    Some(registered_reaction) => {
+                        AnyResult::Ok(Some(to_binary(&QueryRegisteredReactionResponse {
+                            registered_reaction,
+                        })?))
+                    }
+                    None => bail!(DesmosError::RegisteredReactionNotFound(*reaction_id)),
+                }
+            }
+            _ => AnyResult::Ok(None),
+        }
+    }
+
+    /// Answers the [`ReportsQuery`](crate::reports::query::ReportsQuery) that can be served
+    /// from the reports and reasons tracked by the keeper, returning `None` for the others.
+    #[cfg(feature = "reports")]
+    fn query_reports_state(
+        storage: &dyn Storage,
+        query: &ReportsQuery,
+    ) -> AnyResult<Option<Binary>> {
+        match query {
+            ReportsQuery::Reports {
+                subspace_id,
+                target,
+                reporter,
+                ..
+            } => {
+                let reports = mock_state::REPORTS
+                    .prefix(subspace_id.u64())
+                    .range(storage, None, None, Order::Ascending)
+                    .filter(|item| {
+                        item.as_ref().map_or(true, |(_, report)| {
+                            (target.is_none() || target.as_ref() == Some(&report.target))
+                                && (reporter.is_none()
+                                    || reporter.as_ref() == Some(&report.reporter))
+                        })
+                    })
+                    .map(|item| item.map(|(_, report)| report))
+                    .collect::<StdResult<Vec<_>>>()?;
+                let response = QueryReportsResponse {
+                    reports,
+                    pagination: None,
+                };
+                AnyResult::Ok(Some(to_binary(&response)?))
+            }
+            ReportsQuery::Report {
+                subspace_id,
+                report_id,
+            } => match mock_state::REPORTS
+                .may_load(storage, (subspace_id.u64(), report_id.u64()))?
+            {
+                Some(report) => AnyResult::Ok(Some(to_binary(&QueryReportResponse { report })?)),
+                None => bail!(DesmosError::ReportNotFound(report_id.u64())),
+            },
+            ReportsQuery::Reasons { subspace_id, .. } => {
+                let reasons = mock_state::REASONS
+                    .prefix(subspace_id.u64())
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| item.map(|(_, reason)| reason))
+                    .collect::<StdResult<Vec<_>>>()?;
+                let response = QueryReasonsResponse {
+                    reasons,
+                    pagination: None,
+                };
+                AnyResult::Ok(Some(to_binary(&response)?))
+            }
+            ReportsQuery::Reason {
+                subspace_id,
+                reason_id,
+            } => match mock_state::REASONS.may_load(storage, (subspace_id.u64(), *reason_id))? {
+                Some(reason) => AnyResult::Ok(Some(to_binary(&QueryReasonResponse { reason })?)),
+                None => bail!(DesmosError::ReasonNotFound(*reason_id)),
+            },
+            _ => AnyResult::Ok(None),
+        }
+    }
+
+    /// Returns whether the end-blocker still has to run for the provided block,
+    /// which happens when the keeper tracks the Desmos state and the block height or time
+    /// has been advanced since the last time it has run.
+    fn is_end_block_pending(&self, storage: &dyn Storage, block: &BlockInfo) -> StdResult<bool> {
+        if !self.stateful {
+            return Ok(false);
+        }
+        Ok(match mock_state::LAST_END_BLOCK.may_load(storage)? {
+            Some((height, time)) => block.height > height || block.time > time,
+            None => true,
+        })
+    }
+
+    /// Performs the operations that the chain executes at the end of each block:
+    /// * tallies the polls whose voting period has ended;
+    /// * advances the oracle flow of the pending application links;
+    /// * deletes the application links that have expired.
+    ///
+    /// As the chain does, it runs once per block: the keeper runs it before handling the first
+    /// message or query of a block advanced since the last processed one, and
+    /// [`DesmosAppExt::update_desmos_block`](crate::mocks::mock_apps::DesmosAppExt::update_desmos_block)
+    /// runs it as soon as the block is advanced.
+    /// It does nothing when the keeper does not track the Desmos state.
+    pub fn end_block(&self, storage: &mut dyn Storage, block: &BlockInfo) -> AnyResult<()> {
+        if !self.is_end_block_pending(storage, block)? {
+            return Ok(());
+        }
+        mock_state::LAST_END_BLOCK.save(storage, &(block.height, block.time))?;
+        #[cfg(feature = "posts")]
+        {
+            let attachments = mock_state::POST_ATTACHMENTS
+                .range(storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()?;
+            for (key, attachment) in attachments {
+                if let Some(poll) = DesmosKeeper::tally_poll(storage, key, &attachment, block)? {
+                    mock_state::POST_ATTACHMENTS.save(storage, key, &poll)?;
+                }
+            }
+        }
+
+        #[cfg(feature = "profiles")]
+        {
+            self.process_application_links(storage, block)?;
+            let links = mock_state::APPLICATION_LINKS
+                .range(storage, None, None, Order::Ascending)
+                .map(|item| item.map(|(_, link)| link))
+                .collect::<StdResult<Vec<_>>>()?;
+            for link in links {
+                if DesmosKeeper::is_application_link_expired(&link, block)? {
+                    DesmosKeeper::delete_application_link(storage, &link);
+                }
+            }
+        }
+
+        AnyResult::Ok(())
+    }
+
+    /// Deletes all the objects contained inside the subspace with the given id,
+    /// mimicking the hooks called on chain, then returns the events of the performed deletions.
+    #[cfg(feature = "subspaces")]
+    fn delete_subspace_content(
+        storage: &mut dyn Storage,
+        subspace_id: u64,
+    ) -> AnyResult<Vec<Event>> {
+        let mut events = vec![];
+
+        let sections = mock_state::SECTIONS
+            .prefix(subspace_id)
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for section_id in sections {
+            mock_state::SECTIONS.remove(storage, (subspace_id, section_id));
+            events.push(
+                Event::new("delete_section")
+                    .add_attribute("subspace_id", subspace_id.to_string())
+                    .add_attribute("section_id", section_id.to_string()),
+            );
+        }
+
+        let groups = mock_state::USER_GROUPS
+            .prefix(subspace_id)
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for group_id in groups {
+            mock_state::USER_GROUPS.remove(storage, (subspace_id, group_id));
+            events.push(
+                Event::new("delete_user_group")
+                    .add_attribute("subspace_id", subspace_id.to_string())
+                    .add_attribute("user_group_id", group_id.to_string()),
+            );
+        }
+
+        #[cfg(feature = "posts")]
+        {
+            let posts = mock_state::POSTS
+                .prefix(subspace_id)
+                .keys(storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()?;
+            for post_id in posts {
+                events.push(
+                    Event::new("delete_post")
+                        .add_attribute("subspace_id", subspace_id.to_string())
+                        .add_attribute("post_id", post_id.to_string()),
+                );
+                events.extend(DesmosKeeper::delete_post_content(
+                    storage,
+                    subspace_id,
+                    post_id,
+                )?);
+            }
+        }
+
+        #[cfg(feature = "reactions")]
+        {
+            let registered_reactions = mock_state::REGISTERED_REACTIONS
+                .prefix(subspace_id)
+                .keys(storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()?;
+            for registered_reaction_id in registered_reactions {
+                mock_state::REGISTERED_REACTIONS
+                    .remove(storage, (subspace_id, registered_reaction_id));
+                events.push(
+                    Event::new("remove_registered_reaction")
+                        .add_attribute("subspace_id", subspace_id.to_string())
+                        .add_attribute(
+                            "registered_reaction_id",
+                            registered_reaction_id.to_string(),
+                        ),
+                );
+            }
+        }
+
+        #[cfg(feature = "reports")]
+        {
+            let reports = mock_state::REPORTS
+                .prefix(subspace_id)
+                .keys(storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()?;
+            for report_id in reports {
+                mock_state::REPORTS.remove(storage, (subspace_id, report_id));
+                events.push(
+                    Event::new("delete_report")
+                        .add_attribute("subspace_id", subspace_id.to_string())
+                        .add_attribute("report_id", report_id.to_string()),
+                );
+            }
+
+            let reasons = mock_state::REASONS
+                .prefix(subspace_id)
+                .keys(storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()?;
+            for reason_id in reasons {
+                mock_state::REASONS.remove(storage, (subspace_id, reason_id));
+                events.push(
+                    Event::new("remove_reason")
+                        .add_attribute("subspace_id", subspace_id.to_string())
+                        .add_attribute("reason_id", reason_id.to_string()),
+                );
+            }
+        }
+
+        #[cfg(feature = "relationships")]
+        events.extend(DesmosKeeper::delete_relationships(
+            storage,
+            |_, _, relationship_subspace_id| relationship_subspace_id == subspace_id,
+        )?);
+
+        mock_state::SUBSPACES.remove(storage, subspace_id);
+        AnyResult::Ok(events)
+    }
+
+    /// Deletes the post with the given id together with its attachments, poll answers,
+    /// reactions and reports, mimicking the hooks called on chain,
+    /// then returns the events of the performed deletions.
+    #[cfg(feature = "posts")]
+    fn delete_post_content(
+        storage: &mut dyn Storage,
+        subspace_id: u64,
+        post_id: u64,
+    ) -> AnyResult<Vec<Event>> {
+        let mut events = vec![];
+        mock_state::POSTS.remove(storage, (subspace_id, post_id));
+
+        let attachments = mock_state::POST_ATTACHMENTS
+            .prefix((subspace_id, post_id))
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for attachment_id in attachments {
+            mock_state::POST_ATTACHMENTS.remove(storage, (subspace_id, post_id, attachment_id));
+            events.push(
+                Event::new("remove_post_attachment")
+                    .add_attribute("subspace_id", subspace_id.to_string())
+                    .add_attribute("post_id", post_id.to_string())
+                    .add_attribute("attachment_id", attachment_id.to_string()),
+            );
+        }
+
+        let polls = mock_state::POLL_ANSWERS
+            .prefix((subspace_id, post_id))
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for poll_id in polls {
+            mock_state::POLL_ANSWERS.remove(storage, (subspace_id, post_id, poll_id));
+        }
+
+        #[cfg(feature = "desmos-v5")]
+        mock_state::POST_OWNER_TRANSFER_REQUESTS.remove(storage, (subspace_id, post_id));
+
+        #[cfg(feature = "reactions")]
+        {
+            let reactions = mock_state::REACTIONS
+                .prefix((subspace_id, post_id))
+                .keys(storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()?;
+            for reaction_id in reactions {
+                mock_state::REACTIONS.remove(storage, (subspace_id, post_id, reaction_id));
+                events.push(
+                    Event::new("remove_reaction")
+                        .add_attribute("subspace_id", subspace_id.to_string())
+                        .add_attribute("post_id", post_id.to_string())
+                        .add_attribute("reaction_id", reaction_id.to_string()),
+                );
+            }
+        }
+
+        #[cfg(feature = "reports")]
+        {
+            let reports = mock_state::REPORTS
+                .prefix(subspace_id)
+                .range(storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()?;
+            for (report_id, report) in reports {
+                let reported_post_id = match ReportTarget::try_from(report.target)? {
+                    ReportTarget::Post { post_id } => Some(post_id.u64()),
+                    ReportTarget::User { .. } => None,
+                };
+                if reported_post_id == Some(post_id) {
+                    mock_state::REPORTS.remove(storage, (subspace_id, report_id));
+                    events.push(
+                        Event::new("delete_report")
+                            .add_attribute("subspace_id", subspace_id.to_string())
+                            .add_attribute("report_id", report_id.to_string()),
+                    );
+                }
+            }
+        }
+
+        AnyResult::Ok(events)
+    }
+
+    /// Deletes the relationships and the user blocks matching the given filter, which receives
+    /// the two users involved and the subspace id, then returns the events of the performed deletions.
+    #[cfg(all(
+        feature = "relationships",
+        any(feature = "profiles", feature = "subspaces")
+    ))]
+    fn delete_relationships<F>(storage: &mut dyn Storage, filter: F) -> AnyResult<Vec<Event>>
+    where
+        F: Fn(&Addr, &Addr, u64) -> bool,
+    {
+        let mut events = vec![];
+
+        let relationships = mock_state::RELATIONSHIPS
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (creator, counterparty, subspace_id) in relationships {
+            if filter(&creator, &counterparty, subspace_id) {
+                mock_state::RELATIONSHIPS.remove(storage, (&creator, &counterparty, subspace_id));
+                events.push(
+                    Event::new("delete_relationship")
+                        .add_attribute("creator", creator)
+                        .add_attribute("counterparty", counterparty)
+                        .add_attribute("subspace", subspace_id.to_string()),
+                );
+            }
+        }
+
+        let blocks = mock_state::USER_BLOCKS
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (blocker, blocked, subspace_id) in blocks {
+            if filter(&blocker, &blocked, subspace_id) {
+                mock_state::USER_BLOCKS.remove(storage, (&blocker, &blocked, subspace_id));
+                events.push(
+                    Event::new("unblock_user")
+                        .add_attribute("blocker", blocker)
+                        .add_attribute("blocked", blocked)
+                        .add_attribute("subspace", subspace_id.to_string()),
+                );
+            }
+        }
+
+        AnyResult::Ok(events)
+    }
+
     /// Handles [`SubspacesMsg`](crate::subspaces::msg::SubspacesMsg) then returns the response with proper [events](https://github.com/desmos-labs/desmos/blob/master/x/subspaces/spec/05-events.md).
+    /// Deleting a subspace also deletes all the objects it contains, emitting the related events.
     #[cfg(feature = "subspaces")]
-    pub fn handle_subspaces_msg(block: &BlockInfo, msg: SubspacesMsg) -> AnyResult<AppResponse> {
+    pub fn handle_subspaces_msg(
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        msg: SubspacesMsg,
+    ) -> AnyResult<AppResponse> {
         match msg {
-            SubspacesMsg::CreateSubspace { name, creator, .. } => {
+            SubspacesMsg::CreateSubspace {
+                name,
+                description,
+                treasury,
+                owner,
+                creator,
+            } => {
+                let subspace_id = mock_state::next_id(storage, "subspaces")?;
+                let subspace = Subspace {
+                    id: subspace_id.into(),
+                    name: name.clone(),
+                    description,
+                    treasury,
+                    // The chain sets the creator as owner when no owner is given.
+                    owner: if owner.as_str().is_empty() {
+                        creator.clone()
+                    } else {
+                        owner
+                    },
+                    creator: creator.clone(),
+                    creation_time: format_rfc3339_time(block.time),
+                };
+                mock_state::SUBSPACES.save(storage, subspace_id, &subspace)?;
                 let events = vec![Event::new("create_subspace")
-                    .add_attribute("subspace_id", 1.to_string())
+                    .add_attribute("subspace_id", subspace_id.to_string())
                     .add_attribute("subspace_name", name)
                     .add_attribute("subspace_creator", creator)
-                    .add_attribute("creation_date", block.time.to_string())];
+                    .add_attribute("creation_date", subspace.creation_time)];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
-            SubspacesMsg::EditSubspace { subspace_id, .. } => {
+            SubspacesMsg::EditSubspace {
+                subspace_id,
+                name,
+                description,
+                treasury,
+                owner,
+                ..
+            } => {
+                if let Some(mut subspace) =
+                    mock_state::SUBSPACES.may_load(storage, subspace_id.u64())?
+                {
+                    if name != SUBSPACES_DO_NOT_MODIFY {
+                        subspace.name = name;
+                    }
+                    if description != SUBSPACES_DO_NOT_MODIFY {
+                        subspace.description = description;
+                    }
+                    if treasury.as_str() != SUBSPACES_DO_NOT_MODIFY {
+                        subspace.treasury = treasury;
+                    }
+                    if owner.as_str() != SUBSPACES_DO_NOT_MODIFY {
+                        subspace.owner = owner;
+                    }
+                    mock_state::SUBSPACES.save(storage, subspace_id.u64(), &subspace)?;
+                }
                 let events =
                     vec![Event::new("edit_subspace").add_attribute("subspace_id", subspace_id)];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
             SubspacesMsg::DeleteSubspace { subspace_id, .. } => {
-                let events =
+                let mut events =
                     vec![Event::new("delete_subspace").add_attribute("subspace_id", subspace_id)];
+                events.extend(DesmosKeeper::delete_subspace_content(
+                    storage,
+                    subspace_id.u64(),
+                )?);
                 AnyResult::Ok(AppResponse { events, data: None })
             }
-            SubspacesMsg::CreateSection { subspace_id, .. } => {
+            SubspacesMsg::CreateSection {
+                subspace_id,
+                name,
+                description,
+                parent_id,
+                ..
+            } => {
+                let section_id =
+                    mock_state::next_id(storage, &format!("sections/{}", subspace_id))? as u32;
+                let section = Section {
+                    subspace_id,
+                    id: section_id,
+                    parent_id,
+                    name,
+                    description: description.unwrap_or_default(),
+                };
+                mock_state::SECTIONS.save(storage, (subspace_id.u64(), section_id), &section)?;
                 let events = vec![Event::new("create_section")
                     .add_attribute("subspace_id", subspace_id)
-                    .add_attribute("create_section", 1.to_string())];
+                    .add_attribute("section_id", section_id.to_string())];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
             SubspacesMsg::EditSection {
                 subspace_id,
                 section_id,
+                name,
+                description,
                 ..
             } => {
+                let key = (subspace_id.u64(), section_id);
+                if let Some(mut section) = mock_state::SECTIONS.may_load(storage, key)? {
+                    section.name = name.unwrap_or(section.name);
+                    section.description = description.unwrap_or(section.description);
+                    mock_state::SECTIONS.save(storage, key, &section)?;
+                }
                 let events = vec![Event::new("edit_section")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("section_id", section_id.to_string())];
@@ -202,8 +1651,14 @@ impl DesmosKeeper {
             SubspacesMsg::MoveSection {
                 subspace_id,
                 section_id,
+                new_parent_id,
                 ..
             } => {
+                let key = (subspace_id.u64(), section_id);
+                if let Some(mut section) = mock_state::SECTIONS.may_load(storage, key)? {
+                    section.parent_id = Some(new_parent_id);
+                    mock_state::SECTIONS.save(storage, key, &section)?;
+                }
                 let events = vec![Event::new("move_section")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("section_id", section_id.to_string())];
@@ -214,22 +1669,65 @@ impl DesmosKeeper {
                 section_id,
                 ..
             } => {
+                #[cfg(feature = "posts")]
+                for item in mock_state::POSTS.prefix(subspace_id.u64()).range(
+                    storage,
+                    None,
+                    None,
+                    Order::Ascending,
+                ) {
+                    if item?.1.section_id == section_id {
+                        bail!(
+                            "section {} of subspace {} still contains some posts",
+                            section_id,
+                            subspace_id
+                        );
+                    }
+                }
+                mock_state::SECTIONS.remove(storage, (subspace_id.u64(), section_id));
+
                 let events = vec![Event::new("delete_section")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("section_id", section_id.to_string())];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
-            SubspacesMsg::CreateUserGroup { subspace_id, .. } => {
+            SubspacesMsg::CreateUserGroup {
+                subspace_id,
+                section_id,
+                name,
+                description,
+                default_permissions,
+                ..
+            } => {
+                let group_id =
+                    mock_state::next_id(storage, &format!("user_groups/{}", subspace_id))? as u32;
+                let group = UserGroup {
+                    subspace_id,
+                    section_id,
+                    id: group_id,
+                    name,
+                    description: description.unwrap_or_default(),
+                    permissions: default_permissions,
+                };
+                mock_state::USER_GROUPS.save(storage, (subspace_id.u64(), group_id), &group)?;
                 let events = vec![Event::new("create_user_group")
                     .add_attribute("subspace_id", subspace_id)
-                    .add_attribute("user_group_id", 1.to_string())];
+                    .add_attribute("user_group_id", group_id.to_string())];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
             SubspacesMsg::EditUserGroup {
                 subspace_id,
                 group_id,
+                name,
+                description,
                 ..
             } => {
+                let key = (subspace_id.u64(), group_id);
+                if let Some(mut group) = mock_state::USER_GROUPS.may_load(storage, key)? {
+                    group.name = name.unwrap_or(group.name);
+                    group.description = description.unwrap_or(group.description);
+                    mock_state::USER_GROUPS.save(storage, key, &group)?;
+                }
                 let events = vec![Event::new("edit_user_group")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("user_group_id", group_id.to_string())];
@@ -238,8 +1736,14 @@ impl DesmosKeeper {
             SubspacesMsg::MoveUserGroup {
                 subspace_id,
                 group_id,
+                new_section_id,
                 ..
             } => {
+                let key = (subspace_id.u64(), group_id);
+                if let Some(mut group) = mock_state::USER_GROUPS.may_load(storage, key)? {
+                    group.section_id = Some(new_section_id);
+                    mock_state::USER_GROUPS.save(storage, key, &group)?;
+                }
                 let events = vec![Event::new("move_user_group")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("user_group_id", group_id.to_string())];
@@ -248,8 +1752,14 @@ impl DesmosKeeper {
             SubspacesMsg::SetUserGroupPermissions {
                 subspace_id,
                 group_id,
+                permissions,
                 ..
             } => {
+                let key = (subspace_id.u64(), group_id);
+                if let Some(mut group) = mock_state::USER_GROUPS.may_load(storage, key)? {
+                    group.permissions = permissions;
+                    mock_state::USER_GROUPS.save(storage, key, &group)?;
+                }
                 let events = vec![Event::new("set_user_group_permissions")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("user_group_id", group_id.to_string())];
@@ -260,6 +1770,7 @@ impl DesmosKeeper {
                 group_id,
                 ..
             } => {
+                mock_state::USER_GROUPS.remove(storage, (subspace_id.u64(), group_id));
                 let events = vec![Event::new("delete_user_group")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("user_group_id", group_id.to_string())];
@@ -271,7 +1782,7 @@ impl DesmosKeeper {
                 user,
                 ..
             } => {
-                let events = vec![Event::new("add_group_member")
+                let events = vec![Event::new("add_user_to_group")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("user_group_id", group_id.to_string())
                     .add_attribute("user", user)];
@@ -283,7 +1794,7 @@ impl DesmosKeeper {
                 user,
                 ..
             } => {
-                let events = vec![Event::new("remove_group_member")
+                let events = vec![Event::new("remove_user_from_group")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("user_group_id", group_id.to_string())
                     .add_attribute("user", user)];
@@ -297,12 +1808,70 @@ impl DesmosKeeper {
                     .add_attribute("user", user)];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
+            SubspacesMsg::GrantTreasuryAuthorization {
+                subspace_id,
+                granter,
+                grantee,
+                ..
+            } => {
+                let events = vec![Event::new("grant_treasury_authorization")
+                    .add_attribute("subspace_id", subspace_id)
+                    .add_attribute("granter", granter)
+                    .add_attribute("grantee", grantee)];
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            SubspacesMsg::RevokeTreasuryAuthorization {
+                subspace_id,
+                granter,
+                grantee,
+                ..
+            } => {
+                let events = vec![Event::new("revoke_treasury_authorization")
+                    .add_attribute("subspace_id", subspace_id)
+                    .add_attribute("granter", granter)
+                    .add_attribute("grantee", grantee)];
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            SubspacesMsg::GrantAllowance {
+                subspace_id,
+                granter,
+                grantee,
+                ..
+            } => {
+                let event = Event::new("grant_allowance")
+                    .add_attribute("subspace_id", subspace_id)
+                    .add_attribute("granter", granter);
+                let events = vec![match Grantee::try_from(grantee)? {
+                    Grantee::User { user } => event.add_attribute("user_grantee", user),
+                    Grantee::Group { group_id } => {
+                        event.add_attribute("group_grantee", group_id.to_string())
+                    }
+                }];
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            SubspacesMsg::RevokeAllowance {
+                subspace_id,
+                granter,
+                grantee,
+            } => {
+                let event = Event::new("revoke_allowance")
+                    .add_attribute("subspace_id", subspace_id)
+                    .add_attribute("granter", granter);
+                let events = vec![match Grantee::try_from(grantee)? {
+                    Grantee::User { user } => event.add_attribute("user_grantee", user),
+                    Grantee::Group { group_id } => {
+                        event.add_attribute("group_grantee", group_id.to_string())
+                    }
+                }];
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
         }
     }
 
     /// Handles [`RelationshipsMsg`](crate::relationships::msg::RelationshipsMsg) then returns the response with proper [events](https://github.com/desmos-labs/desmos/blob/master/x/relationships/spec/05-events.md).
     #[cfg(feature = "relationships")]
     pub fn handle_relationships_msg(
+        storage: &mut dyn Storage,
         _block: &BlockInfo,
         msg: RelationshipsMsg,
     ) -> AnyResult<AppResponse> {
@@ -312,6 +1881,11 @@ impl DesmosKeeper {
                 counterparty,
                 subspace_id,
             } => {
+                mock_state::RELATIONSHIPS.save(
+                    storage,
+                    (&creator, &counterparty, subspace_id.u64()),
+                    &Empty {},
+                )?;
                 let events = vec![Event::new("create_relationship")
                     .add_attribute("creator", creator)
                     .add_attribute("counterparty", counterparty)
@@ -323,6 +1897,8 @@ impl DesmosKeeper {
                 counterparty,
                 subspace_id,
             } => {
+                mock_state::RELATIONSHIPS
+                    .remove(storage, (&creator, &counterparty, subspace_id.u64()));
                 let events = vec![Event::new("delete_relationship")
                     .add_attribute("creator", creator)
                     .add_attribute("counterparty", counterparty)
@@ -335,6 +1911,11 @@ impl DesmosKeeper {
                 subspace_id,
                 ..
             } => {
+                mock_state::USER_BLOCKS.save(
+                    storage,
+                    (&blocker, &blocked, subspace_id.u64()),
+                    &Empty {},
+                )?;
                 let events = vec![Event::new("block_user")
                     .add_attribute("blocker", blocker)
                     .add_attribute("blocked", blocked)
@@ -346,7 +1927,8 @@ impl DesmosKeeper {
                 blocked,
                 subspace_id,
             } => {
-                let events = vec![Event::new("create_dtag_transfer_request")
+                mock_state::USER_BLOCKS.remove(storage, (&blocker, &blocked, subspace_id.u64()));
+                let events = vec![Event::new("unblock_user")
                     .add_attribute("blocker", blocker)
                     .add_attribute("blocked", blocked)
                     .add_attribute("subspace", subspace_id)];
@@ -356,32 +1938,86 @@ impl DesmosKeeper {
     }
 
     /// Handles [`PostsMsg`](crate::posts::msg::PostsMsg) then returns the response with proper [events](https://github.com/desmos-labs/desmos/blob/master/x/posts/spec/05-events.md).
+    /// Deleting a post also deletes its attachments, poll answers, reactions and reports, emitting the related events.
     #[cfg(feature = "posts")]
-    pub fn handle_posts_msg(block: &BlockInfo, msg: PostsMsg) -> AnyResult<AppResponse> {
+    pub fn handle_posts_msg(
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        msg: PostsMsg,
+    ) -> AnyResult<AppResponse> {
         match msg {
             PostsMsg::CreatePost {
                 subspace_id,
                 section_id,
+                external_id,
+                text,
+                entities,
+                tags,
+                attachments,
                 author,
-                ..
+                conversation_id,
+                reply_settings,
+                referenced_posts,
             } => {
+                let post_id = mock_state::next_id(storage, &format!("posts/{}", subspace_id))?;
+                let post = Post {
+                    id: post_id.into(),
+                    subspace_id,
+                    section_id,
+                    external_id,
+                    text,
+                    entities,
+                    tags,
+                    author: author.clone(),
+                    #[cfg(feature = "desmos-v5")]
+                    owner: author.clone(),
+                    conversation_id,
+                    referenced_posts,
+                    reply_settings,
+                    creation_date: format_rfc3339_time(block.time),
+                    last_edited_date: None,
+                };
+                mock_state::POSTS.save(storage, (subspace_id.u64(), post_id), &post)?;
+                for attachment in attachments.unwrap_or_default() {
+                    let attachment_id = mock_state::next_id(
+                        storage,
+                        &format!("post_attachments/{}/{}", subspace_id, post_id),
+                    )? as u32;
+                    mock_state::POST_ATTACHMENTS.save(
+                        storage,
+                        (subspace_id.u64(), post_id, attachment_id),
+                        &attachment,
+                    )?;
+                }
+
                 let events = vec![Event::new("create_post")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("section_id", section_id.to_string())
-                    .add_attribute("post_id", 1.to_string())
+                    .add_attribute("post_id", post_id.to_string())
                     .add_attribute("author", author)
-                    .add_attribute("creation_time", block.time.to_string())];
+                    .add_attribute("creation_time", format_rfc3339_time(block.time))];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
             PostsMsg::EditPost {
                 subspace_id,
                 post_id,
+                text,
+                entities,
                 ..
             } => {
+                let key = (subspace_id.u64(), post_id.u64());
+                if let Some(mut post) = mock_state::POSTS.may_load(storage, key)? {
+                    if text != "[do-not-modify]" {
+                        post.text = Some(text);
+                    }
+                    post.entities = entities;
+                    post.last_edited_date = Some(format_rfc3339_time(block.time));
+                    mock_state::POSTS.save(storage, key, &post)?;
+                }
                 let events = vec![Event::new("edit_post")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("post_id", post_id)
-                    .add_attribute("last_edit_time", block.time.to_string())];
+                    .add_attribute("last_edit_time", format_rfc3339_time(block.time))];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
             PostsMsg::DeletePost {
@@ -389,21 +2025,36 @@ impl DesmosKeeper {
                 post_id,
                 ..
             } => {
-                let events = vec![Event::new("delete_post")
+                let mut events = vec![Event::new("delete_post")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("post_id", post_id)];
+                events.extend(DesmosKeeper::delete_post_content(
+                    storage,
+                    subspace_id.u64(),
+                    post_id.u64(),
+                )?);
                 AnyResult::Ok(AppResponse { events, data: None })
             }
             PostsMsg::AddPostAttachment {
                 subspace_id,
                 post_id,
+                content,
                 ..
             } => {
+                let attachment_id = mock_state::next_id(
+                    storage,
+                    &format!("post_attachments/{}/{}", subspace_id, post_id),
+                )? as u32;
+                mock_state::POST_ATTACHMENTS.save(
+                    storage,
+                    (subspace_id.u64(), post_id.u64(), attachment_id),
+                    &content,
+                )?;
                 let events = vec![Event::new("add_post_attachment")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("post_id", post_id)
-                    .add_attribute("attachment_id", 1.to_string())
-                    .add_attribute("last_edit_time", block.time.to_string())];
+                    .add_attribute("attachment_id", attachment_id.to_string())
+                    .add_attribute("last_edit_time", format_rfc3339_time(block.time))];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
             PostsMsg::RemovePostAttachment {
@@ -412,44 +2063,266 @@ impl DesmosKeeper {
                 attachment_id,
                 ..
             } => {
+                mock_state::POST_ATTACHMENTS
+                    .remove(storage, (subspace_id.u64(), post_id.u64(), attachment_id));
                 let events = vec![Event::new("remove_post_attachment")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("post_id", post_id)
                     .add_attribute("attachment_id", attachment_id.to_string())
-                    .add_attribute("last_edit_time", block.time.to_string())];
+                    .add_attribute("last_edit_time", format_rfc3339_time(block.time))];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
             PostsMsg::AnswerPoll {
                 subspace_id,
                 post_id,
                 poll_id,
-                ..
+                answers_indexes,
+                signer,
             } => {
+                let key = (subspace_id.u64(), post_id.u64(), poll_id);
+                if let Some(attachment) = mock_state::POST_ATTACHMENTS.may_load(storage, key)? {
+                    if let Some(end_date) = DesmosKeeper::poll_end_date(&attachment) {
+                        if end_date <= block.time {
+                            bail!("the poll voting period has already ended");
+                        }
+                    }
+                }
+                mock_state::POLL_ANSWERS.update(storage, key, |answers| -> StdResult<_> {
+                    let mut answers: Vec<UserAnswer> = answers.unwrap_or_default();
+                    answers.retain(|answer| answer.user != signer);
+                    answers.push(UserAnswer {
+                        subspace_id,
+                        post_id,
+                        poll_id,
+                        answers_indexes,
+                        user: signer,
+                    });
+                    Ok(answers)
+                })?;
                 let events = vec![Event::new("answer_poll")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("post_id", post_id)
                     .add_attribute("poll_id", poll_id.to_string())];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
+            #[cfg(feature = "desmos-v5")]
+            PostsMsg::MovePost {
+                subspace_id,
+                post_id,
+                target_subspace_id,
+                target_section_id,
+                ..
+            } => {
+                let post = match mock_state::POSTS
+                    .may_load(storage, (subspace_id.u64(), post_id.u64()))?
+                {
+                    Some(post) => post,
+                    None => bail!(DesmosError::PostNotFound(post_id.u64())),
+                };
+                let new_post_id =
+                    mock_state::next_id(storage, &format!("posts/{}", target_subspace_id))?;
+                let mut events = vec![Event::new("move_post")
+                    .add_attribute("subspace_id", subspace_id)
+                    .add_attribute("post_id", post_id)
+                    .add_attribute("new_subspace_id", target_subspace_id)
+                    .add_attribute("new_post_id", new_post_id.to_string())];
+
+                // The attachments and the poll answers are moved together with the post,
+                // while its reactions and reports are deleted.
+                let attachments = mock_state::POST_ATTACHMENTS
+                    .prefix((subspace_id.u64(), post_id.u64()))
+                    .range(storage, None, None, Order::Ascending)
+                    .collect::<StdResult<Vec<_>>>()?;
+                for (attachment_id, attachment) in attachments {
+                    mock_state::POST_ATTACHMENTS.save(
+                        storage,
+                        (target_subspace_id.u64(), new_post_id, attachment_id),
+                        &attachment,
+                    )?;
+                }
+                let answers = mock_state::POLL_ANSWERS
+                    .prefix((subspace_id.u64(), post_id.u64()))
+                    .range(storage, None, None, Order::Ascending)
+                    .collect::<StdResult<Vec<_>>>()?;
+                for (poll_id, answers) in answers {
+                    let answers = answers
+                        .into_iter()
+                        .map(|answer| UserAnswer {
+                            subspace_id: target_subspace_id,
+                            post_id: new_post_id.into(),
+                            ..answer
+                        })
+                        .collect::<Vec<_>>();
+                    mock_state::POLL_ANSWERS.save(
+                        storage,
+                        (target_subspace_id.u64(), new_post_id, poll_id),
+                        &answers,
+                    )?;
+                }
+                events.extend(
+                    DesmosKeeper::delete_post_content(storage, subspace_id.u64(), post_id.u64())?
+                        .into_iter()
+                        .filter(|event| event.ty != "remove_post_attachment"),
+                );
+                let post = Post {
+                    id: new_post_id.into(),
+                    subspace_id: target_subspace_id,
+                    section_id: target_section_id,
+                    ..post
+                };
+                mock_state::POSTS.save(storage, (target_subspace_id.u64(), new_post_id), &post)?;
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            #[cfg(feature = "desmos-v5")]
+            PostsMsg::RequestPostOwnerTransfer {
+                subspace_id,
+                post_id,
+                receiver,
+                sender,
+            } => {
+                let key = (subspace_id.u64(), post_id.u64());
+                let post = match mock_state::POSTS.may_load(storage, key)? {
+                    Some(post) => post,
+                    None => bail!(DesmosError::PostNotFound(post_id.u64())),
+                };
+                if post.owner != sender {
+                    bail!(DesmosError::InsufficientPermissions);
+                }
+                if post.owner == receiver {
+                    bail!("the receiver cannot be the current post owner");
+                }
+                if mock_state::POST_OWNER_TRANSFER_REQUESTS.has(storage, key) {
+                    bail!(
+                        "a post owner transfer request for post with id {} already exists",
+                        post_id
+                    );
+                }
+                let request = PostOwnerTransferRequest {
+                    subspace_id,
+                    post_id,
+                    sender,
+                    receiver,
+                };
+                mock_state::POST_OWNER_TRANSFER_REQUESTS.save(storage, key, &request)?;
+
+                let events = vec![Event::new("request_post_owner_transfer")
+                    .add_attribute("subspace_id", subspace_id)
+                    .add_attribute("post_id", post_id)
+                    .add_attribute("receiver", request.receiver)
+                    .add_attribute("sender", request.sender)];
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            #[cfg(feature = "desmos-v5")]
+            PostsMsg::CancelPostOwnerTransferRequest {
+                subspace_id,
+                post_id,
+                sender,
+            } => {
+                let key = (subspace_id.u64(), post_id.u64());
+                let request =
+                    match mock_state::POST_OWNER_TRANSFER_REQUESTS.may_load(storage, key)? {
+                        Some(request) => request,
+                        None => bail!(DesmosError::PostOwnerTransferRequestNotFound(post_id.u64())),
+                    };
+                if request.sender != sender {
+                    bail!(DesmosError::InsufficientPermissions);
+                }
+                mock_state::POST_OWNER_TRANSFER_REQUESTS.remove(storage, key);
+
+                let events = vec![Event::new("cancel_post_owner_transfer_request")
+                    .add_attribute("subspace_id", subspace_id)
+                    .add_attribute("post_id", post_id)
+                    .add_attribute("sender", sender)];
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            #[cfg(feature = "desmos-v5")]
+            PostsMsg::AcceptPostOwnerTransferRequest {
+                subspace_id,
+                post_id,
+                receiver,
+            } => {
+                let key = (subspace_id.u64(), post_id.u64());
+                let request =
+                    match mock_state::POST_OWNER_TRANSFER_REQUESTS.may_load(storage, key)? {
+                        Some(request) => request,
+                        None => bail!(DesmosError::PostOwnerTransferRequestNotFound(post_id.u64())),
+                    };
+                if request.receiver != receiver {
+                    bail!(DesmosError::InsufficientPermissions);
+                }
+                let mut post = match mock_state::POSTS.may_load(storage, key)? {
+                    Some(post) => post,
+                    None => bail!(DesmosError::PostNotFound(post_id.u64())),
+                };
+                post.owner = receiver.clone();
+                mock_state::POSTS.save(storage, key, &post)?;
+                mock_state::POST_OWNER_TRANSFER_REQUESTS.remove(storage, key);
+
+                let events = vec![Event::new("accept_post_owner_transfer_request")
+                    .add_attribute("subspace_id", subspace_id)
+                    .add_attribute("post_id", post_id)
+                    .add_attribute("receiver", receiver)];
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            #[cfg(feature = "desmos-v5")]
+            PostsMsg::RefusePostOwnerTransferRequest {
+                subspace_id,
+                post_id,
+                receiver,
+            } => {
+                let key = (subspace_id.u64(), post_id.u64());
+                let request =
+                    match mock_state::POST_OWNER_TRANSFER_REQUESTS.may_load(storage, key)? {
+                        Some(request) => request,
+                        None => bail!(DesmosError::PostOwnerTransferRequestNotFound(post_id.u64())),
+                    };
+                if request.receiver != receiver {
+                    bail!(DesmosError::InsufficientPermissions);
+                }
+                mock_state::POST_OWNER_TRANSFER_REQUESTS.remove(storage, key);
+
+                let events = vec![Event::new("refuse_post_owner_transfer_request")
+                    .add_attribute("subspace_id", subspace_id)
+                    .add_attribute("post_id", post_id)
+                    .add_attribute("receiver", receiver)];
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
         }
     }
 
     /// Handles [`ReportsMsg`](crate::reports::msg::ReportsMsg) then returns the response with proper [events](https://github.com/desmos-labs/desmos/blob/master/x/reports/spec/05-events.md).
     #[cfg(feature = "reports")]
-    pub fn handle_reports_msg(block: &BlockInfo, msg: ReportsMsg) -> AnyResult<AppResponse> {
+    pub fn handle_reports_msg(
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        msg: ReportsMsg,
+    ) -> AnyResult<AppResponse> {
         match msg {
             ReportsMsg::CreateReport {
                 subspace_id,
+                reasons_ids,
+                message,
                 reporter,
                 target,
-                ..
             } => {
+                let report_target = ReportTarget::try_from(target.clone())?;
+                let report_id = mock_state::next_id(storage, &format!("reports/{}", subspace_id))?;
+                let report = Report {
+                    subspace_id,
+                    id: report_id.into(),
+                    reasons_ids,
+                    message,
+                    reporter: reporter.clone(),
+                    target,
+                    creation_date: format_rfc3339_time(block.time),
+                };
+                mock_state::REPORTS.save(storage, (subspace_id.u64(), report_id), &report)?;
+
                 let mut events = vec![Event::new("create_report")
                     .add_attribute("subspace_id", subspace_id)
-                    .add_attribute("report_id", 1.to_string())
+                    .add_attribute("report_id", report_id.to_string())
                     .add_attribute("reporter", &reporter)
-                    .add_attribute("creation_time", block.time.to_string())];
-                let report_target = ReportTarget::try_from(target)?;
+                    .add_attribute("creation_time", format_rfc3339_time(block.time))];
                 match report_target {
                     ReportTarget::Post { post_id } => {
                         events.push(
@@ -475,6 +2348,7 @@ impl DesmosKeeper {
                 report_id,
                 ..
             } => {
+                mock_state::REPORTS.remove(storage, (subspace_id.u64(), report_id.u64()));
                 let events = vec![Event::new("delete_report")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("report_id", report_id)];
@@ -485,16 +2359,47 @@ impl DesmosKeeper {
                 standard_reason_id,
                 ..
             } => {
+                let standard_reason = match MockReportsQueries::get_mocked_params()
+                    .standard_reasons
+                    .into_iter()
+                    .find(|reason| reason.id == standard_reason_id)
+                {
+                    Some(standard_reason) => standard_reason,
+                    None => bail!("standard reason with id {} not found", standard_reason_id),
+                };
+                let reason_id =
+                    mock_state::next_id(storage, &format!("reasons/{}", subspace_id))? as u32;
+                let reason = Reason {
+                    subspace_id,
+                    id: reason_id,
+                    title: standard_reason.title,
+                    description: standard_reason.description,
+                };
+                mock_state::REASONS.save(storage, (subspace_id.u64(), reason_id), &reason)?;
                 let events = vec![Event::new("support_standard_reason")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("standard_reason_id", standard_reason_id.to_string())
-                    .add_attribute("reason_id", 1.to_string())];
+                    .add_attribute("reason_id", reason_id.to_string())];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
-            ReportsMsg::AddReason { subspace_id, .. } => {
+            ReportsMsg::AddReason {
+                subspace_id,
+                title,
+                description,
+                ..
+            } => {
+                let reason_id =
+                    mock_state::next_id(storage, &format!("reasons/{}", subspace_id))? as u32;
+                let reason = Reason {
+                    subspace_id,
+                    id: reason_id,
+                    title,
+                    description,
+                };
+                mock_state::REASONS.save(storage, (subspace_id.u64(), reason_id), &reason)?;
                 let events = vec![Event::new("add_reason")
                     .add_attribute("subspace_id", subspace_id)
-                    .add_attribute("reason_id", 1.to_string())];
+                    .add_attribute("reason_id", reason_id.to_string())];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
             ReportsMsg::RemoveReason {
@@ -502,7 +2407,8 @@ impl DesmosKeeper {
                 reason_id,
                 ..
             } => {
-                let events = vec![Event::new("create_post")
+                mock_state::REASONS.remove(storage, (subspace_id.u64(), reason_id));
+                let events = vec![Event::new("remove_reason")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("reason_id", reason_id.to_string())];
                 AnyResult::Ok(AppResponse { events, data: None })
@@ -512,18 +2418,38 @@ impl DesmosKeeper {
 
     /// Handles [`ReactionsMsg`](crate::reactions::msg::ReactionsMsg) then returns the response with proper [events](https://github.com/desmos-labs/desmos/blob/master/x/reactions/spec/05-events.md).
     #[cfg(feature = "reactions")]
-    pub fn handle_reactions_msg(_block: &BlockInfo, msg: ReactionsMsg) -> AnyResult<AppResponse> {
+    pub fn handle_reactions_msg(
+        storage: &mut dyn Storage,
+        _block: &BlockInfo,
+        msg: ReactionsMsg,
+    ) -> AnyResult<AppResponse> {
         match msg {
             ReactionsMsg::AddReaction {
                 subspace_id,
                 post_id,
+                value,
                 user,
-                ..
             } => {
+                let reaction_id = mock_state::next_id(
+                    storage,
+                    &format!("reactions/{}/{}", subspace_id, post_id),
+                )? as u32;
+                let reaction = Reaction {
+                    subspace_id,
+                    post_id,
+                    id: reaction_id,
+                    value,
+                    author: user.clone(),
+                };
+                mock_state::REACTIONS.save(
+                    storage,
+                    (subspace_id.u64(), post_id.u64(), reaction_id),
+                    &reaction,
+                )?;
                 let events = vec![Event::new("add_reaction")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("post_id", post_id)
-                    .add_attribute("reaction_id", 1.to_string())
+                    .add_attribute("reaction_id", reaction_id.to_string())
                     .add_attribute("user", user)];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
@@ -533,23 +2459,54 @@ impl DesmosKeeper {
                 reaction_id,
                 ..
             } => {
+                mock_state::REACTIONS
+                    .remove(storage, (subspace_id.u64(), post_id.u64(), reaction_id));
                 let events = vec![Event::new("remove_reaction")
                     .add_attribute("subspace_id", subspace_id)
-                    .add_attribute("post_id	", post_id)
+                    .add_attribute("post_id", post_id)
                     .add_attribute("reaction_id", reaction_id.to_string())];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
-            ReactionsMsg::AddRegisteredReaction { subspace_id, .. } => {
+            ReactionsMsg::AddRegisteredReaction {
+                subspace_id,
+                shorthand_code,
+                display_value,
+                ..
+            } => {
+                let registered_reaction_id =
+                    mock_state::next_id(storage, &format!("registered_reactions/{}", subspace_id))?
+                        as u32;
+                let registered_reaction = RegisteredReaction {
+                    subspace_id,
+                    id: registered_reaction_id,
+                    shorthand_code,
+                    display_value,
+                };
+                mock_state::REGISTERED_REACTIONS.save(
+                    storage,
+                    (subspace_id.u64(), registered_reaction_id),
+                    &registered_reaction,
+                )?;
                 let events = vec![Event::new("add_registered_reaction")
                     .add_attribute("subspace_id", subspace_id)
-                    .add_attribute("registered_reaction_id", 1.to_string())];
+                    .add_attribute("registered_reaction_id", registered_reaction_id.to_string())];
                 AnyResult::Ok(AppResponse { events, data: None })
             }
             ReactionsMsg::EditRegisteredReaction {
                 subspace_id,
                 registered_reaction_id,
+                shorthand_code,
+                display_value,
                 ..
             } => {
+                let key = (subspace_id.u64(), registered_reaction_id);
+                if let Some(mut registered_reaction) =
+                    mock_state::REGISTERED_REACTIONS.may_load(storage, key)?
+                {
+                    registered_reaction.shorthand_code = shorthand_code;
+                    registered_reaction.display_value = display_value;
+                    mock_state::REGISTERED_REACTIONS.save(storage, key, &registered_reaction)?;
+                }
                 let events = vec![Event::new("edit_registered_reaction")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("registered_reaction_id", registered_reaction_id.to_string())];
@@ -560,6 +2517,8 @@ impl DesmosKeeper {
                 registered_reaction_id,
                 ..
             } => {
+                mock_state::REGISTERED_REACTIONS
+                    .remove(storage, (subspace_id.u64(), registered_reaction_id));
                 let events = vec![Event::new("remove_registered_reaction")
                     .add_attribute("subspace_id", subspace_id)
                     .add_attribute("registered_reaction_id", registered_reaction_id.to_string())];
@@ -573,6 +2532,228 @@ impl DesmosKeeper {
             }
         }
     }
+
+    /// Handles [`TokenFactoryMsg`](crate::tokenfactory::msg::TokenFactoryMsg) then returns the response with proper events.
+    /// Minted and burned tokens are tracked through the bank module of the app.
+    #[cfg(feature = "tokenfactory")]
+    pub fn handle_tokenfactory_msg<ExecC, QueryC: CustomQuery>(
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        msg: TokenFactoryMsg,
+    ) -> AnyResult<AppResponse> {
+        match msg {
+            TokenFactoryMsg::CreateDenom {
+                sender,
+                subspace_id,
+                subdenom,
+            } => {
+                let subspace = match mock_state::SUBSPACES.may_load(storage, subspace_id.u64())? {
+                    Some(subspace) => subspace,
+                    None => bail!(DesmosError::SubspaceNotFound(subspace_id.u64())),
+                };
+                if subspace.treasury.as_str().is_empty() {
+                    bail!("subspace with id {} has no treasury", subspace_id);
+                }
+                // Denoms are created on behalf of the subspace, so they belong to its treasury.
+                let denom = get_token_denom(subspace.treasury.as_str(), &subdenom);
+                if mock_state::DENOMS.has(storage, (subspace_id.u64(), &denom)) {
+                    bail!("denom {} already exists", denom);
+                }
+                mock_state::DENOMS.save(storage, (subspace_id.u64(), &denom), &Empty {})?;
+                let events = vec![Event::new("create_denom")
+                    .add_attribute("subspace_id", subspace_id)
+                    .add_attribute("creator", sender.as_str())
+                    .add_attribute("new_token_denom", denom)];
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            TokenFactoryMsg::Mint {
+                subspace_id,
+                amount,
+                mint_to_address,
+                ..
+            } => {
+                let mut response = router.sudo(
+                    api,
+                    storage,
+                    block,
+                    BankSudo::Mint {
+                        to_address: mint_to_address.to_string(),
+                        amount: vec![amount.clone()],
+                    }
+                    .into(),
+                )?;
+                response.events.push(
+                    Event::new("tf_mint")
+                        .add_attribute("subspace_id", subspace_id)
+                        .add_attribute("mint_to_address", mint_to_address)
+                        .add_attribute("amount", amount.to_string()),
+                );
+                AnyResult::Ok(response)
+            }
+            TokenFactoryMsg::Burn {
+                subspace_id,
+                amount,
+                burn_from_address,
+                ..
+            } => {
+                let mut response = router.execute(
+                    api,
+                    storage,
+                    block,
+                    burn_from_address.clone(),
+                    BankMsg::Burn {
+                        amount: vec![amount.clone()],
+                    }
+                    .into(),
+                )?;
+                response.events.push(
+                    Event::new("tf_burn")
+                        .add_attribute("subspace_id", subspace_id)
+                        .add_attribute("burn_from_address", burn_from_address)
+                        .add_attribute("amount", amount.to_string()),
+                );
+                AnyResult::Ok(response)
+            }
+            TokenFactoryMsg::SetDenomMetadata {
+                subspace_id,
+                metadata,
+                ..
+            } => {
+                let events = vec![Event::new("set_denom_metadata")
+                    .add_attribute("subspace_id", subspace_id)
+                    .add_attribute("denom", metadata.base)];
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            TokenFactoryMsg::ChangeAdmin {
+                subspace_id,
+                denom,
+                new_admin,
+                ..
+            } => {
+                let events = vec![Event::new("change_admin")
+                    .add_attribute("subspace_id", subspace_id)
+                    .add_attribute("denom", denom)
+                    .add_attribute("new_admin", new_admin)];
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+        }
+    }
+
+    /// Answers the [`TokenFactoryQuery`](crate::tokenfactory::query::TokenFactoryQuery) that can be
+    /// served from the denoms tracked by the keeper, returning `None` for the others.
+    #[cfg(feature = "tokenfactory")]
+    fn query_tokenfactory_state(
+        storage: &dyn Storage,
+        query: &TokenFactoryQuery,
+    ) -> AnyResult<Option<Binary>> {
+        match query {
+            TokenFactoryQuery::SubspaceDenoms { subspace_id } => {
+                let denoms = mock_state::DENOMS
+                    .prefix(subspace_id.u64())
+                    .keys(storage, None, None, Order::Ascending)
+                    .collect::<StdResult<Vec<String>>>()?;
+                AnyResult::Ok(Some(to_binary(&QuerySubspaceDenomsResponse { denoms })?))
+            }
+            _ => AnyResult::Ok(None),
+        }
+    }
+
+    /// Answers the query using the state tracked by the keeper when possible,
+    /// forwarding it to the querier otherwise.
+    #[cfg_attr(
+        not(any(
+            feature = "profiles",
+            feature = "subspaces",
+            feature = "posts",
+            feature = "reactions",
+            feature = "reports",
+            feature = "tokenfactory"
+        )),
+        allow(unused_variables)
+    )]
+    fn query_desmos(&self, storage: &dyn Storage, request: DesmosQuery) -> AnyResult<Binary> {
+        if !self.stateful {
+            return self.query_querier(request);
+        }
+
+        // Hide this warning since when we compile the package with only the profiles feature
+        // the pattern is irrefutable.
+        #[cfg(feature = "profiles")]
+        #[allow(irrefutable_let_patterns)]
+        if let DesmosQuery::Profiles(query) = &request {
+            if let Some(binary) = DesmosKeeper::query_profiles_state(storage, query)? {
+                return AnyResult::Ok(binary);
+            }
+        }
+        #[cfg(feature = "posts")]
+        #[allow(irrefutable_let_patterns)]
+        if let DesmosQuery::Posts(query) = &request {
+            if let Some(binary) = DesmosKeeper::query_posts_state(storage, query)? {
+                return AnyResult::Ok(binary);
+            }
+        }
+
+        #[cfg(feature = "subspaces")]
+        #[allow(irrefutable_let_patterns)]
+        if let DesmosQuery::Subspaces(query) = &request {
+            if let Some(binary) = DesmosKeeper::query_subspaces_state(storage, query)? {
+                return AnyResult::Ok(binary);
+            }
+        }
+        #[cfg(feature = "reactions")]
+        #[allow(irrefutable_let_patterns)]
+        if let DesmosQuery::Reactions(query) = &request {
+            if let Some(binary) = DesmosKeeper::query_reactions_state(storage, query)? {
+                return AnyResult::Ok(binary);
+            }
+        }
+        #[cfg(feature = "reports")]
+        #[allow(irrefutable_let_patterns)]
+        if let DesmosQuery::Reports(query) = &request {
+            if let Some(binary) = DesmosKeeper::query_reports_state(storage, query)? {
+                return AnyResult::Ok(binary);
+            }
+        }
+
+        #[cfg(feature = "tokenfactory")]
+        if let DesmosQuery::TokenFactory(query) = &request {
+            if let Some(binary) = DesmosKeeper::query_tokenfactory_state(storage, query)? {
+                return AnyResult::Ok(binary);
+            }
+        }
+
+        self.query_querier(request)
+    }
+
+    /// Answers the provided query with the [MockDesmosQuerier] of the keeper.
+    fn query_querier(&self, request: DesmosQuery) -> AnyResult<Binary> {
+        let request = QueryRequest::Custom(request);
+        let result = self.querier.handle_query(&request).into_result();
+
+        if let Result::Err(error) = result {
+            AnyResult::Err(error.into())
+        } else {
+            let contract_result = result.unwrap();
+            match contract_result {
+                ContractResult::Ok(binary) => AnyResult::Ok(binary),
+                ContractResult::Err(err) => AnyResult::Err(anyhow::Error::msg(err)),
+            }
+        }
+    }
+
+    /// Charges the provided message response to the gas meter, if any,
+    /// reporting the gas used inside a [`GAS_EVENT`] event.
+    fn charge_msg(&self, mut response: AppResponse) -> AnyResult<AppResponse> {
+        if let Some(gas_meter) = &self.gas_meter {
+            let gas_used = gas_meter.consume_msg(&response)?;
+            response
+                .events
+                .push(Event::new(GAS_EVENT).add_attribute("gas_used", gas_used.to_string()));
+        }
+        AnyResult::Ok(response)
+    }
 }
 
 impl Module for DesmosKeeper {
@@ -580,29 +2761,56 @@ impl Module for DesmosKeeper {
     type QueryT = DesmosQuery;
     type SudoT = Empty;
 
+    #[cfg_attr(not(feature = "tokenfactory"), allow(unused_variables))]
     fn execute<ExecC, QueryC>(
         &self,
-        _api: &dyn Api,
-        _storage: &mut dyn Storage,
-        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
         block: &BlockInfo,
-        _sender: Addr,
+        sender: Addr,
         msg: DesmosMsg,
-    ) -> AnyResult<AppResponse> {
-        match msg {
+    ) -> AnyResult<AppResponse>
+    where
+        QueryC: CustomQuery,
+    {
+        if let Some(recorder) = &self.recorder {
+            recorder.record_msg(block.height, &sender, &msg);
+        }
+        let index = self.handled_msgs.get() + 1;
+        self.handled_msgs.set(index);
+        MockFailure::check(&self.msg_failures, index, &msg)?;
+
+        if !self.stateful {
+            let response = canned_msg_response(block, msg);
+            return self.charge_msg(response);
+        }
+        self.end_block(storage, block)?;
+        let result: AnyResult<AppResponse> = match msg {
             #[cfg(feature = "profiles")]
-            DesmosMsg::Profiles(msg) => DesmosKeeper::handle_profiles_msg(block, msg),
+            DesmosMsg::Profiles(msg) => DesmosKeeper::handle_profiles_msg(storage, block, msg),
             #[cfg(feature = "subspaces")]
-            DesmosMsg::Subspaces(msg) => DesmosKeeper::handle_subspaces_msg(block, msg),
+            DesmosMsg::Subspaces(msg) => DesmosKeeper::handle_subspaces_msg(storage, block, msg),
             #[cfg(feature = "relationships")]
-            DesmosMsg::Relationships(msg) => DesmosKeeper::handle_relationships_msg(block, msg),
+            DesmosMsg::Relationships(msg) => {
+                DesmosKeeper::handle_relationships_msg(storage, block, msg)
+            }
             #[cfg(feature = "posts")]
-            DesmosMsg::Posts(msg) => DesmosKeeper::handle_posts_msg(block, msg),
+            DesmosMsg::Posts(msg) => DesmosKeeper::handle_posts_msg(storage, block, msg),
             #[cfg(feature = "reports")]
-            DesmosMsg::Reports(msg) => DesmosKeeper::handle_reports_msg(block, msg),
+            DesmosMsg::Reports(msg) => DesmosKeeper::handle_reports_msg(storage, block, msg),
             #[cfg(feature = "reactions")]
-            DesmosMsg::Reactions(msg) => DesmosKeeper::handle_reactions_msg(block, msg),
-        }
+            DesmosMsg::Reactions(msg) => DesmosKeeper::handle_reactions_msg(storage, block, msg),
+            #[cfg(feature = "tokenfactory")]
+            DesmosMsg::TokenFactory(msg) => {
+                DesmosKeeper::handle_tokenfactory_msg(api, storage, router, block, msg)
+            }
+        };
+
+        // Hide this warning since when we compile the package without any module feature
+        // the messages can not be built.
+        #[allow(unreachable_code)]
+        self.charge_msg(result?)
     }
 
     fn sudo<ExecC, QueryC>(
@@ -619,22 +2827,33 @@ impl Module for DesmosKeeper {
     fn query(
         &self,
         _api: &dyn Api,
-        _storage: &dyn Storage,
+        storage: &dyn Storage,
         _querier: &dyn Querier,
-        _block: &BlockInfo,
+        block: &BlockInfo,
         request: DesmosQuery,
     ) -> AnyResult<Binary> {
-        let request = QueryRequest::Custom(request);
-        let result = self.querier.handle_query(&request).into_result();
+        if let Some(recorder) = &self.recorder {
+            recorder.record_query(Some(block.height), &request);
+        }
+        let index = self.handled_queries.get() + 1;
+        self.handled_queries.set(index);
+        MockFailure::check(&self.query_failures, index, &request)?;
 
-        if let Result::Err(error) = result {
-            AnyResult::Err(error.into())
-        } else {
-            let contract_result = result.unwrap();
-            match contract_result {
-                ContractResult::Ok(binary) => AnyResult::Ok(binary),
-                ContractResult::Err(err) => AnyResult::Err(anyhow::Error::msg(err)),
+        let response = if self.is_end_block_pending(storage, block)? {
+            // Queries can not change the state, so the end-blocker runs on a copy of it
+            // and is performed again on the state itself by the next message.
+            let mut state = MockStorage::new();
+            for (key, value) in storage.range(None, None, Order::Ascending) {
+                state.set(&key, &value);
             }
+            self.end_block(&mut state, block)?;
+            self.query_desmos(&state, request)?
+        } else {
+            self.query_desmos(storage, request)?
+        };
+        if let Some(gas_meter) = &self.gas_meter {
+            gas_meter.consume_query(&response)?;
         }
+        AnyResult::Ok(response)
     }
 }
@@ -0,0 +1,44 @@
+//! Contains the fake oracle that the [`DesmosKeeper`](crate::mocks::mock_keeper::DesmosKeeper) uses
+//! to verify the application links created through [`ProfilesMsg::LinkApplication`](crate::profiles::msg::ProfilesMsg::LinkApplication).
+
+#![cfg(not(tarpaulin_include))]
+use crate::profiles::{
+    mocks::MockProfilesQueries,
+    models_app_links::{AppLinkResult, ApplicationLink},
+};
+use cosmwasm_std::BlockInfo;
+
+/// Represents the answer given by the fake oracle to an application link verification request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MockOracleResponse {
+    /// The ownership of the application account has been verified.
+    Success {
+        /// Hex-encoded value that has be signed by the profile.
+        value: String,
+        /// Hex-encoded signature that has been produced by signing the value.
+        signature: String,
+    },
+    /// The ownership of the application account could not be verified.
+    Failure {
+        /// Error that is associated with the failure.
+        error: String,
+    },
+    /// The oracle has not answered yet, so the link can still time out.
+    Pending,
+}
+
+/// Function called each time the fake oracle is asked to verify an application link.
+/// It receives the link to be verified and the block in which the verification is performed.
+pub type MockOracle = Box<dyn Fn(&ApplicationLink, &BlockInfo) -> MockOracleResponse>;
+
+/// Default fake oracle, which verifies all the links successfully using the result of
+/// [`MockProfilesQueries::get_mock_application_link`].
+pub fn mock_oracle_response(_link: &ApplicationLink, _block: &BlockInfo) -> MockOracleResponse {
+    match MockProfilesQueries::get_mock_application_link().result {
+        Some(AppLinkResult::Success { value, signature }) => {
+            MockOracleResponse::Success { value, signature }
+        }
+        Some(AppLinkResult::Failed { error }) => MockOracleResponse::Failure { error },
+        None => MockOracleResponse::Pending,
+    }
+}
@@ -1,5 +1,8 @@
 //! Contains some useful functions to perform unit testing of smart contracts.
 
+use crate::mocks::mock_fixtures::QueryFixtures;
+use crate::mocks::mock_gas::MockGasMeter;
+use crate::mocks::mock_recorder::MockRecorder;
 #[cfg(feature = "posts")]
 use crate::posts::{mocks::mock_posts_query_response, query::PostsQuery};
 #[cfg(feature = "profiles")]
@@ -13,12 +16,16 @@ use crate::relationships::{mocks::mock_relationships_query_response, query::Rela
 use crate::reports::{mocks::mock_reports_query_response, query::ReportsQuery};
 #[cfg(feature = "subspaces")]
 use crate::subspaces::{mocks::mock_subspaces_query_response, query::SubspacesQuery};
+#[cfg(feature = "supply")]
+use crate::supply::{mocks::mock_supply_query_response, query::SupplyQuery};
+#[cfg(feature = "tokenfactory")]
+use crate::tokenfactory::{mocks::mock_tokenfactory_query_response, query::TokenFactoryQuery};
 use cosmwasm_std::testing::MockQuerierCustomHandlerResult;
 use cosmwasm_std::{
     from_slice,
     testing::{MockApi, MockQuerier, MockStorage},
-    Binary, Coin, ContractResult, OwnedDeps, Querier, QuerierResult, QueryRequest, SystemError,
-    SystemResult,
+    to_binary, Binary, Coin, ContractResult, OwnedDeps, Querier, QuerierResult, QueryRequest,
+    SystemError, SystemResult,
 };
 use serde::de::DeserializeOwned;
 use std::marker::PhantomData;
@@ -41,6 +48,14 @@ pub struct MockDesmosQuerier {
     reports_handler: Box<dyn for<'a> Fn(&'a ReportsQuery) -> MockQuerierCustomHandlerResult>,
     #[cfg(feature = "reactions")]
     reactions_handler: Box<dyn for<'a> Fn(&'a ReactionsQuery) -> MockQuerierCustomHandlerResult>,
+    #[cfg(feature = "supply")]
+    supply_handler: Box<dyn for<'a> Fn(&'a SupplyQuery) -> MockQuerierCustomHandlerResult>,
+    #[cfg(feature = "tokenfactory")]
+    tokenfactory_handler:
+        Box<dyn for<'a> Fn(&'a TokenFactoryQuery) -> MockQuerierCustomHandlerResult>,
+    recorder: Option<MockRecorder>,
+    fixtures: Option<QueryFixtures>,
+    gas_meter: Option<MockGasMeter>,
 }
 
 impl MockDesmosQuerier {
@@ -64,6 +79,15 @@ impl MockDesmosQuerier {
             reports_handler: Box::new(|q| SystemResult::Ok(mock_reports_query_response(q))),
             #[cfg(feature = "reactions")]
             reactions_handler: Box::new(|q| SystemResult::Ok(mock_reactions_query_response(q))),
+            #[cfg(feature = "supply")]
+            supply_handler: Box::new(|q| SystemResult::Ok(mock_supply_query_response(q))),
+            #[cfg(feature = "tokenfactory")]
+            tokenfactory_handler: Box::new(|q| {
+                SystemResult::Ok(mock_tokenfactory_query_response(q))
+            }),
+            recorder: None,
+            fixtures: None,
+            gas_meter: None,
         }
     }
 
@@ -71,29 +95,64 @@ impl MockDesmosQuerier {
     pub fn handle_query(&self, request: &QueryRequest<DesmosQuery>) -> QuerierResult {
         match request {
             QueryRequest::Custom(desmos_query) => {
-                match desmos_query {
-                    #[cfg(feature = "profiles")]
-                    DesmosQuery::Profiles(query) => (*self.profiles_handler)(query),
-                    #[cfg(feature = "subspaces")]
-                    DesmosQuery::Subspaces(query) => (*self.subspaces_handler)(query),
-                    #[cfg(feature = "posts")]
-                    DesmosQuery::Posts(query) => (*self.posts_handler)(query),
-                    #[cfg(feature = "relationships")]
-                    DesmosQuery::Relationships(query) => (*self.relationships_handler)(query),
-                    #[cfg(feature = "reports")]
-                    DesmosQuery::Reports(query) => (*self.reports_handler)(query),
-                    #[cfg(feature = "reactions")]
-                    DesmosQuery::Reactions(query) => (*self.reactions_handler)(query),
-                    // Hide this warning since when we compile the package without any module feature
-                    // this pattern is reached.
-                    #[allow(unreachable_patterns)]
-                    _ => SystemResult::Err(SystemError::Unknown {}),
+                if let Some(recorder) = &self.recorder {
+                    recorder.record_query(None, desmos_query);
+                }
+                let result = self.handle_desmos_query(desmos_query);
+                if let (Some(gas_meter), SystemResult::Ok(ContractResult::Ok(response))) =
+                    (&self.gas_meter, &result)
+                {
+                    if let Err(err) = gas_meter.consume_query(response) {
+                        return SystemResult::Err(SystemError::InvalidRequest {
+                            error: err.to_string(),
+                            request: to_binary(desmos_query).unwrap_or_default(),
+                        });
+                    }
                 }
+                result
             }
             _ => self.mock_querier.handle_query(request),
         }
     }
 
+    /// Handle the query request performed towards the Desmos's modules.
+    fn handle_desmos_query(&self, desmos_query: &DesmosQuery) -> QuerierResult {
+        if let Some(fixtures) = &self.fixtures {
+            return match fixtures.response(desmos_query) {
+                Ok(Some(response)) => SystemResult::Ok(ContractResult::Ok(response)),
+                Ok(None) => SystemResult::Err(SystemError::UnsupportedRequest {
+                    kind: format!("no fixture recorded for query {:?}", desmos_query),
+                }),
+                Err(err) => SystemResult::Err(SystemError::InvalidResponse {
+                    error: err.to_string(),
+                    response: Binary::default(),
+                }),
+            };
+        }
+        match desmos_query {
+            #[cfg(feature = "profiles")]
+            DesmosQuery::Profiles(query) => (*self.profiles_handler)(query),
+            #[cfg(feature = "subspaces")]
+            DesmosQuery::Subspaces(query) => (*self.subspaces_handler)(query),
+            #[cfg(feature = "posts")]
+            DesmosQuery::Posts(query) => (*self.posts_handler)(query),
+            #[cfg(feature = "relationships")]
+            DesmosQuery::Relationships(query) => (*self.relationships_handler)(query),
+            #[cfg(feature = "reports")]
+            DesmosQuery::Reports(query) => (*self.reports_handler)(query),
+            #[cfg(feature = "reactions")]
+            DesmosQuery::Reactions(query) => (*self.reactions_handler)(query),
+            #[cfg(feature = "supply")]
+            DesmosQuery::Supply(query) => (*self.supply_handler)(query),
+            #[cfg(feature = "tokenfactory")]
+            DesmosQuery::TokenFactory(query) => (*self.tokenfactory_handler)(query),
+            // Hide this warning since when we compile the package without any module feature
+            // this pattern is reached.
+            #[allow(unreachable_patterns)]
+            _ => SystemResult::Err(SystemError::Unknown {}),
+        }
+    }
+
     /// Utility function to wrap the handler that returns a ContractResult<Binary>
     /// to make it return a SystemResult<ContractResult<Binary>>
     fn wrap_handler<'f, CH, Q>(
@@ -302,6 +361,93 @@ impl MockDesmosQuerier {
         self.reactions_handler = MockDesmosQuerier::wrap_handler(handler);
         self
     }
+
+    /// Function to provide an user defined handler to mock responses to requests made to
+    /// the desmos `supply` module.
+    /// * `handler` - Function that will be called when the contract under test performs a query
+    /// towards the `supply` module.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_std::{ContractResult, to_binary, Uint128};
+    /// use desmos_bindings::mocks::mock_queriers::MockDesmosQuerier;
+    /// use desmos_bindings::supply::models_query::QueryTotalResponse;
+    /// use desmos_bindings::supply::query::SupplyQuery;
+    ///
+    /// let querier =
+    ///     MockDesmosQuerier::default().with_custom_supply_handler(|query| match query {
+    ///         SupplyQuery::Total { .. } => to_binary(&QueryTotalResponse {
+    ///             total_supply: Uint128::new(100),
+    ///         })
+    ///         .into(),
+    ///         _ => ContractResult::Err("not supported".to_string()),
+    ///     });
+    /// ```
+    #[cfg(feature = "supply")]
+    pub fn with_custom_supply_handler<CH>(mut self, handler: CH) -> Self
+    where
+        CH: Fn(&SupplyQuery) -> ContractResult<Binary> + 'static,
+    {
+        self.supply_handler = MockDesmosQuerier::wrap_handler(handler);
+        self
+    }
+
+    /// Function to provide an user defined handler to mock responses to requests made to
+    /// the desmos `tokenfactory` module.
+    /// * `handler` - Function that will be called when the contract under test performs a query
+    /// towards the `tokenfactory` module.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_std::{ContractResult, to_binary};
+    /// use desmos_bindings::mocks::mock_queriers::MockDesmosQuerier;
+    /// use desmos_bindings::tokenfactory::mocks::MockTokenFactoryQueries;
+    /// use desmos_bindings::tokenfactory::models_query::QuerySubspaceDenomsResponse;
+    /// use desmos_bindings::tokenfactory::query::TokenFactoryQuery;
+    ///
+    /// let querier =
+    ///     MockDesmosQuerier::default().with_custom_tokenfactory_handler(|query| match query {
+    ///         TokenFactoryQuery::SubspaceDenoms { .. } => to_binary(&QuerySubspaceDenomsResponse {
+    ///             denoms: vec![MockTokenFactoryQueries::get_mock_denom()],
+    ///         })
+    ///         .into(),
+    ///         _ => ContractResult::Err("not supported".to_string()),
+    ///     });
+    /// ```
+    #[cfg(feature = "tokenfactory")]
+    pub fn with_custom_tokenfactory_handler<CH>(mut self, handler: CH) -> Self
+    where
+        CH: Fn(&TokenFactoryQuery) -> ContractResult<Binary> + 'static,
+    {
+        self.tokenfactory_handler = MockDesmosQuerier::wrap_handler(handler);
+        self
+    }
+
+    /// Function to provide a recorder that keeps track of all the queries
+    /// performed towards the Desmos's modules.
+    /// * `recorder` - Recorder used to store the queries.
+    pub fn with_recorder(mut self, recorder: MockRecorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    /// Function to make the querier answer the queries performed towards the Desmos's modules
+    /// with the responses recorded from a real chain instead of the mocked ones.
+    /// The queries that have not been recorded, including the ones whose pagination configs differ
+    /// from the recorded ones, return an error.
+    /// * `fixtures` - Recorded queries and responses.
+    pub fn with_fixtures(mut self, fixtures: QueryFixtures) -> Self {
+        self.fixtures = Some(fixtures);
+        self
+    }
+
+    /// Function to provide a gas meter that charges the queries performed towards the Desmos's modules,
+    /// making them fail once its limit is exceeded.
+    /// * `gas_meter` - Gas meter used to charge the queries.
+    pub fn with_gas_meter(mut self, gas_meter: MockGasMeter) -> Self {
+        self.gas_meter = Some(gas_meter);
+        self
+    }
 }
 
 impl Querier for MockDesmosQuerier {
@@ -370,18 +516,32 @@ pub fn mock_desmos_dependencies() -> OwnedDeps<MockStorage, MockApi, MockDesmosQ
 
 #[cfg(test)]
 mod tests {
+    use crate::mocks::mock_fixtures::QueryFixtures;
+    use crate::mocks::mock_gas::{GasConfig, MockGasMeter};
     use crate::mocks::mock_queriers::{mock_desmos_dependencies, MockDesmosQuerier};
+    use crate::mocks::mock_recorder::MockRecorder;
+    use crate::mocks::JsonFile;
     use crate::posts::mocks::MockPostsQueries;
     use crate::posts::models_query::QueryPostResponse;
     use crate::posts::querier::PostsQuerier;
     use crate::posts::query::PostsQuery;
     use crate::profiles::query::ProfilesQuery;
+    use crate::query::DesmosQuery;
     use crate::reactions::models::ReactionValue;
     use crate::reactions::models_query::QueryReactionResponse;
     use crate::reactions::query::ReactionsQuery;
     use crate::relationships::query::RelationshipsQuery;
     use crate::reports::query::ReportsQuery;
     use crate::subspaces::query::SubspacesQuery;
+    use crate::supply::mocks::MockSupplyQueries;
+    use crate::supply::models_query::QueryCirculatingResponse;
+    use crate::supply::querier::SupplyQuerier;
+    use crate::supply::query::SupplyQuery;
+    #[cfg(feature = "tokenfactory")]
+    use crate::tokenfactory::{
+        mocks::MockTokenFactoryQueries, models_query::QuerySubspaceDenomsResponse,
+        querier::TokenFactoryQuerier, query::TokenFactoryQuery,
+    };
     use crate::{
         profiles::{
             mocks::MockProfilesQueries, models_query::QueryProfileResponse,
@@ -399,11 +559,13 @@ mod tests {
             mocks::MockReportsQueries, models_query::QueryReportResponse, querier::ReportsQuerier,
         },
         subspaces::{
-            mocks::MockSubspacesQueries, models_query::QuerySubspaceResponse,
+            mocks::MockSubspacesQueries,
+            models_query::{QuerySubspaceResponse, QuerySubspacesResponse},
             querier::SubspacesQuerier,
         },
+        types::{PageRequest, PageResponse},
     };
-    use cosmwasm_std::{to_binary, Addr, ContractResult, Uint64};
+    use cosmwasm_std::{to_binary, Addr, Binary, ContractResult, Uint128, Uint64};
     use std::ops::Deref;
 
     #[test]
@@ -475,6 +637,31 @@ mod tests {
         assert_eq!(expected, response)
     }
 
+    #[test]
+    fn test_supply_querier() {
+        let owned_deps = mock_desmos_dependencies();
+        let deps = owned_deps.as_ref();
+        let querier = SupplyQuerier::new(deps.querier.deref());
+        let response = querier.query_circulating("udsm", 6).unwrap();
+        let expected = QueryCirculatingResponse {
+            circulating_supply: MockSupplyQueries::get_mock_circulating_supply(),
+        };
+        assert_eq!(expected, response);
+    }
+
+    #[test]
+    #[cfg(feature = "tokenfactory")]
+    fn test_tokenfactory_querier() {
+        let owned_deps = mock_desmos_dependencies();
+        let deps = owned_deps.as_ref();
+        let querier = TokenFactoryQuerier::new(deps.querier.deref());
+        let response = querier.query_subspace_denoms(1).unwrap();
+        let expected = QuerySubspaceDenomsResponse {
+            denoms: vec![MockTokenFactoryQueries::get_mock_denom()],
+        };
+        assert_eq!(expected, response);
+    }
+
     #[test]
     fn test_mock_profile_query() {
         let mut profile = MockProfilesQueries::get_mock_profile();
@@ -605,4 +792,158 @@ mod tests {
 
         assert_eq!(response.reaction, reaction);
     }
+
+    #[test]
+    fn test_mock_supply_query() {
+        let response = QueryCirculatingResponse {
+            circulating_supply: Uint128::new(42),
+        };
+
+        let querier =
+            MockDesmosQuerier::default().with_custom_supply_handler(move |query| match query {
+                SupplyQuery::Circulating { .. } => to_binary(&response).into(),
+                _ => ContractResult::Err("not supported".to_string()),
+            });
+
+        let supply_querier = SupplyQuerier::new(&querier);
+        let response = supply_querier.query_circulating("udsm", 0).unwrap();
+
+        assert_eq!(response.circulating_supply, Uint128::new(42));
+    }
+
+    #[test]
+    #[cfg(feature = "tokenfactory")]
+    fn test_mock_tokenfactory_query() {
+        let response = QuerySubspaceDenomsResponse {
+            denoms: vec!["factory/mocked_treasury/mocked".to_string()],
+        };
+
+        let querier =
+            MockDesmosQuerier::default().with_custom_tokenfactory_handler(
+                move |query| match query {
+                    TokenFactoryQuery::SubspaceDenoms { .. } => to_binary(&response).into(),
+                    _ => ContractResult::Err("not supported".to_string()),
+                },
+            );
+
+        let tokenfactory_querier = TokenFactoryQuerier::new(&querier);
+        let response = tokenfactory_querier.query_subspace_denoms(13).unwrap();
+
+        assert_eq!(
+            response.denoms,
+            vec!["factory/mocked_treasury/mocked".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_mock_querier_records_queries() {
+        let recorder = MockRecorder::new();
+        let querier = MockDesmosQuerier::default().with_recorder(recorder.clone());
+
+        let profiles_querier = ProfilesQuerier::new(&querier);
+        profiles_querier.query_profile(Addr::unchecked("")).unwrap();
+        profiles_querier.query_profile(Addr::unchecked("")).unwrap();
+        SupplyQuerier::new(&querier)
+            .query_circulating("udsm", 0)
+            .unwrap();
+
+        recorder.assert_query_count(
+            |query| matches!(query, DesmosQuery::Profiles(ProfilesQuery::Profile { .. })),
+            2,
+        );
+        recorder.assert_query_count(|query| matches!(query, DesmosQuery::Supply(_)), 1);
+        assert!(recorder
+            .queries()
+            .iter()
+            .all(|recorded| recorded.height.is_none()));
+    }
+
+    #[test]
+    fn test_mock_querier_consumes_gas() {
+        let meter = MockGasMeter::new(GasConfig::default()).with_limit(3000);
+        let querier = MockDesmosQuerier::default().with_gas_meter(meter.clone());
+
+        let profiles_querier = ProfilesQuerier::new(&querier);
+        profiles_querier.query_profile(Addr::unchecked("")).unwrap();
+        assert_eq!(2030, meter.gas_used());
+
+        let err = profiles_querier
+            .query_profile(Addr::unchecked(""))
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("out of gas in location: desmos query; gasWanted: 3000, gasUsed: 4060"));
+    }
+
+    #[test]
+    fn test_mock_querier_replays_fixtures() {
+        let page = |key: &[u8]| PageRequest {
+            key: Some(Binary::from(key)),
+            offset: None,
+            limit: Uint64::new(1),
+            count_total: false,
+            reverse: false,
+        };
+        let response = |next_key: Option<&[u8]>| QuerySubspacesResponse {
+            subspaces: vec![MockSubspacesQueries::get_mock_subspace()],
+            pagination: Some(PageResponse {
+                next_key: next_key.map(Binary::from),
+                total: None,
+            }),
+        };
+
+        let mut fixtures = QueryFixtures::new();
+        fixtures
+            .record(
+                SubspacesQuery::Subspaces {
+                    pagination: Some(page(b"first")),
+                }
+                .into(),
+                &to_binary(&response(Some(b"second"))).unwrap(),
+            )
+            .unwrap();
+        fixtures
+            .record(
+                SubspacesQuery::Subspaces {
+                    pagination: Some(page(b"second")),
+                }
+                .into(),
+                &to_binary(&response(None)).unwrap(),
+            )
+            .unwrap();
+
+        // Use a unique file name so that concurrent test runs do not overwrite each other's fixtures.
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!(
+            "desmos_bindings_fixtures_test_{}_{}.json",
+            std::process::id(),
+            nanos
+        ));
+        fixtures.save(&path).unwrap();
+        let loaded = QueryFixtures::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(fixtures, loaded);
+
+        let querier = MockDesmosQuerier::default().with_fixtures(loaded);
+        let subspaces_querier = SubspacesQuerier::new(&querier);
+        assert_eq!(
+            response(Some(b"second")),
+            subspaces_querier
+                .query_subspaces(Some(page(b"first")))
+                .unwrap()
+        );
+        assert_eq!(
+            response(None),
+            subspaces_querier
+                .query_subspaces(Some(page(b"second")))
+                .unwrap()
+        );
+        assert!(subspaces_querier
+            .query_subspaces(Some(page(b"third")))
+            .is_err());
+        assert!(subspaces_querier.query_subspaces(None).is_err());
+    }
 }
@@ -0,0 +1,177 @@
+//! Contains the recorder that keeps track of the Desmos messages and queries issued by the contracts
+//! under test, together with some helpers to perform assertions on them.
+
+#![cfg(not(tarpaulin_include))]
+use crate::msg::DesmosMsg;
+use crate::query::DesmosQuery;
+use cosmwasm_std::Addr;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Represents a [`DesmosMsg`] handled by the [`DesmosKeeper`](crate::mocks::mock_keeper::DesmosKeeper).
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordedMsg {
+    /// Height of the block in which the message has been handled.
+    pub height: u64,
+    /// Address that sent the message.
+    pub sender: Addr,
+    /// The handled message.
+    pub msg: DesmosMsg,
+}
+
+/// Represents a [`DesmosQuery`] handled by the [`DesmosKeeper`](crate::mocks::mock_keeper::DesmosKeeper)
+/// or by the [`MockDesmosQuerier`](crate::mocks::mock_queriers::MockDesmosQuerier).
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordedQuery {
+    /// Height of the block in which the query has been handled,
+    /// or `None` if it has been handled outside of a mock app.
+    pub height: Option<u64>,
+    /// The handled query.
+    pub query: DesmosQuery,
+}
+
+/// Records the Desmos messages and queries handled by the mocks it has been provided to.
+/// Cloning the recorder returns a handle to the same records, so that a clone can be kept by the test
+/// while the original is moved into the mock app.
+///
+/// # Example
+/// ```
+/// use desmos_bindings::mocks::mock_keeper::DesmosKeeper;
+/// use desmos_bindings::mocks::mock_recorder::MockRecorder;
+/// use desmos_bindings::profiles::query::ProfilesQuery;
+/// use desmos_bindings::query::DesmosQuery;
+///
+/// let recorder = MockRecorder::new();
+/// let keeper = DesmosKeeper::new().with_recorder(recorder.clone());
+///
+/// // Execute the contract inside a mock app using the keeper, then check its queries.
+/// recorder.assert_query_count(
+///     |query| matches!(query, DesmosQuery::Profiles(ProfilesQuery::Profile { .. })),
+///     0,
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MockRecorder {
+    msgs: Rc<RefCell<Vec<RecordedMsg>>>,
+    queries: Rc<RefCell<Vec<RecordedQuery>>>,
+}
+
+impl MockRecorder {
+    /// Returns a new [`MockRecorder`] without any record.
+    pub fn new() -> Self {
+        MockRecorder::default()
+    }
+
+    /// Records a message handled in the block with the given height.
+    // Hide this warning since when we compile the package without any module feature
+    // the messages and queries can not be built.
+    #[allow(unreachable_code)]
+    pub(crate) fn record_msg(&self, height: u64, sender: &Addr, msg: &DesmosMsg) {
+        self.msgs.borrow_mut().push(RecordedMsg {
+            height,
+            sender: sender.clone(),
+            msg: msg.clone(),
+        });
+    }
+
+    /// Records a query handled in the block with the given height, if any.
+    // Hide this warning since when we compile the package without any module feature
+    // the messages and queries can not be built.
+    #[allow(unreachable_code)]
+    pub(crate) fn record_query(&self, height: Option<u64>, query: &DesmosQuery) {
+        self.queries.borrow_mut().push(RecordedQuery {
+            height,
+            query: query.clone(),
+        });
+    }
+
+    /// Returns the recorded messages in the order in which they have been handled.
+    pub fn msgs(&self) -> Vec<RecordedMsg> {
+        self.msgs.borrow().clone()
+    }
+
+    /// Returns the recorded queries in the order in which they have been handled.
+    pub fn queries(&self) -> Vec<RecordedQuery> {
+        self.queries.borrow().clone()
+    }
+
+    /// Deletes all the records.
+    pub fn clear(&self) {
+        self.msgs.borrow_mut().clear();
+        self.queries.borrow_mut().clear();
+    }
+
+    /// Returns the number of recorded messages matching the provided predicate.
+    /// * `predicate` - Function that tells whether a message should be counted.
+    pub fn msg_count<F: Fn(&DesmosMsg) -> bool>(&self, predicate: F) -> usize {
+        self.msgs
+            .borrow()
+            .iter()
+            .filter(|recorded| predicate(&recorded.msg))
+            .count()
+    }
+
+    /// Returns the number of recorded queries matching the provided predicate.
+    /// * `predicate` - Function that tells whether a query should be counted.
+    pub fn query_count<F: Fn(&DesmosQuery) -> bool>(&self, predicate: F) -> usize {
+        self.queries
+            .borrow()
+            .iter()
+            .filter(|recorded| predicate(&recorded.query))
+            .count()
+    }
+
+    /// Panics if none of the recorded messages matches the provided predicate.
+    /// * `predicate` - Function that tells whether a message is the expected one.
+    pub fn assert_dispatched<F: Fn(&DesmosMsg) -> bool>(&self, predicate: F) {
+        if self.msg_count(predicate) == 0 {
+            panic!(
+                "no matching message has been dispatched, recorded messages: {:?}",
+                self.msgs()
+            );
+        }
+    }
+
+    /// Panics if any of the recorded messages matches the provided predicate.
+    /// * `predicate` - Function that tells whether a message is the unexpected one.
+    pub fn assert_not_dispatched<F: Fn(&DesmosMsg) -> bool>(&self, predicate: F) {
+        let msgs = self
+            .msgs()
+            .into_iter()
+            .filter(|recorded| predicate(&recorded.msg))
+            .collect::<Vec<_>>();
+        if !msgs.is_empty() {
+            panic!("unexpected messages have been dispatched: {:?}", msgs);
+        }
+    }
+
+    /// Panics if the number of recorded messages matching the provided predicate differs from the expected one.
+    /// * `predicate` - Function that tells whether a message should be counted.
+    /// * `expected` - Expected number of matching messages.
+    pub fn assert_msg_count<F: Fn(&DesmosMsg) -> bool>(&self, predicate: F, expected: usize) {
+        let count = self.msg_count(predicate);
+        if count != expected {
+            panic!(
+                "expected {} matching messages, found {}, recorded messages: {:?}",
+                expected,
+                count,
+                self.msgs()
+            );
+        }
+    }
+
+    /// Panics if the number of recorded queries matching the provided predicate differs from the expected one.
+    /// * `predicate` - Function that tells whether a query should be counted.
+    /// * `expected` - Expected number of matching queries.
+    pub fn assert_query_count<F: Fn(&DesmosQuery) -> bool>(&self, predicate: F, expected: usize) {
+        let count = self.query_count(predicate);
+        if count != expected {
+            panic!(
+                "expected {} matching queries, found {}, recorded queries: {:?}",
+                expected,
+                count,
+                self.queries()
+            );
+        }
+    }
+}
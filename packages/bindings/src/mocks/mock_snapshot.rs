@@ -0,0 +1,128 @@
+//! Contains the snapshots of the state tracked by the [`DesmosKeeper`](crate::mocks::mock_keeper::DesmosKeeper),
+//! which allow to restore it later or to export it to JSON.
+
+#![cfg(not(tarpaulin_include))]
+use crate::mocks::JsonFile;
+use cosmwasm_std::{Binary, Order, StdError, StdResult, Storage};
+use serde::{Deserialize, Serialize};
+use std::convert::TryInto;
+
+/// Prefix shared by the namespaces of all the maps and items defined inside [`mock_state`](crate::mocks::mock_state).
+const NAMESPACE_PREFIX: &[u8] = b"desmos_";
+
+/// Represents a value saved inside the state tracked by the keeper.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SnapshotEntry {
+    /// Namespace of the map or item containing the value.
+    pub namespace: String,
+    /// Key of the value inside its map, empty if the value is an item.
+    pub key: Binary,
+    /// The saved value.
+    pub value: serde_json::Value,
+}
+
+/// Represents a snapshot of the state tracked by the [`DesmosKeeper`](crate::mocks::mock_keeper::DesmosKeeper).
+/// The snapshot contains only the Desmos state, leaving out the one of the other modules of the mock app.
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::Addr;
+/// use cw_multi_test::Executor;
+/// use desmos_bindings::mocks::mock_apps::{mock_stateful_desmos_app, restore_desmos_state, snapshot_desmos_state};
+/// use desmos_bindings::mocks::JsonFile;
+/// use desmos_bindings::subspaces::msg::SubspacesMsg;
+/// use desmos_bindings::msg::DesmosMsg;
+///
+/// let mut app = mock_stateful_desmos_app();
+/// let owner = Addr::unchecked("owner");
+/// let msg = DesmosMsg::Subspaces(SubspacesMsg::create_subspace(
+///     "Test subspace",
+///     "",
+///     owner.clone(),
+///     owner.clone(),
+///     owner.clone(),
+/// ));
+/// app.execute(owner.clone(), msg.into()).unwrap();
+///
+/// // Save the state after the setup so that each case can start from it.
+/// let snapshot = snapshot_desmos_state(&app).unwrap();
+/// let json = snapshot.to_json().unwrap();
+/// restore_desmos_state(&mut app, &snapshot).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct DesmosStateSnapshot {
+    /// Values saved inside the state.
+    pub entries: Vec<SnapshotEntry>,
+}
+
+impl DesmosStateSnapshot {
+    /// Takes a snapshot of the Desmos state saved inside the given storage.
+    /// * `storage` - Storage used by the keeper.
+    pub fn take(storage: &dyn Storage) -> StdResult<Self> {
+        let mut entries = vec![];
+        for (raw_key, value) in storage.range(None, None, Order::Ascending) {
+            if let Some((namespace, key)) = split_key(&raw_key) {
+                let value = serde_json::from_slice(&value)
+                    .map_err(|err| StdError::parse_err("serde_json::Value", err))?;
+                entries.push(SnapshotEntry {
+                    namespace: String::from_utf8(namespace.to_vec())?,
+                    key: Binary::from(key),
+                    value,
+                });
+            }
+        }
+        Ok(DesmosStateSnapshot { entries })
+    }
+
+    /// Replaces the Desmos state saved inside the given storage with the one contained in the snapshot.
+    /// * `storage` - Storage used by the keeper.
+    pub fn restore(&self, storage: &mut dyn Storage) -> StdResult<()> {
+        let current_keys = storage
+            .range(None, None, Order::Ascending)
+            .map(|(key, _)| key)
+            .filter(|key| split_key(key).is_some())
+            .collect::<Vec<_>>();
+        for key in current_keys {
+            storage.remove(&key);
+        }
+
+        for entry in &self.entries {
+            let namespace = entry.namespace.as_bytes();
+            let key = if entry.key.is_empty() {
+                namespace.to_vec()
+            } else {
+                let length: u16 = namespace
+                    .len()
+                    .try_into()
+                    .map_err(|_| StdError::generic_err("namespace too long"))?;
+                [&length.to_be_bytes(), namespace, entry.key.as_slice()].concat()
+            };
+            let value = serde_json::to_vec(&entry.value)
+                .map_err(|err| StdError::serialize_err("serde_json::Value", err))?;
+            storage.set(&key, &value);
+        }
+        Ok(())
+    }
+}
+
+impl JsonFile for DesmosStateSnapshot {}
+
+/// Splits a storage key belonging to the Desmos state into the namespace and the key inside it,
+/// returning `None` for the keys of the other modules.
+/// The items are saved using their namespace as key, while the keys of the maps
+/// are prefixed by the length of their namespace.
+fn split_key(key: &[u8]) -> Option<(&[u8], &[u8])> {
+    if key.starts_with(NAMESPACE_PREFIX) {
+        return Some((key, &[]));
+    }
+    if key.len() < 2 {
+        return None;
+    }
+    let length = u16::from_be_bytes([key[0], key[1]]) as usize;
+    let namespace = key.get(2..2 + length)?;
+    if namespace.starts_with(NAMESPACE_PREFIX) {
+        Some((namespace, &key[2 + length..]))
+    } else {
+        None
+    }
+}
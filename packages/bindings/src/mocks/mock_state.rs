@@ -0,0 +1,152 @@
+//! Contains the state tracked by the [`DesmosKeeper`](crate::mocks::mock_keeper::DesmosKeeper),
+//! so that the messages executed inside a mock app affect the following messages and queries
+//! as they would on chain.
+//! The namespaces of all the maps and items start with `desmos_`, so that the state can be captured
+//! by a [`DesmosStateSnapshot`](crate::mocks::mock_snapshot::DesmosStateSnapshot).
+
+#![cfg(not(tarpaulin_include))]
+#[cfg(all(feature = "posts", feature = "desmos-v5"))]
+use crate::posts::models::PostOwnerTransferRequest;
+#[cfg(feature = "posts")]
+use crate::posts::models::{Post, RawPostAttachment, UserAnswer};
+#[cfg(feature = "profiles")]
+use crate::profiles::{
+    models_app_links::ApplicationLink, models_dtag_requests::DtagTransferRequest,
+    models_profile::Profile,
+};
+#[cfg(feature = "reactions")]
+use crate::reactions::models::{Reaction, RegisteredReaction};
+#[cfg(feature = "reports")]
+use crate::reports::models::{Reason, Report};
+#[cfg(feature = "subspaces")]
+use crate::subspaces::models::{Section, Subspace, UserGroup};
+#[cfg(feature = "profiles")]
+use crate::types::Height;
+#[cfg(feature = "profiles")]
+use cosmwasm_schema::cw_serde;
+#[cfg(any(feature = "profiles", feature = "relationships"))]
+use cosmwasm_std::Addr;
+#[cfg(any(feature = "relationships", feature = "tokenfactory"))]
+use cosmwasm_std::Empty;
+#[cfg(feature = "profiles")]
+use cosmwasm_std::Uint64;
+use cosmwasm_std::{StdResult, Storage, Timestamp};
+use cw_storage_plus::{Item, Map};
+
+/// Height and time of the last block for which the end-blocker has run.
+pub(crate) const LAST_END_BLOCK: Item<(u64, Timestamp)> = Item::new("desmos_last_end_block");
+
+/// Profiles saved inside the mock app, indexed by their owner.
+#[cfg(feature = "profiles")]
+pub(crate) const PROFILES: Map<&Addr, Profile> = Map::new("desmos_profiles");
+
+/// Pending DTag transfer requests, indexed by their receiver and sender.
+#[cfg(feature = "profiles")]
+pub(crate) const DTAG_TRANSFER_REQUESTS: Map<(&Addr, &Addr), DtagTransferRequest> =
+    Map::new("desmos_dtag_transfer_requests");
+
+/// Application links saved inside the mock app, indexed by their user, application and username.
+#[cfg(feature = "profiles")]
+pub(crate) const APPLICATION_LINKS: Map<(&Addr, &str, &str), ApplicationLink> =
+    Map::new("desmos_application_links");
+
+/// Application links still waiting for the oracle result, indexed by their client id.
+#[cfg(feature = "profiles")]
+pub(crate) const PENDING_APPLICATION_LINKS: Map<&str, PendingApplicationLink> =
+    Map::new("desmos_pending_application_links");
+
+/// Id of the last oracle request made to verify an application link.
+#[cfg(feature = "profiles")]
+pub(crate) const LAST_ORACLE_REQUEST_ID: Item<u64> = Item::new("desmos_last_oracle_request_id");
+
+/// Represents the IBC packet sent to the oracle to verify an application link
+/// that has not been resolved yet.
+#[cfg(feature = "profiles")]
+#[cw_serde]
+pub struct PendingApplicationLink {
+    /// User to which the link is associated.
+    pub user: Addr,
+    /// Name of the linked application.
+    pub application: String,
+    /// Username inside the linked application.
+    pub username: String,
+    /// Height of the block in which the packet has been sent.
+    pub sent_height: u64,
+    /// Height after which the packet times out, ignored if zero.
+    pub timeout_height: Height,
+    /// Timestamp in nanoseconds after which the packet times out, ignored if zero.
+    pub timeout_timestamp: Uint64,
+}
+
+/// Last ids assigned to the objects created inside the mock app, indexed by a key
+/// that identifies the kind of object and its parent (eg. `posts/1` for the posts of subspace 1).
+const LAST_IDS: Map<&str, u64> = Map::new("desmos_last_ids");
+
+/// Returns the id to be assigned to the next object of the provided kind.
+///
+/// * `key` - Key identifying the kind of object and its parent.
+pub fn next_id(storage: &mut dyn Storage, key: &str) -> StdResult<u64> {
+    let id = LAST_IDS.may_load(storage, key)?.unwrap_or_default() + 1;
+    LAST_IDS.save(storage, key, &id)?;
+    Ok(id)
+}
+
+/// Subspaces created inside the mock app, indexed by their id.
+#[cfg(feature = "subspaces")]
+pub(crate) const SUBSPACES: Map<u64, Subspace> = Map::new("desmos_subspaces");
+
+/// Sections created inside the mock app, indexed by their subspace id and section id.
+#[cfg(feature = "subspaces")]
+pub(crate) const SECTIONS: Map<(u64, u32), Section> = Map::new("desmos_sections");
+
+/// User groups created inside the mock app, indexed by their subspace id and group id.
+#[cfg(feature = "subspaces")]
+pub(crate) const USER_GROUPS: Map<(u64, u32), UserGroup> = Map::new("desmos_user_groups");
+
+/// Posts created inside the mock app, indexed by their subspace id and post id.
+#[cfg(feature = "posts")]
+pub(crate) const POSTS: Map<(u64, u64), Post> = Map::new("desmos_posts");
+
+/// Contents of the post attachments created inside the mock app, indexed by their subspace id, post id and attachment id.
+#[cfg(feature = "posts")]
+pub(crate) const POST_ATTACHMENTS: Map<(u64, u64, u32), RawPostAttachment> =
+    Map::new("desmos_post_attachments");
+
+/// Answers given to a poll inside the mock app, indexed by the subspace id, post id and poll id.
+#[cfg(feature = "posts")]
+pub(crate) const POLL_ANSWERS: Map<(u64, u64, u32), Vec<UserAnswer>> =
+    Map::new("desmos_poll_answers");
+
+/// Post owner transfer requests sent inside the mock app, indexed by the subspace id and post id.
+#[cfg(all(feature = "posts", feature = "desmos-v5"))]
+pub(crate) const POST_OWNER_TRANSFER_REQUESTS: Map<(u64, u64), PostOwnerTransferRequest> =
+    Map::new("desmos_post_owner_transfer_requests");
+
+/// Reactions added inside the mock app, indexed by their subspace id, post id and reaction id.
+#[cfg(feature = "reactions")]
+pub(crate) const REACTIONS: Map<(u64, u64, u32), Reaction> = Map::new("desmos_reactions");
+
+/// Registered reactions added inside the mock app, indexed by their subspace id and registered reaction id.
+#[cfg(feature = "reactions")]
+pub(crate) const REGISTERED_REACTIONS: Map<(u64, u32), RegisteredReaction> =
+    Map::new("desmos_registered_reactions");
+
+/// Reports created inside the mock app, indexed by their subspace id and report id.
+#[cfg(feature = "reports")]
+pub(crate) const REPORTS: Map<(u64, u64), Report> = Map::new("desmos_reports");
+
+/// Reporting reasons added inside the mock app, indexed by their subspace id and reason id.
+#[cfg(feature = "reports")]
+pub(crate) const REASONS: Map<(u64, u32), Reason> = Map::new("desmos_reasons");
+
+/// Relationships created inside the mock app, indexed by their creator, counterparty and subspace id.
+#[cfg(feature = "relationships")]
+pub(crate) const RELATIONSHIPS: Map<(&Addr, &Addr, u64), Empty> = Map::new("desmos_relationships");
+
+/// User blocks created inside the mock app, indexed by the blocker, the blocked user and the subspace id.
+#[cfg(feature = "relationships")]
+pub(crate) const USER_BLOCKS: Map<(&Addr, &Addr, u64), Empty> = Map::new("desmos_user_blocks");
+
+/// Denoms created inside the mock app, indexed by the id of the subspace that owns them and the denom itself.
+#[cfg(feature = "tokenfactory")]
+pub(crate) const DENOMS: Map<(u64, &str), Empty> = Map::new("desmos_denoms");
@@ -1,5 +1,59 @@
 //! The test utils to mock the quriers and desmos app
 
+use anyhow::Result as AnyResult;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
 pub mod mock_apps;
+mod mock_canned;
+pub mod mock_events;
+pub mod mock_failures;
+pub mod mock_fixtures;
+pub mod mock_gas;
 pub mod mock_keeper;
+#[cfg(feature = "profiles")]
+pub mod mock_oracle;
 pub mod mock_queriers;
+pub mod mock_recorder;
+pub mod mock_snapshot;
+pub mod mock_state;
+
+/// Allows to save a mock value into a JSON file and to load it back,
+/// so that it can be shared between the integration and the unit tests.
+pub trait JsonFile: Serialize + DeserializeOwned + Default {
+    /// Serializes the value into a JSON string.
+    fn to_json(&self) -> AnyResult<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Deserializes a value from the provided JSON string.
+    /// * `json` - JSON produced by [`JsonFile::to_json`].
+    fn from_json(json: &str) -> AnyResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Loads the value from the JSON file at the given path.
+    /// * `path` - Path of the file to be read.
+    fn load<P: AsRef<Path>>(path: P) -> AnyResult<Self> {
+        Self::from_json(&fs::read_to_string(path)?)
+    }
+
+    /// Loads the value from the JSON file at the given path, or returns the default one if the file does not exist.
+    /// * `path` - Path of the file to be read.
+    fn load_or_default<P: AsRef<Path>>(path: P) -> AnyResult<Self> {
+        if path.as_ref().exists() {
+            Self::load(path)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// Saves the value into the JSON file at the given path, replacing its content.
+    /// * `path` - Path of the file to be written.
+    fn save<P: AsRef<Path>>(&self, path: P) -> AnyResult<()> {
+        fs::write(path, self.to_json()?)?;
+        Ok(())
+    }
+}
@@ -12,8 +12,14 @@ use crate::relationships::msg::RelationshipsMsg;
 use crate::reports::msg::ReportsMsg;
 #[cfg(feature = "subspaces")]
 use crate::subspaces::msg::SubspacesMsg;
+#[cfg(feature = "tokenfactory")]
+use crate::tokenfactory::msg::TokenFactoryMsg;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{CosmosMsg, CustomMsg};
+use cosmwasm_std::{Addr, CosmosMsg, CustomMsg, Env, Response};
+#[cfg(feature = "authz")]
+use cosmwasm_std::{Binary, StdResult};
+use std::fmt;
+use thiserror::Error;
 
 /// Enum that defines how the messages are serialized.
 #[cw_serde]
@@ -41,6 +47,10 @@ pub enum DesmosMsg {
     /// Messages relative to the x/reports module.
     #[cfg(feature = "reports")]
     Reports(ReportsMsg),
+
+    /// Messages relative to the x/tokenfactory module.
+    #[cfg(feature = "tokenfactory")]
+    TokenFactory(TokenFactoryMsg),
 }
 
 impl Into<CosmosMsg<DesmosMsg>> for DesmosMsg {
@@ -51,6 +61,159 @@ impl Into<CosmosMsg<DesmosMsg>> for DesmosMsg {
 
 impl CustomMsg for DesmosMsg {}
 
+impl DesmosMsg {
+    /// Returns the address that must sign the message, which is the one that
+    /// the chain checks when the message is dispatched.
+    pub fn signer(&self) -> &Addr {
+        match *self {
+            #[cfg(feature = "profiles")]
+            DesmosMsg::Profiles(ref msg) => msg.signer(),
+            #[cfg(feature = "subspaces")]
+            DesmosMsg::Subspaces(ref msg) => msg.signer(),
+            #[cfg(feature = "relationships")]
+            DesmosMsg::Relationships(ref msg) => msg.signer(),
+            #[cfg(feature = "posts")]
+            DesmosMsg::Posts(ref msg) => msg.signer(),
+            #[cfg(feature = "reactions")]
+            DesmosMsg::Reactions(ref msg) => msg.signer(),
+            #[cfg(feature = "reports")]
+            DesmosMsg::Reports(ref msg) => msg.signer(),
+            #[cfg(feature = "tokenfactory")]
+            DesmosMsg::TokenFactory(ref msg) => msg.signer(),
+        }
+    }
+
+    /// Returns the type url that identifies the message on chain.
+    pub fn type_url(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "profiles")]
+            DesmosMsg::Profiles(ref msg) => msg.type_url(),
+            #[cfg(feature = "subspaces")]
+            DesmosMsg::Subspaces(ref msg) => msg.type_url(),
+            #[cfg(feature = "relationships")]
+            DesmosMsg::Relationships(ref msg) => msg.type_url(),
+            #[cfg(feature = "posts")]
+            DesmosMsg::Posts(ref msg) => msg.type_url(),
+            #[cfg(feature = "reactions")]
+            DesmosMsg::Reactions(ref msg) => msg.type_url(),
+            #[cfg(feature = "reports")]
+            DesmosMsg::Reports(ref msg) => msg.type_url(),
+            #[cfg(feature = "tokenfactory")]
+            DesmosMsg::TokenFactory(ref msg) => msg.type_url(),
+        }
+    }
+
+    /// Encodes the message using its protobuf representation, so that it can be sent
+    /// as a `Stargate` message (eg. inside an authz `MsgExec`).
+    #[cfg(feature = "authz")]
+    pub fn to_proto_bytes(&self) -> StdResult<Binary> {
+        match *self {
+            #[cfg(feature = "profiles")]
+            DesmosMsg::Profiles(ref msg) => msg.to_proto_bytes(),
+            #[cfg(feature = "subspaces")]
+            DesmosMsg::Subspaces(ref msg) => msg.to_proto_bytes(),
+            #[cfg(feature = "relationships")]
+            DesmosMsg::Relationships(ref msg) => msg.to_proto_bytes(),
+            #[cfg(feature = "posts")]
+            DesmosMsg::Posts(ref msg) => msg.to_proto_bytes(),
+            #[cfg(feature = "reactions")]
+            DesmosMsg::Reactions(ref msg) => msg.to_proto_bytes(),
+            #[cfg(feature = "reports")]
+            DesmosMsg::Reports(ref msg) => msg.to_proto_bytes(),
+            #[cfg(feature = "tokenfactory")]
+            DesmosMsg::TokenFactory(ref msg) => msg.to_proto_bytes(),
+        }
+    }
+}
+
+/// Represents a [`DesmosMsg`] of a [`Response`] signed by an unexpected address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnexpectedSigner {
+    /// Index of the message inside the response messages.
+    pub index: usize,
+    /// Type url of the message.
+    pub type_url: String,
+    /// Address that signs the message.
+    pub signer: Addr,
+}
+
+impl fmt::Display for UnexpectedSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "message {} ({}) signed by {}",
+            self.index, self.type_url, self.signer
+        )
+    }
+}
+
+/// Represents the errors that can occur when checking the signers of the messages of a [`Response`].
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum SignerError {
+    /// Error that occurs if some messages are signed by neither the contract nor a declared authz granter.
+    #[error("unexpected signers: {}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "))]
+    UnexpectedSigners(Vec<UnexpectedSigner>),
+}
+
+/// Extension of [`Response`] to check the signers of the [`DesmosMsg`]s it dispatches,
+/// since the chain rejects the messages that are not signed by the contract.
+pub trait CheckSigners: Sized {
+    /// Checks that every [`DesmosMsg`] dispatched by the response is signed either by the contract
+    /// or by one of the provided authz granters, then returns the response.
+    ///
+    /// * `env` - Environment of the contract dispatching the response.
+    /// * `authz_granters` - Addresses that granted the contract the permission to execute their messages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cosmwasm_std::{testing::mock_env, Response};
+    /// use desmos_bindings::msg::{CheckSigners, DesmosMsg};
+    /// use desmos_bindings::relationships::msg::RelationshipsMsg;
+    ///
+    /// let env = mock_env();
+    /// let msg = RelationshipsMsg::create_relationship(
+    ///     env.contract.address.clone(),
+    ///     cosmwasm_std::Addr::unchecked("counterparty"),
+    ///     1,
+    /// );
+    /// let response: Response<DesmosMsg> = Response::new()
+    ///     .add_message(msg)
+    ///     .check_signers(&env, &[])
+    ///     .unwrap();
+    /// ```
+    fn check_signers(self, env: &Env, authz_granters: &[Addr]) -> Result<Self, SignerError>;
+}
+
+impl CheckSigners for Response<DesmosMsg> {
+    fn check_signers(self, env: &Env, authz_granters: &[Addr]) -> Result<Self, SignerError> {
+        let unexpected_signers: Vec<UnexpectedSigner> = self
+            .messages
+            .iter()
+            .enumerate()
+            .filter_map(|(index, sub_msg)| match &sub_msg.msg {
+                CosmosMsg::Custom(msg) => Some((index, msg)),
+                _ => None,
+            })
+            .filter(|(_, msg)| {
+                let signer = msg.signer();
+                signer != &env.contract.address && !authz_granters.contains(signer)
+            })
+            .map(|(index, msg)| UnexpectedSigner {
+                index,
+                type_url: msg.type_url().to_string(),
+                signer: msg.signer().clone(),
+            })
+            .collect();
+
+        if unexpected_signers.is_empty() {
+            Ok(self)
+        } else {
+            Err(SignerError::UnexpectedSigners(unexpected_signers))
+        }
+    }
+}
+
 #[cfg(feature = "profiles")]
 impl From<ProfilesMsg> for DesmosMsg {
     fn from(msg: ProfilesMsg) -> Self {
@@ -135,11 +298,26 @@ impl Into<CosmosMsg<DesmosMsg>> for ReportsMsg {
     }
 }
 
+#[cfg(feature = "tokenfactory")]
+impl From<TokenFactoryMsg> for DesmosMsg {
+    fn from(msg: TokenFactoryMsg) -> Self {
+        Self::TokenFactory(msg)
+    }
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Into<CosmosMsg<DesmosMsg>> for TokenFactoryMsg {
+    fn into(self) -> CosmosMsg<DesmosMsg> {
+        DesmosMsg::from(self).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::reactions::models::ReactionValue;
-    use cosmwasm_std::{Addr, Uint64};
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{BankMsg, Uint64};
     #[test]
     fn test_from_profile_msg() {
         let msg = ProfilesMsg::RequestDtagTransfer {
@@ -282,4 +460,94 @@ mod tests {
             msg.into()
         )
     }
+    #[test]
+    fn test_desmos_msg_signer() {
+        let msg: DesmosMsg = ReactionsMsg::add_reaction(
+            1,
+            1,
+            ReactionValue::FreeText {
+                text: "test".to_string(),
+            },
+            Addr::unchecked("user"),
+        )
+        .into();
+        assert_eq!(&Addr::unchecked("user"), msg.signer());
+    }
+    #[test]
+    fn test_check_signers_properly() {
+        let env = mock_env();
+        let response = Response::<DesmosMsg>::new()
+            .add_message(RelationshipsMsg::create_relationship(
+                env.contract.address.clone(),
+                Addr::unchecked("counterparty"),
+                1,
+            ))
+            .add_message(RelationshipsMsg::block_user(
+                Addr::unchecked("granter"),
+                Addr::unchecked("blocked"),
+                "".to_string(),
+                1,
+            ))
+            .add_message(BankMsg::Burn { amount: vec![] });
+        assert_eq!(
+            response.clone(),
+            response
+                .check_signers(&env, &[Addr::unchecked("granter")])
+                .unwrap()
+        );
+    }
+    #[test]
+    fn test_check_signers_with_unexpected_signers_error() {
+        let env = mock_env();
+        let error = Response::<DesmosMsg>::new()
+            .add_message(RelationshipsMsg::create_relationship(
+                env.contract.address.clone(),
+                Addr::unchecked("counterparty"),
+                1,
+            ))
+            .add_message(RelationshipsMsg::block_user(
+                Addr::unchecked("user"),
+                Addr::unchecked("blocked"),
+                "".to_string(),
+                1,
+            ))
+            .check_signers(&env, &[Addr::unchecked("granter")])
+            .unwrap_err();
+        assert_eq!(
+            SignerError::UnexpectedSigners(vec![UnexpectedSigner {
+                index: 1,
+                type_url: "/desmos.relationships.v1.MsgBlockUser".to_string(),
+                signer: Addr::unchecked("user"),
+            }]),
+            error
+        );
+        assert_eq!(
+            "unexpected signers: message 1 (/desmos.relationships.v1.MsgBlockUser) signed by user",
+            error.to_string()
+        );
+    }
+    #[test]
+    #[cfg(feature = "tokenfactory")]
+    fn test_from_tokenfactory_msg() {
+        let msg = TokenFactoryMsg::CreateDenom {
+            sender: Addr::unchecked("cosmos18atyyv6zycryhvnhpr2mjxgusdcah6kdpkffq0"),
+            subspace_id: Uint64::new(1),
+            subdenom: "minttoken".to_string(),
+        };
+        let expected = DesmosMsg::TokenFactory(msg.clone());
+        assert_eq!(expected, DesmosMsg::from(msg));
+    }
+    #[test]
+    #[cfg(feature = "tokenfactory")]
+    fn test_tokenfactory_msg_into_cosmos_msg() {
+        let msg = TokenFactoryMsg::CreateDenom {
+            sender: Addr::unchecked("cosmos18atyyv6zycryhvnhpr2mjxgusdcah6kdpkffq0"),
+            subspace_id: Uint64::new(1),
+            subdenom: "minttoken".to_string(),
+        };
+        assert_eq!(
+            CosmosMsg::<DesmosMsg>::Custom(DesmosMsg::from(msg.clone())),
+            msg.into()
+        )
+    }
 }
@@ -1,11 +1,15 @@
 //! Contains some useful mocks of the Desmos x/posts module's types made to be used in any test.
 
-use crate::posts::models::{Attachment, Post, PostAttachment, ReplySetting, UserAnswer};
+use crate::posts::models::{Attachment, Params, Post, PostAttachment, ReplySetting, UserAnswer};
 use crate::posts::models_query::{
-    QueryPollAnswersResponse, QueryPostAttachmentsResponse, QueryPostResponse,
+    QueryParamsResponse, QueryPollAnswersResponse, QueryPostAttachmentsResponse, QueryPostResponse,
     QuerySectionPostsResponse, QuerySubspacePostsResponse,
 };
 use crate::posts::query::PostsQuery;
+#[cfg(feature = "desmos-v5")]
+use crate::posts::{
+    models::PostOwnerTransferRequest, models_query::QueryIncomingPostOwnerTransferRequestsResponse,
+};
 use cosmwasm_std::{to_binary, Addr, Binary, ContractResult, Uint64};
 
 /// Struct that contains some utility methods to mock data of the Desmos
@@ -24,6 +28,8 @@ impl MockPostsQueries {
                 entities: None,
                 tags: vec![],
                 author: Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc"),
+                #[cfg(feature = "desmos-v5")]
+                owner: Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc"),
                 id: Uint64::new(0),
                 referenced_posts: vec![],
                 reply_settings: ReplySetting::Everyone,
@@ -39,6 +45,8 @@ impl MockPostsQueries {
                 entities: None,
                 tags: vec![],
                 author: Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc"),
+                #[cfg(feature = "desmos-v5")]
+                owner: Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc"),
                 id: Uint64::new(1),
                 referenced_posts: vec![],
                 reply_settings: ReplySetting::Everyone,
@@ -60,6 +68,8 @@ impl MockPostsQueries {
                 entities: None,
                 tags: vec![],
                 author: Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc"),
+                #[cfg(feature = "desmos-v5")]
+                owner: Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc"),
                 id: Uint64::new(0),
                 referenced_posts: vec![],
                 reply_settings: ReplySetting::Everyone,
@@ -75,6 +85,8 @@ impl MockPostsQueries {
                 entities: None,
                 tags: vec![],
                 author: Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc"),
+                #[cfg(feature = "desmos-v5")]
+                owner: Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc"),
                 id: Uint64::new(1),
                 referenced_posts: vec![],
                 reply_settings: ReplySetting::Everyone,
@@ -96,6 +108,8 @@ impl MockPostsQueries {
             entities: None,
             tags: vec![],
             author: Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc"),
+            #[cfg(feature = "desmos-v5")]
+            owner: Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc"),
             conversation_id: None,
             referenced_posts: vec![],
             reply_settings: ReplySetting::Unspecified,
@@ -148,6 +162,30 @@ impl MockPostsQueries {
             ),
         }]
     }
+
+    /// Functions that mocks the incoming post owner transfer requests.
+    #[cfg(feature = "desmos-v5")]
+    pub fn get_mocked_incoming_post_owner_transfer_requests(
+        subspace_id: &Uint64,
+        receiver: &Option<Addr>,
+    ) -> Vec<PostOwnerTransferRequest> {
+        vec![PostOwnerTransferRequest {
+            subspace_id: *subspace_id,
+            post_id: Uint64::new(1),
+            sender: Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc"),
+            receiver: receiver.as_ref().map_or(
+                Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3"),
+                |addr| addr.clone(),
+            ),
+        }]
+    }
+
+    /// Functions that mocks the x/posts module params.
+    pub fn get_mocked_params() -> Params {
+        Params {
+            max_text_length: 500,
+        }
+    }
 }
 
 /// Functions that mocks the posts query responses.
@@ -190,6 +228,21 @@ pub fn mock_posts_query_response(query: &PostsQuery) -> ContractResult<Binary> {
             answers: MockPostsQueries::get_mocked_poll_answers(subspace_id, post_id, poll_id, user),
             pagination: None,
         }),
+        #[cfg(feature = "desmos-v5")]
+        PostsQuery::IncomingPostOwnerTransferRequests {
+            subspace_id,
+            receiver,
+            ..
+        } => to_binary(&QueryIncomingPostOwnerTransferRequestsResponse {
+            requests: MockPostsQueries::get_mocked_incoming_post_owner_transfer_requests(
+                subspace_id,
+                receiver,
+            ),
+            pagination: None,
+        }),
+        PostsQuery::Params {} => to_binary(&QueryParamsResponse {
+            params: MockPostsQueries::get_mocked_params(),
+        }),
     };
     response.into()
 }
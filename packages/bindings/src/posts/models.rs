@@ -7,9 +7,17 @@ use std::convert::TryFrom;
 use thiserror::Error;
 
 /// Proto type uri of [`PostAttachment::Media`].
+#[cfg(not(feature = "desmos-v5"))]
 pub const MEDIA_TYPE_URI: &str = "/desmos.posts.v2.Media";
+/// Proto type uri of [`PostAttachment::Media`].
+#[cfg(feature = "desmos-v5")]
+pub const MEDIA_TYPE_URI: &str = "/desmos.posts.v3.Media";
 /// Proto type uri of [`PostAttachment::Poll`].
+#[cfg(not(feature = "desmos-v5"))]
 pub const POLL_TYPE_URI: &str = "/desmos.posts.v2.Poll";
+/// Proto type uri of [`PostAttachment::Poll`].
+#[cfg(feature = "desmos-v5")]
+pub const POLL_TYPE_URI: &str = "/desmos.posts.v3.Poll";
 
 /// Contains all the information about a single post.
 #[cw_serde]
@@ -30,6 +38,9 @@ pub struct Post {
     pub tags: Vec<String>,
     /// Author of the post.
     pub author: Addr,
+    /// Owner of the post, who differs from the author once the post ownership is transferred.
+    #[cfg(feature = "desmos-v5")]
+    pub owner: Addr,
     /// Id of the original post of the conversation.
     pub conversation_id: Option<Uint64>,
     /// A list this posts references (either as a reply, repost or quote).
@@ -221,6 +232,20 @@ pub struct UserAnswer {
     pub user: Addr,
 }
 
+/// Represents a request to transfer the ownership of a post to another user.
+#[cw_serde]
+#[cfg(feature = "desmos-v5")]
+pub struct PostOwnerTransferRequest {
+    /// Id of the subspace that holds the post.
+    pub subspace_id: Uint64,
+    /// Id of the post to be transferred.
+    pub post_id: Uint64,
+    /// Address of the current post owner that sent the request.
+    pub sender: Addr,
+    /// Address of the user that, if the request is accepted, will become the new post owner.
+    pub receiver: Addr,
+}
+
 /// Supported attachment that can be attached to a post.
 #[derive(Clone, Debug, PartialEq)]
 pub enum PostAttachment {
@@ -337,6 +362,13 @@ impl TryFrom<RawPostAttachment> for PostAttachment {
     }
 }
 
+/// Contains the parameters of the x/posts module.
+#[cw_serde]
+pub struct Params {
+    /// Maximum length of a post text.
+    pub max_text_length: u32,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -363,7 +395,7 @@ mod tests {
     #[test]
     fn post_media_attachment_try_from_raw_without_mime_type_error() {
         let raw = RawPostAttachment {
-            type_uri: "/desmos.posts.v2.Media".to_string(),
+            type_uri: MEDIA_TYPE_URI.to_string(),
             mime_type: None,
             uri: None,
             question: None,
@@ -381,7 +413,7 @@ mod tests {
     #[test]
     fn post_media_attachment_try_from_raw_without_uri_error() {
         let raw = RawPostAttachment {
-            type_uri: "/desmos.posts.v2.Media".to_string(),
+            type_uri: MEDIA_TYPE_URI.to_string(),
             mime_type: Some("test".to_string()),
             uri: None,
             question: None,
@@ -399,7 +431,7 @@ mod tests {
     #[test]
     fn post_media_attachment_try_from_valid_raw_properly() {
         let raw = RawPostAttachment {
-            type_uri: "/desmos.posts.v2.Media".to_string(),
+            type_uri: MEDIA_TYPE_URI.to_string(),
             mime_type: Some("test".to_string()),
             uri: Some("ipfs://test".to_string()),
             question: None,
@@ -420,7 +452,7 @@ mod tests {
     #[test]
     fn post_poll_attachment_try_from_wrong_raw_without_question_error() {
         let raw = RawPostAttachment {
-            type_uri: "/desmos.posts.v2.Poll".to_string(),
+            type_uri: POLL_TYPE_URI.to_string(),
             mime_type: None,
             uri: None,
             question: None,
@@ -438,7 +470,7 @@ mod tests {
     #[test]
     fn post_poll_attachment_try_from_wrong_raw_without_provided_answers_error() {
         let raw = RawPostAttachment {
-            type_uri: "/desmos.posts.v2.Poll".to_string(),
+            type_uri: POLL_TYPE_URI.to_string(),
             mime_type: None,
             uri: None,
             question: Some("test".to_string()),
@@ -456,7 +488,7 @@ mod tests {
     #[test]
     fn post_poll_attachment_try_from_wrong_raw_without_end_date_error() {
         let raw = RawPostAttachment {
-            type_uri: "/desmos.posts.v2.Poll".to_string(),
+            type_uri: POLL_TYPE_URI.to_string(),
             mime_type: None,
             uri: None,
             question: Some("test".to_string()),
@@ -477,7 +509,7 @@ mod tests {
     #[test]
     fn post_poll_attachment_try_from_wrong_raw_without_allows_multiple_answers_error() {
         let raw = RawPostAttachment {
-            type_uri: "/desmos.posts.v2.Poll".to_string(),
+            type_uri: POLL_TYPE_URI.to_string(),
             mime_type: None,
             uri: None,
             question: Some("test".to_string()),
@@ -498,7 +530,7 @@ mod tests {
     #[test]
     fn post_poll_attachment_try_from_wrong_raw_without_allows_answer_edits_error() {
         let raw = RawPostAttachment {
-            type_uri: "/desmos.posts.v2.Poll".to_string(),
+            type_uri: POLL_TYPE_URI.to_string(),
             mime_type: None,
             uri: None,
             question: Some("test".to_string()),
@@ -519,7 +551,7 @@ mod tests {
     #[test]
     fn post_poll_attachment_try_from_valid_raw_properly() {
         let raw = RawPostAttachment {
-            type_uri: "/desmos.posts.v2.Poll".to_string(),
+            type_uri: POLL_TYPE_URI.to_string(),
             mime_type: None,
             uri: None,
             question: Some("test".to_string()),
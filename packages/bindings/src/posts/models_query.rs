@@ -1,6 +1,8 @@
 //! Contains structures returned from the [PostsQuerier<'a>](crate::posts::querier::PostsQuerier).
 
-use crate::posts::models::{Attachment, Post, UserAnswer};
+#[cfg(feature = "desmos-v5")]
+use crate::posts::models::PostOwnerTransferRequest;
+use crate::posts::models::{Attachment, Params, Post, UserAnswer};
 use crate::types::PageResponse;
 use cosmwasm_schema::cw_serde;
 
@@ -46,3 +48,20 @@ pub struct QueryPollAnswersResponse {
     /// Details of the current fetched page.
     pub pagination: Option<PageResponse>,
 }
+
+/// Response to [`PostsQuery::IncomingPostOwnerTransferRequests`](crate::posts::query::PostsQuery::IncomingPostOwnerTransferRequests).
+#[cw_serde]
+#[cfg(feature = "desmos-v5")]
+pub struct QueryIncomingPostOwnerTransferRequestsResponse {
+    /// Queried post owner transfer requests.
+    pub requests: Vec<PostOwnerTransferRequest>,
+    /// Details of the current fetched page.
+    pub pagination: Option<PageResponse>,
+}
+
+/// Response to [`PostsQuery::Params`](crate::posts::query::PostsQuery::Params).
+#[cw_serde]
+pub struct QueryParamsResponse {
+    /// Parameters of the x/posts module.
+    pub params: Params,
+}
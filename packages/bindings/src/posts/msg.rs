@@ -6,6 +6,62 @@ use crate::posts::models::{
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint64};
 
+/// Type url of [`PostsMsg::CreatePost`].
+#[cfg(not(feature = "desmos-v5"))]
+pub const MSG_CREATE_POST_TYPE_URI: &str = "/desmos.posts.v2.MsgCreatePost";
+/// Type url of [`PostsMsg::CreatePost`].
+#[cfg(feature = "desmos-v5")]
+pub const MSG_CREATE_POST_TYPE_URI: &str = "/desmos.posts.v3.MsgCreatePost";
+/// Type url of [`PostsMsg::EditPost`].
+#[cfg(not(feature = "desmos-v5"))]
+pub const MSG_EDIT_POST_TYPE_URI: &str = "/desmos.posts.v2.MsgEditPost";
+/// Type url of [`PostsMsg::EditPost`].
+#[cfg(feature = "desmos-v5")]
+pub const MSG_EDIT_POST_TYPE_URI: &str = "/desmos.posts.v3.MsgEditPost";
+/// Type url of [`PostsMsg::DeletePost`].
+#[cfg(not(feature = "desmos-v5"))]
+pub const MSG_DELETE_POST_TYPE_URI: &str = "/desmos.posts.v2.MsgDeletePost";
+/// Type url of [`PostsMsg::DeletePost`].
+#[cfg(feature = "desmos-v5")]
+pub const MSG_DELETE_POST_TYPE_URI: &str = "/desmos.posts.v3.MsgDeletePost";
+/// Type url of [`PostsMsg::AddPostAttachment`].
+#[cfg(not(feature = "desmos-v5"))]
+pub const MSG_ADD_POST_ATTACHMENT_TYPE_URI: &str = "/desmos.posts.v2.MsgAddPostAttachment";
+/// Type url of [`PostsMsg::AddPostAttachment`].
+#[cfg(feature = "desmos-v5")]
+pub const MSG_ADD_POST_ATTACHMENT_TYPE_URI: &str = "/desmos.posts.v3.MsgAddPostAttachment";
+/// Type url of [`PostsMsg::RemovePostAttachment`].
+#[cfg(not(feature = "desmos-v5"))]
+pub const MSG_REMOVE_POST_ATTACHMENT_TYPE_URI: &str = "/desmos.posts.v2.MsgRemovePostAttachment";
+/// Type url of [`PostsMsg::RemovePostAttachment`].
+#[cfg(feature = "desmos-v5")]
+pub const MSG_REMOVE_POST_ATTACHMENT_TYPE_URI: &str = "/desmos.posts.v3.MsgRemovePostAttachment";
+/// Type url of [`PostsMsg::AnswerPoll`].
+#[cfg(not(feature = "desmos-v5"))]
+pub const MSG_ANSWER_POLL_TYPE_URI: &str = "/desmos.posts.v2.MsgAnswerPoll";
+/// Type url of [`PostsMsg::AnswerPoll`].
+#[cfg(feature = "desmos-v5")]
+pub const MSG_ANSWER_POLL_TYPE_URI: &str = "/desmos.posts.v3.MsgAnswerPoll";
+/// Type url of [`PostsMsg::MovePost`].
+#[cfg(feature = "desmos-v5")]
+pub const MSG_MOVE_POST_TYPE_URI: &str = "/desmos.posts.v3.MsgMovePost";
+/// Type url of [`PostsMsg::RequestPostOwnerTransfer`].
+#[cfg(feature = "desmos-v5")]
+pub const MSG_REQUEST_POST_OWNER_TRANSFER_TYPE_URI: &str =
+    "/desmos.posts.v3.MsgRequestPostOwnerTransfer";
+/// Type url of [`PostsMsg::CancelPostOwnerTransferRequest`].
+#[cfg(feature = "desmos-v5")]
+pub const MSG_CANCEL_POST_OWNER_TRANSFER_REQUEST_TYPE_URI: &str =
+    "/desmos.posts.v3.MsgCancelPostOwnerTransferRequest";
+/// Type url of [`PostsMsg::AcceptPostOwnerTransferRequest`].
+#[cfg(feature = "desmos-v5")]
+pub const MSG_ACCEPT_POST_OWNER_TRANSFER_REQUEST_TYPE_URI: &str =
+    "/desmos.posts.v3.MsgAcceptPostOwnerTransferRequest";
+/// Type url of [`PostsMsg::RefusePostOwnerTransferRequest`].
+#[cfg(feature = "desmos-v5")]
+pub const MSG_REFUSE_POST_OWNER_TRANSFER_REQUEST_TYPE_URI: &str =
+    "/desmos.posts.v3.MsgRefusePostOwnerTransferRequest";
+
 /// Represents the messages to interact with the posts module.
 #[cw_serde]
 pub enum PostsMsg {
@@ -93,6 +149,62 @@ pub enum PostsMsg {
         /// Address of the user answering the poll.
         signer: Addr,
     },
+    /// Represents the message used to move a post to another subspace or section.
+    #[cfg(feature = "desmos-v5")]
+    MovePost {
+        /// Id of the subspace containing the post.
+        subspace_id: Uint64,
+        /// Id of the post to be moved.
+        post_id: Uint64,
+        /// Id of the subspace where the post will be moved.
+        target_subspace_id: Uint64,
+        /// Id of the section where the post will be moved.
+        target_section_id: u32,
+        /// Address of the post owner.
+        owner: Addr,
+    },
+    /// Represents the message used to request the transfer of a post ownership.
+    #[cfg(feature = "desmos-v5")]
+    RequestPostOwnerTransfer {
+        /// Id of the subspace that holds the post.
+        subspace_id: Uint64,
+        /// Id of the post to be transferred.
+        post_id: Uint64,
+        /// Address of the user that will receive the post ownership.
+        receiver: Addr,
+        /// Address of the current post owner.
+        sender: Addr,
+    },
+    /// Represents the message used to cancel a post owner transfer request.
+    #[cfg(feature = "desmos-v5")]
+    CancelPostOwnerTransferRequest {
+        /// Id of the subspace that holds the post.
+        subspace_id: Uint64,
+        /// Id of the post whose transfer request will be cancelled.
+        post_id: Uint64,
+        /// Address of the user that sent the request.
+        sender: Addr,
+    },
+    /// Represents the message used to accept a post owner transfer request.
+    #[cfg(feature = "desmos-v5")]
+    AcceptPostOwnerTransferRequest {
+        /// Id of the subspace that holds the post.
+        subspace_id: Uint64,
+        /// Id of the post whose transfer request will be accepted.
+        post_id: Uint64,
+        /// Address of the user that received the request.
+        receiver: Addr,
+    },
+    /// Represents the message used to refuse a post owner transfer request.
+    #[cfg(feature = "desmos-v5")]
+    RefusePostOwnerTransferRequest {
+        /// Id of the subspace that holds the post.
+        subspace_id: Uint64,
+        /// Id of the post whose transfer request will be refused.
+        post_id: Uint64,
+        /// Address of the user that received the request.
+        receiver: Addr,
+    },
 }
 
 impl PostsMsg {
@@ -239,6 +351,153 @@ impl PostsMsg {
             signer,
         }
     }
+
+    /// Creates an instance of [`PostsMsg::MovePost`].
+    ///
+    /// * `subspace_id` - Id of the subspace containing the post.
+    /// * `post_id` - Id of the post to be moved.
+    /// * `target_subspace_id` - Id of the subspace where the post will be moved.
+    /// * `target_section_id` - Id of the section where the post will be moved.
+    /// * `owner` - Address of the post owner.
+    #[cfg(feature = "desmos-v5")]
+    pub fn move_post(
+        subspace_id: u64,
+        post_id: u64,
+        target_subspace_id: u64,
+        target_section_id: u32,
+        owner: Addr,
+    ) -> Self {
+        Self::MovePost {
+            subspace_id: subspace_id.into(),
+            post_id: post_id.into(),
+            target_subspace_id: target_subspace_id.into(),
+            target_section_id,
+            owner,
+        }
+    }
+
+    /// Creates an instance of [`PostsMsg::RequestPostOwnerTransfer`].
+    ///
+    /// * `subspace_id` - Id of the subspace that holds the post.
+    /// * `post_id` - Id of the post to be transferred.
+    /// * `receiver` - Address of the user that will receive the post ownership.
+    /// * `sender` - Address of the current post owner.
+    #[cfg(feature = "desmos-v5")]
+    pub fn request_post_owner_transfer(
+        subspace_id: u64,
+        post_id: u64,
+        receiver: Addr,
+        sender: Addr,
+    ) -> Self {
+        Self::RequestPostOwnerTransfer {
+            subspace_id: subspace_id.into(),
+            post_id: post_id.into(),
+            receiver,
+            sender,
+        }
+    }
+
+    /// Creates an instance of [`PostsMsg::CancelPostOwnerTransferRequest`].
+    ///
+    /// * `subspace_id` - Id of the subspace that holds the post.
+    /// * `post_id` - Id of the post whose transfer request will be cancelled.
+    /// * `sender` - Address of the user that sent the request.
+    #[cfg(feature = "desmos-v5")]
+    pub fn cancel_post_owner_transfer_request(
+        subspace_id: u64,
+        post_id: u64,
+        sender: Addr,
+    ) -> Self {
+        Self::CancelPostOwnerTransferRequest {
+            subspace_id: subspace_id.into(),
+            post_id: post_id.into(),
+            sender,
+        }
+    }
+
+    /// Creates an instance of [`PostsMsg::AcceptPostOwnerTransferRequest`].
+    ///
+    /// * `subspace_id` - Id of the subspace that holds the post.
+    /// * `post_id` - Id of the post whose transfer request will be accepted.
+    /// * `receiver` - Address of the user that received the request.
+    #[cfg(feature = "desmos-v5")]
+    pub fn accept_post_owner_transfer_request(
+        subspace_id: u64,
+        post_id: u64,
+        receiver: Addr,
+    ) -> Self {
+        Self::AcceptPostOwnerTransferRequest {
+            subspace_id: subspace_id.into(),
+            post_id: post_id.into(),
+            receiver,
+        }
+    }
+
+    /// Creates an instance of [`PostsMsg::RefusePostOwnerTransferRequest`].
+    ///
+    /// * `subspace_id` - Id of the subspace that holds the post.
+    /// * `post_id` - Id of the post whose transfer request will be refused.
+    /// * `receiver` - Address of the user that received the request.
+    #[cfg(feature = "desmos-v5")]
+    pub fn refuse_post_owner_transfer_request(
+        subspace_id: u64,
+        post_id: u64,
+        receiver: Addr,
+    ) -> Self {
+        Self::RefusePostOwnerTransferRequest {
+            subspace_id: subspace_id.into(),
+            post_id: post_id.into(),
+            receiver,
+        }
+    }
+
+    /// Returns the address that must sign the message, which is the one that
+    /// the chain checks when the message is dispatched.
+    pub fn signer(&self) -> &Addr {
+        match self {
+            PostsMsg::CreatePost { author, .. } => author,
+            PostsMsg::EditPost { editor, .. }
+            | PostsMsg::AddPostAttachment { editor, .. }
+            | PostsMsg::RemovePostAttachment { editor, .. } => editor,
+            PostsMsg::DeletePost { signer, .. } | PostsMsg::AnswerPoll { signer, .. } => signer,
+            #[cfg(feature = "desmos-v5")]
+            PostsMsg::MovePost { owner, .. } => owner,
+            #[cfg(feature = "desmos-v5")]
+            PostsMsg::RequestPostOwnerTransfer { sender, .. }
+            | PostsMsg::CancelPostOwnerTransferRequest { sender, .. } => sender,
+            #[cfg(feature = "desmos-v5")]
+            PostsMsg::AcceptPostOwnerTransferRequest { receiver, .. }
+            | PostsMsg::RefusePostOwnerTransferRequest { receiver, .. } => receiver,
+        }
+    }
+
+    /// Returns the type url that identifies the message on chain.
+    pub fn type_url(&self) -> &'static str {
+        match self {
+            PostsMsg::CreatePost { .. } => MSG_CREATE_POST_TYPE_URI,
+            PostsMsg::EditPost { .. } => MSG_EDIT_POST_TYPE_URI,
+            PostsMsg::DeletePost { .. } => MSG_DELETE_POST_TYPE_URI,
+            PostsMsg::AddPostAttachment { .. } => MSG_ADD_POST_ATTACHMENT_TYPE_URI,
+            PostsMsg::RemovePostAttachment { .. } => MSG_REMOVE_POST_ATTACHMENT_TYPE_URI,
+            PostsMsg::AnswerPoll { .. } => MSG_ANSWER_POLL_TYPE_URI,
+            #[cfg(feature = "desmos-v5")]
+            PostsMsg::MovePost { .. } => MSG_MOVE_POST_TYPE_URI,
+            #[cfg(feature = "desmos-v5")]
+            PostsMsg::RequestPostOwnerTransfer { .. } => MSG_REQUEST_POST_OWNER_TRANSFER_TYPE_URI,
+            #[cfg(feature = "desmos-v5")]
+            PostsMsg::CancelPostOwnerTransferRequest { .. } => {
+                MSG_CANCEL_POST_OWNER_TRANSFER_REQUEST_TYPE_URI
+            }
+            #[cfg(feature = "desmos-v5")]
+            PostsMsg::AcceptPostOwnerTransferRequest { .. } => {
+                MSG_ACCEPT_POST_OWNER_TRANSFER_REQUEST_TYPE_URI
+            }
+            #[cfg(feature = "desmos-v5")]
+            PostsMsg::RefusePostOwnerTransferRequest { .. } => {
+                MSG_REFUSE_POST_OWNER_TRANSFER_REQUEST_TYPE_URI
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -423,4 +682,103 @@ mod tests {
         };
         assert_eq!(expected, msg)
     }
+
+    #[test]
+    #[cfg(feature = "desmos-v5")]
+    fn test_move_post() {
+        let msg = PostsMsg::move_post(
+            1,
+            1,
+            2,
+            1,
+            Addr::unchecked("cosmos1qzskhrcjnkdz2ln4yeafzsdwht8ch08j4wed69"),
+        );
+        let expected = PostsMsg::MovePost {
+            subspace_id: Uint64::new(1),
+            post_id: Uint64::new(1),
+            target_subspace_id: Uint64::new(2),
+            target_section_id: 1,
+            owner: Addr::unchecked("cosmos1qzskhrcjnkdz2ln4yeafzsdwht8ch08j4wed69"),
+        };
+        assert_eq!(expected, msg)
+    }
+
+    #[test]
+    #[cfg(feature = "desmos-v5")]
+    fn test_request_post_owner_transfer() {
+        let msg = PostsMsg::request_post_owner_transfer(
+            1,
+            1,
+            Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+            Addr::unchecked("cosmos1qzskhrcjnkdz2ln4yeafzsdwht8ch08j4wed69"),
+        );
+        let expected = PostsMsg::RequestPostOwnerTransfer {
+            subspace_id: Uint64::new(1),
+            post_id: Uint64::new(1),
+            receiver: Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+            sender: Addr::unchecked("cosmos1qzskhrcjnkdz2ln4yeafzsdwht8ch08j4wed69"),
+        };
+        assert_eq!(expected, msg)
+    }
+
+    #[test]
+    #[cfg(feature = "desmos-v5")]
+    fn test_cancel_post_owner_transfer_request() {
+        let msg = PostsMsg::cancel_post_owner_transfer_request(
+            1,
+            1,
+            Addr::unchecked("cosmos1qzskhrcjnkdz2ln4yeafzsdwht8ch08j4wed69"),
+        );
+        let expected = PostsMsg::CancelPostOwnerTransferRequest {
+            subspace_id: Uint64::new(1),
+            post_id: Uint64::new(1),
+            sender: Addr::unchecked("cosmos1qzskhrcjnkdz2ln4yeafzsdwht8ch08j4wed69"),
+        };
+        assert_eq!(expected, msg)
+    }
+
+    #[test]
+    #[cfg(feature = "desmos-v5")]
+    fn test_accept_post_owner_transfer_request() {
+        let msg = PostsMsg::accept_post_owner_transfer_request(
+            1,
+            1,
+            Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+        );
+        let expected = PostsMsg::AcceptPostOwnerTransferRequest {
+            subspace_id: Uint64::new(1),
+            post_id: Uint64::new(1),
+            receiver: Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+        };
+        assert_eq!(expected, msg)
+    }
+
+    #[test]
+    #[cfg(feature = "desmos-v5")]
+    fn test_refuse_post_owner_transfer_request() {
+        let msg = PostsMsg::refuse_post_owner_transfer_request(
+            1,
+            1,
+            Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+        );
+        let expected = PostsMsg::RefusePostOwnerTransferRequest {
+            subspace_id: Uint64::new(1),
+            post_id: Uint64::new(1),
+            receiver: Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+        };
+        assert_eq!(expected, msg)
+    }
+
+    #[test]
+    fn test_signer() {
+        let msg = PostsMsg::delete_post(
+            1,
+            1,
+            Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+        );
+        assert_eq!(
+            &Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+            msg.signer()
+        )
+    }
 }
@@ -0,0 +1,385 @@
+//! Contains the protobuf encoding of the [`PostsMsg`] variants, used to send them
+//! as `Stargate` messages (eg. inside an authz `MsgExec`).
+
+use crate::posts::models::{
+    Attachment, Entities, PostAttachment, PostReference, PostReferenceType, RawPostAttachment,
+    ReplySetting, TextTagEntity, MEDIA_TYPE_URI, POLL_TYPE_URI,
+};
+use crate::posts::msg::PostsMsg;
+use crate::proto::{any, rfc3339_timestamp, ProtoWriter};
+use cosmwasm_std::{Binary, StdError, StdResult};
+use std::convert::TryFrom;
+
+fn text_tags(tags: &[TextTagEntity]) -> Vec<Vec<u8>> {
+    tags.iter()
+        .map(|tag| {
+            ProtoWriter::new()
+                .uint64(1, tag.start.u64())
+                .uint64(2, tag.end.u64())
+                .string(3, &tag.tag)
+                .finish()
+        })
+        .collect()
+}
+
+fn entities(entities: &Entities) -> Vec<u8> {
+    let urls: Vec<Vec<u8>> = entities
+        .urls
+        .iter()
+        .map(|url| {
+            ProtoWriter::new()
+                .uint64(1, url.start.u64())
+                .uint64(2, url.end.u64())
+                .string(3, &url.url)
+                .string(4, &url.display_url)
+                .finish()
+        })
+        .collect();
+    ProtoWriter::new()
+        .repeated_message(1, &text_tags(&entities.hashtags))
+        .repeated_message(2, &text_tags(&entities.mentions))
+        .repeated_message(3, &urls)
+        .finish()
+}
+
+fn post_reference(reference: &PostReference) -> Vec<u8> {
+    let ref_type = match reference.ref_type {
+        PostReferenceType::Unspecified => 0,
+        PostReferenceType::Replay => 1,
+        PostReferenceType::Quote => 2,
+        PostReferenceType::Repost => 3,
+    };
+    ProtoWriter::new()
+        .enumeration(1, ref_type)
+        .uint64(2, reference.post_id.u64())
+        .uint64(3, reference.position.map(|p| p.u64()).unwrap_or_default())
+        .finish()
+}
+
+fn reply_setting(setting: &ReplySetting) -> i32 {
+    match setting {
+        ReplySetting::Unspecified => 0,
+        ReplySetting::Everyone => 1,
+        ReplySetting::Followers => 2,
+        ReplySetting::Mutual => 3,
+        ReplySetting::Mentions => 4,
+    }
+}
+
+fn attachment(content: &Attachment) -> StdResult<Vec<u8>> {
+    Ok(ProtoWriter::new()
+        .uint64(1, content.subspace_id.u64())
+        .uint64(2, content.post_id.u64())
+        .uint32(3, content.id)
+        .message(4, &post_attachment(&content.content)?)
+        .finish())
+}
+
+fn post_attachment(content: &RawPostAttachment) -> StdResult<Vec<u8>> {
+    let content = PostAttachment::try_from(content.clone())
+        .map_err(|error| StdError::parse_err("RawPostAttachment", error))?;
+    Ok(match content {
+        PostAttachment::Media { mime_type, uri } => any(
+            MEDIA_TYPE_URI,
+            &ProtoWriter::new()
+                .string(1, &uri)
+                .string(2, &mime_type)
+                .finish(),
+        ),
+        PostAttachment::Poll {
+            question,
+            provided_answers,
+            end_date,
+            allows_multiple_answers,
+            allows_answer_edits,
+            final_tally_results,
+        } => {
+            let provided_answers = provided_answers
+                .iter()
+                .map(|answer| {
+                    let attachments = answer
+                        .attachments
+                        .iter()
+                        .map(attachment)
+                        .collect::<StdResult<Vec<_>>>()?;
+                    Ok(ProtoWriter::new()
+                        .string(1, answer.text.as_deref().unwrap_or_default())
+                        .repeated_message(2, &attachments)
+                        .finish())
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+            let final_tally_results = final_tally_results.map(|tally| {
+                let results: Vec<Vec<u8>> = tally
+                    .results
+                    .iter()
+                    .map(|result| {
+                        ProtoWriter::new()
+                            .uint32(1, result.answer_index)
+                            .uint64(2, result.votes.u64())
+                            .finish()
+                    })
+                    .collect();
+                ProtoWriter::new().repeated_message(1, &results).finish()
+            });
+            any(
+                POLL_TYPE_URI,
+                &ProtoWriter::new()
+                    .string(1, &question)
+                    .repeated_message(2, &provided_answers)
+                    .message(3, &rfc3339_timestamp(&end_date)?)
+                    .bool(4, allows_multiple_answers)
+                    .bool(5, allows_answer_edits)
+                    .optional_message(6, final_tally_results.as_deref())
+                    .finish(),
+            )
+        }
+    })
+}
+
+impl PostsMsg {
+    /// Encodes the message using its protobuf representation.
+    pub fn to_proto_bytes(&self) -> StdResult<Binary> {
+        let encoded = match self {
+            PostsMsg::CreatePost {
+                subspace_id,
+                section_id,
+                external_id,
+                text,
+                entities: post_entities,
+                tags,
+                attachments,
+                author,
+                conversation_id,
+                reply_settings,
+                referenced_posts,
+            } => {
+                let attachments = attachments
+                    .iter()
+                    .flatten()
+                    .map(post_attachment)
+                    .collect::<StdResult<Vec<_>>>()?;
+                let referenced_posts: Vec<Vec<u8>> =
+                    referenced_posts.iter().map(post_reference).collect();
+                ProtoWriter::new()
+                    .uint64(1, subspace_id.u64())
+                    .uint32(2, *section_id)
+                    .string(3, external_id.as_deref().unwrap_or_default())
+                    .string(4, text.as_deref().unwrap_or_default())
+                    .optional_message(5, post_entities.as_ref().map(entities).as_deref())
+                    .repeated_string(6, tags)
+                    .repeated_message(7, &attachments)
+                    .string(8, author.as_str())
+                    .uint64(9, conversation_id.map(|id| id.u64()).unwrap_or_default())
+                    .enumeration(10, reply_setting(reply_settings))
+                    .repeated_message(11, &referenced_posts)
+            }
+            PostsMsg::EditPost {
+                subspace_id,
+                post_id,
+                text,
+                entities: post_entities,
+                editor,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint64(2, post_id.u64())
+                .string(3, text)
+                .optional_message(4, post_entities.as_ref().map(entities).as_deref())
+                .string(5, editor.as_str()),
+            PostsMsg::DeletePost {
+                subspace_id,
+                post_id,
+                signer,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint64(2, post_id.u64())
+                .string(3, signer.as_str()),
+            PostsMsg::AddPostAttachment {
+                subspace_id,
+                post_id,
+                content,
+                editor,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint64(2, post_id.u64())
+                .message(3, &post_attachment(content)?)
+                .string(4, editor.as_str()),
+            PostsMsg::RemovePostAttachment {
+                subspace_id,
+                post_id,
+                attachment_id,
+                editor,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint64(2, post_id.u64())
+                .uint32(3, *attachment_id)
+                .string(4, editor.as_str()),
+            PostsMsg::AnswerPoll {
+                subspace_id,
+                post_id,
+                poll_id,
+                answers_indexes,
+                signer,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint64(2, post_id.u64())
+                .uint32(3, *poll_id)
+                .packed_uint32(4, answers_indexes)
+                .string(5, signer.as_str()),
+            #[cfg(feature = "desmos-v5")]
+            PostsMsg::MovePost {
+                subspace_id,
+                post_id,
+                target_subspace_id,
+                target_section_id,
+                owner,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint64(2, post_id.u64())
+                .uint64(3, target_subspace_id.u64())
+                .uint32(4, *target_section_id)
+                .string(5, owner.as_str()),
+            #[cfg(feature = "desmos-v5")]
+            PostsMsg::RequestPostOwnerTransfer {
+                subspace_id,
+                post_id,
+                receiver,
+                sender,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint64(2, post_id.u64())
+                .string(3, receiver.as_str())
+                .string(4, sender.as_str()),
+            #[cfg(feature = "desmos-v5")]
+            PostsMsg::CancelPostOwnerTransferRequest {
+                subspace_id,
+                post_id,
+                sender: signer,
+            }
+            | PostsMsg::AcceptPostOwnerTransferRequest {
+                subspace_id,
+                post_id,
+                receiver: signer,
+            }
+            | PostsMsg::RefusePostOwnerTransferRequest {
+                subspace_id,
+                post_id,
+                receiver: signer,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint64(2, post_id.u64())
+                .string(3, signer.as_str()),
+        };
+        Ok(encoded.finish().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::posts::msg::{MSG_ADD_POST_ATTACHMENT_TYPE_URI, MSG_ANSWER_POLL_TYPE_URI};
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn test_add_post_attachment_encoding() {
+        let msg = PostsMsg::add_post_attachment(
+            1,
+            2,
+            PostAttachment::Media {
+                mime_type: "m".into(),
+                uri: "u".into(),
+            },
+            Addr::unchecked("a"),
+        );
+        let expected = ProtoWriter::new()
+            .uint64(1, 1)
+            .uint64(2, 2)
+            .message(
+                3,
+                &any(MEDIA_TYPE_URI, &[0x0a, 0x01, b'u', 0x12, 0x01, b'm']),
+            )
+            .string(4, "a")
+            .finish();
+        assert_eq!(MSG_ADD_POST_ATTACHMENT_TYPE_URI, msg.type_url());
+        assert_eq!(Binary::from(expected), msg.to_proto_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_answer_poll_encoding() {
+        let msg = PostsMsg::answer_poll(1, 2, 3, vec![0, 1], Addr::unchecked("a"));
+        let expected = ProtoWriter::new()
+            .uint64(1, 1)
+            .uint64(2, 2)
+            .uint32(3, 3)
+            .message(4, &[0x00, 0x01])
+            .string(5, "a")
+            .finish();
+        assert_eq!(MSG_ANSWER_POLL_TYPE_URI, msg.type_url());
+        assert_eq!(Binary::from(expected), msg.to_proto_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_invalid_attachment_encoding_error() {
+        let msg = PostsMsg::AddPostAttachment {
+            subspace_id: 1u64.into(),
+            post_id: 2u64.into(),
+            content: PostAttachment::Poll {
+                question: "q".into(),
+                provided_answers: vec![],
+                end_date: "invalid".into(),
+                allows_multiple_answers: false,
+                allows_answer_edits: false,
+                final_tally_results: None,
+            }
+            .into(),
+            editor: Addr::unchecked("a"),
+        };
+        msg.to_proto_bytes().unwrap_err();
+    }
+
+    #[test]
+    #[cfg(feature = "desmos-v5")]
+    fn test_move_post_encoding() {
+        let msg = PostsMsg::move_post(1, 2, 3, 4, Addr::unchecked("a"));
+        assert_eq!("/desmos.posts.v3.MsgMovePost", msg.type_url());
+        assert_eq!(
+            Binary::from(vec![
+                0x08, 0x01, 0x10, 0x02, 0x18, 0x03, 0x20, 0x04, 0x2a, 0x01, b'a'
+            ]),
+            msg.to_proto_bytes().unwrap()
+        );
+    }
+
+    /// Mirror of the `desmos.posts.v2.MsgAnswerPoll` protobuf message.
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct ProstMsgAnswerPoll {
+        #[prost(uint64, tag = "1")]
+        subspace_id: u64,
+        #[prost(uint64, tag = "2")]
+        post_id: u64,
+        #[prost(uint32, tag = "3")]
+        poll_id: u32,
+        #[prost(uint32, repeated, tag = "4")]
+        answers_indexes: Vec<u32>,
+        #[prost(string, tag = "5")]
+        signer: String,
+    }
+
+    #[test]
+    fn test_answer_poll_prost_encoding() {
+        let msg = PostsMsg::answer_poll(
+            1,
+            200,
+            2,
+            vec![0, 1, 300],
+            Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3"),
+        );
+        let expected = prost::Message::encode_to_vec(&ProstMsgAnswerPoll {
+            subspace_id: 1,
+            post_id: 200,
+            poll_id: 2,
+            answers_indexes: vec![0, 1, 300],
+            signer: "desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3".into(),
+        });
+        assert_eq!(Binary::from(expected), msg.to_proto_bytes().unwrap());
+    }
+}
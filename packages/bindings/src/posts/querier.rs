@@ -1,7 +1,11 @@
 //! Contains the querier that can be used to query data related to the x/posts module.
 
+#[cfg(all(feature = "iterators", feature = "desmos-v5"))]
+use crate::posts::models::PostOwnerTransferRequest;
+#[cfg(feature = "desmos-v5")]
+use crate::posts::models_query::QueryIncomingPostOwnerTransferRequestsResponse;
 use crate::posts::models_query::{
-    QueryPollAnswersResponse, QueryPostAttachmentsResponse, QueryPostResponse,
+    QueryParamsResponse, QueryPollAnswersResponse, QueryPostAttachmentsResponse, QueryPostResponse,
     QuerySectionPostsResponse, QuerySubspacePostsResponse,
 };
 use crate::posts::query::PostsQuery;
@@ -275,6 +279,68 @@ impl<'a> PostsQuerier<'a> {
             page_size,
         )
     }
+
+    /// Queries the incoming post owner transfer requests inside a given subspace.
+    ///
+    /// * `subspace_id` - Id of the subspace to query the requests for.
+    /// * `receiver` - Optional address of the user to query the incoming requests for.
+    /// * `pagination` - Optional pagination configs.
+    #[cfg(feature = "desmos-v5")]
+    pub fn query_incoming_post_owner_transfer_requests(
+        &self,
+        subspace_id: u64,
+        receiver: Option<Addr>,
+        pagination: Option<PageRequest>,
+    ) -> StdResult<QueryIncomingPostOwnerTransferRequestsResponse> {
+        self.querier.query(
+            &DesmosQuery::Posts(PostsQuery::IncomingPostOwnerTransferRequests {
+                subspace_id: subspace_id.into(),
+                receiver,
+                pagination,
+            })
+            .into(),
+        )
+    }
+
+    /// Gives an iterator to scan over the incoming post owner transfer requests inside a given subspace.
+    ///
+    /// * `subspace_id` - Id of the subspace to query the requests for.
+    /// * `receiver` - Optional address of the user to query the incoming requests for.
+    /// * `page_size` - Size of the page requested to the chain.
+    #[cfg(all(feature = "iterators", feature = "desmos-v5"))]
+    pub fn iterate_incoming_post_owner_transfer_requests(
+        &self,
+        subspace_id: u64,
+        receiver: Option<Addr>,
+        page_size: u64,
+    ) -> PageIterator<PostOwnerTransferRequest, Binary> {
+        PageIterator::new(
+            Box::new(move |key, limit| {
+                self.query_incoming_post_owner_transfer_requests(
+                    subspace_id,
+                    receiver.clone(),
+                    Some(PageRequest {
+                        key,
+                        limit: limit.into(),
+                        reverse: false,
+                        count_total: false,
+                        offset: None,
+                    }),
+                )
+                .map(|response| Page {
+                    items: response.requests,
+                    next_page_key: response.pagination.and_then(|response| response.next_key),
+                })
+            }),
+            page_size,
+        )
+    }
+
+    /// Queries the parameters of the x/posts module.
+    pub fn query_params(&self) -> StdResult<QueryParamsResponse> {
+        self.querier
+            .query(&DesmosQuery::Posts(PostsQuery::Params {}).into())
+    }
 }
 
 #[cfg(test)]
@@ -462,4 +528,56 @@ mod tests {
         // The second item should be none since the mock function provides only 1 response.
         assert!(iterator.next().is_none());
     }
+
+    #[test]
+    fn test_query_params() {
+        let owned_deps = mock_desmos_dependencies();
+        let deps = owned_deps.as_ref();
+        let querier = PostsQuerier::new(deps.querier.deref());
+
+        let response = querier.query_params().unwrap();
+
+        assert_eq!(MockPostsQueries::get_mocked_params(), response.params);
+    }
+
+    #[test]
+    #[cfg(feature = "desmos-v5")]
+    fn test_query_incoming_post_owner_transfer_requests() {
+        let owned_deps = mock_desmos_dependencies();
+        let deps = owned_deps.as_ref();
+        let querier = PostsQuerier::new(deps.querier.deref());
+
+        let response = querier
+            .query_incoming_post_owner_transfer_requests(1, None, None)
+            .unwrap();
+
+        assert!(response.pagination.is_none());
+        assert_eq!(
+            MockPostsQueries::get_mocked_incoming_post_owner_transfer_requests(
+                &Uint64::new(1),
+                &None
+            ),
+            response.requests
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "desmos-v5")]
+    fn test_iterate_incoming_post_owner_transfer_requests() {
+        let owned_deps = mock_desmos_dependencies();
+        let deps = owned_deps.as_ref();
+        let querier = PostsQuerier::new(deps.querier.deref());
+
+        let mut iterator = querier.iterate_incoming_post_owner_transfer_requests(1, None, 32);
+        let expected_requests = MockPostsQueries::get_mocked_incoming_post_owner_transfer_requests(
+            &Uint64::new(1),
+            &None,
+        );
+
+        assert_eq!(
+            expected_requests.first().unwrap(),
+            &iterator.next().unwrap().unwrap()
+        );
+        assert!(iterator.next().is_none());
+    }
 }
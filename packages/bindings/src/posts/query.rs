@@ -60,4 +60,18 @@ pub enum PostsQuery {
         /// Pagination configs.
         pagination: Option<PageRequest>,
     },
+    /// Queries the incoming post owner transfer requests inside a given subspace.
+    #[returns(QueryIncomingPostOwnerTransferRequestsResponse)]
+    #[cfg(feature = "desmos-v5")]
+    IncomingPostOwnerTransferRequests {
+        /// Id of the subspace to query the requests for.
+        subspace_id: Uint64,
+        /// Address of the user to query the incoming requests for.
+        receiver: Option<Addr>,
+        /// Pagination configs.
+        pagination: Option<PageRequest>,
+    },
+    /// Queries the parameters of the x/posts module.
+    #[returns(QueryParamsResponse)]
+    Params {},
 }
@@ -0,0 +1,413 @@
+//! Contains a builder that can be used to create the data required to link an external chain
+//! account to a Desmos profile without relying on an external wallet or CLI.
+
+use crate::profiles::models_chain_links::{
+    Address, ChainConfig, Proof, Signature, SignatureValueType, BECH32_ADDRESS_TYPE_URI,
+    SINGLE_SIGNATURE_TYPE_URI,
+};
+use crate::types::PubKey;
+use bech32::{ToBase32, Variant};
+use cosmwasm_std::Binary;
+use ed25519_dalek::Signer as _;
+use ripemd::Ripemd160;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+const SECP256K1_PUB_KEY_TYPE_URI: &str = "/cosmos.crypto.secp256k1.PubKey";
+const ED25519_PUB_KEY_TYPE_URI: &str = "/cosmos.crypto.ed25519.PubKey";
+
+/// Represents the private key used to sign the chain link proof.
+#[derive(Clone)]
+pub enum ChainLinkPrivateKey {
+    /// A raw 32 bytes secp256k1 private key.
+    Secp256k1([u8; 32]),
+    /// A raw 32 bytes ed25519 private key.
+    Ed25519([u8; 32]),
+}
+
+/// Represents the errors that can occur while building a chain link proof.
+#[derive(Error, Debug, PartialEq)]
+pub enum ChainLinkProofError {
+    /// Error returned when the provided private key is not valid.
+    #[error("invalid private key: {0}")]
+    InvalidPrivateKey(String),
+    /// Error returned when the external address can't be encoded with the provided prefix.
+    #[error("invalid bech32 prefix {prefix}: {reason}")]
+    InvalidPrefix {
+        /// The prefix that has been used to encode the address.
+        prefix: String,
+        /// The reason why the encoding failed.
+        reason: String,
+    },
+    /// Error returned when the requested signature value type can't be produced by the builder.
+    #[error("unsupported signature value type: {0:?}")]
+    UnsupportedValueType(SignatureValueType),
+}
+
+/// Contains all the data needed to build a [`ProfilesMsg::LinkChainAccount`](crate::profiles::msg::ProfilesMsg::LinkChainAccount).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChainLinkProof {
+    /// Data of the external chain address to be connected with the Desmos profile.
+    pub address: Address,
+    /// Ownership proof of the external chain address.
+    pub proof: Proof,
+    /// Configuration of the external chain.
+    pub chain_config: ChainConfig,
+}
+
+/// Builder to create a valid [`ChainLinkProof`] signed with a given private key.
+///
+/// The external address is derived from the private key and encoded using the configured
+/// bech32 prefix, while the signed value always contains the Desmos address that will own the link.
+/// The supported signature value types are [`SignatureValueType::Raw`],
+/// [`SignatureValueType::CosmosDirect`] and [`SignatureValueType::CosmosAnimo`].
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::Addr;
+/// use desmos_bindings::profiles::chain_link_proof::{ChainLinkPrivateKey, ChainLinkProofBuilder};
+/// use desmos_bindings::profiles::models_chain_links::SignatureValueType;
+/// use desmos_bindings::profiles::msg::ProfilesMsg;
+///
+/// let desmos_address = "desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3";
+/// let link = ChainLinkProofBuilder::new(ChainLinkPrivateKey::Secp256k1([1; 32]), "cosmos", "cosmos")
+///     .value_type(SignatureValueType::CosmosAnimo)
+///     .chain_id("cosmoshub-4")
+///     .build(desmos_address)
+///     .unwrap();
+///
+/// let msg = ProfilesMsg::link_chain_account(
+///     link.address,
+///     link.proof,
+///     link.chain_config,
+///     Addr::unchecked(desmos_address),
+/// );
+/// ```
+pub struct ChainLinkProofBuilder {
+    private_key: ChainLinkPrivateKey,
+    chain_name: String,
+    bech32_prefix: String,
+    value_type: SignatureValueType,
+    chain_id: String,
+}
+
+impl ChainLinkProofBuilder {
+    /// Creates a new builder that produces [`SignatureValueType::Raw`] proofs.
+    ///
+    /// * `private_key` - Private key of the external account to be linked.
+    /// * `chain_name` - Name of the external chain.
+    /// * `bech32_prefix` - Bech32 prefix used to encode the external address.
+    pub fn new(private_key: ChainLinkPrivateKey, chain_name: &str, bech32_prefix: &str) -> Self {
+        ChainLinkProofBuilder {
+            private_key,
+            chain_name: chain_name.to_owned(),
+            bech32_prefix: bech32_prefix.to_owned(),
+            value_type: SignatureValueType::Raw,
+            chain_id: chain_name.to_owned(),
+        }
+    }
+
+    /// Sets the way in which the Desmos address is encoded before being signed.
+    ///
+    /// * `value_type` - The signature value type of the proof.
+    pub fn value_type(mut self, value_type: SignatureValueType) -> Self {
+        self.value_type = value_type;
+        self
+    }
+
+    /// Sets the chain id used inside the signed transaction when using
+    /// [`SignatureValueType::CosmosDirect`] or [`SignatureValueType::CosmosAnimo`].
+    /// Defaults to the chain name.
+    ///
+    /// * `chain_id` - Id of the external chain.
+    pub fn chain_id(mut self, chain_id: &str) -> Self {
+        self.chain_id = chain_id.to_owned();
+        self
+    }
+
+    /// Builds the [`ChainLinkProof`] proving that the external account wants to be linked
+    /// to the profile of `desmos_address`.
+    ///
+    /// * `desmos_address` - Address of the Desmos profile that will own the link.
+    pub fn build(&self, desmos_address: &str) -> Result<ChainLinkProof, ChainLinkProofError> {
+        let plain_text = self.signed_value(desmos_address)?;
+        let (pub_key, signature) = self.sign(&plain_text)?;
+        let address = self.external_address(&pub_key.key)?;
+
+        Ok(ChainLinkProof {
+            address: Address {
+                proto_type: BECH32_ADDRESS_TYPE_URI.to_string(),
+                value: address,
+                prefix: Some(self.bech32_prefix.clone()),
+            },
+            proof: Proof {
+                pub_key,
+                signature: Signature {
+                    proto_type: SINGLE_SIGNATURE_TYPE_URI.to_string(),
+                    value_type: self.value_type.clone(),
+                    signature,
+                },
+                plain_text: hex::encode(plain_text),
+            },
+            chain_config: ChainConfig {
+                name: self.chain_name.clone(),
+            },
+        })
+    }
+
+    /// Returns the bytes that should be signed based on the configured value type.
+    fn signed_value(&self, desmos_address: &str) -> Result<Vec<u8>, ChainLinkProofError> {
+        match self.value_type {
+            SignatureValueType::Raw => Ok(desmos_address.as_bytes().to_vec()),
+            SignatureValueType::CosmosDirect => Ok(direct_sign_doc(&self.chain_id, desmos_address)),
+            SignatureValueType::CosmosAnimo => Ok(amino_sign_doc(&self.chain_id, desmos_address)),
+            _ => Err(ChainLinkProofError::UnsupportedValueType(
+                self.value_type.clone(),
+            )),
+        }
+    }
+
+    /// Signs the given value returning the public key and the signature.
+    fn sign(&self, value: &[u8]) -> Result<(PubKey, Binary), ChainLinkProofError> {
+        match &self.private_key {
+            ChainLinkPrivateKey::Secp256k1(bytes) => {
+                let signing_key = k256::ecdsa::SigningKey::from_slice(bytes)
+                    .map_err(|error| ChainLinkProofError::InvalidPrivateKey(error.to_string()))?;
+                let signature: k256::ecdsa::Signature = signing_key.sign(value);
+                let pub_key = signing_key.verifying_key().to_encoded_point(true);
+                Ok((
+                    PubKey {
+                        proto_type: SECP256K1_PUB_KEY_TYPE_URI.to_string(),
+                        key: Binary::from(pub_key.as_bytes()),
+                    },
+                    Binary::from(&signature.to_bytes()[..]),
+                ))
+            }
+            ChainLinkPrivateKey::Ed25519(bytes) => {
+                let signing_key = ed25519_dalek::SigningKey::from_bytes(bytes);
+                let signature = signing_key.sign(value);
+                Ok((
+                    PubKey {
+                        proto_type: ED25519_PUB_KEY_TYPE_URI.to_string(),
+                        key: Binary::from(signing_key.verifying_key().as_bytes().as_slice()),
+                    },
+                    Binary::from(&signature.to_bytes()[..]),
+                ))
+            }
+        }
+    }
+
+    /// Derives the bech32 encoded address of the given public key following the Cosmos SDK rules.
+    fn external_address(&self, pub_key: &[u8]) -> Result<String, ChainLinkProofError> {
+        let address_bytes = match self.private_key {
+            ChainLinkPrivateKey::Secp256k1(_) => {
+                Ripemd160::digest(Sha256::digest(pub_key)).to_vec()
+            }
+            ChainLinkPrivateKey::Ed25519(_) => Sha256::digest(pub_key)[..20].to_vec(),
+        };
+        bech32::encode(
+            &self.bech32_prefix,
+            address_bytes.to_base32(),
+            Variant::Bech32,
+        )
+        .map_err(|error| ChainLinkProofError::InvalidPrefix {
+            prefix: self.bech32_prefix.clone(),
+            reason: error.to_string(),
+        })
+    }
+}
+
+/// Amino JSON sign document, fields are declared in alphabetical order
+/// to produce the canonical JSON representation.
+#[derive(Serialize)]
+struct AminoSignDoc<'a> {
+    account_number: &'a str,
+    chain_id: &'a str,
+    fee: AminoFee<'a>,
+    memo: &'a str,
+    msgs: [(); 0],
+    sequence: &'a str,
+}
+
+#[derive(Serialize)]
+struct AminoFee<'a> {
+    amount: [(); 0],
+    gas: &'a str,
+}
+
+/// Builds an empty Amino transaction having the Desmos address as memo.
+fn amino_sign_doc(chain_id: &str, memo: &str) -> Vec<u8> {
+    let sign_doc = AminoSignDoc {
+        account_number: "0",
+        chain_id,
+        fee: AminoFee {
+            amount: [],
+            gas: "0",
+        },
+        memo,
+        msgs: [],
+        sequence: "0",
+    };
+    cosmwasm_std::to_vec(&sign_doc).expect("amino sign doc serialization can't fail")
+}
+
+/// Builds a Protobuf `SignDoc` of an empty transaction having the Desmos address as memo.
+fn direct_sign_doc(chain_id: &str, memo: &str) -> Vec<u8> {
+    // TxBody { memo = 2 }
+    let mut body = Vec::new();
+    encode_proto_bytes(&mut body, 2, memo.as_bytes());
+
+    // SignDoc { body_bytes = 1, auth_info_bytes = 2, chain_id = 3, account_number = 4 }
+    let mut sign_doc = Vec::new();
+    encode_proto_bytes(&mut sign_doc, 1, &body);
+    encode_proto_bytes(&mut sign_doc, 3, chain_id.as_bytes());
+    sign_doc
+}
+
+/// Appends a length delimited Protobuf field to the given buffer.
+fn encode_proto_bytes(buffer: &mut Vec<u8>, field_number: u8, value: &[u8]) {
+    buffer.push(field_number << 3 | 2);
+    let mut length = value.len();
+    while length >= 0x80 {
+        buffer.push((length as u8 & 0x7f) | 0x80);
+        length >>= 7;
+    }
+    buffer.push(length as u8);
+    buffer.extend_from_slice(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::MockApi;
+    use cosmwasm_std::Api;
+
+    const DESMOS_ADDRESS: &str = "desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3";
+
+    fn verify(proof: &ChainLinkProof) -> bool {
+        let plain_text = hex::decode(&proof.proof.plain_text).unwrap();
+        let signature = proof.proof.signature.signature.as_slice();
+        let pub_key = proof.proof.pub_key.key.as_slice();
+        let api = MockApi::default();
+        match proof.proof.pub_key.proto_type.as_str() {
+            SECP256K1_PUB_KEY_TYPE_URI => api
+                .secp256k1_verify(&Sha256::digest(plain_text), signature, pub_key)
+                .unwrap(),
+            _ => api.ed25519_verify(&plain_text, signature, pub_key).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_secp256k1_raw_proof() {
+        let proof =
+            ChainLinkProofBuilder::new(ChainLinkPrivateKey::Secp256k1([1; 32]), "cosmos", "cosmos")
+                .build(DESMOS_ADDRESS)
+                .unwrap();
+
+        assert_eq!(BECH32_ADDRESS_TYPE_URI, proof.address.proto_type);
+        assert!(proof.address.value.starts_with("cosmos1"));
+        assert_eq!(Some("cosmos".to_string()), proof.address.prefix);
+        assert_eq!(SECP256K1_PUB_KEY_TYPE_URI, proof.proof.pub_key.proto_type);
+        assert_eq!(SignatureValueType::Raw, proof.proof.signature.value_type);
+        assert_eq!(hex::encode(DESMOS_ADDRESS), proof.proof.plain_text);
+        assert_eq!(
+            ChainConfig {
+                name: "cosmos".to_string()
+            },
+            proof.chain_config
+        );
+        assert!(verify(&proof));
+    }
+
+    #[test]
+    fn test_ed25519_raw_proof() {
+        let proof =
+            ChainLinkProofBuilder::new(ChainLinkPrivateKey::Ed25519([2; 32]), "osmosis", "osmo")
+                .build(DESMOS_ADDRESS)
+                .unwrap();
+
+        assert!(proof.address.value.starts_with("osmo1"));
+        assert_eq!(ED25519_PUB_KEY_TYPE_URI, proof.proof.pub_key.proto_type);
+        assert!(verify(&proof));
+    }
+
+    #[test]
+    fn test_cosmos_amino_proof() {
+        let proof =
+            ChainLinkProofBuilder::new(ChainLinkPrivateKey::Secp256k1([1; 32]), "cosmos", "cosmos")
+                .value_type(SignatureValueType::CosmosAnimo)
+                .chain_id("cosmoshub-4")
+                .build(DESMOS_ADDRESS)
+                .unwrap();
+
+        let plain_text = String::from_utf8(hex::decode(&proof.proof.plain_text).unwrap()).unwrap();
+        assert_eq!(
+            format!(
+                r#"{{"account_number":"0","chain_id":"cosmoshub-4","fee":{{"amount":[],"gas":"0"}},"memo":"{}","msgs":[],"sequence":"0"}}"#,
+                DESMOS_ADDRESS
+            ),
+            plain_text
+        );
+        assert!(verify(&proof));
+    }
+
+    #[test]
+    fn test_cosmos_direct_proof() {
+        let proof =
+            ChainLinkProofBuilder::new(ChainLinkPrivateKey::Ed25519([2; 32]), "cosmos", "cosmos")
+                .value_type(SignatureValueType::CosmosDirect)
+                .build(DESMOS_ADDRESS)
+                .unwrap();
+
+        let plain_text = hex::decode(&proof.proof.plain_text).unwrap();
+        let mut expected = vec![0x0a, DESMOS_ADDRESS.len() as u8 + 2, 0x12];
+        expected.push(DESMOS_ADDRESS.len() as u8);
+        expected.extend_from_slice(DESMOS_ADDRESS.as_bytes());
+        expected.extend_from_slice(&[0x1a, 6]);
+        expected.extend_from_slice(b"cosmos");
+        assert_eq!(expected, plain_text);
+        assert!(verify(&proof));
+    }
+
+    #[test]
+    fn test_unsupported_value_type() {
+        let result =
+            ChainLinkProofBuilder::new(ChainLinkPrivateKey::Secp256k1([1; 32]), "cosmos", "cosmos")
+                .value_type(SignatureValueType::EVMPersonalSign)
+                .build(DESMOS_ADDRESS);
+
+        assert_eq!(
+            Err(ChainLinkProofError::UnsupportedValueType(
+                SignatureValueType::EVMPersonalSign
+            )),
+            result
+        );
+    }
+
+    #[test]
+    fn test_invalid_secp256k1_private_key() {
+        let result =
+            ChainLinkProofBuilder::new(ChainLinkPrivateKey::Secp256k1([0; 32]), "cosmos", "cosmos")
+                .build(DESMOS_ADDRESS);
+
+        assert!(matches!(
+            result,
+            Err(ChainLinkProofError::InvalidPrivateKey(_))
+        ));
+    }
+
+    #[test]
+    fn test_signature_scheme_matches_chain_link_data() {
+        let plain_text =
+            hex::decode("6465736d6f73316a6e70666130367868666c796a68366b6c776c7271386d6b3535733533637a68366e63646d33")
+                .unwrap();
+        let signature = Binary::from_base64("dhTNhVqap+JDGTu1GshTkSfzE7p3yb6Z5+f/lqGUzSUSlfFZM/NZG4UsBwR3DyJF28nmS66jsarPXOTlLnfTBw==").unwrap();
+        let pub_key = Binary::from_base64("A6p7imM9YY/uFgZFV/ZiNQ45Ki2xbyR4zjG//BFzkVtY").unwrap();
+
+        assert_eq!(DESMOS_ADDRESS.as_bytes(), plain_text.as_slice());
+        assert!(MockApi::default()
+            .secp256k1_verify(&Sha256::digest(plain_text), &signature, &pub_key)
+            .unwrap());
+    }
+}
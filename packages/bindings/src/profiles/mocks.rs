@@ -1,24 +1,25 @@
 //! Contains some useful mocks of the Desmos x/profiles module's types made to be used in any test.
 
 use crate::profiles::models_app_links::ApplicationLinkState;
-use crate::profiles::models_chain_links::{Address, SignatureValueType};
+use crate::profiles::models_chain_links::{Address, SignatureValueType, BECH32_ADDRESS_TYPE_URI};
 use crate::profiles::{
     models_app_links::{
         AppLinkResult, ApplicationLink, ApplicationLinkOwnerDetails, CallData, Data, OracleRequest,
     },
     models_chain_links::{ChainConfig, ChainLink, ChainLinkOwnerDetails, Proof, Signature},
     models_dtag_requests::DtagTransferRequest,
-    models_profile::{Account, Pictures, Profile},
+    models_params::{AppLinksParams, BioParams, DtagParams, NicknameParams, OracleParams, Params},
+    models_profile::{Account, Pictures, Profile, PROFILE_TYPE_URI},
     models_query::{
         QueryApplicationLinkByClientIDResponse, QueryApplicationLinkOwnersResponse,
         QueryApplicationLinksResponse, QueryChainLinkOwnersResponse, QueryChainLinksResponse,
         QueryDefaultExternalAddressesResponse, QueryIncomingDtagTransferRequestsResponse,
-        QueryProfileResponse,
+        QueryParamsResponse, QueryProfileResponse,
     },
     query::ProfilesQuery,
 };
 use crate::types::PubKey;
-use cosmwasm_std::{to_binary, Addr, Binary, ContractResult, Uint64};
+use cosmwasm_std::{coins, to_binary, Addr, Binary, ContractResult, Uint64};
 
 /// Struct that contains some utility methods to mock data of the Desmos
 /// x/profiles module.
@@ -28,7 +29,7 @@ impl MockProfilesQueries {
     /// Gets a mocked instance of [`Profile`].
     pub fn get_mock_profile() -> Profile {
         Profile {
-            proto_type: "/desmos.profiles.v3.Profile".to_string(),
+            proto_type: PROFILE_TYPE_URI.to_string(),
             account: Account {
                 proto_type: "/cosmos.auth.v1beta1.BaseAccount".to_string(),
                 address: Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc"),
@@ -65,7 +66,7 @@ impl MockProfilesQueries {
         ChainLink {
             user: Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc"),
             address: Address {
-                proto_type: "/desmos.profiles.v3.Bech32Addres".to_string(),
+                proto_type: BECH32_ADDRESS_TYPE_URI.to_string(),
                 value: "cosmos18xnmlzqrqr6zt526pnczxe65zk3f4xgmndpxn2".to_string(),
                 prefix: Some("cosmos".to_string()),
             },
@@ -132,6 +133,35 @@ impl MockProfilesQueries {
             username: "goldrake".to_string(),
         }
     }
+
+    /// Gets a mocked instance of [`Params`].
+    pub fn get_mock_params() -> Params {
+        Params {
+            nickname: NicknameParams {
+                min_length: Uint64::new(2),
+                max_length: Uint64::new(1000),
+            },
+            dtag: DtagParams {
+                reg_ex: "^[A-Za-z0-9_]+$".to_string(),
+                min_length: Uint64::new(3),
+                max_length: Uint64::new(30),
+            },
+            bio: BioParams {
+                max_length: Uint64::new(1000),
+            },
+            oracle: OracleParams {
+                script_id: Uint64::new(32),
+                ask_count: Uint64::new(10),
+                min_count: Uint64::new(6),
+                prepare_gas: Uint64::new(50000),
+                execute_gas: Uint64::new(200000),
+                fee_amount: coins(10, "udsm"),
+            },
+            app_links: AppLinksParams {
+                validity_duration: "31536000s".to_string(),
+            },
+        }
+    }
 }
 
 /// Functions that mocks the profile query responses.
@@ -187,6 +217,9 @@ pub fn mock_profiles_query_response(query: &ProfilesQuery) -> ContractResult<Bin
                 pagination: Default::default(),
             })
         }
+        ProfilesQuery::Params {} => to_binary(&QueryParamsResponse {
+            params: MockProfilesQueries::get_mock_params(),
+        }),
     };
     response.into()
 }
@@ -310,4 +343,14 @@ mod tests {
         });
         assert_eq!(response.into_result().ok(), expected.ok())
     }
+
+    #[test]
+    fn test_query_params() {
+        let query = ProfilesQuery::Params {};
+        let response = mock_profiles_query_response(&query);
+        let expected = to_binary(&QueryParamsResponse {
+            params: MockProfilesQueries::get_mock_params(),
+        });
+        assert_eq!(response.into_result().ok(), expected.ok())
+    }
 }
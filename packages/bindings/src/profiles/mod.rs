@@ -1,12 +1,18 @@
 //! Contains utilities,structs and enum to interact with the Desmos x/profiles module.
 
+#[cfg(all(feature = "chain-link-proof", not(target_arch = "wasm32")))]
+pub mod chain_link_proof;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod mocks;
 pub mod models_app_links;
 pub mod models_chain_links;
 pub mod models_dtag_requests;
+pub mod models_params;
 pub mod models_profile;
 pub mod models_query;
 pub mod msg;
+pub mod msg_builders;
+#[cfg(feature = "authz")]
+pub mod msg_proto;
 pub mod querier;
 pub mod query;
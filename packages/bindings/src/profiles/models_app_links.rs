@@ -1,7 +1,9 @@
 //! Contains structs and enums related to the application links.
 
+use crate::types::parse_rfc3339_time;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint64};
+use cosmwasm_std::{Addr, Api, StdError, StdResult, Timestamp, Uint64};
+use sha2::{Digest, Sha256};
 
 /// Defines the state of an application link.
 #[cw_serde]
@@ -47,6 +49,50 @@ pub struct ApplicationLink {
     pub expiration_time: String,
 }
 
+impl ApplicationLink {
+    /// Tells whether the link is expired at the given block time.
+    ///
+    /// * `block_time` - Time of the block in which the check is performed.
+    pub fn is_expired_at(&self, block_time: Timestamp) -> StdResult<bool> {
+        Ok(parse_rfc3339_time(&self.expiration_time)? <= block_time)
+    }
+
+    /// Tells whether the link has been verified successfully and is still valid
+    /// at the given block time.
+    ///
+    /// * `block_time` - Time of the block in which the check is performed.
+    pub fn is_verified_at(&self, block_time: Timestamp) -> StdResult<bool> {
+        Ok(self.verified_username().is_some() && !self.is_expired_at(block_time)?)
+    }
+
+    /// Returns the application username if the link has been verified successfully,
+    /// `None` otherwise.
+    /// Note that this method does not check the link expiration, use [`ApplicationLink::is_verified_at`]
+    /// to also make sure the link is not expired.
+    pub fn verified_username(&self) -> Option<&str> {
+        match (&self.state, &self.result) {
+            (ApplicationLinkState::VerificationSuccess, Some(AppLinkResult::Success { .. })) => {
+                Some(&self.data.username)
+            }
+            _ => None,
+        }
+    }
+
+    /// Verifies the signature contained inside the link result using the given secp256k1 public key.
+    /// Returns `false` if the link has not been verified successfully.
+    ///
+    /// * `api` - Api used to verify the signature.
+    /// * `pub_key` - Serialized secp256k1 public key of the oracle that signed the result.
+    pub fn verify_result(&self, api: &dyn Api, pub_key: &[u8]) -> StdResult<bool> {
+        match (&self.state, &self.result) {
+            (ApplicationLinkState::VerificationSuccess, Some(result)) => {
+                result.verify_signature(api, pub_key)
+            }
+            _ => Ok(false),
+        }
+    }
+}
+
 /// Represents the data associated to a specific user of a
 /// generic centralized application.
 #[cw_serde]
@@ -99,6 +145,27 @@ pub enum AppLinkResult {
     },
 }
 
+impl AppLinkResult {
+    /// Verifies that the signature of a [`AppLinkResult::Success`] has been produced
+    /// by signing the SHA-256 hash of the result value with the given secp256k1 public key.
+    /// Returns `false` if the result is a [`AppLinkResult::Failed`].
+    ///
+    /// * `api` - Api used to verify the signature.
+    /// * `pub_key` - Serialized secp256k1 public key that should have signed the value.
+    pub fn verify_signature(&self, api: &dyn Api, pub_key: &[u8]) -> StdResult<bool> {
+        match self {
+            AppLinkResult::Success { value, signature } => {
+                let value = hex::decode(value)
+                    .map_err(|error| StdError::parse_err("AppLinkResult::value", error))?;
+                let signature = hex::decode(signature)
+                    .map_err(|error| StdError::parse_err("AppLinkResult::signature", error))?;
+                Ok(api.secp256k1_verify(&Sha256::digest(value), &signature, pub_key)?)
+            }
+            AppLinkResult::Failed { .. } => Ok(false),
+        }
+    }
+}
+
 /// Contains the details of a single app link owner.
 #[cw_serde]
 pub struct ApplicationLinkOwnerDetails {
@@ -109,3 +176,108 @@ pub struct ApplicationLinkOwnerDetails {
     /// Unique name of the application target.
     pub username: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profiles::mocks::MockProfilesQueries;
+    use cosmwasm_std::testing::MockApi;
+
+    // Compressed secp256k1 public keys of the [1; 32] and [2; 32] private keys.
+    const ORACLE_PUB_KEY: &str =
+        "031b84c5567b126440995d3ed5aaba0565d71e1834604819ff9c17f5e9d5dd078f";
+    const OTHER_PUB_KEY: &str =
+        "024d4b6cd1361032ca9bd2aeb9d900aa4d45d9ead80ac9423374c451a7254d0766";
+    // Signature of "goldrake" made with the [1; 32] private key.
+    const GOLDRAKE_SIGNATURE: &str = "74d75bb7365255da39a180b07d8c9db6938dbda3783a9b966da786b6f2c4c5c22472cf02a899020ed7ad6b4c17ecc95f838f6ad5c741a4e112e51f1b1082a198";
+
+    #[test]
+    fn test_is_expired_at() {
+        let link = MockProfilesQueries::get_mock_application_link();
+        let expiration = parse_rfc3339_time(&link.expiration_time).unwrap();
+
+        assert!(!link.is_expired_at(expiration.minus_seconds(1)).unwrap());
+        assert!(link.is_expired_at(expiration).unwrap());
+    }
+
+    #[test]
+    fn test_is_verified_at() {
+        let link = MockProfilesQueries::get_mock_application_link();
+        let expiration = parse_rfc3339_time(&link.expiration_time).unwrap();
+        assert!(link.is_verified_at(expiration.minus_seconds(1)).unwrap());
+        assert!(!link.is_verified_at(expiration).unwrap());
+
+        let link = ApplicationLink {
+            state: ApplicationLinkState::VerificationError,
+            ..MockProfilesQueries::get_mock_application_link()
+        };
+        assert!(!link.is_verified_at(expiration.minus_seconds(1)).unwrap());
+
+        let link = ApplicationLink {
+            expiration_time: "invalid".to_string(),
+            ..MockProfilesQueries::get_mock_application_link()
+        };
+        assert!(link.is_verified_at(expiration).is_err());
+    }
+
+    #[test]
+    fn test_verified_username() {
+        let link = MockProfilesQueries::get_mock_application_link();
+        assert_eq!(Some("goldrake"), link.verified_username());
+
+        let link = ApplicationLink {
+            result: Some(AppLinkResult::Failed {
+                error: "error".to_string(),
+            }),
+            ..MockProfilesQueries::get_mock_application_link()
+        };
+        assert_eq!(None, link.verified_username());
+
+        let link = ApplicationLink {
+            state: ApplicationLinkState::TimedOut,
+            ..MockProfilesQueries::get_mock_application_link()
+        };
+        assert_eq!(None, link.verified_username());
+    }
+
+    #[test]
+    fn test_verify_result() {
+        let api = MockApi::default();
+        let oracle_key = hex::decode(ORACLE_PUB_KEY).unwrap();
+        let link = ApplicationLink {
+            result: Some(AppLinkResult::Success {
+                value: hex::encode("goldrake"),
+                signature: GOLDRAKE_SIGNATURE.to_string(),
+            }),
+            ..MockProfilesQueries::get_mock_application_link()
+        };
+        assert!(link.verify_result(&api, &oracle_key).unwrap());
+
+        let other_key = hex::decode(OTHER_PUB_KEY).unwrap();
+        assert!(!link.verify_result(&api, &other_key).unwrap());
+
+        let link = ApplicationLink {
+            state: ApplicationLinkState::VerificationStarted,
+            ..link
+        };
+        assert!(!link.verify_result(&api, &oracle_key).unwrap());
+    }
+
+    #[test]
+    fn test_verify_signature_invalid_hex() {
+        let result = AppLinkResult::Success {
+            value: "not hex".to_string(),
+            signature: "".to_string(),
+        };
+        assert!(result
+            .verify_signature(&MockApi::default(), &[0; 33])
+            .is_err());
+
+        let result = AppLinkResult::Failed {
+            error: "error".to_string(),
+        };
+        assert!(!result
+            .verify_signature(&MockApi::default(), &[0; 33])
+            .unwrap());
+    }
+}
@@ -4,6 +4,11 @@ use crate::types::PubKey;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Binary};
 
+/// Proto type uri of a bech32 [`Address`].
+pub const BECH32_ADDRESS_TYPE_URI: &str = "/desmos.profiles.v3.Bech32Address";
+/// Proto type uri of a [`Signature`] made by a single key.
+pub const SINGLE_SIGNATURE_TYPE_URI: &str = "/desmos.profiles.v3.SingleSignature";
+
 /// Contains the data of the external chain address to be connected with the Desmos profile.
 #[cw_serde]
 pub struct Address {
@@ -0,0 +1,70 @@
+//! Contains the structs representing the params of the x/profiles module.
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, Uint64};
+
+/// Contains the parameters of the x/profiles module.
+#[cw_serde]
+pub struct Params {
+    /// Parameters related to the profiles nickname.
+    pub nickname: NicknameParams,
+    /// Parameters related to the profiles DTag.
+    pub dtag: DtagParams,
+    /// Parameters related to the profiles biography.
+    pub bio: BioParams,
+    /// Parameters used to verify the application links through the oracle.
+    pub oracle: OracleParams,
+    /// Parameters related to the application links.
+    pub app_links: AppLinksParams,
+}
+
+/// Contains the parameters related to the profiles nickname.
+#[cw_serde]
+pub struct NicknameParams {
+    /// Minimum length of a nickname.
+    pub min_length: Uint64,
+    /// Maximum length of a nickname.
+    pub max_length: Uint64,
+}
+
+/// Contains the parameters related to the profiles DTag.
+#[cw_serde]
+pub struct DtagParams {
+    /// Regular expression that a DTag must match.
+    pub reg_ex: String,
+    /// Minimum length of a DTag.
+    pub min_length: Uint64,
+    /// Maximum length of a DTag.
+    pub max_length: Uint64,
+}
+
+/// Contains the parameters related to the profiles biography.
+#[cw_serde]
+pub struct BioParams {
+    /// Maximum length of a biography.
+    pub max_length: Uint64,
+}
+
+/// Contains the parameters used to verify the application links through the oracle.
+#[cw_serde]
+pub struct OracleParams {
+    /// Id of the oracle script used to verify the application links.
+    pub script_id: Uint64,
+    /// Number of validators that are requested to respond to the request.
+    pub ask_count: Uint64,
+    /// Minimum number of validators necessary for the request to proceed to the execution phase.
+    pub min_count: Uint64,
+    /// Amount of gas to pay to prepare the request.
+    pub prepare_gas: Uint64,
+    /// Amount of gas reserved for executing the request.
+    pub execute_gas: Uint64,
+    /// Amount of coins paid for the oracle request.
+    pub fee_amount: Vec<Coin>,
+}
+
+/// Contains the parameters related to the application links.
+#[cw_serde]
+pub struct AppLinksParams {
+    /// Duration of an application link validity, e.g. `31536000s`.
+    pub validity_duration: String,
+}
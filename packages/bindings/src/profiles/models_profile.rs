@@ -4,6 +4,9 @@ use crate::types::PubKey;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint64};
 
+/// Proto type uri of [`Profile`], the `desmos.profiles.v3` package is used by every supported Desmos version.
+pub const PROFILE_TYPE_URI: &str = "/desmos.profiles.v3.Profile";
+
 /// Represents a Desmos profile, contains the information of a single user.
 #[cw_serde]
 pub struct Profile {
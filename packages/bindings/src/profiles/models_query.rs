@@ -5,6 +5,7 @@ use crate::{
         models_app_links::{ApplicationLink, ApplicationLinkOwnerDetails},
         models_chain_links::{ChainLink, ChainLinkOwnerDetails},
         models_dtag_requests::DtagTransferRequest,
+        models_params::Params,
         models_profile::Profile,
     },
     types::PageResponse,
@@ -78,3 +79,10 @@ pub struct QueryApplicationLinkOwnersResponse {
     /// Details of the current fetched page.
     pub pagination: Option<PageResponse>,
 }
+
+/// Response to [`ProfilesQuery::Params`](crate::profiles::query::ProfilesQuery::Params).
+#[cw_serde]
+pub struct QueryParamsResponse {
+    /// Parameters of the x/profiles module.
+    pub params: Params,
+}
@@ -6,6 +6,33 @@ use crate::types::Height;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint64};
 
+/// Type url of [`ProfilesMsg::SaveProfile`].
+pub const MSG_SAVE_PROFILE_TYPE_URI: &str = "/desmos.profiles.v3.MsgSaveProfile";
+/// Type url of [`ProfilesMsg::DeleteProfile`].
+pub const MSG_DELETE_PROFILE_TYPE_URI: &str = "/desmos.profiles.v3.MsgDeleteProfile";
+/// Type url of [`ProfilesMsg::RequestDtagTransfer`].
+pub const MSG_REQUEST_DTAG_TRANSFER_TYPE_URI: &str = "/desmos.profiles.v3.MsgRequestDTagTransfer";
+/// Type url of [`ProfilesMsg::AcceptDtagTransferRequest`].
+pub const MSG_ACCEPT_DTAG_TRANSFER_REQUEST_TYPE_URI: &str =
+    "/desmos.profiles.v3.MsgAcceptDTagTransferRequest";
+/// Type url of [`ProfilesMsg::RefuseDtagTransferRequest`].
+pub const MSG_REFUSE_DTAG_TRANSFER_REQUEST_TYPE_URI: &str =
+    "/desmos.profiles.v3.MsgRefuseDTagTransferRequest";
+/// Type url of [`ProfilesMsg::CancelDtagTransferRequest`].
+pub const MSG_CANCEL_DTAG_TRANSFER_REQUEST_TYPE_URI: &str =
+    "/desmos.profiles.v3.MsgCancelDTagTransferRequest";
+/// Type url of [`ProfilesMsg::LinkChainAccount`].
+pub const MSG_LINK_CHAIN_ACCOUNT_TYPE_URI: &str = "/desmos.profiles.v3.MsgLinkChainAccount";
+/// Type url of [`ProfilesMsg::UnlinkChainAccount`].
+pub const MSG_UNLINK_CHAIN_ACCOUNT_TYPE_URI: &str = "/desmos.profiles.v3.MsgUnlinkChainAccount";
+/// Type url of [`ProfilesMsg::SetDefaultExternalAddress`].
+pub const MSG_SET_DEFAULT_EXTERNAL_ADDRESS_TYPE_URI: &str =
+    "/desmos.profiles.v3.MsgSetDefaultExternalAddress";
+/// Type url of [`ProfilesMsg::LinkApplication`].
+pub const MSG_LINK_APPLICATION_TYPE_URI: &str = "/desmos.profiles.v3.MsgLinkApplication";
+/// Type url of [`ProfilesMsg::UnlinkApplication`].
+pub const MSG_UNLINK_APPLICATION_TYPE_URI: &str = "/desmos.profiles.v3.MsgUnlinkApplication";
+
 /// Represents the messages to interact with the profiles module.
 #[cw_serde]
 pub enum ProfilesMsg {
@@ -291,6 +318,49 @@ impl ProfilesMsg {
             signer,
         }
     }
+
+    /// Returns the address that must sign the message, which is the one that
+    /// the chain checks when the message is dispatched.
+    pub fn signer(&self) -> &Addr {
+        match self {
+            ProfilesMsg::SaveProfile { creator, .. }
+            | ProfilesMsg::DeleteProfile { creator, .. } => creator,
+            ProfilesMsg::RequestDtagTransfer { sender, .. }
+            | ProfilesMsg::CancelDtagTransferRequest { sender, .. }
+            | ProfilesMsg::LinkApplication { sender, .. } => sender,
+            ProfilesMsg::AcceptDtagTransferRequest { receiver, .. }
+            | ProfilesMsg::RefuseDtagTransferRequest { receiver, .. } => receiver,
+            ProfilesMsg::LinkChainAccount { signer, .. }
+            | ProfilesMsg::SetDefaultExternalAddress { signer, .. }
+            | ProfilesMsg::UnlinkApplication { signer, .. } => signer,
+            ProfilesMsg::UnlinkChainAccount { owner, .. } => owner,
+        }
+    }
+
+    /// Returns the type url that identifies the message on chain.
+    pub fn type_url(&self) -> &'static str {
+        match self {
+            ProfilesMsg::SaveProfile { .. } => MSG_SAVE_PROFILE_TYPE_URI,
+            ProfilesMsg::DeleteProfile { .. } => MSG_DELETE_PROFILE_TYPE_URI,
+            ProfilesMsg::RequestDtagTransfer { .. } => MSG_REQUEST_DTAG_TRANSFER_TYPE_URI,
+            ProfilesMsg::AcceptDtagTransferRequest { .. } => {
+                MSG_ACCEPT_DTAG_TRANSFER_REQUEST_TYPE_URI
+            }
+            ProfilesMsg::RefuseDtagTransferRequest { .. } => {
+                MSG_REFUSE_DTAG_TRANSFER_REQUEST_TYPE_URI
+            }
+            ProfilesMsg::CancelDtagTransferRequest { .. } => {
+                MSG_CANCEL_DTAG_TRANSFER_REQUEST_TYPE_URI
+            }
+            ProfilesMsg::LinkChainAccount { .. } => MSG_LINK_CHAIN_ACCOUNT_TYPE_URI,
+            ProfilesMsg::UnlinkChainAccount { .. } => MSG_UNLINK_CHAIN_ACCOUNT_TYPE_URI,
+            ProfilesMsg::SetDefaultExternalAddress { .. } => {
+                MSG_SET_DEFAULT_EXTERNAL_ADDRESS_TYPE_URI
+            }
+            ProfilesMsg::LinkApplication { .. } => MSG_LINK_APPLICATION_TYPE_URI,
+            ProfilesMsg::UnlinkApplication { .. } => MSG_UNLINK_APPLICATION_TYPE_URI,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -513,4 +583,17 @@ mod tests {
         };
         assert_eq!(expected, msg)
     }
+
+    #[test]
+    fn test_signer() {
+        let msg = ProfilesMsg::accept_dtag_transfer_request(
+            "test",
+            Addr::unchecked("cosmos1qzskhrcjnkdz2ln4yeafzsdwht8ch08j4wed69"),
+            Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+        );
+        assert_eq!(
+            &Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+            msg.signer()
+        )
+    }
 }
@@ -0,0 +1,426 @@
+//! Contains the builders that can be used to create the [`ProfilesMsg`] variants
+//! having many fields.
+
+use crate::profiles::models_app_links::Data;
+use crate::profiles::models_chain_links::{Address, ChainConfig, Proof};
+use crate::profiles::msg::ProfilesMsg;
+use crate::types::Height;
+use cosmwasm_std::{Addr, Uint64};
+use thiserror::Error;
+
+pub(crate) const DO_NOT_MODIFY: &str = "[do-not-modify]";
+const IBC_PROFILES_PORT: &str = "ibc-profiles";
+
+/// Represents the errors that can occur while building a [`ProfilesMsg`].
+#[derive(Error, Debug, PartialEq)]
+pub enum ProfilesMsgBuilderError {
+    /// Error returned when the DTag is set to an empty value.
+    #[error("DTag cannot be empty")]
+    EmptyDtag,
+    /// Error returned when neither a timeout height nor a timeout timestamp have been set.
+    #[error("either timeout height or timeout timestamp must be set")]
+    MissingTimeout,
+    /// Error returned when the name of the chain to link is empty.
+    #[error("chain name cannot be empty")]
+    EmptyChainName,
+    /// Error returned when the external chain address to link has not been set.
+    #[error("chain address must be set")]
+    MissingChainAddress,
+    /// Error returned when the ownership proof of the external chain address has not been set.
+    #[error("chain address ownership proof must be set")]
+    MissingProof,
+}
+
+/// Builder to create a [`ProfilesMsg::SaveProfile`].
+/// Fields that are not set are left untouched on chain.
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::Addr;
+/// use desmos_bindings::profiles::msg_builders::SaveProfileBuilder;
+///
+/// let msg = SaveProfileBuilder::new(Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3"))
+///     .dtag("goldrake")
+///     .bio("This is Goldrake")
+///     .build()
+///     .unwrap();
+/// ```
+pub struct SaveProfileBuilder {
+    dtag: String,
+    nickname: String,
+    bio: String,
+    profile_picture: String,
+    cover_picture: String,
+    creator: Addr,
+}
+
+impl SaveProfileBuilder {
+    /// Creates a new builder that doesn't modify any profile field.
+    ///
+    /// * `creator` - Address of the profile creator.
+    pub fn new(creator: Addr) -> Self {
+        SaveProfileBuilder {
+            dtag: DO_NOT_MODIFY.to_string(),
+            nickname: DO_NOT_MODIFY.to_string(),
+            bio: DO_NOT_MODIFY.to_string(),
+            profile_picture: DO_NOT_MODIFY.to_string(),
+            cover_picture: DO_NOT_MODIFY.to_string(),
+            creator,
+        }
+    }
+
+    /// Sets the profile DTag, required when creating a new profile.
+    ///
+    /// * `dtag` - Unique profile tag.
+    pub fn dtag(mut self, dtag: &str) -> Self {
+        self.dtag = dtag.to_owned();
+        self
+    }
+
+    /// Sets the profile nickname.
+    ///
+    /// * `nickname` - Human readable name of the profile.
+    pub fn nickname(mut self, nickname: &str) -> Self {
+        self.nickname = nickname.to_owned();
+        self
+    }
+
+    /// Sets the profile biography.
+    ///
+    /// * `bio` - Biography of the profile.
+    pub fn bio(mut self, bio: &str) -> Self {
+        self.bio = bio.to_owned();
+        self
+    }
+
+    /// Sets the profile picture.
+    ///
+    /// * `profile_picture` - Uri of the profile picture.
+    pub fn profile_picture(mut self, profile_picture: &str) -> Self {
+        self.profile_picture = profile_picture.to_owned();
+        self
+    }
+
+    /// Sets the profile cover picture.
+    ///
+    /// * `cover_picture` - Uri of the cover picture.
+    pub fn cover_picture(mut self, cover_picture: &str) -> Self {
+        self.cover_picture = cover_picture.to_owned();
+        self
+    }
+
+    /// Builds the [`ProfilesMsg::SaveProfile`].
+    pub fn build(self) -> Result<ProfilesMsg, ProfilesMsgBuilderError> {
+        if self.dtag.trim().is_empty() {
+            return Err(ProfilesMsgBuilderError::EmptyDtag);
+        }
+
+        Ok(ProfilesMsg::save_profile(
+            &self.dtag,
+            self.creator,
+            &self.nickname,
+            &self.bio,
+            &self.profile_picture,
+            &self.cover_picture,
+        ))
+    }
+}
+
+/// Builder to create a [`ProfilesMsg::LinkChainAccount`].
+/// The address and the proof can be generated with the `ChainLinkProofBuilder`
+/// available with the `chain-link-proof` feature.
+pub struct LinkChainAccountBuilder {
+    chain_address: Option<Address>,
+    proof: Option<Proof>,
+    chain_name: String,
+    signer: Addr,
+}
+
+impl LinkChainAccountBuilder {
+    /// Creates a new builder without the external address and its ownership proof,
+    /// which must be set before building the message.
+    ///
+    /// * `chain_name` - Name of the external chain.
+    /// * `signer` - Desmos address of the user that will own the link.
+    pub fn new(chain_name: &str, signer: Addr) -> Self {
+        LinkChainAccountBuilder {
+            chain_address: None,
+            proof: None,
+            chain_name: chain_name.to_owned(),
+            signer,
+        }
+    }
+
+    /// Sets the external chain address to be linked.
+    ///
+    /// * `chain_address` - Data of the external chain address.
+    pub fn chain_address(mut self, chain_address: Address) -> Self {
+        self.chain_address = Some(chain_address);
+        self
+    }
+
+    /// Sets the ownership proof of the external chain address.
+    ///
+    /// * `proof` - Proof of the external chain address ownership.
+    pub fn proof(mut self, proof: Proof) -> Self {
+        self.proof = Some(proof);
+        self
+    }
+
+    /// Builds the [`ProfilesMsg::LinkChainAccount`].
+    pub fn build(self) -> Result<ProfilesMsg, ProfilesMsgBuilderError> {
+        if self.chain_name.trim().is_empty() {
+            return Err(ProfilesMsgBuilderError::EmptyChainName);
+        }
+        let chain_address = self
+            .chain_address
+            .ok_or(ProfilesMsgBuilderError::MissingChainAddress)?;
+        let proof = self.proof.ok_or(ProfilesMsgBuilderError::MissingProof)?;
+
+        Ok(ProfilesMsg::link_chain_account(
+            chain_address,
+            proof,
+            ChainConfig {
+                name: self.chain_name,
+            },
+            self.signer,
+        ))
+    }
+}
+
+/// Builder to create a [`ProfilesMsg::LinkApplication`].
+pub struct LinkApplicationBuilder {
+    sender: Addr,
+    link_data: Data,
+    call_data: String,
+    source_port: String,
+    source_channel: String,
+    timeout_height: Height,
+    timeout_timestamp: u64,
+}
+
+impl LinkApplicationBuilder {
+    /// Creates a new builder that sends the oracle request using the `ibc-profiles` port.
+    ///
+    /// * `sender` - Sender of the connection request.
+    /// * `application` - The application name (eg. Twitter, GitHub, etc).
+    /// * `username` - Username on the application.
+    /// * `call_data` - Hex encoded call data sent to the data source to verify the link.
+    /// * `source_channel` - The channel by which the packet will be sent.
+    pub fn new(
+        sender: Addr,
+        application: &str,
+        username: &str,
+        call_data: &str,
+        source_channel: &str,
+    ) -> Self {
+        LinkApplicationBuilder {
+            sender,
+            link_data: Data {
+                application: application.to_owned(),
+                username: username.to_owned(),
+            },
+            call_data: call_data.to_owned(),
+            source_port: IBC_PROFILES_PORT.to_string(),
+            source_channel: source_channel.to_owned(),
+            timeout_height: Height {
+                revision_number: Uint64::zero(),
+                revision_height: Uint64::zero(),
+            },
+            timeout_timestamp: 0,
+        }
+    }
+
+    /// Sets the port on which the packet will be sent.
+    ///
+    /// * `source_port` - The port on which the packet will be sent.
+    pub fn source_port(mut self, source_port: &str) -> Self {
+        self.source_port = source_port.to_owned();
+        self
+    }
+
+    /// Sets the block height after which the packet times out.
+    ///
+    /// * `revision_number` - The revision of the counterparty chain.
+    /// * `revision_height` - The height within the given revision.
+    pub fn timeout_height(mut self, revision_number: u64, revision_height: u64) -> Self {
+        self.timeout_height = Height {
+            revision_number: revision_number.into(),
+            revision_height: revision_height.into(),
+        };
+        self
+    }
+
+    /// Sets the timestamp after which the packet times out.
+    ///
+    /// * `timeout_timestamp` - Timeout timestamp in absolute nanoseconds since unix epoch.
+    pub fn timeout_timestamp(mut self, timeout_timestamp: u64) -> Self {
+        self.timeout_timestamp = timeout_timestamp;
+        self
+    }
+
+    /// Builds the [`ProfilesMsg::LinkApplication`].
+    pub fn build(self) -> Result<ProfilesMsg, ProfilesMsgBuilderError> {
+        if self.timeout_height.revision_height.is_zero() && self.timeout_timestamp == 0 {
+            return Err(ProfilesMsgBuilderError::MissingTimeout);
+        }
+
+        Ok(ProfilesMsg::link_application(
+            self.sender,
+            self.link_data,
+            self.call_data,
+            self.source_port,
+            self.source_channel,
+            self.timeout_height,
+            self.timeout_timestamp,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profiles::mocks::MockProfilesQueries;
+
+    const CREATOR: &str = "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc";
+
+    #[test]
+    fn test_save_profile_builder() {
+        let creator = Addr::unchecked(CREATOR);
+        let msg = SaveProfileBuilder::new(creator.clone())
+            .dtag("goldrake")
+            .nickname("Goldrake")
+            .bio("This is Goldrake")
+            .profile_picture("https://profilePic.jpg")
+            .cover_picture("https://coverPic.jpg")
+            .build()
+            .unwrap();
+        assert_eq!(
+            ProfilesMsg::save_profile(
+                "goldrake",
+                creator.clone(),
+                "Goldrake",
+                "This is Goldrake",
+                "https://profilePic.jpg",
+                "https://coverPic.jpg"
+            ),
+            msg
+        );
+
+        let msg = SaveProfileBuilder::new(creator.clone())
+            .bio("New bio")
+            .build()
+            .unwrap();
+        assert_eq!(
+            ProfilesMsg::save_profile(
+                DO_NOT_MODIFY,
+                creator.clone(),
+                DO_NOT_MODIFY,
+                "New bio",
+                DO_NOT_MODIFY,
+                DO_NOT_MODIFY
+            ),
+            msg
+        );
+
+        let result = SaveProfileBuilder::new(creator).dtag("").build();
+        assert_eq!(Err(ProfilesMsgBuilderError::EmptyDtag), result);
+    }
+
+    #[test]
+    fn test_link_chain_account_builder() {
+        let signer = Addr::unchecked(CREATOR);
+        let chain_link = MockProfilesQueries::get_mock_chain_link();
+
+        let msg = LinkChainAccountBuilder::new("cosmos", signer.clone())
+            .chain_address(chain_link.address.clone())
+            .proof(chain_link.proof.clone())
+            .build()
+            .unwrap();
+        assert_eq!(
+            ProfilesMsg::link_chain_account(
+                chain_link.address.clone(),
+                chain_link.proof.clone(),
+                ChainConfig {
+                    name: "cosmos".to_string()
+                },
+                signer.clone()
+            ),
+            msg
+        );
+
+        let result = LinkChainAccountBuilder::new("cosmos", signer.clone())
+            .proof(chain_link.proof.clone())
+            .build();
+        assert_eq!(Err(ProfilesMsgBuilderError::MissingChainAddress), result);
+
+        let result = LinkChainAccountBuilder::new("cosmos", signer.clone())
+            .chain_address(chain_link.address.clone())
+            .build();
+        assert_eq!(Err(ProfilesMsgBuilderError::MissingProof), result);
+
+        let result = LinkChainAccountBuilder::new(" ", signer)
+            .chain_address(chain_link.address)
+            .proof(chain_link.proof)
+            .build();
+        assert_eq!(Err(ProfilesMsgBuilderError::EmptyChainName), result);
+    }
+
+    #[test]
+    fn test_link_application_builder() {
+        let sender = Addr::unchecked(CREATOR);
+        let msg = LinkApplicationBuilder::new(
+            sender.clone(),
+            "twitter",
+            "goldrake",
+            "call_data",
+            "channel-0",
+        )
+        .timeout_height(1, 100)
+        .build()
+        .unwrap();
+        assert_eq!(
+            ProfilesMsg::link_application(
+                sender.clone(),
+                Data {
+                    application: "twitter".to_string(),
+                    username: "goldrake".to_string(),
+                },
+                "call_data".to_string(),
+                IBC_PROFILES_PORT.to_string(),
+                "channel-0".to_string(),
+                Height {
+                    revision_number: Uint64::new(1),
+                    revision_height: Uint64::new(100),
+                },
+                0
+            ),
+            msg
+        );
+
+        let msg = LinkApplicationBuilder::new(
+            sender.clone(),
+            "twitter",
+            "goldrake",
+            "call_data",
+            "channel-0",
+        )
+        .source_port("port")
+        .timeout_timestamp(10)
+        .build()
+        .unwrap();
+        assert!(matches!(
+            msg,
+            ProfilesMsg::LinkApplication {
+                timeout_timestamp,
+                ref source_port,
+                ..
+            } if timeout_timestamp == Uint64::new(10) && source_port == "port"
+        ));
+
+        let result =
+            LinkApplicationBuilder::new(sender, "twitter", "goldrake", "call_data", "channel-0")
+                .build();
+        assert_eq!(Err(ProfilesMsgBuilderError::MissingTimeout), result);
+    }
+}
@@ -0,0 +1,252 @@
+//! Contains the protobuf encoding of the [`ProfilesMsg`] variants, used to send them
+//! as `Stargate` messages (eg. inside an authz `MsgExec`).
+
+use crate::profiles::models_chain_links::{Address, Proof, Signature, SignatureValueType};
+use crate::profiles::msg::ProfilesMsg;
+use crate::proto::{any, ProtoWriter};
+use crate::types::PubKey;
+use cosmwasm_std::{Binary, StdResult};
+
+fn address(address: &Address) -> Vec<u8> {
+    any(
+        &address.proto_type,
+        &ProtoWriter::new()
+            .string(1, &address.value)
+            .string(2, address.prefix.as_deref().unwrap_or_default())
+            .finish(),
+    )
+}
+
+fn pub_key(pub_key: &PubKey) -> Vec<u8> {
+    any(
+        &pub_key.proto_type,
+        &ProtoWriter::new().bytes(1, &pub_key.key).finish(),
+    )
+}
+
+fn signature(signature: &Signature) -> Vec<u8> {
+    let value_type = match signature.value_type {
+        SignatureValueType::Unspecified => 0,
+        SignatureValueType::Raw => 1,
+        SignatureValueType::CosmosDirect => 2,
+        SignatureValueType::CosmosAnimo => 3,
+        SignatureValueType::EVMPersonalSign => 4,
+    };
+    any(
+        &signature.proto_type,
+        &ProtoWriter::new()
+            .enumeration(1, value_type)
+            .bytes(2, &signature.signature)
+            .finish(),
+    )
+}
+
+fn proof(proof: &Proof) -> Vec<u8> {
+    ProtoWriter::new()
+        .message(1, &pub_key(&proof.pub_key))
+        .message(2, &signature(&proof.signature))
+        .string(3, &proof.plain_text)
+        .finish()
+}
+
+impl ProfilesMsg {
+    /// Encodes the message using its protobuf representation.
+    pub fn to_proto_bytes(&self) -> StdResult<Binary> {
+        let encoded = match self {
+            ProfilesMsg::SaveProfile {
+                dtag,
+                nickname,
+                bio,
+                profile_picture,
+                cover_picture,
+                creator,
+            } => ProtoWriter::new()
+                .string(1, dtag)
+                .string(2, nickname)
+                .string(3, bio)
+                .string(4, profile_picture)
+                .string(5, cover_picture)
+                .string(6, creator.as_str()),
+            ProfilesMsg::DeleteProfile { creator } => {
+                ProtoWriter::new().string(1, creator.as_str())
+            }
+            ProfilesMsg::RequestDtagTransfer { receiver, sender }
+            | ProfilesMsg::CancelDtagTransferRequest { receiver, sender } => ProtoWriter::new()
+                .string(1, receiver.as_str())
+                .string(2, sender.as_str()),
+            ProfilesMsg::AcceptDtagTransferRequest {
+                new_dtag,
+                sender,
+                receiver,
+            } => ProtoWriter::new()
+                .string(1, new_dtag)
+                .string(2, sender.as_str())
+                .string(3, receiver.as_str()),
+            ProfilesMsg::RefuseDtagTransferRequest { sender, receiver } => ProtoWriter::new()
+                .string(1, sender.as_str())
+                .string(2, receiver.as_str()),
+            ProfilesMsg::LinkChainAccount {
+                chain_address,
+                proof: chain_proof,
+                chain_config,
+                signer,
+            } => ProtoWriter::new()
+                .message(1, &address(chain_address))
+                .message(2, &proof(chain_proof))
+                .message(
+                    3,
+                    &ProtoWriter::new().string(1, &chain_config.name).finish(),
+                )
+                .string(4, signer.as_str()),
+            ProfilesMsg::UnlinkChainAccount {
+                owner,
+                chain_name,
+                target,
+            } => ProtoWriter::new()
+                .string(1, owner.as_str())
+                .string(2, chain_name)
+                .string(3, target),
+            ProfilesMsg::SetDefaultExternalAddress {
+                chain_name,
+                target,
+                signer,
+            } => ProtoWriter::new()
+                .string(1, chain_name)
+                .string(2, target)
+                .string(3, signer.as_str()),
+            ProfilesMsg::LinkApplication {
+                sender,
+                link_data,
+                call_data,
+                source_port,
+                source_channel,
+                timeout_height,
+                timeout_timestamp,
+            } => ProtoWriter::new()
+                .string(1, sender.as_str())
+                .message(
+                    2,
+                    &ProtoWriter::new()
+                        .string(1, &link_data.application)
+                        .string(2, &link_data.username)
+                        .finish(),
+                )
+                .string(3, call_data)
+                .string(4, source_port)
+                .string(5, source_channel)
+                .message(
+                    6,
+                    &ProtoWriter::new()
+                        .uint64(1, timeout_height.revision_number.u64())
+                        .uint64(2, timeout_height.revision_height.u64())
+                        .finish(),
+                )
+                .uint64(7, timeout_timestamp.u64()),
+            ProfilesMsg::UnlinkApplication {
+                application,
+                username,
+                signer,
+            } => ProtoWriter::new()
+                .string(1, application)
+                .string(2, username)
+                .string(3, signer.as_str()),
+        };
+        Ok(encoded.finish().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profiles::models_chain_links::ChainConfig;
+    use crate::profiles::msg::{MSG_LINK_CHAIN_ACCOUNT_TYPE_URI, MSG_SAVE_PROFILE_TYPE_URI};
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn test_save_profile_encoding() {
+        let msg = ProfilesMsg::save_profile("a", Addr::unchecked("b"), "", "", "", "");
+        assert_eq!(MSG_SAVE_PROFILE_TYPE_URI, msg.type_url());
+        assert_eq!(
+            Binary::from(vec![0x0a, 0x01, b'a', 0x32, 0x01, b'b']),
+            msg.to_proto_bytes().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_link_chain_account_encoding() {
+        let msg = ProfilesMsg::LinkChainAccount {
+            chain_address: Address {
+                proto_type: "/a".into(),
+                value: "v".into(),
+                prefix: None,
+            },
+            proof: Proof {
+                pub_key: PubKey {
+                    proto_type: "/k".into(),
+                    key: Binary::from(vec![0x01]),
+                },
+                signature: Signature {
+                    proto_type: "/s".into(),
+                    value_type: SignatureValueType::Raw,
+                    signature: Binary::from(vec![0x02]),
+                },
+                plain_text: "t".into(),
+            },
+            chain_config: ChainConfig { name: "c".into() },
+            signer: Addr::unchecked("d"),
+        };
+        let expected = ProtoWriter::new()
+            .message(1, &any("/a", &[0x0a, 0x01, b'v']))
+            .message(
+                2,
+                &ProtoWriter::new()
+                    .message(1, &any("/k", &[0x0a, 0x01, 0x01]))
+                    .message(2, &any("/s", &[0x08, 0x01, 0x12, 0x01, 0x02]))
+                    .string(3, "t")
+                    .finish(),
+            )
+            .message(3, &[0x0a, 0x01, b'c'])
+            .string(4, "d")
+            .finish();
+        assert_eq!(MSG_LINK_CHAIN_ACCOUNT_TYPE_URI, msg.type_url());
+        assert_eq!(Binary::from(expected), msg.to_proto_bytes().unwrap());
+    }
+
+    /// Mirror of the `desmos.profiles.v3.MsgSaveProfile` protobuf message.
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct ProstMsgSaveProfile {
+        #[prost(string, tag = "1")]
+        dtag: String,
+        #[prost(string, tag = "2")]
+        nickname: String,
+        #[prost(string, tag = "3")]
+        bio: String,
+        #[prost(string, tag = "4")]
+        profile_picture: String,
+        #[prost(string, tag = "5")]
+        cover_picture: String,
+        #[prost(string, tag = "6")]
+        creator: String,
+    }
+
+    #[test]
+    fn test_save_profile_prost_encoding() {
+        let msg = ProfilesMsg::save_profile(
+            "dtag",
+            Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3"),
+            "nickname",
+            "",
+            "https://example.com/profile.png",
+            "https://example.com/cover.png",
+        );
+        let expected = prost::Message::encode_to_vec(&ProstMsgSaveProfile {
+            dtag: "dtag".into(),
+            nickname: "nickname".into(),
+            bio: "".into(),
+            profile_picture: "https://example.com/profile.png".into(),
+            cover_picture: "https://example.com/cover.png".into(),
+            creator: "desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3".into(),
+        });
+        assert_eq!(Binary::from(expected), msg.to_proto_bytes().unwrap());
+    }
+}
@@ -16,7 +16,7 @@ use crate::{
             QueryApplicationLinkByClientIDResponse, QueryApplicationLinkOwnersResponse,
             QueryApplicationLinksResponse, QueryChainLinkOwnersResponse, QueryChainLinksResponse,
             QueryDefaultExternalAddressesResponse, QueryIncomingDtagTransferRequestsResponse,
-            QueryProfileResponse,
+            QueryParamsResponse, QueryProfileResponse,
         },
         query::ProfilesQuery,
     },
@@ -429,6 +429,13 @@ impl<'a> ProfilesQuerier<'a> {
             page_size,
         )
     }
+
+    /// Queries the parameters of the x/profiles module.
+    pub fn query_params(&self) -> StdResult<QueryParamsResponse> {
+        let request = DesmosQuery::Profiles(ProfilesQuery::Params {});
+        let res: QueryParamsResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
 }
 
 #[cfg(test)]
@@ -701,4 +708,18 @@ mod tests {
         );
         assert!(it.next().is_none());
     }
+
+    #[test]
+    fn test_query_params() {
+        let owned_deps = mock_desmos_dependencies();
+        let deps = owned_deps.as_ref();
+        let profiles_querier = ProfilesQuerier::new(deps.querier.deref());
+
+        let response = profiles_querier.query_params().unwrap();
+        let expected = QueryParamsResponse {
+            params: MockProfilesQueries::get_mock_params(),
+        };
+
+        assert_eq!(response, expected)
+    }
 }
@@ -91,4 +91,7 @@ pub enum ProfilesQuery {
         /// Optional pagination configs.
         pagination: Option<PageRequest>,
     },
+    /// Message to query the parameters of the x/profiles module.
+    #[returns(QueryParamsResponse)]
+    Params {},
 }
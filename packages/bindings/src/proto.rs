@@ -0,0 +1,401 @@
+//! Contains a minimal protobuf encoder used to serialize the messages that
+//! must be sent to the chain as `Stargate` messages.
+//! It is compiled only along with the `authz` feature, and each module enabled with it
+//! uses a different subset of the encoders.
+#![allow(dead_code)]
+
+use crate::types::{
+    parse_rfc3339_time, Allowance, Authorization, AuthzGrant, RawAllowance, RawAuthorization,
+    BASIC_ALLOWANCE_TYPE_URI, GENERIC_AUTHORIZATION_TYPE_URI, PERIODIC_ALLOWANCE_TYPE_URI,
+    SEND_AUTHORIZATION_TYPE_URI,
+};
+use cosmwasm_std::{Coin, StdError, StdResult, Timestamp};
+use std::convert::TryFrom;
+
+const WIRE_TYPE_VARINT: u64 = 0;
+const WIRE_TYPE_LENGTH_DELIMITED: u64 = 2;
+
+/// Builder that writes the fields of a protobuf message.
+/// Scalar fields having their default value are skipped as the protobuf
+/// encoding requires, while embedded messages are always written.
+#[derive(Default)]
+pub(crate) struct ProtoWriter {
+    buf: Vec<u8>,
+}
+
+impl ProtoWriter {
+    /// Creates a new empty message.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buf.push((value as u8 & 0x7f) | 0x80);
+            value >>= 7;
+        }
+        self.buf.push(value as u8);
+    }
+
+    fn key(&mut self, field: u32, wire_type: u64) {
+        self.varint((u64::from(field) << 3) | wire_type);
+    }
+
+    fn length_delimited(&mut self, field: u32, value: &[u8]) {
+        self.key(field, WIRE_TYPE_LENGTH_DELIMITED);
+        self.varint(value.len() as u64);
+        self.buf.extend_from_slice(value);
+    }
+
+    /// Writes an `uint64` field.
+    pub fn uint64(mut self, field: u32, value: u64) -> Self {
+        if value != 0 {
+            self.key(field, WIRE_TYPE_VARINT);
+            self.varint(value);
+        }
+        self
+    }
+
+    /// Writes an `uint32` field.
+    pub fn uint32(self, field: u32, value: u32) -> Self {
+        self.uint64(field, u64::from(value))
+    }
+
+    /// Writes an enum field given the number of its variant.
+    /// Negative values are sign extended to 64 bits as for `int32` fields.
+    pub fn enumeration(self, field: u32, value: i32) -> Self {
+        self.uint64(field, i64::from(value) as u64)
+    }
+
+    /// Writes a `bool` field.
+    pub fn bool(self, field: u32, value: bool) -> Self {
+        self.uint64(field, u64::from(value))
+    }
+
+    /// Writes a `string` field.
+    pub fn string(mut self, field: u32, value: &str) -> Self {
+        if !value.is_empty() {
+            self.length_delimited(field, value.as_bytes());
+        }
+        self
+    }
+
+    /// Writes a `bytes` field.
+    pub fn bytes(mut self, field: u32, value: &[u8]) -> Self {
+        if !value.is_empty() {
+            self.length_delimited(field, value);
+        }
+        self
+    }
+
+    /// Writes an embedded message field.
+    pub fn message(mut self, field: u32, value: &[u8]) -> Self {
+        self.length_delimited(field, value);
+        self
+    }
+
+    /// Writes an embedded message field only if its value is present.
+    pub fn optional_message(self, field: u32, value: Option<&[u8]>) -> Self {
+        match value {
+            Some(value) => self.message(field, value),
+            None => self,
+        }
+    }
+
+    /// Writes a `repeated string` field.
+    pub fn repeated_string<T: AsRef<str>>(mut self, field: u32, values: &[T]) -> Self {
+        for value in values {
+            self.length_delimited(field, value.as_ref().as_bytes());
+        }
+        self
+    }
+
+    /// Writes a repeated embedded message field.
+    pub fn repeated_message<T: AsRef<[u8]>>(mut self, field: u32, values: &[T]) -> Self {
+        for value in values {
+            self.length_delimited(field, value.as_ref());
+        }
+        self
+    }
+
+    /// Writes a packed `repeated uint32` field.
+    pub fn packed_uint32(mut self, field: u32, values: &[u32]) -> Self {
+        if !values.is_empty() {
+            let mut packed = ProtoWriter::new();
+            for value in values {
+                packed.varint(u64::from(*value));
+            }
+            self.length_delimited(field, &packed.buf);
+        }
+        self
+    }
+
+    /// Returns the encoded message.
+    pub fn finish(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+/// Encodes a `google.protobuf.Any` wrapping the given encoded message.
+pub(crate) fn any(type_url: &str, value: &[u8]) -> Vec<u8> {
+    ProtoWriter::new()
+        .string(1, type_url)
+        .bytes(2, value)
+        .finish()
+}
+
+/// Encodes a `cosmos.base.v1beta1.Coin`.
+pub(crate) fn coin(coin: &Coin) -> Vec<u8> {
+    ProtoWriter::new()
+        .string(1, &coin.denom)
+        .string(2, &coin.amount.to_string())
+        .finish()
+}
+
+/// Encodes a list of `cosmos.base.v1beta1.Coin`.
+pub(crate) fn coins(coins: &[Coin]) -> Vec<Vec<u8>> {
+    coins.iter().map(coin).collect()
+}
+
+/// Encodes a `google.protobuf.Timestamp`.
+pub(crate) fn timestamp(time: Timestamp) -> Vec<u8> {
+    ProtoWriter::new()
+        .uint64(1, time.seconds())
+        .uint64(2, time.subsec_nanos())
+        .finish()
+}
+
+/// Encodes a RFC 3339 formatted time as a `google.protobuf.Timestamp`.
+pub(crate) fn rfc3339_timestamp(time: &str) -> StdResult<Vec<u8>> {
+    parse_rfc3339_time(time).map(timestamp)
+}
+
+/// Encodes a duration formatted as the seconds followed by `s` (eg. `86400s` or `1.5s`)
+/// as a `google.protobuf.Duration`.
+pub(crate) fn duration(duration: &str) -> StdResult<Vec<u8>> {
+    let invalid = || StdError::parse_err("Duration", format!("invalid duration: {}", duration));
+    let value = duration.strip_suffix('s').ok_or_else(invalid)?;
+    let (seconds, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if seconds.is_empty()
+        || fraction.len() > 9
+        || !seconds
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    let seconds = seconds.parse::<u64>().map_err(|_| invalid())?;
+    let nanos = format!("{:0<9}", fraction)
+        .parse::<u64>()
+        .map_err(|_| invalid())?;
+    Ok(ProtoWriter::new()
+        .uint64(1, seconds)
+        .uint64(2, nanos)
+        .finish())
+}
+
+/// Encodes a [`RawAuthorization`] as a `google.protobuf.Any`.
+pub(crate) fn authorization(authorization: &RawAuthorization) -> StdResult<Vec<u8>> {
+    let authorization = Authorization::try_from(authorization.clone())
+        .map_err(|error| StdError::parse_err("RawAuthorization", error))?;
+    Ok(match authorization {
+        Authorization::Generic { msg } => any(
+            GENERIC_AUTHORIZATION_TYPE_URI,
+            &ProtoWriter::new().string(1, &msg).finish(),
+        ),
+        Authorization::Send { spend_limit } => any(
+            SEND_AUTHORIZATION_TYPE_URI,
+            &ProtoWriter::new()
+                .repeated_message(1, &coins(&spend_limit))
+                .finish(),
+        ),
+    })
+}
+
+/// Encodes an [`AuthzGrant`] as a `cosmos.authz.v1beta1.Grant`.
+pub(crate) fn authz_grant(grant: &AuthzGrant) -> StdResult<Vec<u8>> {
+    let expiration = grant
+        .expiration
+        .as_deref()
+        .map(rfc3339_timestamp)
+        .transpose()?;
+    Ok(ProtoWriter::new()
+        .message(1, &authorization(&grant.authorization)?)
+        .optional_message(2, expiration.as_deref())
+        .finish())
+}
+
+/// Encodes a `cosmos.feegrant.v1beta1.BasicAllowance`.
+fn basic_allowance(spend_limit: &[Coin], expiration: Option<&str>) -> StdResult<Vec<u8>> {
+    let expiration = expiration.map(rfc3339_timestamp).transpose()?;
+    Ok(ProtoWriter::new()
+        .repeated_message(1, &coins(spend_limit))
+        .optional_message(2, expiration.as_deref())
+        .finish())
+}
+
+/// Encodes a [`RawAllowance`] as a `google.protobuf.Any`.
+pub(crate) fn allowance(allowance: &RawAllowance) -> StdResult<Vec<u8>> {
+    let allowance = Allowance::try_from(allowance.clone())
+        .map_err(|error| StdError::parse_err("RawAllowance", error))?;
+    Ok(match allowance {
+        Allowance::Basic {
+            spend_limit,
+            expiration,
+        } => any(
+            BASIC_ALLOWANCE_TYPE_URI,
+            &basic_allowance(&spend_limit, expiration.as_deref())?,
+        ),
+        Allowance::Periodic {
+            basic,
+            period,
+            period_spend_limit,
+            period_can_spend,
+            period_reset,
+        } => {
+            let period_reset = period_reset.as_deref().map(rfc3339_timestamp).transpose()?;
+            any(
+                PERIODIC_ALLOWANCE_TYPE_URI,
+                &ProtoWriter::new()
+                    .message(
+                        1,
+                        &basic_allowance(&basic.spend_limit, basic.expiration.as_deref())?,
+                    )
+                    .message(2, &duration(&period)?)
+                    .repeated_message(3, &coins(&period_spend_limit))
+                    .repeated_message(4, &coins(&period_can_spend))
+                    .optional_message(5, period_reset.as_deref())
+                    .finish(),
+            )
+        }
+    })
+}
+
+// The posts and subspaces modules use all the encoders.
+#[cfg(all(test, feature = "posts", feature = "subspaces"))]
+mod tests {
+    use super::*;
+    use crate::types::BasicAllowance;
+    use cosmwasm_std::coin;
+
+    #[test]
+    fn test_write_scalars() {
+        let encoded = ProtoWriter::new()
+            .uint64(1, 300)
+            .uint32(2, 0)
+            .bool(3, true)
+            .string(4, "ab")
+            .string(5, "")
+            .bytes(6, &[0xff])
+            .finish();
+        assert_eq!(
+            vec![0x08, 0xac, 0x02, 0x18, 0x01, 0x22, 0x02, b'a', b'b', 0x32, 0x01, 0xff],
+            encoded
+        );
+    }
+
+    #[test]
+    fn test_write_messages() {
+        let encoded = ProtoWriter::new()
+            .message(1, &[])
+            .optional_message(2, None)
+            .repeated_string(3, &["a", ""])
+            .repeated_message(4, &[vec![0x08, 0x01]])
+            .finish();
+        assert_eq!(
+            vec![0x0a, 0x00, 0x1a, 0x01, b'a', 0x1a, 0x00, 0x22, 0x02, 0x08, 0x01],
+            encoded
+        );
+    }
+
+    #[test]
+    fn test_write_packed_uint32() {
+        let encoded = ProtoWriter::new()
+            .packed_uint32(1, &[1, 150])
+            .packed_uint32(2, &[])
+            .finish();
+        assert_eq!(vec![0x0a, 0x03, 0x01, 0x96, 0x01], encoded);
+    }
+
+    #[test]
+    fn test_encode_any() {
+        assert_eq!(
+            vec![0x0a, 0x02, b'/', b'a', 0x12, 0x02, 0x08, 0x01],
+            any("/a", &[0x08, 0x01])
+        );
+    }
+
+    #[test]
+    fn test_encode_coin() {
+        assert_eq!(
+            vec![0x0a, 0x01, b'a', 0x12, 0x02, b'1', b'0'],
+            super::coin(&coin(10, "a"))
+        );
+    }
+
+    #[test]
+    fn test_encode_rfc3339_timestamp() {
+        assert_eq!(
+            vec![0x08, 0x01, 0x10, 0x0a],
+            rfc3339_timestamp("1970-01-01T00:00:01.00000001Z").unwrap()
+        );
+        rfc3339_timestamp("invalid").unwrap_err();
+    }
+
+    #[test]
+    fn test_encode_duration() {
+        assert_eq!(vec![0x08, 0x3c], duration("60s").unwrap());
+        assert_eq!(
+            vec![0x08, 0x01, 0x10, 0x80, 0xca, 0xb5, 0xee, 0x01],
+            duration("1.5s").unwrap()
+        );
+        duration("60").unwrap_err();
+        duration("1.s5").unwrap_err();
+    }
+
+    #[test]
+    fn test_encode_authz_grant() {
+        let grant = AuthzGrant {
+            authorization: Authorization::Generic {
+                msg: "/a".to_string(),
+            }
+            .into(),
+            expiration: Some("1970-01-01T00:00:01Z".to_string()),
+        };
+        let authorization = any(
+            GENERIC_AUTHORIZATION_TYPE_URI,
+            &ProtoWriter::new().string(1, "/a").finish(),
+        );
+        assert_eq!(
+            ProtoWriter::new()
+                .message(1, &authorization)
+                .message(2, &[0x08, 0x01])
+                .finish(),
+            authz_grant(&grant).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_encode_periodic_allowance() {
+        let raw: RawAllowance = Allowance::periodic(
+            BasicAllowance {
+                spend_limit: vec![],
+                expiration: None,
+            },
+            60,
+            vec![coin(10, "a")],
+        )
+        .into();
+        let expected = any(
+            PERIODIC_ALLOWANCE_TYPE_URI,
+            &ProtoWriter::new()
+                .message(1, &[])
+                .message(2, &[0x08, 0x3c])
+                .repeated_message(3, &[super::coin(&coin(10, "a"))])
+                .repeated_message(4, &[super::coin(&coin(10, "a"))])
+                .finish(),
+        );
+        assert_eq!(expected, allowance(&raw).unwrap());
+    }
+}
@@ -12,6 +12,10 @@ use crate::relationships::query::RelationshipsQuery;
 use crate::reports::query::ReportsQuery;
 #[cfg(feature = "subspaces")]
 use crate::subspaces::query::SubspacesQuery;
+#[cfg(feature = "supply")]
+use crate::supply::query::SupplyQuery;
+#[cfg(feature = "tokenfactory")]
+use crate::tokenfactory::query::TokenFactoryQuery;
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{CustomQuery, QueryRequest};
 
@@ -51,6 +55,14 @@ pub enum DesmosQuery {
     /// Queries relative to the x/reports module.
     #[cfg(feature = "reports")]
     Reports(ReportsQuery),
+
+    /// Queries relative to the x/supply module.
+    #[cfg(feature = "supply")]
+    Supply(SupplyQuery),
+
+    /// Queries relative to the x/tokenfactory module.
+    #[cfg(feature = "tokenfactory")]
+    TokenFactory(TokenFactoryQuery),
 }
 
 impl CustomQuery for DesmosQuery {}
@@ -139,6 +151,34 @@ impl Into<QueryRequest<DesmosQuery>> for ReportsQuery {
     }
 }
 
+#[cfg(feature = "supply")]
+impl From<SupplyQuery> for DesmosQuery {
+    fn from(query: SupplyQuery) -> Self {
+        Self::Supply(query)
+    }
+}
+
+#[cfg(feature = "supply")]
+impl Into<QueryRequest<DesmosQuery>> for SupplyQuery {
+    fn into(self) -> QueryRequest<DesmosQuery> {
+        QueryRequest::Custom(self.into())
+    }
+}
+
+#[cfg(feature = "tokenfactory")]
+impl From<TokenFactoryQuery> for DesmosQuery {
+    fn from(query: TokenFactoryQuery) -> Self {
+        Self::TokenFactory(query)
+    }
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Into<QueryRequest<DesmosQuery>> for TokenFactoryQuery {
+    fn into(self) -> QueryRequest<DesmosQuery> {
+        QueryRequest::Custom(self.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -275,4 +315,44 @@ mod tests {
             query.into()
         )
     }
+    #[test]
+    fn test_from_supply_query() {
+        let query = SupplyQuery::Total {
+            denom: "udsm".to_string(),
+            divider_exponent: Uint64::new(6),
+        };
+        let expected = DesmosQuery::Supply(query.clone());
+        assert_eq!(expected, DesmosQuery::from(query))
+    }
+    #[test]
+    fn test_supply_query_into_query_request() {
+        let query = SupplyQuery::Total {
+            denom: "udsm".to_string(),
+            divider_exponent: Uint64::new(6),
+        };
+        assert_eq!(
+            QueryRequest::<DesmosQuery>::Custom(DesmosQuery::from(query.clone())),
+            query.into()
+        )
+    }
+    #[test]
+    #[cfg(feature = "tokenfactory")]
+    fn test_from_tokenfactory_query() {
+        let query = TokenFactoryQuery::SubspaceDenoms {
+            subspace_id: Uint64::new(1),
+        };
+        let expected = DesmosQuery::TokenFactory(query.clone());
+        assert_eq!(expected, DesmosQuery::from(query))
+    }
+    #[test]
+    #[cfg(feature = "tokenfactory")]
+    fn test_tokenfactory_query_into_query_request() {
+        let query = TokenFactoryQuery::SubspaceDenoms {
+            subspace_id: Uint64::new(1),
+        };
+        assert_eq!(
+            QueryRequest::<DesmosQuery>::Custom(DesmosQuery::from(query.clone())),
+            query.into()
+        )
+    }
 }
@@ -4,7 +4,7 @@ use crate::reactions::models::UnwrapReactionValueError::{
     InvalidFreeTextValue, InvalidRegisteredReactionValue,
 };
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint64};
+use cosmwasm_std::{Addr, StdError, Uint64};
 use std::convert::TryFrom;
 use thiserror::Error;
 
@@ -162,9 +162,177 @@ pub struct RegisteredReactionValueParams {
     pub enabled: bool,
 }
 
+/// Represents the errors that can occur when validating a [`ReactionValue`]
+/// against the [`SubspaceReactionsParams`].
+#[derive(Error, Debug, PartialEq)]
+pub enum InvalidReactionValueError {
+    /// Error that occur if [`ReactionValue::Registered`] reactions are disabled.
+    #[error("registered reaction values are not enabled")]
+    RegisteredReactionDisabled,
+    /// Error that occur if the registered reaction doesn't exist inside the subspace.
+    #[error("registered reaction with id {0} not found")]
+    RegisteredReactionNotFound(u32),
+    /// Error that occur if [`ReactionValue::FreeText`] reactions are disabled.
+    #[error("free text values are not enabled")]
+    FreeTextDisabled,
+    /// Error that occur if the free text is longer than [`FreeTextValueParams::max_length`].
+    #[error("free text value exceeds max length of {0}")]
+    FreeTextTooLong(u32),
+    /// Error that occur if the free text doesn't match [`FreeTextValueParams::reg_ex`].
+    #[error("free text value does not match the regex {0}")]
+    FreeTextNotMatching(String),
+    /// Error that occur if [`FreeTextValueParams::reg_ex`] is not a valid regex.
+    #[error("invalid free text regex {0}: {1}")]
+    InvalidRegEx(String, String),
+    /// Error that occur if the data required to validate the value can't be fetched.
+    #[error("{0}")]
+    Std(#[from] StdError),
+}
+
+impl SubspaceReactionsParams {
+    /// Checks that the given reaction value can be added inside the subspace
+    /// having these params.
+    /// The free text values are checked against the params regex only when the `reactions-regex`
+    /// feature is enabled, otherwise the regex is left to the chain.
+    /// Note that the existence of the [`ReactionValue::Registered`] reaction is not checked,
+    /// use [`ReactionsQuerier::validate_reaction_value`](crate::reactions::querier::ReactionsQuerier::validate_reaction_value)
+    /// to also perform that check.
+    ///
+    /// * `value` - The reaction value to validate.
+    pub fn validate_reaction_value(
+        &self,
+        value: &ReactionValue,
+    ) -> Result<(), InvalidReactionValueError> {
+        match value {
+            ReactionValue::Registered { .. } => {
+                if !self.registered_reaction.enabled {
+                    return Err(InvalidReactionValueError::RegisteredReactionDisabled);
+                }
+            }
+            ReactionValue::FreeText { text } => {
+                let params = &self.free_text;
+                if !params.enabled {
+                    return Err(InvalidReactionValueError::FreeTextDisabled);
+                }
+                // The max length is checked in bytes as done on chain
+                if text.len() > params.max_length as usize {
+                    return Err(InvalidReactionValueError::FreeTextTooLong(
+                        params.max_length,
+                    ));
+                }
+                #[cfg(feature = "reactions-regex")]
+                if !params.reg_ex.is_empty() {
+                    let reg_ex = regex_lite::Regex::new(&params.reg_ex).map_err(|error| {
+                        InvalidReactionValueError::InvalidRegEx(
+                            params.reg_ex.clone(),
+                            error.to_string(),
+                        )
+                    })?;
+                    if !reg_ex.is_match(text) {
+                        return Err(InvalidReactionValueError::FreeTextNotMatching(
+                            params.reg_ex.clone(),
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    fn reactions_params(
+        registered_enabled: bool,
+        free_text_enabled: bool,
+        reg_ex: &str,
+    ) -> SubspaceReactionsParams {
+        SubspaceReactionsParams {
+            subspace_id: Uint64::new(1),
+            registered_reaction: RegisteredReactionValueParams {
+                enabled: registered_enabled,
+            },
+            free_text: FreeTextValueParams {
+                enabled: free_text_enabled,
+                max_length: 5,
+                reg_ex: reg_ex.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn validate_registered_reaction_value() {
+        let value = ReactionValue::Registered {
+            registered_reaction_id: 1,
+        };
+        assert_eq!(
+            Ok(()),
+            reactions_params(true, false, "").validate_reaction_value(&value)
+        );
+        assert_eq!(
+            Err(InvalidReactionValueError::RegisteredReactionDisabled),
+            reactions_params(false, true, "").validate_reaction_value(&value)
+        );
+    }
+
+    #[test]
+    fn validate_free_text_value() {
+        let value = ReactionValue::FreeText {
+            text: "abc".to_string(),
+        };
+        assert_eq!(
+            Ok(()),
+            reactions_params(false, true, "").validate_reaction_value(&value)
+        );
+        assert_eq!(
+            Ok(()),
+            reactions_params(false, true, "^[a-z]+$").validate_reaction_value(&value)
+        );
+        assert_eq!(
+            Err(InvalidReactionValueError::FreeTextDisabled),
+            reactions_params(true, false, "").validate_reaction_value(&value)
+        );
+
+        let value = ReactionValue::FreeText {
+            text: "abcdef".to_string(),
+        };
+        assert_eq!(
+            Err(InvalidReactionValueError::FreeTextTooLong(5)),
+            reactions_params(true, true, "").validate_reaction_value(&value)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "reactions-regex")]
+    fn validate_free_text_value_regex() {
+        let value = ReactionValue::FreeText {
+            text: "abc".to_string(),
+        };
+        assert_eq!(
+            Err(InvalidReactionValueError::FreeTextNotMatching(
+                "^[0-9]+$".to_string()
+            )),
+            reactions_params(true, true, "^[0-9]+$").validate_reaction_value(&value)
+        );
+        assert!(matches!(
+            reactions_params(true, true, "[a-z").validate_reaction_value(&value),
+            Err(InvalidReactionValueError::InvalidRegEx(..))
+        ));
+    }
+
+    #[test]
+    #[cfg(not(feature = "reactions-regex"))]
+    fn validate_free_text_value_ignores_regex() {
+        let value = ReactionValue::FreeText {
+            text: "abc".to_string(),
+        };
+        assert_eq!(
+            Ok(()),
+            reactions_params(true, true, "^[0-9]+$").validate_reaction_value(&value)
+        );
+    }
     #[test]
     fn reaction_value_try_from_raw_with_invalid_type_uri_error() {
         let raw = RawReactionValue {
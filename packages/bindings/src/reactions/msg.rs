@@ -7,6 +7,22 @@ use crate::reactions::models::{
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint64};
 
+/// Type url of [`ReactionsMsg::AddReaction`].
+pub const MSG_ADD_REACTION_TYPE_URI: &str = "/desmos.reactions.v1.MsgAddReaction";
+/// Type url of [`ReactionsMsg::RemoveReaction`].
+pub const MSG_REMOVE_REACTION_TYPE_URI: &str = "/desmos.reactions.v1.MsgRemoveReaction";
+/// Type url of [`ReactionsMsg::AddRegisteredReaction`].
+pub const MSG_ADD_REGISTERED_REACTION_TYPE_URI: &str =
+    "/desmos.reactions.v1.MsgAddRegisteredReaction";
+/// Type url of [`ReactionsMsg::EditRegisteredReaction`].
+pub const MSG_EDIT_REGISTERED_REACTION_TYPE_URI: &str =
+    "/desmos.reactions.v1.MsgEditRegisteredReaction";
+/// Type url of [`ReactionsMsg::RemoveRegisteredReaction`].
+pub const MSG_REMOVE_REGISTERED_REACTION_TYPE_URI: &str =
+    "/desmos.reactions.v1.MsgRemoveRegisteredReaction";
+/// Type url of [`ReactionsMsg::SetReactionsParams`].
+pub const MSG_SET_REACTIONS_PARAMS_TYPE_URI: &str = "/desmos.reactions.v1.MsgSetReactionsParams";
+
 /// Represents the messages to interact with the reactions module.
 #[cw_serde]
 pub enum ReactionsMsg {
@@ -183,6 +199,33 @@ impl ReactionsMsg {
             user,
         }
     }
+
+    /// Returns the address that must sign the message, which is the one that
+    /// the chain checks when the message is dispatched.
+    pub fn signer(&self) -> &Addr {
+        match self {
+            ReactionsMsg::AddReaction { user, .. }
+            | ReactionsMsg::RemoveReaction { user, .. }
+            | ReactionsMsg::AddRegisteredReaction { user, .. }
+            | ReactionsMsg::EditRegisteredReaction { user, .. }
+            | ReactionsMsg::RemoveRegisteredReaction { user, .. }
+            | ReactionsMsg::SetReactionsParams { user, .. } => user,
+        }
+    }
+
+    /// Returns the type url that identifies the message on chain.
+    pub fn type_url(&self) -> &'static str {
+        match self {
+            ReactionsMsg::AddReaction { .. } => MSG_ADD_REACTION_TYPE_URI,
+            ReactionsMsg::RemoveReaction { .. } => MSG_REMOVE_REACTION_TYPE_URI,
+            ReactionsMsg::AddRegisteredReaction { .. } => MSG_ADD_REGISTERED_REACTION_TYPE_URI,
+            ReactionsMsg::EditRegisteredReaction { .. } => MSG_EDIT_REGISTERED_REACTION_TYPE_URI,
+            ReactionsMsg::RemoveRegisteredReaction { .. } => {
+                MSG_REMOVE_REGISTERED_REACTION_TYPE_URI
+            }
+            ReactionsMsg::SetReactionsParams { .. } => MSG_SET_REACTIONS_PARAMS_TYPE_URI,
+        }
+    }
 }
 
 #[cfg(test)]
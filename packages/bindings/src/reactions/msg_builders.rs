@@ -0,0 +1,470 @@
+//! Contains the builders that can be used to create the [`ReactionsMsg`] variants
+//! having many fields.
+
+use crate::reactions::models::{FreeTextValueParams, ReactionValue, RegisteredReactionValueParams};
+use crate::reactions::msg::ReactionsMsg;
+use cosmwasm_std::Addr;
+use thiserror::Error;
+
+/// Represents the errors that can occur while building a [`ReactionsMsg`].
+#[derive(Error, Debug, PartialEq)]
+pub enum ReactionsMsgBuilderError {
+    /// Error returned when the subspace id is 0.
+    #[error("invalid subspace id: {0}")]
+    InvalidSubspaceId(u64),
+    /// Error returned when the post id is 0.
+    #[error("invalid post id: {0}")]
+    InvalidPostId(u64),
+    /// Error returned when a reaction is added without setting its value.
+    #[error("reaction value must be set")]
+    MissingValue,
+    /// Error returned when a free text reaction has an empty text.
+    #[error("free text reaction cannot be empty")]
+    EmptyFreeText,
+    /// Error returned when a registered reaction has an empty shorthand code.
+    #[error("shorthand code cannot be empty")]
+    EmptyShorthandCode,
+    /// Error returned when a registered reaction has an empty display value.
+    #[error("display value cannot be empty")]
+    EmptyDisplayValue,
+}
+
+fn validate_subspace_id(subspace_id: u64) -> Result<(), ReactionsMsgBuilderError> {
+    if subspace_id == 0 {
+        return Err(ReactionsMsgBuilderError::InvalidSubspaceId(subspace_id));
+    }
+    Ok(())
+}
+
+fn validate_registered_reaction(
+    shorthand_code: &str,
+    display_value: &str,
+) -> Result<(), ReactionsMsgBuilderError> {
+    if shorthand_code.trim().is_empty() {
+        return Err(ReactionsMsgBuilderError::EmptyShorthandCode);
+    }
+    if display_value.trim().is_empty() {
+        return Err(ReactionsMsgBuilderError::EmptyDisplayValue);
+    }
+    Ok(())
+}
+
+/// Builder to create a [`ReactionsMsg::AddReaction`].
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::Addr;
+/// use desmos_bindings::reactions::msg_builders::AddReactionBuilder;
+///
+/// let msg = AddReactionBuilder::new(1, 1, Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3"))
+///     .free_text(":)")
+///     .build()
+///     .unwrap();
+/// ```
+pub struct AddReactionBuilder {
+    subspace_id: u64,
+    post_id: u64,
+    value: Option<ReactionValue>,
+    user: Addr,
+}
+
+impl AddReactionBuilder {
+    /// Creates a new builder without the reaction value, which must be set with either
+    /// [`AddReactionBuilder::registered`] or [`AddReactionBuilder::free_text`].
+    ///
+    /// * `subspace_id` - Id of the subspace inside which the reaction will be added.
+    /// * `post_id` - Id of the post to react to.
+    /// * `user` - User adding the reaction.
+    pub fn new(subspace_id: u64, post_id: u64, user: Addr) -> Self {
+        AddReactionBuilder {
+            subspace_id,
+            post_id,
+            value: None,
+            user,
+        }
+    }
+
+    /// Sets a [`ReactionValue::Registered`] as the reaction value.
+    ///
+    /// * `registered_reaction_id` - Id of the registered reaction.
+    pub fn registered(mut self, registered_reaction_id: u32) -> Self {
+        self.value = Some(ReactionValue::Registered {
+            registered_reaction_id,
+        });
+        self
+    }
+
+    /// Sets a [`ReactionValue::FreeText`] as the reaction value.
+    ///
+    /// * `text` - Text of the reaction.
+    pub fn free_text(mut self, text: &str) -> Self {
+        self.value = Some(ReactionValue::FreeText {
+            text: text.to_owned(),
+        });
+        self
+    }
+
+    /// Builds the [`ReactionsMsg::AddReaction`].
+    pub fn build(self) -> Result<ReactionsMsg, ReactionsMsgBuilderError> {
+        validate_subspace_id(self.subspace_id)?;
+        if self.post_id == 0 {
+            return Err(ReactionsMsgBuilderError::InvalidPostId(self.post_id));
+        }
+        let value = self.value.ok_or(ReactionsMsgBuilderError::MissingValue)?;
+        if let ReactionValue::FreeText { text } = &value {
+            if text.trim().is_empty() {
+                return Err(ReactionsMsgBuilderError::EmptyFreeText);
+            }
+        }
+
+        Ok(ReactionsMsg::add_reaction(
+            self.subspace_id,
+            self.post_id,
+            value,
+            self.user,
+        ))
+    }
+}
+
+/// Builder to create a [`ReactionsMsg::AddRegisteredReaction`].
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::Addr;
+/// use desmos_bindings::reactions::msg_builders::AddRegisteredReactionBuilder;
+///
+/// let msg = AddRegisteredReactionBuilder::new(1, Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3"))
+///     .shorthand_code(":hello:")
+///     .display_value("https://example.com?image=hello.png")
+///     .build()
+///     .unwrap();
+/// ```
+pub struct AddRegisteredReactionBuilder {
+    subspace_id: u64,
+    shorthand_code: String,
+    display_value: String,
+    user: Addr,
+}
+
+impl AddRegisteredReactionBuilder {
+    /// Creates a new builder without the shorthand code and the display value,
+    /// which must be set before building the message.
+    ///
+    /// * `subspace_id` - Id of the subspace inside which the reaction will be registered.
+    /// * `user` - User registering the reaction.
+    pub fn new(subspace_id: u64, user: Addr) -> Self {
+        AddRegisteredReactionBuilder {
+            subspace_id,
+            shorthand_code: String::new(),
+            display_value: String::new(),
+            user,
+        }
+    }
+
+    /// Sets the shorthand code of the reaction.
+    ///
+    /// * `shorthand_code` - Shorthand code of the reaction (eg. `:hello:`).
+    pub fn shorthand_code(mut self, shorthand_code: &str) -> Self {
+        self.shorthand_code = shorthand_code.to_owned();
+        self
+    }
+
+    /// Sets the value that will be displayed in place of the shorthand code.
+    ///
+    /// * `display_value` - Value to be displayed (eg. an image URL).
+    pub fn display_value(mut self, display_value: &str) -> Self {
+        self.display_value = display_value.to_owned();
+        self
+    }
+
+    /// Builds the [`ReactionsMsg::AddRegisteredReaction`].
+    pub fn build(self) -> Result<ReactionsMsg, ReactionsMsgBuilderError> {
+        validate_subspace_id(self.subspace_id)?;
+        validate_registered_reaction(&self.shorthand_code, &self.display_value)?;
+
+        Ok(ReactionsMsg::add_registered_reaction(
+            self.subspace_id,
+            &self.shorthand_code,
+            &self.display_value,
+            self.user,
+        ))
+    }
+}
+
+/// Builder to create a [`ReactionsMsg::EditRegisteredReaction`].
+pub struct EditRegisteredReactionBuilder {
+    subspace_id: u64,
+    registered_reaction_id: u32,
+    shorthand_code: String,
+    display_value: String,
+    user: Addr,
+}
+
+impl EditRegisteredReactionBuilder {
+    /// Creates a new builder without the new shorthand code and display value,
+    /// which must be set before building the message.
+    ///
+    /// * `subspace_id` - Id of the subspace inside which the reaction is registered.
+    /// * `registered_reaction_id` - Id of the registered reaction to be edited.
+    /// * `user` - User editing the registered reaction.
+    pub fn new(subspace_id: u64, registered_reaction_id: u32, user: Addr) -> Self {
+        EditRegisteredReactionBuilder {
+            subspace_id,
+            registered_reaction_id,
+            shorthand_code: String::new(),
+            display_value: String::new(),
+            user,
+        }
+    }
+
+    /// Sets the new shorthand code of the reaction.
+    ///
+    /// * `shorthand_code` - New shorthand code of the reaction.
+    pub fn shorthand_code(mut self, shorthand_code: &str) -> Self {
+        self.shorthand_code = shorthand_code.to_owned();
+        self
+    }
+
+    /// Sets the new value that will be displayed in place of the shorthand code.
+    ///
+    /// * `display_value` - New value to be displayed.
+    pub fn display_value(mut self, display_value: &str) -> Self {
+        self.display_value = display_value.to_owned();
+        self
+    }
+
+    /// Builds the [`ReactionsMsg::EditRegisteredReaction`].
+    pub fn build(self) -> Result<ReactionsMsg, ReactionsMsgBuilderError> {
+        validate_subspace_id(self.subspace_id)?;
+        validate_registered_reaction(&self.shorthand_code, &self.display_value)?;
+
+        Ok(ReactionsMsg::edit_registered_reaction(
+            self.subspace_id,
+            self.registered_reaction_id,
+            &self.shorthand_code,
+            &self.display_value,
+            self.user,
+        ))
+    }
+}
+
+/// Builder to create a [`ReactionsMsg::SetReactionsParams`].
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::Addr;
+/// use desmos_bindings::reactions::msg_builders::SetReactionsParamsBuilder;
+///
+/// let msg = SetReactionsParamsBuilder::new(1, Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3"))
+///     .free_text_max_length(5)
+///     .free_text_reg_ex("^[a-z]+$")
+///     .build()
+///     .unwrap();
+/// ```
+pub struct SetReactionsParamsBuilder {
+    subspace_id: u64,
+    registered_reaction: RegisteredReactionValueParams,
+    free_text: FreeTextValueParams,
+    user: Addr,
+}
+
+impl SetReactionsParamsBuilder {
+    /// Creates a new builder initialized with the default Desmos reactions params:
+    /// registered reactions enabled and free text reactions enabled with a max length of 30.
+    ///
+    /// * `subspace_id` - Id of the subspace for which to set the params.
+    /// * `user` - User setting the params.
+    pub fn new(subspace_id: u64, user: Addr) -> Self {
+        SetReactionsParamsBuilder {
+            subspace_id,
+            registered_reaction: RegisteredReactionValueParams { enabled: true },
+            free_text: FreeTextValueParams {
+                enabled: true,
+                max_length: 30,
+                reg_ex: String::new(),
+            },
+            user,
+        }
+    }
+
+    /// Enables or disables the [`ReactionValue::Registered`](crate::reactions::models::ReactionValue::Registered) reactions.
+    ///
+    /// * `enabled` - Whether registered reactions should be enabled.
+    pub fn registered_reaction(mut self, enabled: bool) -> Self {
+        self.registered_reaction.enabled = enabled;
+        self
+    }
+
+    /// Enables or disables the [`ReactionValue::FreeText`](crate::reactions::models::ReactionValue::FreeText) reactions.
+    ///
+    /// * `enabled` - Whether free text reactions should be enabled.
+    pub fn free_text(mut self, enabled: bool) -> Self {
+        self.free_text.enabled = enabled;
+        self
+    }
+
+    /// Sets the max length of the free text reactions.
+    ///
+    /// * `max_length` - The max length that free text reactions should have.
+    pub fn free_text_max_length(mut self, max_length: u32) -> Self {
+        self.free_text.max_length = max_length;
+        self
+    }
+
+    /// Sets the RegEx that the free text reactions should respect.
+    ///
+    /// * `reg_ex` - RegEx that each free text reaction should respect.
+    pub fn free_text_reg_ex(mut self, reg_ex: &str) -> Self {
+        self.free_text.reg_ex = reg_ex.to_owned();
+        self
+    }
+
+    /// Builds the [`ReactionsMsg::SetReactionsParams`].
+    pub fn build(self) -> Result<ReactionsMsg, ReactionsMsgBuilderError> {
+        validate_subspace_id(self.subspace_id)?;
+
+        Ok(ReactionsMsg::set_reactions_params(
+            self.subspace_id,
+            self.registered_reaction,
+            self.free_text,
+            self.user,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_reaction_builder() {
+        let user = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+
+        let msg = AddReactionBuilder::new(1, 2, user.clone())
+            .registered(3)
+            .build()
+            .unwrap();
+        assert_eq!(
+            ReactionsMsg::add_reaction(
+                1,
+                2,
+                ReactionValue::Registered {
+                    registered_reaction_id: 3
+                },
+                user.clone()
+            ),
+            msg
+        );
+
+        let msg = AddReactionBuilder::new(1, 2, user.clone())
+            .free_text(":)")
+            .build()
+            .unwrap();
+        assert_eq!(
+            ReactionsMsg::add_reaction(
+                1,
+                2,
+                ReactionValue::FreeText {
+                    text: ":)".to_string()
+                },
+                user.clone()
+            ),
+            msg
+        );
+
+        let result = AddReactionBuilder::new(1, 2, user.clone()).build();
+        assert_eq!(Err(ReactionsMsgBuilderError::MissingValue), result);
+
+        let result = AddReactionBuilder::new(1, 2, user.clone())
+            .free_text(" ")
+            .build();
+        assert_eq!(Err(ReactionsMsgBuilderError::EmptyFreeText), result);
+
+        let result = AddReactionBuilder::new(1, 0, user).registered(3).build();
+        assert_eq!(Err(ReactionsMsgBuilderError::InvalidPostId(0)), result);
+    }
+
+    #[test]
+    fn test_add_registered_reaction_builder() {
+        let user = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+
+        let msg = AddRegisteredReactionBuilder::new(1, user.clone())
+            .shorthand_code(":hello:")
+            .display_value("https://example.com?image=hello.png")
+            .build()
+            .unwrap();
+        assert_eq!(
+            ReactionsMsg::add_registered_reaction(
+                1,
+                ":hello:",
+                "https://example.com?image=hello.png",
+                user.clone()
+            ),
+            msg
+        );
+
+        let result = AddRegisteredReactionBuilder::new(1, user.clone())
+            .display_value("https://example.com?image=hello.png")
+            .build();
+        assert_eq!(Err(ReactionsMsgBuilderError::EmptyShorthandCode), result);
+
+        let result = AddRegisteredReactionBuilder::new(1, user)
+            .shorthand_code(":hello:")
+            .build();
+        assert_eq!(Err(ReactionsMsgBuilderError::EmptyDisplayValue), result);
+    }
+
+    #[test]
+    fn test_edit_registered_reaction_builder() {
+        let user = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+
+        let msg = EditRegisteredReactionBuilder::new(1, 2, user.clone())
+            .shorthand_code(":hello:")
+            .display_value("https://example.com?image=hello.png")
+            .build()
+            .unwrap();
+        assert_eq!(
+            ReactionsMsg::edit_registered_reaction(
+                1,
+                2,
+                ":hello:",
+                "https://example.com?image=hello.png",
+                user.clone()
+            ),
+            msg
+        );
+
+        let result = EditRegisteredReactionBuilder::new(0, 2, user)
+            .shorthand_code(":hello:")
+            .display_value("https://example.com?image=hello.png")
+            .build();
+        assert_eq!(Err(ReactionsMsgBuilderError::InvalidSubspaceId(0)), result);
+    }
+
+    #[test]
+    fn test_set_reactions_params_builder() {
+        let user = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+        let msg = SetReactionsParamsBuilder::new(1, user.clone())
+            .registered_reaction(false)
+            .free_text_max_length(5)
+            .free_text_reg_ex("^[a-z]+$")
+            .build()
+            .unwrap();
+        assert_eq!(
+            ReactionsMsg::set_reactions_params(
+                1,
+                RegisteredReactionValueParams { enabled: false },
+                FreeTextValueParams {
+                    enabled: true,
+                    max_length: 5,
+                    reg_ex: "^[a-z]+$".to_string(),
+                },
+                user.clone()
+            ),
+            msg
+        );
+
+        let result = SetReactionsParamsBuilder::new(0, user).build();
+        assert_eq!(Err(ReactionsMsgBuilderError::InvalidSubspaceId(0)), result);
+    }
+}
@@ -0,0 +1,190 @@
+//! Contains the protobuf encoding of the [`ReactionsMsg`] variants, used to send them
+//! as `Stargate` messages (eg. inside an authz `MsgExec`).
+
+use crate::proto::{any, ProtoWriter};
+use crate::reactions::models::{
+    RawReactionValue, ReactionValue, FREE_TEXT_VALUE_TYPE_URI, REGISTERED_REACTION_VALUE_TYPE_URI,
+};
+use crate::reactions::msg::ReactionsMsg;
+use cosmwasm_std::{Binary, StdError, StdResult};
+use std::convert::TryFrom;
+
+fn reaction_value(value: &RawReactionValue) -> StdResult<Vec<u8>> {
+    let value = ReactionValue::try_from(value.clone())
+        .map_err(|error| StdError::parse_err("RawReactionValue", error))?;
+    Ok(match value {
+        ReactionValue::Registered {
+            registered_reaction_id,
+        } => any(
+            REGISTERED_REACTION_VALUE_TYPE_URI,
+            &ProtoWriter::new()
+                .uint32(1, registered_reaction_id)
+                .finish(),
+        ),
+        ReactionValue::FreeText { text } => any(
+            FREE_TEXT_VALUE_TYPE_URI,
+            &ProtoWriter::new().string(1, &text).finish(),
+        ),
+    })
+}
+
+impl ReactionsMsg {
+    /// Encodes the message using its protobuf representation.
+    pub fn to_proto_bytes(&self) -> StdResult<Binary> {
+        let encoded = match self {
+            ReactionsMsg::AddReaction {
+                subspace_id,
+                post_id,
+                value,
+                user,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint64(2, post_id.u64())
+                .message(3, &reaction_value(value)?)
+                .string(4, user.as_str()),
+            ReactionsMsg::RemoveReaction {
+                subspace_id,
+                post_id,
+                reaction_id,
+                user,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint64(2, post_id.u64())
+                .uint32(3, *reaction_id)
+                .string(4, user.as_str()),
+            ReactionsMsg::AddRegisteredReaction {
+                subspace_id,
+                shorthand_code,
+                display_value,
+                user,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .string(2, shorthand_code)
+                .string(3, display_value)
+                .string(4, user.as_str()),
+            ReactionsMsg::EditRegisteredReaction {
+                subspace_id,
+                registered_reaction_id,
+                shorthand_code,
+                display_value,
+                user,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint32(2, *registered_reaction_id)
+                .string(3, shorthand_code)
+                .string(4, display_value)
+                .string(5, user.as_str()),
+            ReactionsMsg::RemoveRegisteredReaction {
+                subspace_id,
+                registered_reaction_id,
+                user,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint32(2, *registered_reaction_id)
+                .string(3, user.as_str()),
+            ReactionsMsg::SetReactionsParams {
+                subspace_id,
+                registered_reaction,
+                free_text,
+                user,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .message(
+                    2,
+                    &ProtoWriter::new()
+                        .bool(1, registered_reaction.enabled)
+                        .finish(),
+                )
+                .message(
+                    3,
+                    &ProtoWriter::new()
+                        .bool(1, free_text.enabled)
+                        .uint32(2, free_text.max_length)
+                        .string(3, &free_text.reg_ex)
+                        .finish(),
+                )
+                .string(4, user.as_str()),
+        };
+        Ok(encoded.finish().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reactions::msg::MSG_ADD_REACTION_TYPE_URI;
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn test_add_reaction_encoding() {
+        let msg = ReactionsMsg::add_reaction(
+            1,
+            2,
+            ReactionValue::Registered {
+                registered_reaction_id: 3,
+            },
+            Addr::unchecked("a"),
+        );
+        let expected = ProtoWriter::new()
+            .uint64(1, 1)
+            .uint64(2, 2)
+            .message(3, &any(REGISTERED_REACTION_VALUE_TYPE_URI, &[0x08, 0x03]))
+            .string(4, "a")
+            .finish();
+        assert_eq!(MSG_ADD_REACTION_TYPE_URI, msg.type_url());
+        assert_eq!(Binary::from(expected), msg.to_proto_bytes().unwrap());
+    }
+
+    /// Mirror of the `google.protobuf.Any` protobuf message.
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct ProstAny {
+        #[prost(string, tag = "1")]
+        type_url: String,
+        #[prost(bytes = "vec", tag = "2")]
+        value: Vec<u8>,
+    }
+
+    /// Mirror of the `desmos.reactions.v1.FreeTextValue` protobuf message.
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct ProstFreeTextValue {
+        #[prost(string, tag = "1")]
+        text: String,
+    }
+
+    /// Mirror of the `desmos.reactions.v1.MsgAddReaction` protobuf message.
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct ProstMsgAddReaction {
+        #[prost(uint64, tag = "1")]
+        subspace_id: u64,
+        #[prost(uint64, tag = "2")]
+        post_id: u64,
+        #[prost(message, optional, tag = "3")]
+        value: Option<ProstAny>,
+        #[prost(string, tag = "4")]
+        user: String,
+    }
+
+    #[test]
+    fn test_add_reaction_prost_encoding() {
+        let msg = ReactionsMsg::add_reaction(
+            1,
+            2,
+            ReactionValue::FreeText {
+                text: "👍".into()
+            },
+            Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3"),
+        );
+        let expected = prost::Message::encode_to_vec(&ProstMsgAddReaction {
+            subspace_id: 1,
+            post_id: 2,
+            value: Some(ProstAny {
+                type_url: "/desmos.reactions.v1.FreeTextValue".into(),
+                value: prost::Message::encode_to_vec(&ProstFreeTextValue {
+                    text: "👍".into()
+                }),
+            }),
+            user: "desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3".into(),
+        });
+        assert_eq!(Binary::from(expected), msg.to_proto_bytes().unwrap());
+    }
+}
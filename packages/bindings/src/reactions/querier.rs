@@ -11,6 +11,7 @@ use cosmwasm_std::Binary;
 use crate::{
     query::DesmosQuery,
     reactions::{
+        models::{InvalidReactionValueError, ReactionValue},
         models_query::{
             QueryReactionResponse, QueryReactionsParamsResponse, QueryReactionsResponse,
             QueryRegisteredReactionResponse, QueryRegisteredReactionsResponse,
@@ -19,7 +20,7 @@ use crate::{
     },
     types::PageRequest,
 };
-use cosmwasm_std::{Addr, Querier, QuerierWrapper, StdResult};
+use cosmwasm_std::{Addr, Querier, QuerierWrapper, StdError, StdResult};
 
 /// Querier able to query data from the Desmos x/reactions module.
 pub struct ReactionsQuerier<'a> {
@@ -211,13 +212,68 @@ impl<'a> ReactionsQuerier<'a> {
         let res: QueryReactionsParamsResponse = self.querier.query(&request.into())?;
         Ok(res)
     }
+
+    /// Checks that the given reaction value can be added to a post of the given subspace,
+    /// validating it against the subspace reactions params and making sure that the
+    /// referenced registered reaction exists.
+    ///
+    /// * `subspace_id` - Id of the subspace inside which the reaction will be added.
+    /// * `value` - The reaction value to validate.
+    pub fn validate_reaction_value(
+        &self,
+        subspace_id: u64,
+        value: &ReactionValue,
+    ) -> Result<(), InvalidReactionValueError> {
+        let params = self.query_reactions_params(subspace_id)?.params;
+        params.validate_reaction_value(value)?;
+
+        if let ReactionValue::Registered {
+            registered_reaction_id,
+        } = value
+        {
+            self.query_registered_reaction(subspace_id, *registered_reaction_id)
+                .map_err(|error| {
+                    if is_registered_reaction_not_found(&error, *registered_reaction_id) {
+                        InvalidReactionValueError::RegisteredReactionNotFound(
+                            *registered_reaction_id,
+                        )
+                    } else {
+                        InvalidReactionValueError::Std(error)
+                    }
+                })?;
+        }
+        Ok(())
+    }
+}
+
+/// Tells if the given error is the one returned when querying a missing registered reaction.
+/// The chain reports it through the querier as a generic error containing the gRPC `NotFound`
+/// status code, or the not found message.
+///
+/// * `error` - Error returned by the registered reaction query.
+/// * `registered_reaction_id` - Id of the queried registered reaction.
+fn is_registered_reaction_not_found(error: &StdError, registered_reaction_id: u32) -> bool {
+    match error {
+        StdError::NotFound { .. } => true,
+        StdError::GenericErr { msg, .. } => {
+            msg.contains("code = NotFound")
+                || msg.contains(&format!(
+                    "registered reaction with id {} not found",
+                    registered_reaction_id
+                ))
+        }
+        _ => false,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::mocks::mock_queriers::mock_desmos_dependencies;
-    use crate::reactions::mocks::MockReactionsQueries;
+    use crate::mocks::mock_queriers::{
+        mock_desmos_dependencies, mock_desmos_dependencies_with_custom_querier, MockDesmosQuerier,
+    };
+    use crate::reactions::mocks::{mock_reactions_query_response, MockReactionsQueries};
+    use cosmwasm_std::ContractResult;
     use std::ops::Deref;
 
     #[test]
@@ -282,6 +338,79 @@ mod tests {
         assert_eq!(response.ok(), Some(expected));
     }
 
+    #[test]
+    fn test_validate_reaction_value() {
+        let owned_deps = mock_desmos_dependencies();
+        let deps = owned_deps.as_ref();
+        let querier = ReactionsQuerier::new(deps.querier.deref());
+
+        let value = ReactionValue::Registered {
+            registered_reaction_id: 1,
+        };
+        assert_eq!(Ok(()), querier.validate_reaction_value(1, &value));
+
+        let value = ReactionValue::FreeText {
+            text: "a".repeat(101),
+        };
+        assert_eq!(
+            Err(InvalidReactionValueError::FreeTextTooLong(100)),
+            querier.validate_reaction_value(1, &value)
+        );
+    }
+
+    #[test]
+    fn test_validate_not_found_registered_reaction_value() {
+        for error in [
+            "rpc error: code = NotFound desc = registered reaction with id 2 not found",
+            "registered reaction with id 2 not found",
+        ] {
+            let owned_deps = mock_desmos_dependencies_with_custom_querier(
+                MockDesmosQuerier::default().with_custom_reactions_handler(
+                    move |query| match query {
+                        ReactionsQuery::RegisteredReaction { .. } => {
+                            ContractResult::Err(error.to_string())
+                        }
+                        _ => mock_reactions_query_response(query),
+                    },
+                ),
+            );
+            let deps = owned_deps.as_ref();
+            let querier = ReactionsQuerier::new(deps.querier.deref());
+
+            let value = ReactionValue::Registered {
+                registered_reaction_id: 2,
+            };
+            assert_eq!(
+                Err(InvalidReactionValueError::RegisteredReactionNotFound(2)),
+                querier.validate_reaction_value(1, &value)
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_registered_reaction_value_query_error() {
+        let owned_deps = mock_desmos_dependencies_with_custom_querier(
+            MockDesmosQuerier::default().with_custom_reactions_handler(|query| match query {
+                ReactionsQuery::RegisteredReaction { .. } => {
+                    ContractResult::Err("connection refused".to_string())
+                }
+                _ => mock_reactions_query_response(query),
+            }),
+        );
+        let deps = owned_deps.as_ref();
+        let querier = ReactionsQuerier::new(deps.querier.deref());
+
+        let value = ReactionValue::Registered {
+            registered_reaction_id: 1,
+        };
+        let result = querier.validate_reaction_value(1, &value);
+        assert!(matches!(
+            result,
+            Err(InvalidReactionValueError::Std(StdError::GenericErr { ref msg, .. }))
+                if msg.contains("connection refused")
+        ));
+    }
+
     #[test]
     fn test_iterate_reactions() {
         let owned_deps = mock_desmos_dependencies();
@@ -3,6 +3,15 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint64};
 
+/// Type url of [`RelationshipsMsg::CreateRelationship`].
+pub const MSG_CREATE_RELATIONSHIP_TYPE_URI: &str = "/desmos.relationships.v1.MsgCreateRelationship";
+/// Type url of [`RelationshipsMsg::DeleteRelationship`].
+pub const MSG_DELETE_RELATIONSHIP_TYPE_URI: &str = "/desmos.relationships.v1.MsgDeleteRelationship";
+/// Type url of [`RelationshipsMsg::BlockUser`].
+pub const MSG_BLOCK_USER_TYPE_URI: &str = "/desmos.relationships.v1.MsgBlockUser";
+/// Type url of [`RelationshipsMsg::UnblockUser`].
+pub const MSG_UNBLOCK_USER_TYPE_URI: &str = "/desmos.relationships.v1.MsgUnblockUser";
+
 /// Represents the messages to interact with x/relatioships module.
 #[cw_serde]
 pub enum RelationshipsMsg {
@@ -113,6 +122,27 @@ impl RelationshipsMsg {
             subspace_id: subspace_id.into(),
         }
     }
+
+    /// Returns the address that must sign the message, which is the one that
+    /// the chain checks when the message is dispatched.
+    pub fn signer(&self) -> &Addr {
+        match self {
+            RelationshipsMsg::CreateRelationship { signer, .. }
+            | RelationshipsMsg::DeleteRelationship { signer, .. } => signer,
+            RelationshipsMsg::BlockUser { blocker, .. }
+            | RelationshipsMsg::UnblockUser { blocker, .. } => blocker,
+        }
+    }
+
+    /// Returns the type url that identifies the message on chain.
+    pub fn type_url(&self) -> &'static str {
+        match self {
+            RelationshipsMsg::CreateRelationship { .. } => MSG_CREATE_RELATIONSHIP_TYPE_URI,
+            RelationshipsMsg::DeleteRelationship { .. } => MSG_DELETE_RELATIONSHIP_TYPE_URI,
+            RelationshipsMsg::BlockUser { .. } => MSG_BLOCK_USER_TYPE_URI,
+            RelationshipsMsg::UnblockUser { .. } => MSG_UNBLOCK_USER_TYPE_URI,
+        }
+    }
 }
 
 #[cfg(test)]
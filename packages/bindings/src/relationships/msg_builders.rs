@@ -0,0 +1,191 @@
+//! Contains the builders that can be used to create the [`RelationshipsMsg`] variants
+//! having many fields.
+
+use crate::relationships::msg::RelationshipsMsg;
+use cosmwasm_std::Addr;
+use thiserror::Error;
+
+/// Represents the errors that can occur while building a [`RelationshipsMsg`].
+#[derive(Error, Debug, PartialEq)]
+pub enum RelationshipsMsgBuilderError {
+    /// Error returned when a user tries to block itself.
+    #[error("blocker and blocked must be different")]
+    SelfBlock,
+    /// Error returned when a user tries to create a relationship with itself.
+    #[error("signer and counterparty must be different")]
+    SelfRelationship,
+}
+
+/// Builder to create a [`RelationshipsMsg::CreateRelationship`].
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::Addr;
+/// use desmos_bindings::relationships::msg_builders::CreateRelationshipBuilder;
+///
+/// let msg = CreateRelationshipBuilder::new(
+///     Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3"),
+///     Addr::unchecked("desmos1ptvq7l4jt7n9sc3fky22mfvc6waf2jd8nuc0jv"),
+/// )
+/// .subspace_id(1)
+/// .build()
+/// .unwrap();
+/// ```
+pub struct CreateRelationshipBuilder {
+    signer: Addr,
+    counterparty: Addr,
+    subspace_id: u64,
+}
+
+impl CreateRelationshipBuilder {
+    /// Creates a new builder for a relationship that is not tied to any subspace.
+    ///
+    /// * `signer` - Address of the user creating the relationship.
+    /// * `counterparty` - Address of the relationship counterparty.
+    pub fn new(signer: Addr, counterparty: Addr) -> Self {
+        CreateRelationshipBuilder {
+            signer,
+            counterparty,
+            subspace_id: 0,
+        }
+    }
+
+    /// Sets the subspace inside which the relationship will be created.
+    ///
+    /// * `subspace_id` - Id of the subspace inside which the relationship will be created.
+    pub fn subspace_id(mut self, subspace_id: u64) -> Self {
+        self.subspace_id = subspace_id;
+        self
+    }
+
+    /// Builds the [`RelationshipsMsg::CreateRelationship`].
+    pub fn build(self) -> Result<RelationshipsMsg, RelationshipsMsgBuilderError> {
+        if self.signer == self.counterparty {
+            return Err(RelationshipsMsgBuilderError::SelfRelationship);
+        }
+
+        Ok(RelationshipsMsg::create_relationship(
+            self.signer,
+            self.counterparty,
+            self.subspace_id,
+        ))
+    }
+}
+
+/// Builder to create a [`RelationshipsMsg::BlockUser`].
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::Addr;
+/// use desmos_bindings::relationships::msg_builders::BlockUserBuilder;
+///
+/// let msg = BlockUserBuilder::new(
+///     Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3"),
+///     Addr::unchecked("desmos1ptvq7l4jt7n9sc3fky22mfvc6waf2jd8nuc0jv"),
+///     1,
+/// )
+/// .reason("spam")
+/// .build()
+/// .unwrap();
+/// ```
+pub struct BlockUserBuilder {
+    blocker: Addr,
+    blocked: Addr,
+    reason: String,
+    subspace_id: u64,
+}
+
+impl BlockUserBuilder {
+    /// Creates a new builder that blocks an user without specifying a reason.
+    ///
+    /// * `blocker` - Address of the user blocking.
+    /// * `blocked` - Address of the user to be blocked.
+    /// * `subspace_id` - Id of the subspace inside which the user should be blocked.
+    pub fn new(blocker: Addr, blocked: Addr, subspace_id: u64) -> Self {
+        BlockUserBuilder {
+            blocker,
+            blocked,
+            reason: String::new(),
+            subspace_id,
+        }
+    }
+
+    /// Sets the reason why the user has been blocked.
+    ///
+    /// * `reason` - Reason of the block.
+    pub fn reason(mut self, reason: &str) -> Self {
+        self.reason = reason.to_owned();
+        self
+    }
+
+    /// Builds the [`RelationshipsMsg::BlockUser`].
+    pub fn build(self) -> Result<RelationshipsMsg, RelationshipsMsgBuilderError> {
+        if self.blocker == self.blocked {
+            return Err(RelationshipsMsgBuilderError::SelfBlock);
+        }
+
+        Ok(RelationshipsMsg::block_user(
+            self.blocker,
+            self.blocked,
+            self.reason,
+            self.subspace_id,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_relationship_builder() {
+        let signer = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+        let counterparty = Addr::unchecked("desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3");
+
+        let msg = CreateRelationshipBuilder::new(signer.clone(), counterparty.clone())
+            .subspace_id(1)
+            .build()
+            .unwrap();
+        assert_eq!(
+            RelationshipsMsg::create_relationship(signer.clone(), counterparty.clone(), 1),
+            msg
+        );
+
+        let msg = CreateRelationshipBuilder::new(signer.clone(), counterparty.clone())
+            .build()
+            .unwrap();
+        assert_eq!(
+            RelationshipsMsg::create_relationship(signer.clone(), counterparty, 0),
+            msg
+        );
+
+        let result = CreateRelationshipBuilder::new(signer.clone(), signer).build();
+        assert_eq!(Err(RelationshipsMsgBuilderError::SelfRelationship), result);
+    }
+
+    #[test]
+    fn test_block_user_builder() {
+        let blocker = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+        let blocked = Addr::unchecked("desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3");
+
+        let msg = BlockUserBuilder::new(blocker.clone(), blocked.clone(), 1)
+            .reason("spam")
+            .build()
+            .unwrap();
+        assert_eq!(
+            RelationshipsMsg::block_user(blocker.clone(), blocked.clone(), "spam".to_string(), 1),
+            msg
+        );
+
+        let msg = BlockUserBuilder::new(blocker.clone(), blocked.clone(), 1)
+            .build()
+            .unwrap();
+        assert_eq!(
+            RelationshipsMsg::block_user(blocker.clone(), blocked, "".to_string(), 1),
+            msg
+        );
+
+        let result = BlockUserBuilder::new(blocker.clone(), blocker, 1).build();
+        assert_eq!(Err(RelationshipsMsgBuilderError::SelfBlock), result);
+    }
+}
@@ -0,0 +1,94 @@
+//! Contains the protobuf encoding of the [`RelationshipsMsg`] variants, used to send them
+//! as `Stargate` messages (eg. inside an authz `MsgExec`).
+
+use crate::proto::ProtoWriter;
+use crate::relationships::msg::RelationshipsMsg;
+use cosmwasm_std::{Binary, StdResult};
+
+impl RelationshipsMsg {
+    /// Encodes the message using its protobuf representation.
+    pub fn to_proto_bytes(&self) -> StdResult<Binary> {
+        let encoded = match self {
+            RelationshipsMsg::CreateRelationship {
+                signer,
+                counterparty,
+                subspace_id,
+            }
+            | RelationshipsMsg::DeleteRelationship {
+                signer,
+                counterparty,
+                subspace_id,
+            } => ProtoWriter::new()
+                .string(1, signer.as_str())
+                .string(2, counterparty.as_str())
+                .uint64(3, subspace_id.u64()),
+            RelationshipsMsg::BlockUser {
+                blocker,
+                blocked,
+                reason,
+                subspace_id,
+            } => ProtoWriter::new()
+                .string(1, blocker.as_str())
+                .string(2, blocked.as_str())
+                .string(3, reason)
+                .uint64(4, subspace_id.u64()),
+            RelationshipsMsg::UnblockUser {
+                blocker,
+                blocked,
+                subspace_id,
+            } => ProtoWriter::new()
+                .string(1, blocker.as_str())
+                .string(2, blocked.as_str())
+                .uint64(3, subspace_id.u64()),
+        };
+        Ok(encoded.finish().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::relationships::msg::MSG_BLOCK_USER_TYPE_URI;
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn test_block_user_encoding() {
+        let msg =
+            RelationshipsMsg::block_user(Addr::unchecked("a"), Addr::unchecked("b"), "".into(), 1);
+        assert_eq!(MSG_BLOCK_USER_TYPE_URI, msg.type_url());
+        assert_eq!(
+            Binary::from(vec![0x0a, 0x01, b'a', 0x12, 0x01, b'b', 0x20, 0x01]),
+            msg.to_proto_bytes().unwrap()
+        );
+    }
+
+    /// Mirror of the `desmos.relationships.v1.MsgBlockUser` protobuf message.
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct ProstMsgBlockUser {
+        #[prost(string, tag = "1")]
+        blocker: String,
+        #[prost(string, tag = "2")]
+        blocked: String,
+        #[prost(string, tag = "3")]
+        reason: String,
+        #[prost(uint64, tag = "4")]
+        subspace_id: u64,
+    }
+
+    #[test]
+    fn test_block_user_prost_encoding() {
+        let msg = RelationshipsMsg::block_user(
+            Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3"),
+            Addr::unchecked("desmos1ptvq7l4jt7n9sc3fky22mfvc6waf2jd8nuc0jv"),
+            "spam".into(),
+            7,
+        );
+        let expected = prost::Message::encode_to_vec(&ProstMsgBlockUser {
+            blocker: "desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3".into(),
+            blocked: "desmos1ptvq7l4jt7n9sc3fky22mfvc6waf2jd8nuc0jv".into(),
+            reason: "spam".into(),
+            subspace_id: 7,
+        });
+        assert_eq!(Binary::from(expected), msg.to_proto_bytes().unwrap());
+    }
+}
@@ -1,8 +1,9 @@
 //! Contains some useful mocks of the Desmos x/reports module's types made to be used in any test.
 
-use crate::reports::models::{Reason, Report, ReportTarget};
+use crate::reports::models::{Params, Reason, Report, ReportTarget, StandardReason};
 use crate::reports::models_query::{
-    QueryReasonResponse, QueryReasonsResponse, QueryReportResponse, QueryReportsResponse,
+    QueryParamsResponse, QueryReasonResponse, QueryReasonsResponse, QueryReportResponse,
+    QueryReportsResponse,
 };
 use crate::reports::query::ReportsQuery;
 use cosmwasm_std::{to_binary, Addr, Binary, ContractResult, Uint64};
@@ -85,6 +86,24 @@ impl MockReportsQueries {
             description: None,
         }
     }
+
+    /// Functions that generate the mocked x/reports module params.
+    pub fn get_mocked_params() -> Params {
+        Params {
+            standard_reasons: vec![
+                StandardReason {
+                    id: 1,
+                    title: "Spam".to_string(),
+                    description: Some("Spam content".to_string()),
+                },
+                StandardReason {
+                    id: 2,
+                    title: "Scam".to_string(),
+                    description: None,
+                },
+            ],
+        }
+    }
 }
 
 /// Functions that mocks the reports query responses.
@@ -104,6 +123,9 @@ pub fn mock_reports_query_response(query: &ReportsQuery) -> ContractResult<Binar
         ReportsQuery::Reason { subspace_id, .. } => to_binary(&QueryReasonResponse {
             reason: MockReportsQueries::get_mocked_reason(subspace_id),
         }),
+        ReportsQuery::Params {} => to_binary(&QueryParamsResponse {
+            params: MockReportsQueries::get_mocked_params(),
+        }),
     };
     response.into()
 }
@@ -5,8 +5,8 @@ use cosmwasm_std::{Addr, Uint64};
 use std::convert::TryFrom;
 use thiserror::Error;
 
-const USER_REPORT_TARGET_TYPE_URI: &str = "/desmos.reports.v1.UserTarget";
-const POST_REPORT_TARGET_TYPE_URI: &str = "/desmos.reports.v1.PostTarget";
+pub(crate) const USER_REPORT_TARGET_TYPE_URI: &str = "/desmos.reports.v1.UserTarget";
+pub(crate) const POST_REPORT_TARGET_TYPE_URI: &str = "/desmos.reports.v1.PostTarget";
 
 /// Represents a generic report.
 #[cw_serde]
@@ -68,6 +68,24 @@ pub struct Reason {
     pub description: Option<String>,
 }
 
+/// Contains the data about a reporting reason that subspaces can choose to support.
+#[cw_serde]
+pub struct StandardReason {
+    /// Id of the standard reason.
+    pub id: u32,
+    /// Title of the reason.
+    pub title: String,
+    /// Extended description of the reason and the cases it applies to.
+    pub description: Option<String>,
+}
+
+/// Contains the parameters of the x/reports module.
+#[cw_serde]
+pub struct Params {
+    /// Reasons that subspaces can choose to support.
+    pub standard_reasons: Vec<StandardReason>,
+}
+
 impl From<ReportTarget> for RawReportTarget {
     fn from(target: ReportTarget) -> Self {
         match target {
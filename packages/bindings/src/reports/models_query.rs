@@ -1,6 +1,6 @@
 //! Contains structures returned from the [ReportsQuerier<'a>](crate::reports::querier::ReportsQuerier).
 
-use crate::reports::models::{Reason, Report};
+use crate::reports::models::{Params, Reason, Report};
 use crate::types::PageResponse;
 use cosmwasm_schema::cw_serde;
 
@@ -35,3 +35,10 @@ pub struct QueryReasonResponse {
     /// Queried report reason.
     pub reason: Reason,
 }
+
+/// Response to [`ReportsQuery::Params`](crate::reports::query::ReportsQuery::Params).
+#[cw_serde]
+pub struct QueryParamsResponse {
+    /// Parameters of the x/reports module.
+    pub params: Params,
+}
@@ -4,6 +4,18 @@ use crate::reports::models::{RawReportTarget, ReportTarget};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint64};
 
+/// Type url of [`ReportsMsg::CreateReport`].
+pub const MSG_CREATE_REPORT_TYPE_URI: &str = "/desmos.reports.v1.MsgCreateReport";
+/// Type url of [`ReportsMsg::DeleteReport`].
+pub const MSG_DELETE_REPORT_TYPE_URI: &str = "/desmos.reports.v1.MsgDeleteReport";
+/// Type url of [`ReportsMsg::SupportStandardReason`].
+pub const MSG_SUPPORT_STANDARD_REASON_TYPE_URI: &str =
+    "/desmos.reports.v1.MsgSupportStandardReason";
+/// Type url of [`ReportsMsg::AddReason`].
+pub const MSG_ADD_REASON_TYPE_URI: &str = "/desmos.reports.v1.MsgAddReason";
+/// Type url of [`ReportsMsg::RemoveReason`].
+pub const MSG_REMOVE_REASON_TYPE_URI: &str = "/desmos.reports.v1.MsgRemoveReason";
+
 /// Represents the messages to interact with the reports module.
 #[cw_serde]
 pub enum ReportsMsg {
@@ -146,6 +158,29 @@ impl ReportsMsg {
             signer,
         }
     }
+
+    /// Returns the address that must sign the message, which is the one that
+    /// the chain checks when the message is dispatched.
+    pub fn signer(&self) -> &Addr {
+        match self {
+            ReportsMsg::CreateReport { reporter, .. } => reporter,
+            ReportsMsg::DeleteReport { signer, .. }
+            | ReportsMsg::SupportStandardReason { signer, .. }
+            | ReportsMsg::AddReason { signer, .. }
+            | ReportsMsg::RemoveReason { signer, .. } => signer,
+        }
+    }
+
+    /// Returns the type url that identifies the message on chain.
+    pub fn type_url(&self) -> &'static str {
+        match self {
+            ReportsMsg::CreateReport { .. } => MSG_CREATE_REPORT_TYPE_URI,
+            ReportsMsg::DeleteReport { .. } => MSG_DELETE_REPORT_TYPE_URI,
+            ReportsMsg::SupportStandardReason { .. } => MSG_SUPPORT_STANDARD_REASON_TYPE_URI,
+            ReportsMsg::AddReason { .. } => MSG_ADD_REASON_TYPE_URI,
+            ReportsMsg::RemoveReason { .. } => MSG_REMOVE_REASON_TYPE_URI,
+        }
+    }
 }
 
 #[cfg(test)]
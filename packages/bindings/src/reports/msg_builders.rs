@@ -0,0 +1,205 @@
+//! Contains the builders that can be used to create the [`ReportsMsg`] variants
+//! having many optional fields.
+
+use crate::reports::models::ReportTarget;
+use crate::reports::msg::ReportsMsg;
+use cosmwasm_std::Addr;
+use thiserror::Error;
+
+/// Represents the errors that can occur while building a [`ReportsMsg`].
+#[derive(Error, Debug, PartialEq)]
+pub enum ReportsMsgBuilderError {
+    /// Error returned when the subspace id is 0.
+    #[error("invalid subspace id: {0}")]
+    InvalidSubspaceId(u64),
+    /// Error returned when a report is created without any reason.
+    #[error("reasons ids cannot be empty")]
+    EmptyReasons,
+    /// Error returned when a reason is created with an empty title.
+    #[error("reason title cannot be empty")]
+    EmptyTitle,
+}
+
+/// Builder to create a [`ReportsMsg::CreateReport`].
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::Addr;
+/// use desmos_bindings::reports::models::ReportTarget;
+/// use desmos_bindings::reports::msg_builders::CreateReportBuilder;
+///
+/// let msg = CreateReportBuilder::new(
+///     1,
+///     ReportTarget::User { user: Addr::unchecked("desmos1ptvq7l4jt7n9sc3fky22mfvc6waf2jd8nuc0jv") },
+///     Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3"),
+/// )
+/// .reason(1)
+/// .message("Spammer")
+/// .build()
+/// .unwrap();
+/// ```
+pub struct CreateReportBuilder {
+    subspace_id: u64,
+    reasons_ids: Vec<u32>,
+    message: Option<String>,
+    reporter: Addr,
+    target: ReportTarget,
+}
+
+impl CreateReportBuilder {
+    /// Creates a new builder for a report without any reason and message.
+    ///
+    /// * `subspace_id` - Id of the subspace for which the report should be stored.
+    /// * `target` - Target of the report.
+    /// * `reporter` - Address of the reporter.
+    pub fn new(subspace_id: u64, target: ReportTarget, reporter: Addr) -> Self {
+        CreateReportBuilder {
+            subspace_id,
+            reasons_ids: vec![],
+            message: None,
+            reporter,
+            target,
+        }
+    }
+
+    /// Adds a reason to the report.
+    ///
+    /// * `reason_id` - Id of the reason this report is created for.
+    pub fn reason(mut self, reason_id: u32) -> Self {
+        self.reasons_ids.push(reason_id);
+        self
+    }
+
+    /// Sets the message attached to the report.
+    ///
+    /// * `message` - Message attached to this report.
+    pub fn message(mut self, message: &str) -> Self {
+        self.message = Some(message.to_owned());
+        self
+    }
+
+    /// Builds the [`ReportsMsg::CreateReport`].
+    pub fn build(self) -> Result<ReportsMsg, ReportsMsgBuilderError> {
+        if self.subspace_id == 0 {
+            return Err(ReportsMsgBuilderError::InvalidSubspaceId(self.subspace_id));
+        }
+        if self.reasons_ids.is_empty() {
+            return Err(ReportsMsgBuilderError::EmptyReasons);
+        }
+
+        Ok(ReportsMsg::create_report(
+            self.subspace_id,
+            self.reasons_ids,
+            self.message,
+            self.reporter,
+            self.target,
+        ))
+    }
+}
+
+/// Builder to create a [`ReportsMsg::AddReason`].
+pub struct AddReasonBuilder {
+    subspace_id: u64,
+    title: String,
+    description: Option<String>,
+    signer: Addr,
+}
+
+impl AddReasonBuilder {
+    /// Creates a new builder for a reason without description.
+    ///
+    /// * `subspace_id` - Id of the subspace for which to add the reason.
+    /// * `title` - Title of the reason.
+    /// * `signer` - Address of the user adding the supported reason.
+    pub fn new(subspace_id: u64, title: &str, signer: Addr) -> Self {
+        AddReasonBuilder {
+            subspace_id,
+            title: title.to_owned(),
+            description: None,
+            signer,
+        }
+    }
+
+    /// Sets the reason description.
+    ///
+    /// * `description` - Extended description of the reason and the cases it applies to.
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_owned());
+        self
+    }
+
+    /// Builds the [`ReportsMsg::AddReason`].
+    pub fn build(self) -> Result<ReportsMsg, ReportsMsgBuilderError> {
+        if self.subspace_id == 0 {
+            return Err(ReportsMsgBuilderError::InvalidSubspaceId(self.subspace_id));
+        }
+        if self.title.trim().is_empty() {
+            return Err(ReportsMsgBuilderError::EmptyTitle);
+        }
+
+        Ok(ReportsMsg::add_reason(
+            self.subspace_id,
+            self.title,
+            self.description,
+            self.signer,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::Uint64;
+
+    const REPORTER: &str = "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc";
+
+    #[test]
+    fn test_create_report_builder() {
+        let reporter = Addr::unchecked(REPORTER);
+        let target = ReportTarget::Post {
+            post_id: Uint64::new(1),
+        };
+
+        let msg = CreateReportBuilder::new(1, target.clone(), reporter.clone())
+            .reason(1)
+            .reason(2)
+            .message("message")
+            .build()
+            .unwrap();
+        assert_eq!(
+            ReportsMsg::create_report(
+                1,
+                vec![1, 2],
+                Some("message"),
+                reporter.clone(),
+                target.clone()
+            ),
+            msg
+        );
+
+        let result = CreateReportBuilder::new(1, target.clone(), reporter.clone()).build();
+        assert_eq!(Err(ReportsMsgBuilderError::EmptyReasons), result);
+
+        let result = CreateReportBuilder::new(0, target, reporter)
+            .reason(1)
+            .build();
+        assert_eq!(Err(ReportsMsgBuilderError::InvalidSubspaceId(0)), result);
+    }
+
+    #[test]
+    fn test_add_reason_builder() {
+        let signer = Addr::unchecked(REPORTER);
+
+        let msg = AddReasonBuilder::new(1, "title", signer.clone())
+            .description("description")
+            .build()
+            .unwrap();
+        assert_eq!(
+            ReportsMsg::add_reason(1, "title", Some("description"), signer.clone()),
+            msg
+        );
+
+        let result = AddReasonBuilder::new(1, "", signer).build();
+        assert_eq!(Err(ReportsMsgBuilderError::EmptyTitle), result);
+    }
+}
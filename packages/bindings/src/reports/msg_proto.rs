@@ -0,0 +1,165 @@
+//! Contains the protobuf encoding of the [`ReportsMsg`] variants, used to send them
+//! as `Stargate` messages (eg. inside an authz `MsgExec`).
+
+use crate::proto::{any, ProtoWriter};
+use crate::reports::models::{
+    RawReportTarget, ReportTarget, POST_REPORT_TARGET_TYPE_URI, USER_REPORT_TARGET_TYPE_URI,
+};
+use crate::reports::msg::ReportsMsg;
+use cosmwasm_std::{Binary, StdError, StdResult};
+use std::convert::TryFrom;
+
+fn report_target(target: &RawReportTarget) -> StdResult<Vec<u8>> {
+    let target = ReportTarget::try_from(target.clone())
+        .map_err(|error| StdError::parse_err("RawReportTarget", error))?;
+    Ok(match target {
+        ReportTarget::User { user } => any(
+            USER_REPORT_TARGET_TYPE_URI,
+            &ProtoWriter::new().string(1, user.as_str()).finish(),
+        ),
+        ReportTarget::Post { post_id } => any(
+            POST_REPORT_TARGET_TYPE_URI,
+            &ProtoWriter::new().uint64(1, post_id.u64()).finish(),
+        ),
+    })
+}
+
+impl ReportsMsg {
+    /// Encodes the message using its protobuf representation.
+    pub fn to_proto_bytes(&self) -> StdResult<Binary> {
+        let encoded = match self {
+            ReportsMsg::CreateReport {
+                subspace_id,
+                reasons_ids,
+                message,
+                reporter,
+                target,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .packed_uint32(2, reasons_ids)
+                .string(3, message.as_deref().unwrap_or_default())
+                .string(4, reporter.as_str())
+                .message(5, &report_target(target)?),
+            ReportsMsg::DeleteReport {
+                subspace_id,
+                report_id,
+                signer,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint64(2, report_id.u64())
+                .string(3, signer.as_str()),
+            ReportsMsg::SupportStandardReason {
+                subspace_id,
+                standard_reason_id,
+                signer,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint32(2, *standard_reason_id)
+                .string(3, signer.as_str()),
+            ReportsMsg::AddReason {
+                subspace_id,
+                title,
+                description,
+                signer,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .string(2, title)
+                .string(3, description.as_deref().unwrap_or_default())
+                .string(4, signer.as_str()),
+            ReportsMsg::RemoveReason {
+                subspace_id,
+                reason_id,
+                signer,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint32(2, *reason_id)
+                .string(3, signer.as_str()),
+        };
+        Ok(encoded.finish().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reports::msg::MSG_CREATE_REPORT_TYPE_URI;
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn test_create_report_encoding() {
+        let msg = ReportsMsg::create_report(
+            1,
+            vec![1, 2],
+            None::<String>,
+            Addr::unchecked("a"),
+            ReportTarget::Post {
+                post_id: 3u64.into(),
+            },
+        );
+        let expected = ProtoWriter::new()
+            .uint64(1, 1)
+            .message(2, &[0x01, 0x02])
+            .string(4, "a")
+            .message(5, &any(POST_REPORT_TARGET_TYPE_URI, &[0x08, 0x03]))
+            .finish();
+        assert_eq!(MSG_CREATE_REPORT_TYPE_URI, msg.type_url());
+        assert_eq!(Binary::from(expected), msg.to_proto_bytes().unwrap());
+    }
+
+    /// Mirror of the `google.protobuf.Any` protobuf message.
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct ProstAny {
+        #[prost(string, tag = "1")]
+        type_url: String,
+        #[prost(bytes = "vec", tag = "2")]
+        value: Vec<u8>,
+    }
+
+    /// Mirror of the `desmos.reports.v1.UserTarget` protobuf message.
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct ProstUserTarget {
+        #[prost(string, tag = "1")]
+        user: String,
+    }
+
+    /// Mirror of the `desmos.reports.v1.MsgCreateReport` protobuf message.
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct ProstMsgCreateReport {
+        #[prost(uint64, tag = "1")]
+        subspace_id: u64,
+        #[prost(uint32, repeated, tag = "2")]
+        reasons_ids: Vec<u32>,
+        #[prost(string, tag = "3")]
+        message: String,
+        #[prost(string, tag = "4")]
+        reporter: String,
+        #[prost(message, optional, tag = "5")]
+        target: Option<ProstAny>,
+    }
+
+    #[test]
+    fn test_create_report_prost_encoding() {
+        let msg = ReportsMsg::create_report(
+            1,
+            vec![1, 2],
+            Some("spam"),
+            Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3"),
+            ReportTarget::User {
+                user: Addr::unchecked("desmos1ptvq7l4jt7n9sc3fky22mfvc6waf2jd8nuc0jv"),
+            },
+        );
+        let expected = prost::Message::encode_to_vec(&ProstMsgCreateReport {
+            subspace_id: 1,
+            reasons_ids: vec![1, 2],
+            message: "spam".into(),
+            reporter: "desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3".into(),
+            target: Some(ProstAny {
+                type_url: "/desmos.reports.v1.UserTarget".into(),
+                value: prost::Message::encode_to_vec(&ProstUserTarget {
+                    user: "desmos1ptvq7l4jt7n9sc3fky22mfvc6waf2jd8nuc0jv".into(),
+                }),
+            }),
+        });
+        assert_eq!(Binary::from(expected), msg.to_proto_bytes().unwrap());
+    }
+}
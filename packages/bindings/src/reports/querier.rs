@@ -1,13 +1,16 @@
 //! Contains the querier that can be used to query data related to the x/reports module.
 
 use crate::query::DesmosQuery;
-use crate::reports::models::{RawReportTarget, ReportTarget};
+use crate::reports::models::{RawReportTarget, ReportTarget, StandardReason};
 use crate::reports::models_query::{
-    QueryReasonResponse, QueryReasonsResponse, QueryReportResponse, QueryReportsResponse,
+    QueryParamsResponse, QueryReasonResponse, QueryReasonsResponse, QueryReportResponse,
+    QueryReportsResponse,
 };
+use crate::reports::msg::ReportsMsg;
 use crate::reports::query::ReportsQuery;
-use crate::types::PageRequest;
-use cosmwasm_std::{Addr, Querier, QuerierWrapper, StdResult};
+use crate::types::{collect_pages, PageRequest};
+use cosmwasm_std::{Addr, Querier, QuerierWrapper, StdError, StdResult};
+use std::collections::HashSet;
 #[cfg(feature = "iterators")]
 use {
     crate::iter::page_iterator::{Page, PageIterator},
@@ -178,14 +181,79 @@ impl<'a> ReportsQuerier<'a> {
 
         self.querier.query(&request.into())
     }
+
+    /// Queries the standard reasons that subspaces can choose to support.
+    /// They are stored inside the x/reports module params, so they are read from them.
+    pub fn query_standard_reasons(&self) -> StdResult<Vec<StandardReason>> {
+        Ok(self.query_params()?.params.standard_reasons)
+    }
+
+    /// Gives an iterator to scan over the standard reasons that subspaces can choose to support.
+    /// The standard reasons are fetched all at once from the x/reports module params,
+    /// then iterated locally.
+    pub fn iterate_standard_reasons(&self) -> StdResult<impl Iterator<Item = StandardReason>> {
+        Ok(self.query_standard_reasons()?.into_iter())
+    }
+
+    /// Builds the messages needed to make a subspace support the given standard reasons.
+    /// A standard reason is skipped if the subspace already has a reason with the same title.
+    ///
+    /// * `subspace_id` - Id of the subspace that should support the reasons.
+    /// * `standard_reasons_ids` - Ids of the standard reasons that should be supported.
+    /// * `signer` - Address of the user that will sign the messages.
+    pub fn sync_standard_reasons(
+        &self,
+        subspace_id: u64,
+        standard_reasons_ids: &[u32],
+        signer: Addr,
+    ) -> StdResult<Vec<ReportsMsg>> {
+        let standard_reasons = self.query_standard_reasons()?;
+        let mut supported_titles = collect_pages(|pagination| {
+            self.query_reasons(subspace_id, pagination)
+                .map(|response| (response.reasons, response.pagination))
+        })?
+        .into_iter()
+        .map(|reason| reason.title)
+        .collect::<HashSet<_>>();
+
+        let mut msgs = Vec::new();
+        for id in standard_reasons_ids {
+            let standard_reason = standard_reasons
+                .iter()
+                .find(|reason| reason.id == *id)
+                .ok_or_else(|| StdError::not_found(format!("standard reason {}", id)))?;
+            if supported_titles.insert(standard_reason.title.clone()) {
+                msgs.push(ReportsMsg::support_standard_reason(
+                    subspace_id,
+                    *id,
+                    signer.clone(),
+                ));
+            }
+        }
+        Ok(msgs)
+    }
+
+    /// Queries the parameters of the x/reports module.
+    pub fn query_params(&self) -> StdResult<QueryParamsResponse> {
+        let request = DesmosQuery::Reports(ReportsQuery::Params {});
+
+        self.querier.query(&request.into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::mocks::mock_queriers::mock_desmos_dependencies;
-    use crate::reports::mocks::MockReportsQueries;
+    use crate::mocks::mock_queriers::{
+        mock_desmos_dependencies, mock_desmos_dependencies_with_custom_querier, MockDesmosQuerier,
+    };
+    use crate::reports::mocks::{mock_reports_query_response, MockReportsQueries};
+    use crate::reports::models::Reason;
+    use crate::reports::models_query::QueryReasonsResponse;
+    use crate::reports::msg::ReportsMsg;
     use crate::reports::querier::ReportsQuerier;
-    use cosmwasm_std::Uint64;
+    use crate::reports::query::ReportsQuery;
+    use crate::types::PageResponse;
+    use cosmwasm_std::{to_binary, Addr, Binary, StdError, Uint64};
     use std::ops::Deref;
 
     #[test]
@@ -273,4 +341,104 @@ mod tests {
             response.reason
         );
     }
+
+    #[test]
+    fn test_query_params() {
+        let owned_deps = mock_desmos_dependencies();
+        let deps = owned_deps.as_ref();
+        let reports_querier = ReportsQuerier::new(deps.querier.deref());
+
+        let response = reports_querier.query_params().unwrap();
+        assert_eq!(MockReportsQueries::get_mocked_params(), response.params);
+    }
+
+    #[test]
+    fn test_query_standard_reasons() {
+        let owned_deps = mock_desmos_dependencies();
+        let deps = owned_deps.as_ref();
+        let reports_querier = ReportsQuerier::new(deps.querier.deref());
+
+        let standard_reasons = reports_querier.query_standard_reasons().unwrap();
+        assert_eq!(
+            MockReportsQueries::get_mocked_params().standard_reasons,
+            standard_reasons
+        );
+    }
+
+    #[test]
+    fn test_iterate_standard_reasons() {
+        let owned_deps = mock_desmos_dependencies();
+        let deps = owned_deps.as_ref();
+        let reports_querier = ReportsQuerier::new(deps.querier.deref());
+
+        let it = reports_querier.iterate_standard_reasons().unwrap();
+        let items = it.collect::<Vec<_>>();
+        assert_eq!(
+            MockReportsQueries::get_mocked_params().standard_reasons,
+            items
+        );
+    }
+
+    #[test]
+    fn test_sync_standard_reasons() {
+        let owned_deps = mock_desmos_dependencies_with_custom_querier(
+            MockDesmosQuerier::default().with_custom_reports_handler(|query| match query {
+                ReportsQuery::Reasons { subspace_id, .. } => to_binary(&QueryReasonsResponse {
+                    reasons: vec![Reason {
+                        subspace_id: *subspace_id,
+                        id: 1,
+                        title: "Spam".to_string(),
+                        description: None,
+                    }],
+                    pagination: None,
+                })
+                .into(),
+                _ => mock_reports_query_response(query),
+            }),
+        );
+        let deps = owned_deps.as_ref();
+        let reports_querier = ReportsQuerier::new(deps.querier.deref());
+        let signer = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+
+        let msgs = reports_querier
+            .sync_standard_reasons(1, &[1, 2, 2], signer.clone())
+            .unwrap();
+        assert_eq!(
+            vec![ReportsMsg::support_standard_reason(1, 2, signer.clone())],
+            msgs
+        );
+
+        let error = reports_querier
+            .sync_standard_reasons(1, &[3], signer)
+            .unwrap_err();
+        assert_eq!(StdError::not_found("standard reason 3"), error);
+    }
+
+    #[test]
+    fn test_sync_standard_reasons_repeated_next_key() {
+        let owned_deps = mock_desmos_dependencies_with_custom_querier(
+            MockDesmosQuerier::default().with_custom_reports_handler(|query| match query {
+                ReportsQuery::Reasons { subspace_id, .. } => to_binary(&QueryReasonsResponse {
+                    reasons: MockReportsQueries::get_mocked_reasons(subspace_id),
+                    pagination: Some(PageResponse {
+                        next_key: Some(Binary::from(b"key")),
+                        total: None,
+                    }),
+                })
+                .into(),
+                _ => mock_reports_query_response(query),
+            }),
+        );
+        let deps = owned_deps.as_ref();
+        let reports_querier = ReportsQuerier::new(deps.querier.deref());
+        let signer = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+
+        let error = reports_querier
+            .sync_standard_reasons(1, &[1], signer)
+            .unwrap_err();
+        assert_eq!(
+            StdError::generic_err("pagination next key a2V5 has already been returned"),
+            error
+        );
+    }
 }
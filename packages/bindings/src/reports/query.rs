@@ -48,4 +48,7 @@ pub enum ReportsQuery {
         /// Id of the reason to query for.
         reason_id: u32,
     },
+    /// Query the parameters of the x/reports module.
+    #[returns(QueryParamsResponse)]
+    Params {},
 }
@@ -1,7 +1,10 @@
 //! Contains some useful mocks of the Desmos x/subspaces module's types made to be used in any test.
 
-use crate::subspaces::models::{Permission, Section, UserPermissions};
-use crate::subspaces::models_query::{QuerySectionResponse, QuerySectionsResponse};
+use crate::subspaces::models::{Grant, Grantee, Permission, Section, UserPermissions};
+use crate::subspaces::models_query::{
+    QueryGroupAllowancesResponse, QuerySectionResponse, QuerySectionsResponse,
+    QueryUserAllowancesResponse,
+};
 use crate::subspaces::{
     models::{PermissionDetail, Subspace, UserGroup},
     models_query::{
@@ -10,7 +13,8 @@ use crate::subspaces::{
     },
     query::SubspacesQuery,
 };
-use cosmwasm_std::{to_binary, Addr, Binary, ContractResult, Uint64};
+use crate::types::Allowance;
+use cosmwasm_std::{coins, to_binary, Addr, Binary, ContractResult, Uint64};
 
 /// Struct that contains some utility methods to mock data of the Desmos
 /// x/subspaces module.
@@ -70,6 +74,37 @@ impl MockSubspacesQueries {
             group: None,
         }
     }
+
+    /// Gets a mocked instance of [`Grant`] made to a user.
+    pub fn get_mock_user_grant() -> Grant {
+        Grant {
+            subspace_id: Uint64::new(1),
+            granter: Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+            grantee: Grantee::User {
+                user: Addr::unchecked("cosmos1qzskhrcjnkdz2ln4yeafzsdwht8ch08j4wed69"),
+            }
+            .into(),
+            allowance: Allowance::Basic {
+                spend_limit: coins(100, "udsm"),
+                expiration: None,
+            }
+            .into(),
+        }
+    }
+
+    /// Gets a mocked instance of [`Grant`] made to a user group.
+    pub fn get_mock_group_grant() -> Grant {
+        Grant {
+            subspace_id: Uint64::new(1),
+            granter: Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+            grantee: Grantee::Group { group_id: 1 }.into(),
+            allowance: Allowance::Basic {
+                spend_limit: coins(100, "udsm"),
+                expiration: None,
+            }
+            .into(),
+        }
+    }
 }
 
 /// Functions that mocks the subspaces query responses.
@@ -118,6 +153,14 @@ pub fn mock_subspaces_query_response(query: &SubspacesQuery) -> ContractResult<B
                 details: vec![permission],
             })
         }
+        SubspacesQuery::UserAllowances { .. } => to_binary(&QueryUserAllowancesResponse {
+            grants: vec![MockSubspacesQueries::get_mock_user_grant()],
+            pagination: Default::default(),
+        }),
+        SubspacesQuery::GroupAllowances { .. } => to_binary(&QueryGroupAllowancesResponse {
+            grants: vec![MockSubspacesQueries::get_mock_group_grant()],
+            pagination: Default::default(),
+        }),
     };
     response.into()
 }
@@ -208,4 +251,34 @@ mod tests {
         });
         assert_eq!(response.into_result().ok(), expected.ok());
     }
+
+    #[test]
+    fn test_query_user_allowances() {
+        let query = SubspacesQuery::UserAllowances {
+            subspace_id: Uint64::new(1),
+            grantee: None,
+            pagination: Default::default(),
+        };
+        let response = mock_subspaces_query_response(&query);
+        let expected = to_binary(&QueryUserAllowancesResponse {
+            grants: vec![MockSubspacesQueries::get_mock_user_grant()],
+            pagination: Default::default(),
+        });
+        assert_eq!(response.into_result().ok(), expected.ok());
+    }
+
+    #[test]
+    fn test_query_group_allowances() {
+        let query = SubspacesQuery::GroupAllowances {
+            subspace_id: Uint64::new(1),
+            group_id: None,
+            pagination: Default::default(),
+        };
+        let response = mock_subspaces_query_response(&query);
+        let expected = to_binary(&QueryGroupAllowancesResponse {
+            grants: vec![MockSubspacesQueries::get_mock_group_grant()],
+            pagination: Default::default(),
+        });
+        assert_eq!(response.into_result().ok(), expected.ok());
+    }
 }
@@ -5,5 +5,8 @@ pub mod mocks;
 pub mod models;
 pub mod models_query;
 pub mod msg;
+pub mod msg_builders;
+#[cfg(feature = "authz")]
+pub mod msg_proto;
 pub mod querier;
 pub mod query;
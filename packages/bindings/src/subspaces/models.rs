@@ -1,7 +1,13 @@
 //! Contains structs and enums related to the x/subspaces module.
 
+use crate::types::RawAllowance;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint64};
+use std::convert::TryFrom;
+use thiserror::Error;
+
+pub(crate) const USER_GRANTEE_TYPE_URI: &str = "/desmos.subspaces.v3.UserGrantee";
+pub(crate) const GROUP_GRANTEE_TYPE_URI: &str = "/desmos.subspaces.v3.GroupGrantee";
 
 /// Struct that represents a subspace.
 #[cw_serde]
@@ -122,3 +128,147 @@ pub enum Permission {
     #[serde(rename = "MODERATE_CONTENT")]
     ModerateContent,
 }
+
+/// Serializable representation of [`Grantee`].
+/// To create an instance of this struct use the `into()` method of [`Grantee`].
+#[cw_serde]
+pub struct RawGrantee {
+    #[serde(rename = "@type")]
+    type_uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<Addr>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group_id: Option<u32>,
+}
+
+/// Types of grantee that can receive a subspace fee allowance.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Grantee {
+    /// Represents a single user of the subspace.
+    User {
+        /// Address of the user.
+        user: Addr,
+    },
+    /// Represents all the members of a user group.
+    Group {
+        /// Id of the user group.
+        group_id: u32,
+    },
+}
+
+impl From<Grantee> for RawGrantee {
+    fn from(grantee: Grantee) -> Self {
+        match grantee {
+            Grantee::User { user } => RawGrantee {
+                type_uri: USER_GRANTEE_TYPE_URI.to_string(),
+                user: Some(user),
+                group_id: None,
+            },
+            Grantee::Group { group_id } => RawGrantee {
+                type_uri: GROUP_GRANTEE_TYPE_URI.to_string(),
+                user: None,
+                group_id: Some(group_id),
+            },
+        }
+    }
+}
+
+/// Represents the errors that can occur when converting a [`RawGrantee`] into a [`Grantee`].
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum UnwrapGranteeError {
+    /// Error that occurs if [`RawGrantee`] has an unknown type.
+    #[error("unknown grantee type: {0}")]
+    UnknownType(String),
+    /// Error that occurs if [`RawGrantee`] has type `/desmos.subspaces.v3.UserGrantee` but
+    /// one field is undefined.
+    #[error("invalid user grantee field {0} is none")]
+    InvalidUserGrantee(String),
+    /// Error that occurs if [`RawGrantee`] has type `/desmos.subspaces.v3.GroupGrantee` but
+    /// one field is undefined.
+    #[error("invalid group grantee field {0} is none")]
+    InvalidGroupGrantee(String),
+}
+
+impl TryFrom<RawGrantee> for Grantee {
+    type Error = UnwrapGranteeError;
+
+    fn try_from(value: RawGrantee) -> Result<Self, Self::Error> {
+        match value.type_uri.as_str() {
+            USER_GRANTEE_TYPE_URI => Ok(Grantee::User {
+                user: value
+                    .user
+                    .ok_or_else(|| UnwrapGranteeError::InvalidUserGrantee("user".to_string()))?,
+            }),
+            GROUP_GRANTEE_TYPE_URI => Ok(Grantee::Group {
+                group_id: value.group_id.ok_or_else(|| {
+                    UnwrapGranteeError::InvalidGroupGrantee("group_id".to_string())
+                })?,
+            }),
+            _ => Err(UnwrapGranteeError::UnknownType(value.type_uri)),
+        }
+    }
+}
+
+/// Represents a fee allowance granted by a subspace treasury.
+#[cw_serde]
+pub struct Grant {
+    /// Id of the subspace inside which the allowance is valid.
+    pub subspace_id: Uint64,
+    /// Address of the user that granted the allowance.
+    pub granter: Addr,
+    /// Target of the allowance.
+    pub grantee: RawGrantee,
+    /// Allowance granted to the grantee.
+    pub allowance: RawAllowance,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grantee_raw_conversion() {
+        let grantee = Grantee::User {
+            user: Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc"),
+        };
+        let raw: RawGrantee = grantee.clone().into();
+        assert_eq!(grantee, Grantee::try_from(raw).unwrap());
+
+        let grantee = Grantee::Group { group_id: 1 };
+        let raw: RawGrantee = grantee.clone().into();
+        assert_eq!(grantee, Grantee::try_from(raw).unwrap());
+    }
+
+    #[test]
+    fn test_grantee_try_from_invalid_raw_error() {
+        let raw = RawGrantee {
+            type_uri: "/desmos.subspaces.v3.InvalidGrantee".to_string(),
+            user: None,
+            group_id: None,
+        };
+        assert_eq!(
+            UnwrapGranteeError::UnknownType("/desmos.subspaces.v3.InvalidGrantee".to_string()),
+            Grantee::try_from(raw).unwrap_err()
+        );
+
+        let raw = RawGrantee {
+            type_uri: USER_GRANTEE_TYPE_URI.to_string(),
+            user: None,
+            group_id: None,
+        };
+        assert_eq!(
+            UnwrapGranteeError::InvalidUserGrantee("user".to_string()),
+            Grantee::try_from(raw).unwrap_err()
+        );
+
+        let raw = RawGrantee {
+            type_uri: GROUP_GRANTEE_TYPE_URI.to_string(),
+            user: None,
+            group_id: None,
+        };
+        assert_eq!(
+            UnwrapGranteeError::InvalidGroupGrantee("group_id".to_string()),
+            Grantee::try_from(raw).unwrap_err()
+        );
+    }
+}
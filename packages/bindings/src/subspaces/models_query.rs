@@ -1,6 +1,6 @@
 //! Contains the types definitions of all the responses to the x/subspaces query messages.
 
-use crate::subspaces::models::{Permission, Section};
+use crate::subspaces::models::{Grant, Permission, Section};
 use crate::{
     subspaces::models::{PermissionDetail, Subspace, UserGroup},
     types::PageResponse,
@@ -73,3 +73,21 @@ pub struct QueryUserPermissionsResponse {
     /// List of the user's permissions.
     pub details: Vec<PermissionDetail>,
 }
+
+/// Response to [`UserAllowances`](crate::subspaces::query::SubspacesQuery::UserAllowances).
+#[cw_serde]
+pub struct QueryUserAllowancesResponse {
+    /// Allowances granted to users.
+    pub grants: Vec<Grant>,
+    /// Details of the current fetched page.
+    pub pagination: Option<PageResponse>,
+}
+
+/// Response to [`GroupAllowances`](crate::subspaces::query::SubspacesQuery::GroupAllowances).
+#[cw_serde]
+pub struct QueryGroupAllowancesResponse {
+    /// Allowances granted to user groups.
+    pub grants: Vec<Grant>,
+    /// Details of the current fetched page.
+    pub pagination: Option<PageResponse>,
+}
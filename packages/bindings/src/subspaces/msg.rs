@@ -1,9 +1,53 @@
 //! Contains the messages that can be sent to the chain to interact with the x/subspaces module.
 
-use crate::subspaces::models::Permission;
+use crate::subspaces::models::{Grantee, Permission, RawGrantee};
+use crate::types::{Allowance, Authorization, AuthzGrant, RawAllowance};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint64};
 
+/// Type url of [`SubspacesMsg::CreateSubspace`].
+pub const MSG_CREATE_SUBSPACE_TYPE_URI: &str = "/desmos.subspaces.v3.MsgCreateSubspace";
+/// Type url of [`SubspacesMsg::EditSubspace`].
+pub const MSG_EDIT_SUBSPACE_TYPE_URI: &str = "/desmos.subspaces.v3.MsgEditSubspace";
+/// Type url of [`SubspacesMsg::DeleteSubspace`].
+pub const MSG_DELETE_SUBSPACE_TYPE_URI: &str = "/desmos.subspaces.v3.MsgDeleteSubspace";
+/// Type url of [`SubspacesMsg::CreateSection`].
+pub const MSG_CREATE_SECTION_TYPE_URI: &str = "/desmos.subspaces.v3.MsgCreateSection";
+/// Type url of [`SubspacesMsg::EditSection`].
+pub const MSG_EDIT_SECTION_TYPE_URI: &str = "/desmos.subspaces.v3.MsgEditSection";
+/// Type url of [`SubspacesMsg::MoveSection`].
+pub const MSG_MOVE_SECTION_TYPE_URI: &str = "/desmos.subspaces.v3.MsgMoveSection";
+/// Type url of [`SubspacesMsg::DeleteSection`].
+pub const MSG_DELETE_SECTION_TYPE_URI: &str = "/desmos.subspaces.v3.MsgDeleteSection";
+/// Type url of [`SubspacesMsg::CreateUserGroup`].
+pub const MSG_CREATE_USER_GROUP_TYPE_URI: &str = "/desmos.subspaces.v3.MsgCreateUserGroup";
+/// Type url of [`SubspacesMsg::EditUserGroup`].
+pub const MSG_EDIT_USER_GROUP_TYPE_URI: &str = "/desmos.subspaces.v3.MsgEditUserGroup";
+/// Type url of [`SubspacesMsg::MoveUserGroup`].
+pub const MSG_MOVE_USER_GROUP_TYPE_URI: &str = "/desmos.subspaces.v3.MsgMoveUserGroup";
+/// Type url of [`SubspacesMsg::SetUserGroupPermissions`].
+pub const MSG_SET_USER_GROUP_PERMISSIONS_TYPE_URI: &str =
+    "/desmos.subspaces.v3.MsgSetUserGroupPermissions";
+/// Type url of [`SubspacesMsg::DeleteUserGroup`].
+pub const MSG_DELETE_USER_GROUP_TYPE_URI: &str = "/desmos.subspaces.v3.MsgDeleteUserGroup";
+/// Type url of [`SubspacesMsg::AddUserToUserGroup`].
+pub const MSG_ADD_USER_TO_USER_GROUP_TYPE_URI: &str = "/desmos.subspaces.v3.MsgAddUserToUserGroup";
+/// Type url of [`SubspacesMsg::RemoveUserFromUserGroup`].
+pub const MSG_REMOVE_USER_FROM_USER_GROUP_TYPE_URI: &str =
+    "/desmos.subspaces.v3.MsgRemoveUserFromUserGroup";
+/// Type url of [`SubspacesMsg::SetUserPermissions`].
+pub const MSG_SET_USER_PERMISSIONS_TYPE_URI: &str = "/desmos.subspaces.v3.MsgSetUserPermissions";
+/// Type url of [`SubspacesMsg::GrantTreasuryAuthorization`].
+pub const MSG_GRANT_TREASURY_AUTHORIZATION_TYPE_URI: &str =
+    "/desmos.subspaces.v3.MsgGrantTreasuryAuthorization";
+/// Type url of [`SubspacesMsg::RevokeTreasuryAuthorization`].
+pub const MSG_REVOKE_TREASURY_AUTHORIZATION_TYPE_URI: &str =
+    "/desmos.subspaces.v3.MsgRevokeTreasuryAuthorization";
+/// Type url of [`SubspacesMsg::GrantAllowance`].
+pub const MSG_GRANT_ALLOWANCE_TYPE_URI: &str = "/desmos.subspaces.v3.MsgGrantAllowance";
+/// Type url of [`SubspacesMsg::RevokeAllowance`].
+pub const MSG_REVOKE_ALLOWANCE_TYPE_URI: &str = "/desmos.subspaces.v3.MsgRevokeAllowance";
+
 /// Represents the messages to interact with the x/subspaces module.
 #[cw_serde]
 pub enum SubspacesMsg {
@@ -188,6 +232,48 @@ pub enum SubspacesMsg {
         /// Address of who wants update the user's permissions.
         signer: Addr,
     },
+    /// Message to grant an authorization on behalf of the subspace treasury.
+    GrantTreasuryAuthorization {
+        /// Id of the subspace whose treasury grants the authorization.
+        subspace_id: Uint64,
+        /// Address of the user granting the authorization.
+        granter: Addr,
+        /// Address of the user receiving the authorization.
+        grantee: Addr,
+        /// Authorization to be granted.
+        grant: AuthzGrant,
+    },
+    /// Message to revoke an authorization previously granted on behalf of the subspace treasury.
+    RevokeTreasuryAuthorization {
+        /// Id of the subspace whose treasury granted the authorization.
+        subspace_id: Uint64,
+        /// Address of the user revoking the authorization.
+        granter: Addr,
+        /// Address of the user whose authorization is revoked.
+        grantee: Addr,
+        /// Type url of the message whose authorization is revoked.
+        msg_type_url: String,
+    },
+    /// Message to grant a fee allowance paid by the subspace treasury.
+    GrantAllowance {
+        /// Id of the subspace inside which the allowance is valid.
+        subspace_id: Uint64,
+        /// Address of the user granting the allowance.
+        granter: Addr,
+        /// Target of the allowance.
+        grantee: RawGrantee,
+        /// Allowance to be granted.
+        allowance: RawAllowance,
+    },
+    /// Message to revoke a fee allowance previously granted inside a subspace.
+    RevokeAllowance {
+        /// Id of the subspace inside which the allowance is valid.
+        subspace_id: Uint64,
+        /// Address of the user revoking the allowance.
+        granter: Addr,
+        /// Target of the allowance to revoke.
+        grantee: RawGrantee,
+    },
 }
 
 impl SubspacesMsg {
@@ -499,6 +585,141 @@ impl SubspacesMsg {
             signer,
         }
     }
+
+    /// Creates a new instance of [`SubspacesMsg::GrantTreasuryAuthorization`].
+    ///
+    /// * `subspace_id` - Id of the subspace whose treasury grants the authorization.
+    /// * `granter` - Address of the user granting the authorization.
+    /// * `grantee` - Address of the user receiving the authorization.
+    /// * `authorization` - Authorization to be granted.
+    /// * `expiration` - Time in RFC 3339 format after which the authorization expires.
+    pub fn grant_treasury_authorization(
+        subspace_id: u64,
+        granter: Addr,
+        grantee: Addr,
+        authorization: Authorization,
+        expiration: Option<String>,
+    ) -> SubspacesMsg {
+        SubspacesMsg::GrantTreasuryAuthorization {
+            subspace_id: subspace_id.into(),
+            granter,
+            grantee,
+            grant: AuthzGrant {
+                authorization: authorization.into(),
+                expiration,
+            },
+        }
+    }
+
+    /// Creates a new instance of [`SubspacesMsg::RevokeTreasuryAuthorization`].
+    ///
+    /// * `subspace_id` - Id of the subspace whose treasury granted the authorization.
+    /// * `granter` - Address of the user revoking the authorization.
+    /// * `grantee` - Address of the user whose authorization is revoked.
+    /// * `msg_type_url` - Type url of the message whose authorization is revoked.
+    pub fn revoke_treasury_authorization(
+        subspace_id: u64,
+        granter: Addr,
+        grantee: Addr,
+        msg_type_url: &str,
+    ) -> SubspacesMsg {
+        SubspacesMsg::RevokeTreasuryAuthorization {
+            subspace_id: subspace_id.into(),
+            granter,
+            grantee,
+            msg_type_url: msg_type_url.into(),
+        }
+    }
+
+    /// Creates a new instance of [`SubspacesMsg::GrantAllowance`].
+    ///
+    /// * `subspace_id` - Id of the subspace inside which the allowance is valid.
+    /// * `granter` - Address of the user granting the allowance.
+    /// * `grantee` - Target of the allowance.
+    /// * `allowance` - Allowance to be granted.
+    pub fn grant_allowance(
+        subspace_id: u64,
+        granter: Addr,
+        grantee: Grantee,
+        allowance: Allowance,
+    ) -> SubspacesMsg {
+        SubspacesMsg::GrantAllowance {
+            subspace_id: subspace_id.into(),
+            granter,
+            grantee: grantee.into(),
+            allowance: allowance.into(),
+        }
+    }
+
+    /// Creates a new instance of [`SubspacesMsg::RevokeAllowance`].
+    ///
+    /// * `subspace_id` - Id of the subspace inside which the allowance is valid.
+    /// * `granter` - Address of the user revoking the allowance.
+    /// * `grantee` - Target of the allowance to revoke.
+    pub fn revoke_allowance(subspace_id: u64, granter: Addr, grantee: Grantee) -> SubspacesMsg {
+        SubspacesMsg::RevokeAllowance {
+            subspace_id: subspace_id.into(),
+            granter,
+            grantee: grantee.into(),
+        }
+    }
+
+    /// Returns the address that must sign the message, which is the one that
+    /// the chain checks when the message is dispatched.
+    pub fn signer(&self) -> &Addr {
+        match self {
+            SubspacesMsg::CreateSubspace { creator, .. }
+            | SubspacesMsg::CreateSection { creator, .. }
+            | SubspacesMsg::CreateUserGroup { creator, .. } => creator,
+            SubspacesMsg::EditSection { editor, .. } => editor,
+            SubspacesMsg::EditSubspace { signer, .. }
+            | SubspacesMsg::DeleteSubspace { signer, .. }
+            | SubspacesMsg::MoveSection { signer, .. }
+            | SubspacesMsg::DeleteSection { signer, .. }
+            | SubspacesMsg::EditUserGroup { signer, .. }
+            | SubspacesMsg::MoveUserGroup { signer, .. }
+            | SubspacesMsg::SetUserGroupPermissions { signer, .. }
+            | SubspacesMsg::DeleteUserGroup { signer, .. }
+            | SubspacesMsg::AddUserToUserGroup { signer, .. }
+            | SubspacesMsg::RemoveUserFromUserGroup { signer, .. }
+            | SubspacesMsg::SetUserPermissions { signer, .. } => signer,
+            SubspacesMsg::GrantTreasuryAuthorization { granter, .. }
+            | SubspacesMsg::RevokeTreasuryAuthorization { granter, .. }
+            | SubspacesMsg::GrantAllowance { granter, .. }
+            | SubspacesMsg::RevokeAllowance { granter, .. } => granter,
+        }
+    }
+
+    /// Returns the type url that identifies the message on chain.
+    pub fn type_url(&self) -> &'static str {
+        match self {
+            SubspacesMsg::CreateSubspace { .. } => MSG_CREATE_SUBSPACE_TYPE_URI,
+            SubspacesMsg::EditSubspace { .. } => MSG_EDIT_SUBSPACE_TYPE_URI,
+            SubspacesMsg::DeleteSubspace { .. } => MSG_DELETE_SUBSPACE_TYPE_URI,
+            SubspacesMsg::CreateSection { .. } => MSG_CREATE_SECTION_TYPE_URI,
+            SubspacesMsg::EditSection { .. } => MSG_EDIT_SECTION_TYPE_URI,
+            SubspacesMsg::MoveSection { .. } => MSG_MOVE_SECTION_TYPE_URI,
+            SubspacesMsg::DeleteSection { .. } => MSG_DELETE_SECTION_TYPE_URI,
+            SubspacesMsg::CreateUserGroup { .. } => MSG_CREATE_USER_GROUP_TYPE_URI,
+            SubspacesMsg::EditUserGroup { .. } => MSG_EDIT_USER_GROUP_TYPE_URI,
+            SubspacesMsg::MoveUserGroup { .. } => MSG_MOVE_USER_GROUP_TYPE_URI,
+            SubspacesMsg::SetUserGroupPermissions { .. } => MSG_SET_USER_GROUP_PERMISSIONS_TYPE_URI,
+            SubspacesMsg::DeleteUserGroup { .. } => MSG_DELETE_USER_GROUP_TYPE_URI,
+            SubspacesMsg::AddUserToUserGroup { .. } => MSG_ADD_USER_TO_USER_GROUP_TYPE_URI,
+            SubspacesMsg::RemoveUserFromUserGroup { .. } => {
+                MSG_REMOVE_USER_FROM_USER_GROUP_TYPE_URI
+            }
+            SubspacesMsg::SetUserPermissions { .. } => MSG_SET_USER_PERMISSIONS_TYPE_URI,
+            SubspacesMsg::GrantTreasuryAuthorization { .. } => {
+                MSG_GRANT_TREASURY_AUTHORIZATION_TYPE_URI
+            }
+            SubspacesMsg::RevokeTreasuryAuthorization { .. } => {
+                MSG_REVOKE_TREASURY_AUTHORIZATION_TYPE_URI
+            }
+            SubspacesMsg::GrantAllowance { .. } => MSG_GRANT_ALLOWANCE_TYPE_URI,
+            SubspacesMsg::RevokeAllowance { .. } => MSG_REVOKE_ALLOWANCE_TYPE_URI,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -772,4 +993,86 @@ mod tests {
         };
         assert_eq!(msg, expected)
     }
+
+    #[test]
+    fn test_grant_treasury_authorization() {
+        let msg = SubspacesMsg::grant_treasury_authorization(
+            1,
+            Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+            Addr::unchecked("cosmos18atyyv6zycryhvnhpr2mjxgusdcah6kdpkffq0"),
+            Authorization::Generic {
+                msg: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            },
+            None,
+        );
+        let expected = SubspacesMsg::GrantTreasuryAuthorization {
+            subspace_id: Uint64::new(1),
+            granter: Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+            grantee: Addr::unchecked("cosmos18atyyv6zycryhvnhpr2mjxgusdcah6kdpkffq0"),
+            grant: AuthzGrant {
+                authorization: Authorization::Generic {
+                    msg: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                }
+                .into(),
+                expiration: None,
+            },
+        };
+        assert_eq!(msg, expected)
+    }
+
+    #[test]
+    fn test_revoke_treasury_authorization() {
+        let msg = SubspacesMsg::revoke_treasury_authorization(
+            1,
+            Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+            Addr::unchecked("cosmos18atyyv6zycryhvnhpr2mjxgusdcah6kdpkffq0"),
+            "/cosmos.bank.v1beta1.MsgSend",
+        );
+        let expected = SubspacesMsg::RevokeTreasuryAuthorization {
+            subspace_id: Uint64::new(1),
+            granter: Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+            grantee: Addr::unchecked("cosmos18atyyv6zycryhvnhpr2mjxgusdcah6kdpkffq0"),
+            msg_type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+        };
+        assert_eq!(msg, expected)
+    }
+
+    #[test]
+    fn test_grant_allowance() {
+        let allowance = Allowance::Basic {
+            spend_limit: vec![],
+            expiration: None,
+        };
+        let msg = SubspacesMsg::grant_allowance(
+            1,
+            Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+            Grantee::Group { group_id: 1 },
+            allowance.clone(),
+        );
+        let expected = SubspacesMsg::GrantAllowance {
+            subspace_id: Uint64::new(1),
+            granter: Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+            grantee: Grantee::Group { group_id: 1 }.into(),
+            allowance: allowance.into(),
+        };
+        assert_eq!(msg, expected)
+    }
+
+    #[test]
+    fn test_revoke_allowance() {
+        let grantee = Grantee::User {
+            user: Addr::unchecked("cosmos18atyyv6zycryhvnhpr2mjxgusdcah6kdpkffq0"),
+        };
+        let msg = SubspacesMsg::revoke_allowance(
+            1,
+            Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+            grantee.clone(),
+        );
+        let expected = SubspacesMsg::RevokeAllowance {
+            subspace_id: Uint64::new(1),
+            granter: Addr::unchecked("cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t"),
+            grantee: grantee.into(),
+        };
+        assert_eq!(msg, expected)
+    }
 }
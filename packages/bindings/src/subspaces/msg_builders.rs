@@ -0,0 +1,937 @@
+//! Contains the builders that can be used to create the [`SubspacesMsg`] variants
+//! having many fields.
+
+use crate::subspaces::models::Permission;
+use crate::subspaces::msg::SubspacesMsg;
+use cosmwasm_std::Addr;
+use thiserror::Error;
+
+pub(crate) const DO_NOT_MODIFY: &str = "[do-not-modify]";
+
+/// Represents the errors that can occur while building a [`SubspacesMsg`].
+#[derive(Error, Debug, PartialEq)]
+pub enum SubspacesMsgBuilderError {
+    /// Error returned when the subspace id is 0.
+    #[error("invalid subspace id: {0}")]
+    InvalidSubspaceId(u64),
+    /// Error returned when an empty name is provided.
+    #[error("name cannot be empty")]
+    EmptyName,
+    /// Error returned when trying to move the root section.
+    #[error("invalid section id: {0}")]
+    InvalidSectionId(u32),
+    /// Error returned when a section is moved inside itself.
+    #[error("section cannot be moved inside itself")]
+    SelfParent,
+    /// Error returned when trying to modify the default user group.
+    #[error("invalid group id: {0}")]
+    InvalidGroupId(u32),
+}
+
+fn validate_subspace_id(subspace_id: u64) -> Result<(), SubspacesMsgBuilderError> {
+    if subspace_id == 0 {
+        return Err(SubspacesMsgBuilderError::InvalidSubspaceId(subspace_id));
+    }
+    Ok(())
+}
+
+fn validate_group_id(group_id: u32) -> Result<(), SubspacesMsgBuilderError> {
+    if group_id == 0 {
+        return Err(SubspacesMsgBuilderError::InvalidGroupId(group_id));
+    }
+    Ok(())
+}
+
+fn validate_name(name: &str) -> Result<(), SubspacesMsgBuilderError> {
+    if name.trim().is_empty() {
+        return Err(SubspacesMsgBuilderError::EmptyName);
+    }
+    Ok(())
+}
+
+/// Builder to create a [`SubspacesMsg::CreateSubspace`].
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::Addr;
+/// use desmos_bindings::subspaces::msg_builders::CreateSubspaceBuilder;
+///
+/// let msg = CreateSubspaceBuilder::new("Test subspace", Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3"))
+///     .description("Subspace used for testing")
+///     .build()
+///     .unwrap();
+/// ```
+pub struct CreateSubspaceBuilder {
+    name: String,
+    description: String,
+    treasury: Option<Addr>,
+    owner: Option<Addr>,
+    creator: Addr,
+}
+
+impl CreateSubspaceBuilder {
+    /// Creates a new builder for a subspace without description and treasury
+    /// whose owner is the creator itself.
+    ///
+    /// * `name` - Subspace name.
+    /// * `creator` - Address of who wants to create the subspace.
+    pub fn new(name: &str, creator: Addr) -> Self {
+        CreateSubspaceBuilder {
+            name: name.to_owned(),
+            description: String::new(),
+            treasury: None,
+            owner: None,
+            creator,
+        }
+    }
+
+    /// Sets the subspace description.
+    ///
+    /// * `description` - Subspace description.
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = description.to_owned();
+        self
+    }
+
+    /// Sets the subspace treasury.
+    ///
+    /// * `treasury` - Address that will pay for the fees needed to performs application links.
+    pub fn treasury(mut self, treasury: Addr) -> Self {
+        self.treasury = Some(treasury);
+        self
+    }
+
+    /// Sets the subspace owner.
+    ///
+    /// * `owner` - Address of who will be the subspace owner.
+    pub fn owner(mut self, owner: Addr) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+
+    /// Builds the [`SubspacesMsg::CreateSubspace`].
+    pub fn build(self) -> Result<SubspacesMsg, SubspacesMsgBuilderError> {
+        validate_name(&self.name)?;
+
+        let creator = self.creator;
+        Ok(SubspacesMsg::create_subspace(
+            &self.name,
+            &self.description,
+            self.treasury.unwrap_or_else(|| Addr::unchecked("")),
+            self.owner.unwrap_or_else(|| creator.clone()),
+            creator,
+        ))
+    }
+}
+
+/// Builder to create a [`SubspacesMsg::EditSubspace`].
+/// Fields that are not set are left untouched on chain.
+pub struct EditSubspaceBuilder {
+    subspace_id: u64,
+    name: String,
+    description: String,
+    treasury: Addr,
+    owner: Addr,
+    signer: Addr,
+}
+
+impl EditSubspaceBuilder {
+    /// Creates a new builder that doesn't modify any subspace field.
+    ///
+    /// * `subspace_id` - Id of the subspace to edit.
+    /// * `signer` - Address of who wants edit the subspace.
+    pub fn new(subspace_id: u64, signer: Addr) -> Self {
+        EditSubspaceBuilder {
+            subspace_id,
+            name: DO_NOT_MODIFY.to_string(),
+            description: DO_NOT_MODIFY.to_string(),
+            treasury: Addr::unchecked(DO_NOT_MODIFY),
+            owner: Addr::unchecked(DO_NOT_MODIFY),
+            signer,
+        }
+    }
+
+    /// Sets the new subspace name.
+    ///
+    /// * `name` - New subspace name.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = name.to_owned();
+        self
+    }
+
+    /// Sets the new subspace description.
+    ///
+    /// * `description` - New subspace description.
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = description.to_owned();
+        self
+    }
+
+    /// Sets the new subspace treasury.
+    ///
+    /// * `treasury` - New subspace treasury.
+    pub fn treasury(mut self, treasury: Addr) -> Self {
+        self.treasury = treasury;
+        self
+    }
+
+    /// Sets the new subspace owner.
+    ///
+    /// * `owner` - New subspace owner.
+    pub fn owner(mut self, owner: Addr) -> Self {
+        self.owner = owner;
+        self
+    }
+
+    /// Builds the [`SubspacesMsg::EditSubspace`].
+    pub fn build(self) -> Result<SubspacesMsg, SubspacesMsgBuilderError> {
+        validate_subspace_id(self.subspace_id)?;
+        validate_name(&self.name)?;
+
+        Ok(SubspacesMsg::edit_subspace(
+            self.subspace_id,
+            &self.name,
+            &self.description,
+            self.treasury,
+            self.owner,
+            self.signer,
+        ))
+    }
+}
+
+/// Builder to create a [`SubspacesMsg::CreateSection`].
+pub struct CreateSectionBuilder {
+    subspace_id: u64,
+    name: String,
+    description: Option<String>,
+    parent_id: Option<u32>,
+    creator: Addr,
+}
+
+impl CreateSectionBuilder {
+    /// Creates a new builder for a section without description placed inside the root section.
+    ///
+    /// * `subspace_id` - Id of the subspace inside which the section will be placed.
+    /// * `name` - Name of the section to be created.
+    /// * `creator` - User creating the section.
+    pub fn new(subspace_id: u64, name: &str, creator: Addr) -> Self {
+        CreateSectionBuilder {
+            subspace_id,
+            name: name.to_owned(),
+            description: None,
+            parent_id: None,
+            creator,
+        }
+    }
+
+    /// Sets the section description.
+    ///
+    /// * `description` - Description of the section.
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_owned());
+        self
+    }
+
+    /// Sets the parent section.
+    ///
+    /// * `parent_id` - Id of the parent section.
+    pub fn parent_id(mut self, parent_id: u32) -> Self {
+        self.parent_id = Some(parent_id);
+        self
+    }
+
+    /// Builds the [`SubspacesMsg::CreateSection`].
+    pub fn build(self) -> Result<SubspacesMsg, SubspacesMsgBuilderError> {
+        validate_subspace_id(self.subspace_id)?;
+        validate_name(&self.name)?;
+
+        Ok(SubspacesMsg::create_section(
+            self.subspace_id,
+            self.name,
+            self.description,
+            self.parent_id,
+            self.creator,
+        ))
+    }
+}
+
+/// Builder to create a [`SubspacesMsg::EditSection`].
+pub struct EditSectionBuilder {
+    subspace_id: u64,
+    section_id: u32,
+    name: Option<String>,
+    description: Option<String>,
+    editor: Addr,
+}
+
+impl EditSectionBuilder {
+    /// Creates a new builder that doesn't set any section field.
+    ///
+    /// * `subspace_id` - Id of the subspace inside which the section to be edited is.
+    /// * `section_id` - Id of the section to be edited.
+    /// * `editor` - User editing the section.
+    pub fn new(subspace_id: u64, section_id: u32, editor: Addr) -> Self {
+        EditSectionBuilder {
+            subspace_id,
+            section_id,
+            name: None,
+            description: None,
+            editor,
+        }
+    }
+
+    /// Sets the new section name.
+    ///
+    /// * `name` - New name of the section.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    /// Sets the new section description.
+    ///
+    /// * `description` - New description of the section.
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_owned());
+        self
+    }
+
+    /// Builds the [`SubspacesMsg::EditSection`].
+    pub fn build(self) -> Result<SubspacesMsg, SubspacesMsgBuilderError> {
+        validate_subspace_id(self.subspace_id)?;
+
+        Ok(SubspacesMsg::edit_section(
+            self.subspace_id,
+            self.section_id,
+            self.name,
+            self.description,
+            self.editor,
+        ))
+    }
+}
+
+/// Builder to create a [`SubspacesMsg::MoveSection`].
+pub struct MoveSectionBuilder {
+    subspace_id: u64,
+    section_id: u32,
+    new_parent_id: u32,
+    signer: Addr,
+}
+
+impl MoveSectionBuilder {
+    /// Creates a new builder that moves the section inside the root section.
+    ///
+    /// * `subspace_id` - Id of the subspace inside which the section lies.
+    /// * `section_id` - Id of the section to be moved.
+    /// * `signer` - User moving the section.
+    pub fn new(subspace_id: u64, section_id: u32, signer: Addr) -> Self {
+        MoveSectionBuilder {
+            subspace_id,
+            section_id,
+            new_parent_id: 0,
+            signer,
+        }
+    }
+
+    /// Sets the section inside which the section will be moved.
+    ///
+    /// * `new_parent_id` - Id of the new parent section.
+    pub fn new_parent_id(mut self, new_parent_id: u32) -> Self {
+        self.new_parent_id = new_parent_id;
+        self
+    }
+
+    /// Builds the [`SubspacesMsg::MoveSection`].
+    pub fn build(self) -> Result<SubspacesMsg, SubspacesMsgBuilderError> {
+        validate_subspace_id(self.subspace_id)?;
+        if self.section_id == 0 {
+            return Err(SubspacesMsgBuilderError::InvalidSectionId(self.section_id));
+        }
+        if self.section_id == self.new_parent_id {
+            return Err(SubspacesMsgBuilderError::SelfParent);
+        }
+
+        Ok(SubspacesMsg::move_section(
+            self.subspace_id,
+            self.section_id,
+            self.new_parent_id,
+            self.signer,
+        ))
+    }
+}
+
+/// Builder to create a [`SubspacesMsg::CreateUserGroup`].
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::Addr;
+/// use desmos_bindings::subspaces::models::Permission;
+/// use desmos_bindings::subspaces::msg_builders::CreateUserGroupBuilder;
+///
+/// let msg = CreateUserGroupBuilder::new(1, "Moderators", Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3"))
+///     .member(Addr::unchecked("desmos1ptvq7l4jt7n9sc3fky22mfvc6waf2jd8nuc0jv"))
+///     .permission(Permission::ModerateContent)
+///     .build()
+///     .unwrap();
+/// ```
+pub struct CreateUserGroupBuilder {
+    subspace_id: u64,
+    section_id: Option<u32>,
+    name: String,
+    description: Option<String>,
+    default_permissions: Vec<Permission>,
+    initial_members: Vec<Addr>,
+    creator: Addr,
+}
+
+impl CreateUserGroupBuilder {
+    /// Creates a new builder for a group without members and permissions
+    /// placed inside the root section.
+    ///
+    /// * `subspace_id` - Subspace id to which the group will belong.
+    /// * `name` - Group name.
+    /// * `creator` - Address of who wants to create the group.
+    pub fn new(subspace_id: u64, name: &str, creator: Addr) -> Self {
+        CreateUserGroupBuilder {
+            subspace_id,
+            section_id: None,
+            name: name.to_owned(),
+            description: None,
+            default_permissions: vec![],
+            initial_members: vec![],
+            creator,
+        }
+    }
+
+    /// Sets the section inside which the group will be created.
+    ///
+    /// * `section_id` - Id of the section inside which the group will be created.
+    pub fn section_id(mut self, section_id: u32) -> Self {
+        self.section_id = Some(section_id);
+        self
+    }
+
+    /// Sets the group description.
+    ///
+    /// * `description` - Group description.
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_owned());
+        self
+    }
+
+    /// Adds a permission that the members will inherit.
+    ///
+    /// * `permission` - Permission to be added.
+    pub fn permission(mut self, permission: Permission) -> Self {
+        self.default_permissions.push(permission);
+        self
+    }
+
+    /// Adds a member to the group.
+    ///
+    /// * `member` - Address of the user to be added to the group.
+    pub fn member(mut self, member: Addr) -> Self {
+        self.initial_members.push(member);
+        self
+    }
+
+    /// Builds the [`SubspacesMsg::CreateUserGroup`].
+    pub fn build(self) -> Result<SubspacesMsg, SubspacesMsgBuilderError> {
+        validate_subspace_id(self.subspace_id)?;
+        validate_name(&self.name)?;
+
+        Ok(SubspacesMsg::create_user_group(
+            self.subspace_id,
+            self.section_id,
+            self.name,
+            self.description,
+            self.default_permissions,
+            self.initial_members,
+            self.creator,
+        ))
+    }
+}
+
+/// Builder to create a [`SubspacesMsg::EditUserGroup`].
+pub struct EditUserGroupBuilder {
+    subspace_id: u64,
+    group_id: u32,
+    name: Option<String>,
+    description: Option<String>,
+    signer: Addr,
+}
+
+impl EditUserGroupBuilder {
+    /// Creates a new builder that doesn't set any group field.
+    ///
+    /// * `subspace_id` - Subspace id to which the group belongs.
+    /// * `group_id` - Id of the group to edit.
+    /// * `signer` - Address of who wants edit the user group.
+    pub fn new(subspace_id: u64, group_id: u32, signer: Addr) -> Self {
+        EditUserGroupBuilder {
+            subspace_id,
+            group_id,
+            name: None,
+            description: None,
+            signer,
+        }
+    }
+
+    /// Sets the new group name.
+    ///
+    /// * `name` - New group name.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    /// Sets the new group description.
+    ///
+    /// * `description` - New group description.
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_owned());
+        self
+    }
+
+    /// Builds the [`SubspacesMsg::EditUserGroup`].
+    pub fn build(self) -> Result<SubspacesMsg, SubspacesMsgBuilderError> {
+        validate_subspace_id(self.subspace_id)?;
+
+        Ok(SubspacesMsg::edit_user_group(
+            self.subspace_id,
+            self.group_id,
+            self.name,
+            self.description,
+            self.signer,
+        ))
+    }
+}
+
+/// Builder to create a [`SubspacesMsg::MoveUserGroup`].
+pub struct MoveUserGroupBuilder {
+    subspace_id: u64,
+    group_id: u32,
+    new_section_id: u32,
+    signer: Addr,
+}
+
+impl MoveUserGroupBuilder {
+    /// Creates a new builder that moves the group inside the root section.
+    ///
+    /// * `subspace_id` - Subspace id to which the group belongs.
+    /// * `group_id` - Id of the group to be moved.
+    /// * `signer` - Address of who wants to move the group.
+    pub fn new(subspace_id: u64, group_id: u32, signer: Addr) -> Self {
+        MoveUserGroupBuilder {
+            subspace_id,
+            group_id,
+            new_section_id: 0,
+            signer,
+        }
+    }
+
+    /// Sets the section inside which the group will be moved.
+    ///
+    /// * `new_section_id` - Id of the section inside which the group will be moved.
+    pub fn new_section_id(mut self, new_section_id: u32) -> Self {
+        self.new_section_id = new_section_id;
+        self
+    }
+
+    /// Builds the [`SubspacesMsg::MoveUserGroup`].
+    pub fn build(self) -> Result<SubspacesMsg, SubspacesMsgBuilderError> {
+        validate_subspace_id(self.subspace_id)?;
+        validate_group_id(self.group_id)?;
+
+        Ok(SubspacesMsg::move_user_group(
+            self.subspace_id,
+            self.group_id,
+            self.new_section_id,
+            self.signer,
+        ))
+    }
+}
+
+/// Builder to create a [`SubspacesMsg::AddUserToUserGroup`].
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::Addr;
+/// use desmos_bindings::subspaces::msg_builders::AddUserToUserGroupBuilder;
+///
+/// let msg = AddUserToUserGroupBuilder::new(
+///     1,
+///     1,
+///     Addr::unchecked("desmos1ptvq7l4jt7n9sc3fky22mfvc6waf2jd8nuc0jv"),
+///     Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3"),
+/// )
+/// .build()
+/// .unwrap();
+/// ```
+pub struct AddUserToUserGroupBuilder {
+    subspace_id: u64,
+    group_id: u32,
+    user: Addr,
+    signer: Addr,
+}
+
+impl AddUserToUserGroupBuilder {
+    /// Creates a new builder that adds the user to the group.
+    ///
+    /// * `subspace_id` - Subspace id to which the group belongs.
+    /// * `group_id` - Id of the group to which the user will be added.
+    /// * `user` - Address of the user to be added to the group.
+    /// * `signer` - Address of who wants to add the user.
+    pub fn new(subspace_id: u64, group_id: u32, user: Addr, signer: Addr) -> Self {
+        AddUserToUserGroupBuilder {
+            subspace_id,
+            group_id,
+            user,
+            signer,
+        }
+    }
+
+    /// Builds the [`SubspacesMsg::AddUserToUserGroup`].
+    pub fn build(self) -> Result<SubspacesMsg, SubspacesMsgBuilderError> {
+        validate_subspace_id(self.subspace_id)?;
+        validate_group_id(self.group_id)?;
+
+        Ok(SubspacesMsg::add_user_to_user_group(
+            self.subspace_id,
+            self.group_id,
+            self.user,
+            self.signer,
+        ))
+    }
+}
+
+/// Builder to create a [`SubspacesMsg::SetUserGroupPermissions`].
+pub struct SetUserGroupPermissionsBuilder {
+    subspace_id: u64,
+    group_id: u32,
+    permissions: Vec<Permission>,
+    signer: Addr,
+}
+
+impl SetUserGroupPermissionsBuilder {
+    /// Creates a new builder that removes all the group permissions.
+    ///
+    /// * `subspace_id` - Subspace id to which the group belongs.
+    /// * `group_id` - Id of the group.
+    /// * `signer` - Address of who wants to set the permissions.
+    pub fn new(subspace_id: u64, group_id: u32, signer: Addr) -> Self {
+        SetUserGroupPermissionsBuilder {
+            subspace_id,
+            group_id,
+            permissions: vec![],
+            signer,
+        }
+    }
+
+    /// Adds a permission to the group.
+    ///
+    /// * `permission` - Permission to be added.
+    pub fn permission(mut self, permission: Permission) -> Self {
+        self.permissions.push(permission);
+        self
+    }
+
+    /// Builds the [`SubspacesMsg::SetUserGroupPermissions`].
+    pub fn build(self) -> Result<SubspacesMsg, SubspacesMsgBuilderError> {
+        validate_subspace_id(self.subspace_id)?;
+
+        Ok(SubspacesMsg::set_user_group_permissions(
+            self.subspace_id,
+            self.group_id,
+            self.permissions,
+            self.signer,
+        ))
+    }
+}
+
+/// Builder to create a [`SubspacesMsg::SetUserPermissions`].
+pub struct SetUserPermissionsBuilder {
+    subspace_id: u64,
+    section_id: u32,
+    user: Addr,
+    permissions: Vec<Permission>,
+    signer: Addr,
+}
+
+impl SetUserPermissionsBuilder {
+    /// Creates a new builder that removes all the user permissions inside the root section.
+    ///
+    /// * `subspace_id` - Id of the subspace.
+    /// * `user` - Address of the user to which the permissions will be set.
+    /// * `signer` - Address of who wants to set the permissions.
+    pub fn new(subspace_id: u64, user: Addr, signer: Addr) -> Self {
+        SetUserPermissionsBuilder {
+            subspace_id,
+            section_id: 0,
+            user,
+            permissions: vec![],
+            signer,
+        }
+    }
+
+    /// Sets the section to which the permissions will be applied.
+    ///
+    /// * `section_id` - Id of the section.
+    pub fn section_id(mut self, section_id: u32) -> Self {
+        self.section_id = section_id;
+        self
+    }
+
+    /// Adds a permission to the user.
+    ///
+    /// * `permission` - Permission to be added.
+    pub fn permission(mut self, permission: Permission) -> Self {
+        self.permissions.push(permission);
+        self
+    }
+
+    /// Builds the [`SubspacesMsg::SetUserPermissions`].
+    pub fn build(self) -> Result<SubspacesMsg, SubspacesMsgBuilderError> {
+        validate_subspace_id(self.subspace_id)?;
+
+        Ok(SubspacesMsg::set_user_permissions(
+            self.subspace_id,
+            self.section_id,
+            self.user,
+            self.permissions,
+            self.signer,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CREATOR: &str = "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc";
+    const USER: &str = "desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3";
+
+    #[test]
+    fn test_create_subspace_builder() {
+        let creator = Addr::unchecked(CREATOR);
+        let msg = CreateSubspaceBuilder::new("name", creator.clone())
+            .build()
+            .unwrap();
+        assert_eq!(
+            SubspacesMsg::create_subspace(
+                "name",
+                "",
+                Addr::unchecked(""),
+                creator.clone(),
+                creator.clone()
+            ),
+            msg
+        );
+
+        let msg = CreateSubspaceBuilder::new("name", creator.clone())
+            .description("description")
+            .treasury(Addr::unchecked(USER))
+            .owner(Addr::unchecked(USER))
+            .build()
+            .unwrap();
+        assert_eq!(
+            SubspacesMsg::create_subspace(
+                "name",
+                "description",
+                Addr::unchecked(USER),
+                Addr::unchecked(USER),
+                creator.clone()
+            ),
+            msg
+        );
+
+        let result = CreateSubspaceBuilder::new(" ", creator).build();
+        assert_eq!(Err(SubspacesMsgBuilderError::EmptyName), result);
+    }
+
+    #[test]
+    fn test_edit_subspace_builder() {
+        let signer = Addr::unchecked(CREATOR);
+        let msg = EditSubspaceBuilder::new(1, signer.clone())
+            .name("name")
+            .owner(Addr::unchecked(USER))
+            .build()
+            .unwrap();
+        assert_eq!(
+            SubspacesMsg::edit_subspace(
+                1,
+                "name",
+                DO_NOT_MODIFY,
+                Addr::unchecked(DO_NOT_MODIFY),
+                Addr::unchecked(USER),
+                signer.clone()
+            ),
+            msg
+        );
+
+        let result = EditSubspaceBuilder::new(0, signer).build();
+        assert_eq!(Err(SubspacesMsgBuilderError::InvalidSubspaceId(0)), result);
+    }
+
+    #[test]
+    fn test_create_section_builder() {
+        let creator = Addr::unchecked(CREATOR);
+        let msg = CreateSectionBuilder::new(1, "name", creator.clone())
+            .description("description")
+            .parent_id(2)
+            .build()
+            .unwrap();
+        assert_eq!(
+            SubspacesMsg::create_section(
+                1,
+                "name",
+                Some("description".to_string()),
+                Some(2),
+                creator.clone()
+            ),
+            msg
+        );
+
+        let result = CreateSectionBuilder::new(1, "", creator).build();
+        assert_eq!(Err(SubspacesMsgBuilderError::EmptyName), result);
+    }
+
+    #[test]
+    fn test_edit_section_builder() {
+        let editor = Addr::unchecked(CREATOR);
+        let msg = EditSectionBuilder::new(1, 2, editor.clone())
+            .name("name")
+            .build()
+            .unwrap();
+        assert_eq!(
+            SubspacesMsg::edit_section(1, 2, Some("name".to_string()), None, editor),
+            msg
+        );
+    }
+
+    #[test]
+    fn test_create_user_group_builder() {
+        let creator = Addr::unchecked(CREATOR);
+        let msg = CreateUserGroupBuilder::new(1, "name", creator.clone())
+            .section_id(2)
+            .description("description")
+            .permission(Permission::EditSubspace)
+            .permission(Permission::ModerateContent)
+            .member(Addr::unchecked(USER))
+            .build()
+            .unwrap();
+        assert_eq!(
+            SubspacesMsg::create_user_group(
+                1,
+                Some(2),
+                "name".to_string(),
+                Some("description".to_string()),
+                vec![Permission::EditSubspace, Permission::ModerateContent],
+                vec![Addr::unchecked(USER)],
+                creator.clone()
+            ),
+            msg
+        );
+
+        let result = CreateUserGroupBuilder::new(0, "name", creator).build();
+        assert_eq!(Err(SubspacesMsgBuilderError::InvalidSubspaceId(0)), result);
+    }
+
+    #[test]
+    fn test_edit_user_group_builder() {
+        let signer = Addr::unchecked(CREATOR);
+        let msg = EditUserGroupBuilder::new(1, 2, signer.clone())
+            .description("description")
+            .build()
+            .unwrap();
+        assert_eq!(
+            SubspacesMsg::edit_user_group(1, 2, None, Some("description".to_string()), signer),
+            msg
+        );
+    }
+
+    #[test]
+    fn test_move_section_builder() {
+        let signer = Addr::unchecked(CREATOR);
+        let msg = MoveSectionBuilder::new(1, 2, signer.clone())
+            .new_parent_id(3)
+            .build()
+            .unwrap();
+        assert_eq!(SubspacesMsg::move_section(1, 2, 3, signer.clone()), msg);
+
+        let msg = MoveSectionBuilder::new(1, 2, signer.clone())
+            .build()
+            .unwrap();
+        assert_eq!(SubspacesMsg::move_section(1, 2, 0, signer.clone()), msg);
+
+        let result = MoveSectionBuilder::new(1, 0, signer.clone()).build();
+        assert_eq!(Err(SubspacesMsgBuilderError::InvalidSectionId(0)), result);
+
+        let result = MoveSectionBuilder::new(1, 2, signer)
+            .new_parent_id(2)
+            .build();
+        assert_eq!(Err(SubspacesMsgBuilderError::SelfParent), result);
+    }
+
+    #[test]
+    fn test_move_user_group_builder() {
+        let signer = Addr::unchecked(CREATOR);
+        let msg = MoveUserGroupBuilder::new(1, 2, signer.clone())
+            .new_section_id(3)
+            .build()
+            .unwrap();
+        assert_eq!(SubspacesMsg::move_user_group(1, 2, 3, signer.clone()), msg);
+
+        let result = MoveUserGroupBuilder::new(1, 0, signer).build();
+        assert_eq!(Err(SubspacesMsgBuilderError::InvalidGroupId(0)), result);
+    }
+
+    #[test]
+    fn test_add_user_to_user_group_builder() {
+        let signer = Addr::unchecked(CREATOR);
+        let user = Addr::unchecked(USER);
+        let msg = AddUserToUserGroupBuilder::new(1, 2, user.clone(), signer.clone())
+            .build()
+            .unwrap();
+        assert_eq!(
+            SubspacesMsg::add_user_to_user_group(1, 2, user.clone(), signer.clone()),
+            msg
+        );
+
+        let result = AddUserToUserGroupBuilder::new(1, 0, user, signer).build();
+        assert_eq!(Err(SubspacesMsgBuilderError::InvalidGroupId(0)), result);
+    }
+
+    #[test]
+    fn test_set_user_group_permissions_builder() {
+        let signer = Addr::unchecked(CREATOR);
+        let msg = SetUserGroupPermissionsBuilder::new(1, 2, signer.clone())
+            .permission(Permission::EditSubspace)
+            .build()
+            .unwrap();
+        assert_eq!(
+            SubspacesMsg::set_user_group_permissions(1, 2, vec![Permission::EditSubspace], signer),
+            msg
+        );
+    }
+
+    #[test]
+    fn test_set_user_permissions_builder() {
+        let signer = Addr::unchecked(CREATOR);
+        let msg = SetUserPermissionsBuilder::new(1, Addr::unchecked(USER), signer.clone())
+            .section_id(2)
+            .permission(Permission::EditSubspace)
+            .build()
+            .unwrap();
+        assert_eq!(
+            SubspacesMsg::set_user_permissions(
+                1,
+                2,
+                Addr::unchecked(USER),
+                vec![Permission::EditSubspace],
+                signer
+            ),
+            msg
+        );
+    }
+}
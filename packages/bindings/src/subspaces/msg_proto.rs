@@ -0,0 +1,359 @@
+//! Contains the protobuf encoding of the [`SubspacesMsg`] variants, used to send them
+//! as `Stargate` messages (eg. inside an authz `MsgExec`).
+
+use crate::proto::{allowance, any, authz_grant, ProtoWriter};
+use crate::subspaces::models::{
+    Grantee, Permission, RawGrantee, GROUP_GRANTEE_TYPE_URI, USER_GRANTEE_TYPE_URI,
+};
+use crate::subspaces::msg::SubspacesMsg;
+use cosmwasm_std::{Binary, StdError, StdResult};
+use std::convert::TryFrom;
+
+fn permissions(permissions: &[Permission]) -> Vec<&'static str> {
+    permissions
+        .iter()
+        .map(|permission| match permission {
+            Permission::EditSubspace => "EDIT_SUBSPACE",
+            Permission::DeleteSubspace => "DELETE_SUBSPACE",
+            Permission::ManageSections => "MANAGE_SECTIONS",
+            Permission::ManageGroups => "MANAGE_GROUPS",
+            Permission::SetPermissions => "SET_PERMISSIONS",
+            Permission::Everything => "EVERYTHING",
+            Permission::Write => "WRITE_CONTENT",
+            Permission::InteractWithContent => "INTERACT_WITH_CONTENT",
+            Permission::EditOwnContent => "EDIT_OWN_CONTENT",
+            Permission::ModerateContent => "MODERATE_CONTENT",
+        })
+        .collect()
+}
+
+fn grantee(grantee: &RawGrantee) -> StdResult<Vec<u8>> {
+    let grantee = Grantee::try_from(grantee.clone())
+        .map_err(|error| StdError::parse_err("RawGrantee", error))?;
+    Ok(match grantee {
+        Grantee::User { user } => any(
+            USER_GRANTEE_TYPE_URI,
+            &ProtoWriter::new().string(1, user.as_str()).finish(),
+        ),
+        Grantee::Group { group_id } => any(
+            GROUP_GRANTEE_TYPE_URI,
+            &ProtoWriter::new().uint32(1, group_id).finish(),
+        ),
+    })
+}
+
+impl SubspacesMsg {
+    /// Encodes the message using its protobuf representation.
+    pub fn to_proto_bytes(&self) -> StdResult<Binary> {
+        let encoded = match self {
+            SubspacesMsg::CreateSubspace {
+                name,
+                description,
+                treasury,
+                owner,
+                creator,
+            } => ProtoWriter::new()
+                .string(1, name)
+                .string(2, description)
+                .string(3, treasury.as_str())
+                .string(4, owner.as_str())
+                .string(5, creator.as_str()),
+            SubspacesMsg::EditSubspace {
+                subspace_id,
+                name,
+                description,
+                treasury,
+                owner,
+                signer,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .string(2, name)
+                .string(3, description)
+                .string(4, treasury.as_str())
+                .string(5, owner.as_str())
+                .string(6, signer.as_str()),
+            SubspacesMsg::DeleteSubspace {
+                subspace_id,
+                signer,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .string(2, signer.as_str()),
+            SubspacesMsg::CreateSection {
+                subspace_id,
+                name,
+                description,
+                parent_id,
+                creator,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .string(2, name)
+                .string(3, description.as_deref().unwrap_or_default())
+                .uint32(4, parent_id.unwrap_or_default())
+                .string(5, creator.as_str()),
+            SubspacesMsg::EditSection {
+                subspace_id,
+                section_id,
+                name,
+                description,
+                editor,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint32(2, *section_id)
+                .string(3, name.as_deref().unwrap_or_default())
+                .string(4, description.as_deref().unwrap_or_default())
+                .string(5, editor.as_str()),
+            SubspacesMsg::MoveSection {
+                subspace_id,
+                section_id,
+                new_parent_id,
+                signer,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint32(2, *section_id)
+                .uint32(3, *new_parent_id)
+                .string(4, signer.as_str()),
+            SubspacesMsg::DeleteSection {
+                subspace_id,
+                section_id,
+                signer,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint32(2, *section_id)
+                .string(3, signer.as_str()),
+            SubspacesMsg::CreateUserGroup {
+                subspace_id,
+                section_id,
+                name,
+                description,
+                initial_members,
+                default_permissions,
+                creator,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint32(2, section_id.unwrap_or_default())
+                .string(3, name)
+                .string(4, description.as_deref().unwrap_or_default())
+                .repeated_string(5, &permissions(default_permissions))
+                .repeated_string(6, initial_members)
+                .string(7, creator.as_str()),
+            SubspacesMsg::EditUserGroup {
+                subspace_id,
+                group_id,
+                name,
+                description,
+                signer,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint32(2, *group_id)
+                .string(3, name.as_deref().unwrap_or_default())
+                .string(4, description.as_deref().unwrap_or_default())
+                .string(5, signer.as_str()),
+            SubspacesMsg::MoveUserGroup {
+                subspace_id,
+                group_id,
+                new_section_id,
+                signer,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint32(2, *group_id)
+                .uint32(3, *new_section_id)
+                .string(4, signer.as_str()),
+            SubspacesMsg::SetUserGroupPermissions {
+                subspace_id,
+                group_id,
+                permissions: group_permissions,
+                signer,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint32(2, *group_id)
+                .repeated_string(3, &permissions(group_permissions))
+                .string(4, signer.as_str()),
+            SubspacesMsg::DeleteUserGroup {
+                subspace_id,
+                group_id,
+                signer,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint32(2, *group_id)
+                .string(3, signer.as_str()),
+            SubspacesMsg::AddUserToUserGroup {
+                subspace_id,
+                group_id,
+                user,
+                signer,
+            }
+            | SubspacesMsg::RemoveUserFromUserGroup {
+                subspace_id,
+                group_id,
+                user,
+                signer,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint32(2, *group_id)
+                .string(3, user.as_str())
+                .string(4, signer.as_str()),
+            SubspacesMsg::SetUserPermissions {
+                subspace_id,
+                section_id,
+                user,
+                permissions: user_permissions,
+                signer,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .uint32(2, *section_id)
+                .string(3, user.as_str())
+                .repeated_string(4, &permissions(user_permissions))
+                .string(5, signer.as_str()),
+            SubspacesMsg::GrantTreasuryAuthorization {
+                subspace_id,
+                granter,
+                grantee,
+                grant,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .string(2, granter.as_str())
+                .string(3, grantee.as_str())
+                .message(4, &authz_grant(grant)?),
+            SubspacesMsg::RevokeTreasuryAuthorization {
+                subspace_id,
+                granter,
+                grantee,
+                msg_type_url,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .string(2, granter.as_str())
+                .string(3, grantee.as_str())
+                .string(4, msg_type_url),
+            SubspacesMsg::GrantAllowance {
+                subspace_id,
+                granter,
+                grantee: allowance_grantee,
+                allowance: granted_allowance,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .string(2, granter.as_str())
+                .message(3, &grantee(allowance_grantee)?)
+                .message(4, &allowance(granted_allowance)?),
+            SubspacesMsg::RevokeAllowance {
+                subspace_id,
+                granter,
+                grantee: allowance_grantee,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .string(2, granter.as_str())
+                .message(3, &grantee(allowance_grantee)?),
+        };
+        Ok(encoded.finish().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subspaces::msg::{
+        MSG_CREATE_USER_GROUP_TYPE_URI, MSG_GRANT_ALLOWANCE_TYPE_URI,
+        MSG_GRANT_TREASURY_AUTHORIZATION_TYPE_URI,
+    };
+    use crate::types::{Authorization, AuthzGrant};
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn test_create_user_group_encoding() {
+        let msg = SubspacesMsg::create_user_group(
+            1,
+            None,
+            "g".into(),
+            None,
+            vec![Permission::Write],
+            vec![Addr::unchecked("a")],
+            Addr::unchecked("b"),
+        );
+        let expected = ProtoWriter::new()
+            .uint64(1, 1)
+            .string(3, "g")
+            .string(5, "WRITE_CONTENT")
+            .string(6, "a")
+            .string(7, "b")
+            .finish();
+        assert_eq!(MSG_CREATE_USER_GROUP_TYPE_URI, msg.type_url());
+        assert_eq!(Binary::from(expected), msg.to_proto_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_grant_treasury_authorization_encoding() {
+        let grant = AuthzGrant::generic("/a", None);
+        let msg = SubspacesMsg::grant_treasury_authorization(
+            1,
+            Addr::unchecked("b"),
+            Addr::unchecked("c"),
+            Authorization::Generic {
+                msg: "/a".to_string(),
+            },
+            None,
+        );
+        let expected = ProtoWriter::new()
+            .uint64(1, 1)
+            .string(2, "b")
+            .string(3, "c")
+            .message(4, &authz_grant(&grant).unwrap())
+            .finish();
+        assert_eq!(MSG_GRANT_TREASURY_AUTHORIZATION_TYPE_URI, msg.type_url());
+        assert_eq!(Binary::from(expected), msg.to_proto_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_grant_allowance_with_group_grantee_encoding() {
+        let msg = SubspacesMsg::GrantAllowance {
+            subspace_id: 1u64.into(),
+            granter: Addr::unchecked("b"),
+            grantee: Grantee::Group { group_id: 2 }.into(),
+            allowance: crate::types::Allowance::Basic {
+                spend_limit: vec![],
+                expiration: None,
+            }
+            .into(),
+        };
+        let expected = ProtoWriter::new()
+            .uint64(1, 1)
+            .string(2, "b")
+            .message(3, &any(GROUP_GRANTEE_TYPE_URI, &[0x08, 0x02]))
+            .message(4, &any("/cosmos.feegrant.v1beta1.BasicAllowance", &[]))
+            .finish();
+        assert_eq!(MSG_GRANT_ALLOWANCE_TYPE_URI, msg.type_url());
+        assert_eq!(Binary::from(expected), msg.to_proto_bytes().unwrap());
+    }
+
+    /// Mirror of the `desmos.subspaces.v3.MsgCreateSection` protobuf message.
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct ProstMsgCreateSection {
+        #[prost(uint64, tag = "1")]
+        subspace_id: u64,
+        #[prost(string, tag = "2")]
+        name: String,
+        #[prost(string, tag = "3")]
+        description: String,
+        #[prost(uint32, tag = "4")]
+        parent_id: u32,
+        #[prost(string, tag = "5")]
+        creator: String,
+    }
+
+    #[test]
+    fn test_create_section_prost_encoding() {
+        let msg = SubspacesMsg::create_section(
+            1,
+            "section",
+            Some("description".into()),
+            Some(3),
+            Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3"),
+        );
+        let expected = prost::Message::encode_to_vec(&ProstMsgCreateSection {
+            subspace_id: 1,
+            name: "section".into(),
+            description: "description".into(),
+            parent_id: 3,
+            creator: "desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3".into(),
+        });
+        assert_eq!(Binary::from(expected), msg.to_proto_bytes().unwrap());
+    }
+}
@@ -5,8 +5,9 @@ use crate::{
     query::DesmosQuery,
     subspaces::{
         models_query::{
-            QuerySubspaceResponse, QuerySubspacesResponse, QueryUserGroupMembersResponse,
-            QueryUserGroupResponse, QueryUserGroupsResponse, QueryUserPermissionsResponse,
+            QueryGroupAllowancesResponse, QuerySubspaceResponse, QuerySubspacesResponse,
+            QueryUserAllowancesResponse, QueryUserGroupMembersResponse, QueryUserGroupResponse,
+            QueryUserGroupsResponse, QueryUserPermissionsResponse,
         },
         query::SubspacesQuery,
     },
@@ -17,7 +18,7 @@ use cosmwasm_std::{Addr, Querier, QuerierWrapper, StdResult, Uint64};
 use {
     crate::iter::page_iterator::{Page, PageIterator},
     crate::subspaces::models::Section,
-    crate::subspaces::models::{Subspace, UserGroup},
+    crate::subspaces::models::{Grant, Subspace, UserGroup},
     cosmwasm_std::Binary,
 };
 
@@ -302,6 +303,114 @@ impl<'a> SubspacesQuerier<'a> {
         let res: QueryUserPermissionsResponse = self.querier.query(&request.into())?;
         Ok(res)
     }
+
+    /// Queries the fee allowances granted to users inside a subspace.
+    ///
+    /// * `subspace_id` - Subspace to which the allowances belong.
+    /// * `grantee` - Optional address of the grantee to query the allowances for.
+    /// * `pagination` - Optional pagination configs.
+    pub fn query_user_allowances(
+        &self,
+        subspace_id: u64,
+        grantee: Option<Addr>,
+        pagination: Option<PageRequest>,
+    ) -> StdResult<QueryUserAllowancesResponse> {
+        let request = DesmosQuery::from(SubspacesQuery::UserAllowances {
+            subspace_id: subspace_id.into(),
+            grantee,
+            pagination,
+        });
+        let res: QueryUserAllowancesResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    /// Gives an iterator to scan over the fee allowances granted to users inside a subspace.
+    ///
+    /// * `subspace_id` - Subspace to which the allowances belong.
+    /// * `grantee` - Optional address of the grantee to query the allowances for.
+    /// * `page_size` - Size of the page requested to the chain.
+    #[cfg(feature = "iterators")]
+    pub fn iterate_user_allowances(
+        &self,
+        subspace_id: u64,
+        grantee: Option<Addr>,
+        page_size: u64,
+    ) -> PageIterator<Grant, Binary> {
+        PageIterator::new(
+            Box::new(move |key, limit| {
+                self.query_user_allowances(
+                    subspace_id,
+                    grantee.clone(),
+                    Some(PageRequest {
+                        key,
+                        limit: limit.into(),
+                        reverse: false,
+                        count_total: false,
+                        offset: None,
+                    }),
+                )
+                .map(|response| Page {
+                    items: response.grants,
+                    next_page_key: response.pagination.and_then(|response| response.next_key),
+                })
+            }),
+            page_size,
+        )
+    }
+
+    /// Queries the fee allowances granted to user groups inside a subspace.
+    ///
+    /// * `subspace_id` - Subspace to which the allowances belong.
+    /// * `group_id` - Optional id of the group to query the allowances for.
+    /// * `pagination` - Optional pagination configs.
+    pub fn query_group_allowances(
+        &self,
+        subspace_id: u64,
+        group_id: Option<u32>,
+        pagination: Option<PageRequest>,
+    ) -> StdResult<QueryGroupAllowancesResponse> {
+        let request = DesmosQuery::from(SubspacesQuery::GroupAllowances {
+            subspace_id: subspace_id.into(),
+            group_id,
+            pagination,
+        });
+        let res: QueryGroupAllowancesResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    /// Gives an iterator to scan over the fee allowances granted to user groups inside a subspace.
+    ///
+    /// * `subspace_id` - Subspace to which the allowances belong.
+    /// * `group_id` - Optional id of the group to query the allowances for.
+    /// * `page_size` - Size of the page requested to the chain.
+    #[cfg(feature = "iterators")]
+    pub fn iterate_group_allowances(
+        &self,
+        subspace_id: u64,
+        group_id: Option<u32>,
+        page_size: u64,
+    ) -> PageIterator<Grant, Binary> {
+        PageIterator::new(
+            Box::new(move |key, limit| {
+                self.query_group_allowances(
+                    subspace_id,
+                    group_id,
+                    Some(PageRequest {
+                        key,
+                        limit: limit.into(),
+                        reverse: false,
+                        count_total: false,
+                        offset: None,
+                    }),
+                )
+                .map(|response| Page {
+                    items: response.grants,
+                    next_page_key: response.pagination.and_then(|response| response.next_key),
+                })
+            }),
+            page_size,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -475,4 +584,60 @@ mod tests {
         };
         assert_eq!(response.ok(), Some(expected));
     }
+
+    #[test]
+    fn test_query_user_allowances() {
+        let owned_deps = mock_desmos_dependencies();
+        let deps = owned_deps.as_ref();
+        let querier = SubspacesQuerier::new(deps.querier.deref());
+        let response = querier.query_user_allowances(1, None, Default::default());
+        let expected = QueryUserAllowancesResponse {
+            grants: vec![MockSubspacesQueries::get_mock_user_grant()],
+            pagination: Default::default(),
+        };
+        assert_eq!(response.ok(), Some(expected));
+    }
+
+    #[test]
+    fn test_iterate_user_allowances() {
+        let owned_deps = mock_desmos_dependencies();
+        let deps = owned_deps.as_ref();
+        let querier = SubspacesQuerier::new(deps.querier.deref());
+
+        let mut it = querier.iterate_user_allowances(1, None, 10);
+
+        assert_eq!(
+            it.next().unwrap().unwrap(),
+            MockSubspacesQueries::get_mock_user_grant()
+        );
+        assert!(it.next().is_none());
+    }
+
+    #[test]
+    fn test_query_group_allowances() {
+        let owned_deps = mock_desmos_dependencies();
+        let deps = owned_deps.as_ref();
+        let querier = SubspacesQuerier::new(deps.querier.deref());
+        let response = querier.query_group_allowances(1, None, Default::default());
+        let expected = QueryGroupAllowancesResponse {
+            grants: vec![MockSubspacesQueries::get_mock_group_grant()],
+            pagination: Default::default(),
+        };
+        assert_eq!(response.ok(), Some(expected));
+    }
+
+    #[test]
+    fn test_iterate_group_allowances() {
+        let owned_deps = mock_desmos_dependencies();
+        let deps = owned_deps.as_ref();
+        let querier = SubspacesQuerier::new(deps.querier.deref());
+
+        let mut it = querier.iterate_group_allowances(1, None, 10);
+
+        assert_eq!(
+            it.next().unwrap().unwrap(),
+            MockSubspacesQueries::get_mock_group_grant()
+        );
+        assert!(it.next().is_none());
+    }
 }
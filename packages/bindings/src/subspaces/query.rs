@@ -85,4 +85,26 @@ pub enum SubspacesQuery {
         /// User address.
         user: Addr,
     },
+    /// Message to query the fee allowances granted to users inside a subspace.
+    /// Response: [QueryUserAllowancesResponse](crate::subspaces::models_query::QueryUserAllowancesResponse).
+    #[returns(QueryUserAllowancesResponse)]
+    UserAllowances {
+        /// Subspace id to which the allowances belong.
+        subspace_id: Uint64,
+        /// Optional address of the grantee, if `None` all the user allowances are returned.
+        grantee: Option<Addr>,
+        /// Pagination configs.
+        pagination: Option<PageRequest>,
+    },
+    /// Message to query the fee allowances granted to user groups inside a subspace.
+    /// Response: [QueryGroupAllowancesResponse](crate::subspaces::models_query::QueryGroupAllowancesResponse).
+    #[returns(QueryGroupAllowancesResponse)]
+    GroupAllowances {
+        /// Subspace id to which the allowances belong.
+        subspace_id: Uint64,
+        /// Optional id of the grantee group, if `None` all the group allowances are returned.
+        group_id: Option<u32>,
+        /// Pagination configs.
+        pagination: Option<PageRequest>,
+    },
 }
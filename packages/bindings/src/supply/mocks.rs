@@ -0,0 +1,66 @@
+//! Contains some useful mocks of the Desmos x/supply module's types made to be used in any test.
+
+use crate::supply::models_query::{QueryCirculatingResponse, QueryTotalResponse};
+use crate::supply::query::SupplyQuery;
+use cosmwasm_std::{to_binary, Binary, ContractResult, Uint128};
+
+/// Struct that contains some utility methods to mock data of the Desmos
+/// x/supply module.
+pub struct MockSupplyQueries {}
+
+impl MockSupplyQueries {
+    /// Gets a mocked total supply.
+    pub fn get_mock_total_supply() -> Uint128 {
+        Uint128::new(1_000_000_000_000)
+    }
+
+    /// Gets a mocked circulating supply.
+    pub fn get_mock_circulating_supply() -> Uint128 {
+        Uint128::new(400_000_000_000)
+    }
+}
+
+/// Functions that mocks the supply query responses.
+pub fn mock_supply_query_response(query: &SupplyQuery) -> ContractResult<Binary> {
+    let response = match query {
+        SupplyQuery::Total { .. } => to_binary(&QueryTotalResponse {
+            total_supply: MockSupplyQueries::get_mock_total_supply(),
+        }),
+        SupplyQuery::Circulating { .. } => to_binary(&QueryCirculatingResponse {
+            circulating_supply: MockSupplyQueries::get_mock_circulating_supply(),
+        }),
+    };
+    response.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::Uint64;
+
+    #[test]
+    fn test_query_total() {
+        let query = SupplyQuery::Total {
+            denom: "udsm".to_string(),
+            divider_exponent: Uint64::zero(),
+        };
+        let response = mock_supply_query_response(&query);
+        let expected = to_binary(&QueryTotalResponse {
+            total_supply: MockSupplyQueries::get_mock_total_supply(),
+        });
+        assert_eq!(response.into_result().ok(), expected.ok())
+    }
+
+    #[test]
+    fn test_query_circulating() {
+        let query = SupplyQuery::Circulating {
+            denom: "udsm".to_string(),
+            divider_exponent: Uint64::zero(),
+        };
+        let response = mock_supply_query_response(&query);
+        let expected = to_binary(&QueryCirculatingResponse {
+            circulating_supply: MockSupplyQueries::get_mock_circulating_supply(),
+        });
+        assert_eq!(response.into_result().ok(), expected.ok())
+    }
+}
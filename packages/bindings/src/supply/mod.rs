@@ -0,0 +1,7 @@
+//! Contains utilities,structs and enum to interact with the Desmos x/supply module.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod mocks;
+pub mod models_query;
+pub mod querier;
+pub mod query;
@@ -0,0 +1,18 @@
+//! Contains structures returned from the [SupplyQuerier<'a>](crate::supply::querier::SupplyQuerier).
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+
+/// Response to [`SupplyQuery::Total`](crate::supply::query::SupplyQuery::Total).
+#[cw_serde]
+pub struct QueryTotalResponse {
+    /// Total supply of the queried denom.
+    pub total_supply: Uint128,
+}
+
+/// Response to [`SupplyQuery::Circulating`](crate::supply::query::SupplyQuery::Circulating).
+#[cw_serde]
+pub struct QueryCirculatingResponse {
+    /// Circulating supply of the queried denom.
+    pub circulating_supply: Uint128,
+}
@@ -0,0 +1,96 @@
+//! Contains the querier that can be used to query data related to the x/supply module.
+
+use crate::query::DesmosQuery;
+use crate::supply::models_query::{QueryCirculatingResponse, QueryTotalResponse};
+use crate::supply::query::SupplyQuery;
+use cosmwasm_std::{Querier, QuerierWrapper, StdResult};
+
+/// Querier able to query data from the Desmos x/supply module.
+pub struct SupplyQuerier<'a> {
+    querier: QuerierWrapper<'a, DesmosQuery>,
+}
+
+impl<'a> SupplyQuerier<'a> {
+    /// Creates a new instance of [`SupplyQuerier`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::ops::Deref;
+    /// use cosmwasm_std::{DepsMut, MessageInfo};
+    /// use desmos_bindings::supply::querier::SupplyQuerier;
+    ///
+    /// pub fn contract_action(deps: DepsMut, _: MessageInfo) {
+    ///     let querier = SupplyQuerier::new(deps.querier.deref());
+    ///     let total_supply = querier.query_total("udsm", 6);
+    /// }
+    /// ```
+    pub fn new(querier: &'a dyn Querier) -> Self {
+        Self {
+            querier: QuerierWrapper::<'a, DesmosQuery>::new(querier),
+        }
+    }
+
+    /// Queries the total supply of the given denom.
+    ///
+    /// * `denom` - Denom of the token to query the supply for.
+    /// * `divider_exponent` - Exponent of the power of ten by which the supply is divided.
+    pub fn query_total(&self, denom: &str, divider_exponent: u64) -> StdResult<QueryTotalResponse> {
+        let request = DesmosQuery::Supply(SupplyQuery::Total {
+            denom: denom.into(),
+            divider_exponent: divider_exponent.into(),
+        });
+
+        self.querier.query(&request.into())
+    }
+
+    /// Queries the circulating supply of the given denom.
+    ///
+    /// * `denom` - Denom of the token to query the supply for.
+    /// * `divider_exponent` - Exponent of the power of ten by which the supply is divided.
+    pub fn query_circulating(
+        &self,
+        denom: &str,
+        divider_exponent: u64,
+    ) -> StdResult<QueryCirculatingResponse> {
+        let request = DesmosQuery::Supply(SupplyQuery::Circulating {
+            denom: denom.into(),
+            divider_exponent: divider_exponent.into(),
+        });
+
+        self.querier.query(&request.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mocks::mock_queriers::mock_desmos_dependencies;
+    use crate::supply::mocks::MockSupplyQueries;
+    use crate::supply::querier::SupplyQuerier;
+    use std::ops::Deref;
+
+    #[test]
+    fn test_query_total() {
+        let owned_deps = mock_desmos_dependencies();
+        let deps = owned_deps.as_ref();
+        let querier = SupplyQuerier::new(deps.querier.deref());
+
+        let response = querier.query_total("udsm", 0).unwrap();
+        assert_eq!(
+            MockSupplyQueries::get_mock_total_supply(),
+            response.total_supply
+        );
+    }
+
+    #[test]
+    fn test_query_circulating() {
+        let owned_deps = mock_desmos_dependencies();
+        let deps = owned_deps.as_ref();
+        let querier = SupplyQuerier::new(deps.querier.deref());
+
+        let response = querier.query_circulating("udsm", 0).unwrap();
+        assert_eq!(
+            MockSupplyQueries::get_mock_circulating_supply(),
+            response.circulating_supply
+        );
+    }
+}
@@ -0,0 +1,29 @@
+//! Contains the query actions that can be sent to the chain in order to query data related
+//! to the x/supply module.
+
+use crate::supply::models_query::*;
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint64;
+
+/// Query messages that can be sent to the x/supply module.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum SupplyQuery {
+    /// Queries the total supply of the given denom.
+    #[returns(QueryTotalResponse)]
+    Total {
+        /// Denom of the token to query the supply for.
+        denom: String,
+        /// Exponent of the power of ten by which the supply is divided.
+        divider_exponent: Uint64,
+    },
+    /// Queries the circulating supply of the given denom,
+    /// that is the total supply minus the community pool and the vested tokens.
+    #[returns(QueryCirculatingResponse)]
+    Circulating {
+        /// Denom of the token to query the supply for.
+        denom: String,
+        /// Exponent of the power of ten by which the supply is divided.
+        divider_exponent: Uint64,
+    },
+}
@@ -0,0 +1,93 @@
+//! Contains some useful mocks of the Desmos x/tokenfactory module's types made to be used in any test.
+
+use crate::tokenfactory::{
+    models::{get_token_denom, DenomUnit, Metadata, Params},
+    models_query::{QueryParamsResponse, QuerySubspaceDenomsResponse},
+    query::TokenFactoryQuery,
+};
+use cosmwasm_std::{coins, to_binary, Binary, ContractResult, Uint64};
+
+/// Struct that contains some utility methods to mock data of the Desmos
+/// x/tokenfactory module.
+pub struct MockTokenFactoryQueries {}
+
+impl MockTokenFactoryQueries {
+    /// Gets a mocked denom created by a subspace.
+    pub fn get_mock_denom() -> String {
+        get_token_denom("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3", "minttoken")
+    }
+
+    /// Gets a mocked [`Metadata`] of the denom returned by [`MockTokenFactoryQueries::get_mock_denom`].
+    pub fn get_mock_metadata() -> Metadata {
+        let denom = MockTokenFactoryQueries::get_mock_denom();
+        Metadata {
+            description: "Test token".to_string(),
+            denom_units: vec![
+                DenomUnit {
+                    denom: denom.clone(),
+                    exponent: 0,
+                    aliases: vec![],
+                },
+                DenomUnit {
+                    denom: "mint".to_string(),
+                    exponent: 6,
+                    aliases: vec![],
+                },
+            ],
+            base: denom,
+            display: "mint".to_string(),
+            name: "Mint".to_string(),
+            symbol: "MINT".to_string(),
+            uri: "".to_string(),
+            uri_hash: "".to_string(),
+        }
+    }
+
+    /// Gets a mocked [`Params`].
+    pub fn get_mock_params() -> Params {
+        Params {
+            denom_creation_fee: coins(10_000_000, "udsm"),
+            denom_creation_gas_consume: Uint64::zero(),
+        }
+    }
+}
+
+/// Functions that mocks the tokenfactory query responses.
+pub fn mock_tokenfactory_query_response(query: &TokenFactoryQuery) -> ContractResult<Binary> {
+    let response = match query {
+        TokenFactoryQuery::SubspaceDenoms { .. } => to_binary(&QuerySubspaceDenomsResponse {
+            denoms: vec![MockTokenFactoryQueries::get_mock_denom()],
+        }),
+        TokenFactoryQuery::Params {} => to_binary(&QueryParamsResponse {
+            params: MockTokenFactoryQueries::get_mock_params(),
+        }),
+    };
+    response.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_subspace_denoms() {
+        let query = TokenFactoryQuery::SubspaceDenoms {
+            subspace_id: Uint64::new(1),
+        };
+        let response = mock_tokenfactory_query_response(&query);
+        let expected = to_binary(&QuerySubspaceDenomsResponse {
+            denoms: vec![MockTokenFactoryQueries::get_mock_denom()],
+        });
+        assert_eq!(response.into_result().ok(), expected.ok())
+    }
+
+    #[test]
+    fn test_query_params() {
+        let query = TokenFactoryQuery::Params {};
+        let response = mock_tokenfactory_query_response(&query);
+        let expected = to_binary(&QueryParamsResponse {
+            params: MockTokenFactoryQueries::get_mock_params(),
+        });
+        assert_eq!(response.into_result().ok(), expected.ok())
+    }
+}
@@ -0,0 +1,11 @@
+//! Contains utilities,structs and enum to interact with the Desmos x/tokenfactory module.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod mocks;
+pub mod models;
+pub mod models_query;
+pub mod msg;
+#[cfg(feature = "authz")]
+pub mod msg_proto;
+pub mod querier;
+pub mod query;
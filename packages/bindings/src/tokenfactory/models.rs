@@ -0,0 +1,70 @@
+//! Contains structs and enums related to the x/tokenfactory module.
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, Uint64};
+
+/// Prefix of the denoms created through the x/tokenfactory module.
+pub const DENOM_PREFIX: &str = "factory";
+
+/// Builds the full denom of a token created through the x/tokenfactory module.
+///
+/// * `creator` - Address of the subspace treasury that created the denom.
+/// * `subdenom` - Subdenom provided when creating the denom.
+pub fn get_token_denom(creator: &str, subdenom: &str) -> String {
+    format!("{}/{}/{}", DENOM_PREFIX, creator, subdenom)
+}
+
+/// Represents a struct containing information about a denom unit.
+#[cw_serde]
+pub struct DenomUnit {
+    /// Name of the denom unit.
+    pub denom: String,
+    /// Power of 10 that one must raise the base denom to in order to
+    /// equal the given denom unit.
+    pub exponent: u32,
+    /// List of aliases for the denom unit.
+    pub aliases: Vec<String>,
+}
+
+/// Represents the metadata of a token.
+#[cw_serde]
+pub struct Metadata {
+    /// Description of the token.
+    pub description: String,
+    /// List of the token denom units.
+    pub denom_units: Vec<DenomUnit>,
+    /// Base denom of the token, that is the denom unit with exponent 0.
+    pub base: String,
+    /// Suggested denom that should be displayed in clients.
+    pub display: String,
+    /// Name of the token.
+    pub name: String,
+    /// Symbol of the token.
+    pub symbol: String,
+    /// URI to a document containing additional information about the token.
+    pub uri: String,
+    /// SHA256 hash of the document pointed by `uri`.
+    pub uri_hash: String,
+}
+
+/// Contains the parameters of the x/tokenfactory module.
+#[cw_serde]
+pub struct Params {
+    /// Fee required to create a new denom.
+    pub denom_creation_fee: Vec<Coin>,
+    /// Amount of gas consumed when creating a new denom.
+    pub denom_creation_gas_consume: Uint64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_token_denom() {
+        assert_eq!(
+            "factory/desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3/minttoken",
+            get_token_denom("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3", "minttoken")
+        )
+    }
+}
@@ -0,0 +1,18 @@
+//! Contains structures returned from the [TokenFactoryQuerier<'a>](crate::tokenfactory::querier::TokenFactoryQuerier).
+
+use crate::tokenfactory::models::Params;
+use cosmwasm_schema::cw_serde;
+
+/// Response to [`TokenFactoryQuery::SubspaceDenoms`](crate::tokenfactory::query::TokenFactoryQuery::SubspaceDenoms).
+#[cw_serde]
+pub struct QuerySubspaceDenomsResponse {
+    /// Denoms created by the subspace.
+    pub denoms: Vec<String>,
+}
+
+/// Response to [`TokenFactoryQuery::Params`](crate::tokenfactory::query::TokenFactoryQuery::Params).
+#[cw_serde]
+pub struct QueryParamsResponse {
+    /// Parameters of the x/tokenfactory module.
+    pub params: Params,
+}
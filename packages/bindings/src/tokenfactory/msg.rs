@@ -0,0 +1,276 @@
+//! Contains the messages that can be sent to the chain to interact with the x/tokenfactory module.
+
+use crate::tokenfactory::models::Metadata;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Uint64};
+
+/// Type url of [`TokenFactoryMsg::CreateDenom`].
+pub const MSG_CREATE_DENOM_TYPE_URI: &str = "/desmos.tokenfactory.v1.MsgCreateDenom";
+/// Type url of [`TokenFactoryMsg::Mint`].
+pub const MSG_MINT_TYPE_URI: &str = "/desmos.tokenfactory.v1.MsgMint";
+/// Type url of [`TokenFactoryMsg::Burn`].
+pub const MSG_BURN_TYPE_URI: &str = "/desmos.tokenfactory.v1.MsgBurn";
+/// Type url of [`TokenFactoryMsg::SetDenomMetadata`].
+pub const MSG_SET_DENOM_METADATA_TYPE_URI: &str = "/desmos.tokenfactory.v1.MsgSetDenomMetadata";
+/// Type url of [`TokenFactoryMsg::ChangeAdmin`].
+pub const MSG_CHANGE_ADMIN_TYPE_URI: &str = "/desmos.tokenfactory.v1.MsgChangeAdmin";
+
+/// Represents the messages to interact with the x/tokenfactory module.
+#[cw_serde]
+pub enum TokenFactoryMsg {
+    /// Message to create a new denom owned by a subspace.
+    /// The created denom will be `factory/{subspace treasury}/{subdenom}`.
+    CreateDenom {
+        /// Address of who wants to create the denom.
+        sender: Addr,
+        /// Id of the subspace that will own the denom.
+        subspace_id: Uint64,
+        /// Subdenom of the denom that will be created.
+        subdenom: String,
+    },
+    /// Message to mint some tokens of a denom owned by a subspace.
+    Mint {
+        /// Address of who wants to mint the tokens.
+        sender: Addr,
+        /// Id of the subspace that owns the denom.
+        subspace_id: Uint64,
+        /// Amount of tokens to mint.
+        amount: Coin,
+        /// Address that will receive the minted tokens.
+        mint_to_address: Addr,
+    },
+    /// Message to burn some tokens of a denom owned by a subspace.
+    Burn {
+        /// Address of who wants to burn the tokens.
+        sender: Addr,
+        /// Id of the subspace that owns the denom.
+        subspace_id: Uint64,
+        /// Amount of tokens to burn.
+        amount: Coin,
+        /// Address from which the tokens will be burned.
+        burn_from_address: Addr,
+    },
+    /// Message to set the metadata of a denom owned by a subspace.
+    SetDenomMetadata {
+        /// Address of who wants to set the metadata.
+        sender: Addr,
+        /// Id of the subspace that owns the denom.
+        subspace_id: Uint64,
+        /// Metadata of the denom.
+        metadata: Metadata,
+    },
+    /// Message to change the admin of a denom owned by a subspace.
+    ChangeAdmin {
+        /// Address of who wants to change the admin.
+        sender: Addr,
+        /// Id of the subspace that owns the denom.
+        subspace_id: Uint64,
+        /// Denom whose admin will be changed.
+        denom: String,
+        /// Address of the new admin.
+        new_admin: Addr,
+    },
+}
+
+impl TokenFactoryMsg {
+    /// Creates a new instance of [`TokenFactoryMsg::CreateDenom`].
+    ///
+    /// * `sender` - Address of who wants to create the denom.
+    /// * `subspace_id` - Id of the subspace that will own the denom.
+    /// * `subdenom` - Subdenom of the denom that will be created.
+    pub fn create_denom(sender: Addr, subspace_id: u64, subdenom: &str) -> TokenFactoryMsg {
+        TokenFactoryMsg::CreateDenom {
+            sender,
+            subspace_id: subspace_id.into(),
+            subdenom: subdenom.into(),
+        }
+    }
+
+    /// Creates a new instance of [`TokenFactoryMsg::Mint`].
+    ///
+    /// * `sender` - Address of who wants to mint the tokens.
+    /// * `subspace_id` - Id of the subspace that owns the denom.
+    /// * `amount` - Amount of tokens to mint.
+    /// * `mint_to_address` - Address that will receive the minted tokens.
+    pub fn mint(
+        sender: Addr,
+        subspace_id: u64,
+        amount: Coin,
+        mint_to_address: Addr,
+    ) -> TokenFactoryMsg {
+        TokenFactoryMsg::Mint {
+            sender,
+            subspace_id: subspace_id.into(),
+            amount,
+            mint_to_address,
+        }
+    }
+
+    /// Creates a new instance of [`TokenFactoryMsg::Burn`].
+    ///
+    /// * `sender` - Address of who wants to burn the tokens.
+    /// * `subspace_id` - Id of the subspace that owns the denom.
+    /// * `amount` - Amount of tokens to burn.
+    /// * `burn_from_address` - Address from which the tokens will be burned.
+    pub fn burn(
+        sender: Addr,
+        subspace_id: u64,
+        amount: Coin,
+        burn_from_address: Addr,
+    ) -> TokenFactoryMsg {
+        TokenFactoryMsg::Burn {
+            sender,
+            subspace_id: subspace_id.into(),
+            amount,
+            burn_from_address,
+        }
+    }
+
+    /// Creates a new instance of [`TokenFactoryMsg::SetDenomMetadata`].
+    ///
+    /// * `sender` - Address of who wants to set the metadata.
+    /// * `subspace_id` - Id of the subspace that owns the denom.
+    /// * `metadata` - Metadata of the denom.
+    pub fn set_denom_metadata(
+        sender: Addr,
+        subspace_id: u64,
+        metadata: Metadata,
+    ) -> TokenFactoryMsg {
+        TokenFactoryMsg::SetDenomMetadata {
+            sender,
+            subspace_id: subspace_id.into(),
+            metadata,
+        }
+    }
+
+    /// Creates a new instance of [`TokenFactoryMsg::ChangeAdmin`].
+    ///
+    /// * `sender` - Address of who wants to change the admin.
+    /// * `subspace_id` - Id of the subspace that owns the denom.
+    /// * `denom` - Denom whose admin will be changed.
+    /// * `new_admin` - Address of the new admin.
+    pub fn change_admin(
+        sender: Addr,
+        subspace_id: u64,
+        denom: &str,
+        new_admin: Addr,
+    ) -> TokenFactoryMsg {
+        TokenFactoryMsg::ChangeAdmin {
+            sender,
+            subspace_id: subspace_id.into(),
+            denom: denom.into(),
+            new_admin,
+        }
+    }
+
+    /// Returns the address that must sign the message, which is the one that
+    /// the chain checks when the message is dispatched.
+    pub fn signer(&self) -> &Addr {
+        match self {
+            TokenFactoryMsg::CreateDenom { sender, .. }
+            | TokenFactoryMsg::Mint { sender, .. }
+            | TokenFactoryMsg::Burn { sender, .. }
+            | TokenFactoryMsg::SetDenomMetadata { sender, .. }
+            | TokenFactoryMsg::ChangeAdmin { sender, .. } => sender,
+        }
+    }
+
+    /// Returns the type url that identifies the message on chain.
+    pub fn type_url(&self) -> &'static str {
+        match self {
+            TokenFactoryMsg::CreateDenom { .. } => MSG_CREATE_DENOM_TYPE_URI,
+            TokenFactoryMsg::Mint { .. } => MSG_MINT_TYPE_URI,
+            TokenFactoryMsg::Burn { .. } => MSG_BURN_TYPE_URI,
+            TokenFactoryMsg::SetDenomMetadata { .. } => MSG_SET_DENOM_METADATA_TYPE_URI,
+            TokenFactoryMsg::ChangeAdmin { .. } => MSG_CHANGE_ADMIN_TYPE_URI,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tokenfactory::mocks::MockTokenFactoryQueries;
+    use crate::tokenfactory::msg::TokenFactoryMsg;
+    use cosmwasm_std::{coin, Addr, Uint64};
+
+    #[test]
+    fn test_create_denom() {
+        let msg = TokenFactoryMsg::create_denom(
+            Addr::unchecked("cosmos18xnmlzqrqr6zt526pnczxe65zk3f4xgmndpxn2"),
+            1,
+            "minttoken",
+        );
+        let expected = TokenFactoryMsg::CreateDenom {
+            sender: Addr::unchecked("cosmos18xnmlzqrqr6zt526pnczxe65zk3f4xgmndpxn2"),
+            subspace_id: Uint64::new(1),
+            subdenom: "minttoken".to_string(),
+        };
+        assert_eq!(expected, msg)
+    }
+
+    #[test]
+    fn test_mint() {
+        let msg = TokenFactoryMsg::mint(
+            Addr::unchecked("cosmos18xnmlzqrqr6zt526pnczxe65zk3f4xgmndpxn2"),
+            1,
+            coin(100, MockTokenFactoryQueries::get_mock_denom()),
+            Addr::unchecked("cosmos1qzskhrcjnkdz2ln4yeafzsdwht8ch08j4wed69"),
+        );
+        let expected = TokenFactoryMsg::Mint {
+            sender: Addr::unchecked("cosmos18xnmlzqrqr6zt526pnczxe65zk3f4xgmndpxn2"),
+            subspace_id: Uint64::new(1),
+            amount: coin(100, MockTokenFactoryQueries::get_mock_denom()),
+            mint_to_address: Addr::unchecked("cosmos1qzskhrcjnkdz2ln4yeafzsdwht8ch08j4wed69"),
+        };
+        assert_eq!(expected, msg)
+    }
+
+    #[test]
+    fn test_burn() {
+        let msg = TokenFactoryMsg::burn(
+            Addr::unchecked("cosmos18xnmlzqrqr6zt526pnczxe65zk3f4xgmndpxn2"),
+            1,
+            coin(100, MockTokenFactoryQueries::get_mock_denom()),
+            Addr::unchecked("cosmos1qzskhrcjnkdz2ln4yeafzsdwht8ch08j4wed69"),
+        );
+        let expected = TokenFactoryMsg::Burn {
+            sender: Addr::unchecked("cosmos18xnmlzqrqr6zt526pnczxe65zk3f4xgmndpxn2"),
+            subspace_id: Uint64::new(1),
+            amount: coin(100, MockTokenFactoryQueries::get_mock_denom()),
+            burn_from_address: Addr::unchecked("cosmos1qzskhrcjnkdz2ln4yeafzsdwht8ch08j4wed69"),
+        };
+        assert_eq!(expected, msg)
+    }
+
+    #[test]
+    fn test_set_denom_metadata() {
+        let msg = TokenFactoryMsg::set_denom_metadata(
+            Addr::unchecked("cosmos18xnmlzqrqr6zt526pnczxe65zk3f4xgmndpxn2"),
+            1,
+            MockTokenFactoryQueries::get_mock_metadata(),
+        );
+        let expected = TokenFactoryMsg::SetDenomMetadata {
+            sender: Addr::unchecked("cosmos18xnmlzqrqr6zt526pnczxe65zk3f4xgmndpxn2"),
+            subspace_id: Uint64::new(1),
+            metadata: MockTokenFactoryQueries::get_mock_metadata(),
+        };
+        assert_eq!(expected, msg)
+    }
+
+    #[test]
+    fn test_change_admin() {
+        let msg = TokenFactoryMsg::change_admin(
+            Addr::unchecked("cosmos18xnmlzqrqr6zt526pnczxe65zk3f4xgmndpxn2"),
+            1,
+            &MockTokenFactoryQueries::get_mock_denom(),
+            Addr::unchecked("cosmos1qzskhrcjnkdz2ln4yeafzsdwht8ch08j4wed69"),
+        );
+        let expected = TokenFactoryMsg::ChangeAdmin {
+            sender: Addr::unchecked("cosmos18xnmlzqrqr6zt526pnczxe65zk3f4xgmndpxn2"),
+            subspace_id: Uint64::new(1),
+            denom: MockTokenFactoryQueries::get_mock_denom(),
+            new_admin: Addr::unchecked("cosmos1qzskhrcjnkdz2ln4yeafzsdwht8ch08j4wed69"),
+        };
+        assert_eq!(expected, msg)
+    }
+}
@@ -0,0 +1,134 @@
+//! Contains the protobuf encoding of the [`TokenFactoryMsg`] variants, used to send them
+//! as `Stargate` messages (eg. inside an authz `MsgExec`).
+
+use crate::proto::{coin, ProtoWriter};
+use crate::tokenfactory::models::Metadata;
+use crate::tokenfactory::msg::TokenFactoryMsg;
+use cosmwasm_std::{Binary, StdResult};
+
+fn metadata(metadata: &Metadata) -> Vec<u8> {
+    let denom_units: Vec<Vec<u8>> = metadata
+        .denom_units
+        .iter()
+        .map(|unit| {
+            ProtoWriter::new()
+                .string(1, &unit.denom)
+                .uint32(2, unit.exponent)
+                .repeated_string(3, &unit.aliases)
+                .finish()
+        })
+        .collect();
+    ProtoWriter::new()
+        .string(1, &metadata.description)
+        .repeated_message(2, &denom_units)
+        .string(3, &metadata.base)
+        .string(4, &metadata.display)
+        .string(5, &metadata.name)
+        .string(6, &metadata.symbol)
+        .string(7, &metadata.uri)
+        .string(8, &metadata.uri_hash)
+        .finish()
+}
+
+impl TokenFactoryMsg {
+    /// Encodes the message using its protobuf representation.
+    pub fn to_proto_bytes(&self) -> StdResult<Binary> {
+        let encoded = match self {
+            TokenFactoryMsg::CreateDenom {
+                sender,
+                subspace_id,
+                subdenom,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .string(2, sender.as_str())
+                .string(3, subdenom),
+            TokenFactoryMsg::Mint {
+                sender,
+                subspace_id,
+                amount,
+                mint_to_address: address,
+            }
+            | TokenFactoryMsg::Burn {
+                sender,
+                subspace_id,
+                amount,
+                burn_from_address: address,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .string(2, sender.as_str())
+                .message(3, &coin(amount))
+                .string(4, address.as_str()),
+            TokenFactoryMsg::SetDenomMetadata {
+                sender,
+                subspace_id,
+                metadata: denom_metadata,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .string(2, sender.as_str())
+                .message(3, &metadata(denom_metadata)),
+            TokenFactoryMsg::ChangeAdmin {
+                sender,
+                subspace_id,
+                denom,
+                new_admin,
+            } => ProtoWriter::new()
+                .uint64(1, subspace_id.u64())
+                .string(2, sender.as_str())
+                .string(3, denom)
+                .string(4, new_admin.as_str()),
+        };
+        Ok(encoded.finish().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenfactory::msg::{MSG_CREATE_DENOM_TYPE_URI, MSG_MINT_TYPE_URI};
+    use cosmwasm_std::{coin as new_coin, Addr};
+
+    #[test]
+    fn test_mint_encoding() {
+        let msg = TokenFactoryMsg::mint(
+            Addr::unchecked("a"),
+            1,
+            new_coin(10, "b"),
+            Addr::unchecked("c"),
+        );
+        let expected = ProtoWriter::new()
+            .uint64(1, 1)
+            .string(2, "a")
+            .message(3, &[0x0a, 0x01, b'b', 0x12, 0x02, b'1', b'0'])
+            .string(4, "c")
+            .finish();
+        assert_eq!(MSG_MINT_TYPE_URI, msg.type_url());
+        assert_eq!(Binary::from(expected), msg.to_proto_bytes().unwrap());
+    }
+
+    /// Mirror of the `desmos.tokenfactory.v1.MsgCreateDenom` protobuf message.
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct ProstMsgCreateDenom {
+        #[prost(uint64, tag = "1")]
+        subspace_id: u64,
+        #[prost(string, tag = "2")]
+        sender: String,
+        #[prost(string, tag = "3")]
+        subdenom: String,
+    }
+
+    #[test]
+    fn test_create_denom_prost_encoding() {
+        let msg = TokenFactoryMsg::create_denom(
+            Addr::unchecked("desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3"),
+            1,
+            "minttoken",
+        );
+        let expected = prost::Message::encode_to_vec(&ProstMsgCreateDenom {
+            subspace_id: 1,
+            sender: "desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3".into(),
+            subdenom: "minttoken".into(),
+        });
+        assert_eq!(MSG_CREATE_DENOM_TYPE_URI, msg.type_url());
+        assert_eq!(Binary::from(expected), msg.to_proto_bytes().unwrap());
+    }
+}
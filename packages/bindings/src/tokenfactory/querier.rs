@@ -0,0 +1,84 @@
+//! Contains the querier that can be used to query data related to the x/tokenfactory module.
+
+use crate::query::DesmosQuery;
+use crate::tokenfactory::models_query::{QueryParamsResponse, QuerySubspaceDenomsResponse};
+use crate::tokenfactory::query::TokenFactoryQuery;
+use cosmwasm_std::{Querier, QuerierWrapper, StdResult};
+
+/// Querier able to query data from the Desmos x/tokenfactory module.
+pub struct TokenFactoryQuerier<'a> {
+    querier: QuerierWrapper<'a, DesmosQuery>,
+}
+
+impl<'a> TokenFactoryQuerier<'a> {
+    /// Creates a new instance of [`TokenFactoryQuerier`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::ops::Deref;
+    /// use cosmwasm_std::{DepsMut, MessageInfo};
+    /// use desmos_bindings::tokenfactory::querier::TokenFactoryQuerier;
+    ///
+    /// pub fn contract_action(deps: DepsMut, _: MessageInfo) {
+    ///     let querier = TokenFactoryQuerier::new(deps.querier.deref());
+    /// }
+    /// ```
+    pub fn new(querier: &'a dyn Querier) -> Self {
+        Self {
+            querier: QuerierWrapper::<'a, DesmosQuery>::new(querier),
+        }
+    }
+
+    /// Queries the denoms created by a subspace.
+    ///
+    /// * `subspace_id` - Id of the subspace that created the denoms.
+    pub fn query_subspace_denoms(
+        &self,
+        subspace_id: u64,
+    ) -> StdResult<QuerySubspaceDenomsResponse> {
+        let request = DesmosQuery::TokenFactory(TokenFactoryQuery::SubspaceDenoms {
+            subspace_id: subspace_id.into(),
+        });
+
+        self.querier.query(&request.into())
+    }
+
+    /// Queries the parameters of the x/tokenfactory module.
+    pub fn query_params(&self) -> StdResult<QueryParamsResponse> {
+        self.querier
+            .query(&DesmosQuery::TokenFactory(TokenFactoryQuery::Params {}).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mocks::mock_queriers::mock_desmos_dependencies;
+    use crate::tokenfactory::mocks::MockTokenFactoryQueries;
+    use crate::tokenfactory::querier::TokenFactoryQuerier;
+    use std::ops::Deref;
+
+    #[test]
+    fn test_query_subspace_denoms() {
+        let owned_deps = mock_desmos_dependencies();
+        let deps = owned_deps.as_ref();
+        let querier = TokenFactoryQuerier::new(deps.querier.deref());
+
+        let response = querier.query_subspace_denoms(1).unwrap();
+
+        assert_eq!(
+            vec![MockTokenFactoryQueries::get_mock_denom()],
+            response.denoms
+        );
+    }
+
+    #[test]
+    fn test_query_params() {
+        let owned_deps = mock_desmos_dependencies();
+        let deps = owned_deps.as_ref();
+        let querier = TokenFactoryQuerier::new(deps.querier.deref());
+
+        let response = querier.query_params().unwrap();
+
+        assert_eq!(MockTokenFactoryQueries::get_mock_params(), response.params);
+    }
+}
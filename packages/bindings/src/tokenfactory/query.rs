@@ -0,0 +1,21 @@
+//! Contains the query actions that can be sent to the chain in order to query data related
+//! to the x/tokenfactory module.
+
+use crate::tokenfactory::models_query::*;
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint64;
+
+/// Query messages that can be sent to the x/tokenfactory module.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum TokenFactoryQuery {
+    /// Queries the denoms created by a subspace.
+    #[returns(QuerySubspaceDenomsResponse)]
+    SubspaceDenoms {
+        /// Id of the subspace that created the denoms.
+        subspace_id: Uint64,
+    },
+    /// Queries the parameters of the x/tokenfactory module.
+    #[returns(QueryParamsResponse)]
+    Params {},
+}
@@ -1,7 +1,15 @@
 //! Contains some basic types of a cosmos sdk based chain.
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Binary, Uint64};
+use cosmwasm_std::{Binary, Coin, StdError, StdResult, Timestamp, Uint64};
+use std::convert::TryFrom;
+use thiserror::Error;
+
+pub(crate) const GENERIC_AUTHORIZATION_TYPE_URI: &str =
+    "/cosmos.authz.v1beta1.GenericAuthorization";
+pub(crate) const SEND_AUTHORIZATION_TYPE_URI: &str = "/cosmos.bank.v1beta1.SendAuthorization";
+pub(crate) const BASIC_ALLOWANCE_TYPE_URI: &str = "/cosmos.feegrant.v1beta1.BasicAllowance";
+pub(crate) const PERIODIC_ALLOWANCE_TYPE_URI: &str = "/cosmos.feegrant.v1beta1.PeriodicAllowance";
 
 /// Represents a chain block height.
 /// Normally the `revision_height` is incremented at each height while keeping `revision_number` the same.
@@ -56,3 +64,576 @@ pub struct PageResponse {
     /// Total number of results available if [PageRequest::count_total] was set, its value is `None` otherwise.
     pub total: Option<Uint64>,
 }
+
+/// Fetches all the pages of a paginated query, returning an error if the same
+/// next key is returned twice since that would make the pagination loop forever.
+#[cfg(any(feature = "reactions", feature = "reports"))]
+pub(crate) fn collect_pages<T>(
+    query: impl Fn(Option<PageRequest>) -> StdResult<(Vec<T>, Option<PageResponse>)>,
+) -> StdResult<Vec<T>> {
+    let mut items = Vec::new();
+    let mut key: Option<Binary> = None;
+    let mut seen_keys = std::collections::HashSet::new();
+    loop {
+        let (page, pagination) = query(key.map(|key| PageRequest {
+            key: Some(key),
+            offset: None,
+            limit: 100u64.into(),
+            count_total: false,
+            reverse: false,
+        }))?;
+        items.extend(page);
+        key = pagination.and_then(|pagination| pagination.next_key);
+        match &key {
+            None => return Ok(items),
+            Some(next_key) if !seen_keys.insert(next_key.clone()) => {
+                return Err(StdError::generic_err(format!(
+                    "pagination next key {} has already been returned",
+                    next_key
+                )));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Serializable representation of [`Authorization`].
+/// To create an instance of this struct use the `into()` method of [`Authorization`].
+#[cw_serde]
+pub struct RawAuthorization {
+    #[serde(rename = "@type")]
+    type_uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    msg: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spend_limit: Option<Vec<Coin>>,
+}
+
+/// Types of authorization that can be granted through the x/authz module.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Authorization {
+    /// Gives the grantee unrestricted permissions to execute the provided message on behalf of the granter.
+    Generic {
+        /// Type url of the message that the grantee is allowed to execute, e.g. `/cosmos.bank.v1beta1.MsgSend`.
+        msg: String,
+    },
+    /// Allows the grantee to spend up to `spend_limit` coins from the granter's account.
+    Send {
+        /// Maximum amount of coins the grantee can spend.
+        spend_limit: Vec<Coin>,
+    },
+}
+
+impl From<Authorization> for RawAuthorization {
+    fn from(authorization: Authorization) -> Self {
+        match authorization {
+            Authorization::Generic { msg } => RawAuthorization {
+                type_uri: GENERIC_AUTHORIZATION_TYPE_URI.to_string(),
+                msg: Some(msg),
+                spend_limit: None,
+            },
+            Authorization::Send { spend_limit } => RawAuthorization {
+                type_uri: SEND_AUTHORIZATION_TYPE_URI.to_string(),
+                msg: None,
+                spend_limit: Some(spend_limit),
+            },
+        }
+    }
+}
+
+/// Represents the errors that can occur when converting a [`RawAuthorization`] into an [`Authorization`].
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum UnwrapAuthorizationError {
+    /// Error that occurs if [`RawAuthorization`] has an unknown type.
+    #[error("unknown authorization type: {0}")]
+    UnknownType(String),
+    /// Error that occurs if [`RawAuthorization`] has type `/cosmos.authz.v1beta1.GenericAuthorization`
+    /// but one field is undefined.
+    #[error("invalid generic authorization field {0} is none")]
+    InvalidGenericAuthorization(String),
+}
+
+impl TryFrom<RawAuthorization> for Authorization {
+    type Error = UnwrapAuthorizationError;
+
+    fn try_from(value: RawAuthorization) -> Result<Self, Self::Error> {
+        match value.type_uri.as_str() {
+            GENERIC_AUTHORIZATION_TYPE_URI => Ok(Authorization::Generic {
+                msg: value.msg.ok_or_else(|| {
+                    UnwrapAuthorizationError::InvalidGenericAuthorization("msg".to_string())
+                })?,
+            }),
+            SEND_AUTHORIZATION_TYPE_URI => Ok(Authorization::Send {
+                spend_limit: value.spend_limit.unwrap_or_default(),
+            }),
+            _ => Err(UnwrapAuthorizationError::UnknownType(value.type_uri)),
+        }
+    }
+}
+
+/// Represents an authorization granted through the x/authz module.
+#[cw_serde]
+pub struct AuthzGrant {
+    /// Authorization that has been granted.
+    pub authorization: RawAuthorization,
+    /// Time in RFC 3339 format after which the grant expires,
+    /// if `None` the grant never expires.
+    pub expiration: Option<String>,
+}
+
+impl AuthzGrant {
+    /// Creates a new [`AuthzGrant`] giving the grantee unrestricted permissions to execute
+    /// the messages having the provided type url on behalf of the granter.
+    ///
+    /// * `msg_type_url` - Type url of the message that the grantee is allowed to execute.
+    /// * `expiration` - Time after which the grant expires, if `None` the grant never expires.
+    pub fn generic(msg_type_url: &str, expiration: Option<Timestamp>) -> Self {
+        AuthzGrant {
+            authorization: Authorization::Generic {
+                msg: msg_type_url.into(),
+            }
+            .into(),
+            expiration: expiration.map(format_rfc3339_time),
+        }
+    }
+}
+
+/// Represents a fee allowance that never resets.
+#[cw_serde]
+pub struct BasicAllowance {
+    /// Maximum amount of coins the grantee can use to pay fees,
+    /// if empty there is no spend limit.
+    pub spend_limit: Vec<Coin>,
+    /// Time in RFC 3339 format after which the allowance expires,
+    /// if `None` the allowance never expires.
+    pub expiration: Option<String>,
+}
+
+/// Serializable representation of [`Allowance`].
+/// To create an instance of this struct use the `into()` method of [`Allowance`].
+#[cw_serde]
+pub struct RawAllowance {
+    #[serde(rename = "@type")]
+    type_uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spend_limit: Option<Vec<Coin>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expiration: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    basic: Option<BasicAllowance>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    period: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    period_spend_limit: Option<Vec<Coin>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    period_can_spend: Option<Vec<Coin>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    period_reset: Option<String>,
+}
+
+/// Types of fee allowance that can be granted through the x/feegrant module.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Allowance {
+    /// Allowance that grants a fixed amount of coins until it expires.
+    Basic {
+        /// Maximum amount of coins the grantee can use to pay fees,
+        /// if empty there is no spend limit.
+        spend_limit: Vec<Coin>,
+        /// Time in RFC 3339 format after which the allowance expires,
+        /// if `None` the allowance never expires.
+        expiration: Option<String>,
+    },
+    /// Allowance that grants an amount of coins that is reset after each period.
+    Periodic {
+        /// Overall limits of the allowance.
+        basic: BasicAllowance,
+        /// Duration of each period, e.g. `86400s`.
+        period: String,
+        /// Maximum amount of coins the grantee can spend in each period.
+        period_spend_limit: Vec<Coin>,
+        /// Amount of coins left to spend before the period reset, managed by the chain.
+        period_can_spend: Vec<Coin>,
+        /// Time in RFC 3339 format at which the current period resets, managed by the chain.
+        period_reset: Option<String>,
+    },
+}
+
+impl Allowance {
+    /// Creates a new [`Allowance::Periodic`] whose first period starts when it is granted.
+    ///
+    /// * `basic` - Overall limits of the allowance.
+    /// * `period_secs` - Duration of each period in seconds.
+    /// * `period_spend_limit` - Maximum amount of coins the grantee can spend in each period.
+    pub fn periodic(
+        basic: BasicAllowance,
+        period_secs: u64,
+        period_spend_limit: Vec<Coin>,
+    ) -> Self {
+        Allowance::Periodic {
+            basic,
+            period: format!("{}s", period_secs),
+            period_can_spend: period_spend_limit.clone(),
+            period_spend_limit,
+            period_reset: None,
+        }
+    }
+}
+
+impl From<Allowance> for RawAllowance {
+    fn from(allowance: Allowance) -> Self {
+        match allowance {
+            Allowance::Basic {
+                spend_limit,
+                expiration,
+            } => RawAllowance {
+                type_uri: BASIC_ALLOWANCE_TYPE_URI.to_string(),
+                spend_limit: Some(spend_limit),
+                expiration,
+                basic: None,
+                period: None,
+                period_spend_limit: None,
+                period_can_spend: None,
+                period_reset: None,
+            },
+            Allowance::Periodic {
+                basic,
+                period,
+                period_spend_limit,
+                period_can_spend,
+                period_reset,
+            } => RawAllowance {
+                type_uri: PERIODIC_ALLOWANCE_TYPE_URI.to_string(),
+                spend_limit: None,
+                expiration: None,
+                basic: Some(basic),
+                period: Some(period),
+                period_spend_limit: Some(period_spend_limit),
+                period_can_spend: Some(period_can_spend),
+                period_reset,
+            },
+        }
+    }
+}
+
+/// Represents the errors that can occur when converting a [`RawAllowance`] into an [`Allowance`].
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum UnwrapAllowanceError {
+    /// Error that occurs if [`RawAllowance`] has an unknown type.
+    #[error("unknown allowance type: {0}")]
+    UnknownType(String),
+    /// Error that occurs if [`RawAllowance`] has type `/cosmos.feegrant.v1beta1.PeriodicAllowance`
+    /// but one field is undefined.
+    #[error("invalid periodic allowance field {0} is none")]
+    InvalidPeriodicAllowance(String),
+}
+
+impl TryFrom<RawAllowance> for Allowance {
+    type Error = UnwrapAllowanceError;
+
+    fn try_from(value: RawAllowance) -> Result<Self, Self::Error> {
+        match value.type_uri.as_str() {
+            BASIC_ALLOWANCE_TYPE_URI => Ok(Allowance::Basic {
+                spend_limit: value.spend_limit.unwrap_or_default(),
+                expiration: value.expiration,
+            }),
+            PERIODIC_ALLOWANCE_TYPE_URI => Ok(Allowance::Periodic {
+                basic: value.basic.ok_or_else(|| {
+                    UnwrapAllowanceError::InvalidPeriodicAllowance("basic".to_string())
+                })?,
+                period: value.period.ok_or_else(|| {
+                    UnwrapAllowanceError::InvalidPeriodicAllowance("period".to_string())
+                })?,
+                period_spend_limit: value.period_spend_limit.unwrap_or_default(),
+                period_can_spend: value.period_can_spend.unwrap_or_default(),
+                period_reset: value.period_reset,
+            }),
+            _ => Err(UnwrapAllowanceError::UnknownType(value.type_uri)),
+        }
+    }
+}
+
+/// Parses a RFC 3339 formatted time, as the ones returned by the Desmos queries,
+/// into a [`Timestamp`].
+///
+/// * `time` - The time to parse (eg. `2022-02-21T13:18:57.800827Z`).
+pub fn parse_rfc3339_time(time: &str) -> StdResult<Timestamp> {
+    let invalid = || StdError::parse_err("Timestamp", format!("invalid RFC 3339 time: {}", time));
+    let number = |value: &str| -> StdResult<i64> {
+        if value.is_empty() || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        value.parse::<i64>().map_err(|_| invalid())
+    };
+
+    if time.len() < 20 || !time.is_char_boundary(19) {
+        return Err(invalid());
+    }
+    let (date_time, rest) = time.split_at(19);
+    let bytes = date_time.as_bytes();
+    if bytes[4] != b'-' || bytes[7] != b'-' || !matches!(bytes[10], b'T' | b't' | b' ') {
+        return Err(invalid());
+    }
+    if bytes[13] != b':' || bytes[16] != b':' {
+        return Err(invalid());
+    }
+    let year = number(&date_time[0..4])?;
+    let month = number(&date_time[5..7])?;
+    let day = number(&date_time[8..10])?;
+    let hour = number(&date_time[11..13])?;
+    let minute = number(&date_time[14..16])?;
+    let second = number(&date_time[17..19])?;
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return Err(invalid());
+    }
+
+    // Parse the optional fractional seconds
+    let (nanos, offset) = match rest.strip_prefix('.') {
+        Some(fraction) => {
+            let digits = fraction
+                .find(|c: char| !c.is_ascii_digit())
+                .ok_or_else(invalid)?;
+            if digits == 0 || digits > 9 {
+                return Err(invalid());
+            }
+            let nanos = number(&fraction[..digits])? * 10_i64.pow(9 - digits as u32);
+            (nanos, &fraction[digits..])
+        }
+        None => (0, rest),
+    };
+
+    // Parse the offset from UTC
+    let offset_seconds = match offset {
+        "Z" | "z" => 0,
+        _ if offset.len() == 6 && offset.as_bytes()[3] == b':' => {
+            let sign = match offset.as_bytes()[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return Err(invalid()),
+            };
+            sign * (number(&offset[1..3])? * 3600 + number(&offset[4..6])? * 60)
+        }
+        _ => return Err(invalid()),
+    };
+
+    // Convert the civil date into days since the unix epoch
+    let shifted_year = if month <= 2 { year - 1 } else { year };
+    let era = shifted_year.div_euclid(400);
+    let year_of_era = shifted_year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+
+    let seconds = days * 86400 + hour * 3600 + minute * 60 + second - offset_seconds;
+    if seconds < 0 {
+        return Err(invalid());
+    }
+    Ok(Timestamp::from_seconds(seconds as u64).plus_nanos(nanos as u64))
+}
+
+/// Formats a [`Timestamp`] as a RFC 3339 UTC time, using the same representation
+/// of the times returned by the Desmos queries.
+///
+/// * `time` - The timestamp to format.
+pub fn format_rfc3339_time(time: Timestamp) -> String {
+    let seconds = time.seconds() as i64;
+    let nanos = time.subsec_nanos();
+
+    // Convert the days since the unix epoch into a civil date
+    let days = seconds.div_euclid(86400) + 719468;
+    let seconds_of_day = seconds.rem_euclid(86400);
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    let mut formatted = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60,
+        seconds_of_day % 60
+    );
+    if nanos > 0 {
+        let fraction = format!("{:09}", nanos);
+        formatted.push('.');
+        formatted.push_str(fraction.trim_end_matches('0'));
+    }
+    formatted.push('Z');
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::coins;
+
+    #[test]
+    fn test_parse_rfc3339_time() {
+        assert_eq!(
+            Timestamp::from_seconds(1645449537).plus_nanos(800827000),
+            parse_rfc3339_time("2022-02-21T13:18:57.800827Z").unwrap()
+        );
+        assert_eq!(
+            Timestamp::from_seconds(0),
+            parse_rfc3339_time("1970-01-01T00:00:00Z").unwrap()
+        );
+        assert_eq!(
+            Timestamp::from_seconds(951782400),
+            parse_rfc3339_time("2000-02-29T02:00:00+02:00").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_format_rfc3339_time() {
+        assert_eq!(
+            "2022-02-21T13:18:57.800827Z",
+            format_rfc3339_time(Timestamp::from_seconds(1645449537).plus_nanos(800827000))
+        );
+        assert_eq!(
+            "1970-01-01T00:00:00Z",
+            format_rfc3339_time(Timestamp::from_seconds(0))
+        );
+        assert_eq!(
+            "2000-02-29T00:00:00Z",
+            format_rfc3339_time(Timestamp::from_seconds(951782400))
+        );
+        let time = Timestamp::from_nanos(4102444799999999999);
+        assert_eq!(
+            time,
+            parse_rfc3339_time(&format_rfc3339_time(time)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_rfc3339_time() {
+        assert!(parse_rfc3339_time("").is_err());
+        assert!(parse_rfc3339_time("2022-02-21").is_err());
+        assert!(parse_rfc3339_time("2022-13-21T13:18:57Z").is_err());
+        assert!(parse_rfc3339_time("2022-02-21T13:18:57").is_err());
+        assert!(parse_rfc3339_time("2022-02-21T13:18:57.Z").is_err());
+        assert!(parse_rfc3339_time("1969-12-31T23:59:59Z").is_err());
+    }
+
+    #[test]
+    fn test_authorization_raw_conversion() {
+        let authorization = Authorization::Generic {
+            msg: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+        };
+        let raw: RawAuthorization = authorization.clone().into();
+        assert_eq!(authorization, Authorization::try_from(raw).unwrap());
+
+        let authorization = Authorization::Send {
+            spend_limit: coins(100, "udsm"),
+        };
+        let raw: RawAuthorization = authorization.clone().into();
+        assert_eq!(authorization, Authorization::try_from(raw).unwrap());
+    }
+
+    #[test]
+    fn test_authorization_try_from_invalid_raw_error() {
+        let raw = RawAuthorization {
+            type_uri: "/cosmos.authz.v1beta1.InvalidAuthorization".to_string(),
+            msg: None,
+            spend_limit: None,
+        };
+        assert_eq!(
+            UnwrapAuthorizationError::UnknownType(
+                "/cosmos.authz.v1beta1.InvalidAuthorization".to_string()
+            ),
+            Authorization::try_from(raw).unwrap_err()
+        );
+
+        let raw = RawAuthorization {
+            type_uri: GENERIC_AUTHORIZATION_TYPE_URI.to_string(),
+            msg: None,
+            spend_limit: None,
+        };
+        assert_eq!(
+            UnwrapAuthorizationError::InvalidGenericAuthorization("msg".to_string()),
+            Authorization::try_from(raw).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_generic_authz_grant() {
+        let grant = AuthzGrant::generic(
+            "/desmos.posts.v2.MsgCreatePost",
+            Some(Timestamp::from_seconds(1645449537)),
+        );
+        assert_eq!(
+            AuthzGrant {
+                authorization: Authorization::Generic {
+                    msg: "/desmos.posts.v2.MsgCreatePost".to_string()
+                }
+                .into(),
+                expiration: Some("2022-02-21T13:18:57Z".to_string()),
+            },
+            grant
+        );
+    }
+
+    #[test]
+    fn test_allowance_raw_conversion() {
+        let basic = BasicAllowance {
+            spend_limit: coins(100, "udsm"),
+            expiration: Some("2140-01-01T10:00:20.021Z".to_string()),
+        };
+        let allowance = Allowance::Basic {
+            spend_limit: basic.spend_limit.clone(),
+            expiration: basic.expiration.clone(),
+        };
+        let raw: RawAllowance = allowance.clone().into();
+        assert_eq!(allowance, Allowance::try_from(raw).unwrap());
+
+        let allowance = Allowance::periodic(basic, 86400, coins(10, "udsm"));
+        assert!(matches!(
+            allowance,
+            Allowance::Periodic { ref period, ref period_can_spend, .. }
+                if period == "86400s" && period_can_spend == &coins(10, "udsm")
+        ));
+        let raw: RawAllowance = allowance.clone().into();
+        assert_eq!(allowance, Allowance::try_from(raw).unwrap());
+    }
+
+    #[test]
+    fn test_allowance_try_from_invalid_raw_error() {
+        let mut raw: RawAllowance = Allowance::periodic(
+            BasicAllowance {
+                spend_limit: vec![],
+                expiration: None,
+            },
+            60,
+            coins(10, "udsm"),
+        )
+        .into();
+        raw.basic = None;
+        assert_eq!(
+            UnwrapAllowanceError::InvalidPeriodicAllowance("basic".to_string()),
+            Allowance::try_from(raw.clone()).unwrap_err()
+        );
+
+        raw.type_uri = "/cosmos.feegrant.v1beta1.InvalidAllowance".to_string();
+        assert_eq!(
+            UnwrapAllowanceError::UnknownType(
+                "/cosmos.feegrant.v1beta1.InvalidAllowance".to_string()
+            ),
+            Allowance::try_from(raw).unwrap_err()
+        );
+    }
+}